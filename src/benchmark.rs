@@ -0,0 +1,286 @@
+use crate::common::array_manager::ArrayData;
+use crate::common::clipboard::markdown_table;
+use crate::common::paths::config_file;
+use crate::sort_algorithms::{headless_runs, HeadlessRun};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fs;
+use std::path::PathBuf;
+
+const BENCHMARK_FLAG: &str = "--benchmark";
+const DEFAULT_SIZE: usize = 50;
+const DEFAULT_RUNS: usize = 10;
+// Same value range as the random array generator
+const MIN_VALUE: u32 = 1;
+const MAX_VALUE: u32 = 100;
+
+/// How the benchmark table is printed
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TableFormat {
+    Text,     // Aligned columns for the terminal
+    Markdown, // Pipe-delimited table to paste into issues or docs
+    Csv,      // Same rows as the `--csv` file
+}
+
+/// What `--benchmark` was asked to run
+#[derive(Debug, PartialEq)]
+pub struct BenchmarkOptions {
+    pub size: usize,          // Length of every random array
+    pub runs: usize,          // Number of random arrays per algorithm
+    pub seed: u64,            // Run i uses seed + i, so a benchmark can be repeated exactly
+    pub csv: Option<PathBuf>, // Where to also write the table as CSV
+    pub format: TableFormat,  // How the table is printed
+}
+
+/// Reads the benchmark flags from the command line arguments.
+/// Returns `Ok(None)` when `--benchmark` is not given.
+///
+/// `--benchmark [--size N] [--runs M] [--seed S] [--csv [FILE]] [--format text|md|csv]`, a bare `--csv`
+/// writes benchmark.csv to the config directory.
+pub fn options_from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Option<BenchmarkOptions>, String> {
+    let args: Vec<String> = args.into_iter().collect();
+    if !args.iter().any(|arg| arg == BENCHMARK_FLAG) {
+        return Ok(None);
+    }
+
+    let mut options = BenchmarkOptions {
+        size: DEFAULT_SIZE,
+        runs: DEFAULT_RUNS,
+        seed: rand::rng().random(),
+        csv: None,
+        format: TableFormat::Text,
+    };
+    let mut args = args.into_iter().peekable();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => options.size = parse_value(&arg, args.next())?,
+            "--runs" => options.runs = parse_value(&arg, args.next())?,
+            "--seed" => options.seed = parse_value(&arg, args.next())?,
+            "--format" => {
+                options.format = match args.next().as_deref() {
+                    Some("text") => TableFormat::Text,
+                    Some("md") | Some("markdown") => TableFormat::Markdown,
+                    Some("csv") => TableFormat::Csv,
+                    _ => return Err("--format must be text, md or csv".to_string()),
+                }
+            }
+            "--csv" => {
+                let path = args.next_if(|next| !next.starts_with("--"));
+                options.csv = Some(path.map(PathBuf::from).unwrap_or_else(|| config_file("benchmark.csv")));
+            }
+            _ => {}
+        }
+    }
+    if options.size < 2 || options.runs == 0 {
+        return Err("--size must be at least 2 and --runs at least 1".to_string());
+    }
+    Ok(Some(options))
+}
+
+fn parse_value<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    value
+        .as_deref()
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| format!("{} needs a non-negative number", flag))
+}
+
+/// Mean and sample standard deviation of one measurement over all runs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+    pub mean: f64,
+    pub std_dev: f64,
+}
+
+impl Summary {
+    pub fn of(values: &[f64]) -> Self {
+        if values.is_empty() {
+            return Self { mean: 0.0, std_dev: 0.0 };
+        }
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let std_dev = if values.len() < 2 {
+            0.0
+        } else {
+            let variance = values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / (values.len() - 1) as f64;
+            variance.sqrt()
+        };
+        Self { mean, std_dev }
+    }
+}
+
+/// Averages of one algorithm over every run
+pub struct AlgorithmSummary {
+    pub name: &'static str,
+    pub comparisons: Summary,
+    pub swaps: Summary,
+    pub moves: Summary,
+    pub time_us: Summary,
+}
+
+/// Runs every sorting algorithm on `runs` random arrays and summarizes the counters.
+pub fn run_benchmark(options: &BenchmarkOptions) -> Vec<AlgorithmSummary> {
+    let mut runs_by_algorithm: Vec<(&'static str, Vec<HeadlessRun>)> = Vec::new();
+    for run in 0..options.runs {
+        let mut rng = StdRng::seed_from_u64(options.seed.wrapping_add(run as u64));
+        let data = (0..options.size).map(|_| rng.random_range(MIN_VALUE..=MAX_VALUE)).collect();
+        let array_data = ArrayData::new(data, format!("Benchmark_{}", run + 1));
+        for (i, (name, result)) in headless_runs(&array_data).into_iter().enumerate() {
+            if run == 0 {
+                runs_by_algorithm.push((name, Vec::with_capacity(options.runs)));
+            }
+            runs_by_algorithm[i].1.push(result);
+        }
+    }
+
+    runs_by_algorithm
+        .into_iter()
+        .map(|(name, runs)| {
+            let summarize = |value: fn(&HeadlessRun) -> f64| Summary::of(&runs.iter().map(value).collect::<Vec<f64>>());
+            AlgorithmSummary {
+                name,
+                comparisons: summarize(|run| run.comparisons as f64),
+                swaps: summarize(|run| run.swaps as f64),
+                moves: summarize(|run| run.moves as f64),
+                time_us: summarize(|run| run.duration.as_secs_f64() * 1_000_000.0),
+            }
+        })
+        .collect()
+}
+
+/// Formats the summaries as an aligned plain-text table.
+pub fn format_table(options: &BenchmarkOptions, summaries: &[AlgorithmSummary]) -> String {
+    let mut table = format!(
+        "Benchmark: {} random arrays of size {} (seed {})\n\n{:<16}{:>24}{:>24}{:>24}{:>24}\n",
+        options.runs, options.size, options.seed, "Algorithm", "Comparisons", "Swaps", "Moves", "Time (µs)"
+    );
+    let cell = |summary: Summary| format!("{:.1} ± {:.1}", summary.mean, summary.std_dev);
+    for summary in summaries {
+        table.push_str(&format!(
+            "{:<16}{:>24}{:>24}{:>24}{:>24}\n",
+            summary.name,
+            cell(summary.comparisons),
+            cell(summary.swaps),
+            cell(summary.moves),
+            cell(summary.time_us)
+        ));
+    }
+    table.push_str("\nA swap exchanges two positions, a move writes one value (a shift, a placement or a buffer copy).\n");
+    table
+}
+
+/// Formats the summaries as a Markdown table with right-aligned numbers.
+pub fn format_markdown(options: &BenchmarkOptions, summaries: &[AlgorithmSummary]) -> String {
+    let cell = |summary: Summary| format!("{:.1} ± {:.1}", summary.mean, summary.std_dev);
+    let rows: Vec<Vec<String>> = summaries
+        .iter()
+        .map(|summary| {
+            vec![
+                summary.name.to_string(),
+                cell(summary.comparisons),
+                cell(summary.swaps),
+                cell(summary.moves),
+                cell(summary.time_us),
+            ]
+        })
+        .collect();
+    format!(
+        "Benchmark: {} random arrays of size {} (seed {})\n\n{}",
+        options.runs,
+        options.size,
+        options.seed,
+        markdown_table(&["Algorithm", "Comparisons", "Swaps", "Moves", "Time (µs)"], &rows)
+    )
+}
+
+/// Formats the summaries as CSV with one row per algorithm.
+pub fn format_csv(options: &BenchmarkOptions, summaries: &[AlgorithmSummary]) -> String {
+    let mut csv = String::from(
+        "algorithm,size,runs,seed,comparisons_mean,comparisons_std_dev,swaps_mean,swaps_std_dev,moves_mean,moves_std_dev,time_us_mean,time_us_std_dev\n",
+    );
+    for summary in summaries {
+        csv.push_str(&format!(
+            "{},{},{},{},{:.3},{:.3},{:.3},{:.3},{:.3},{:.3},{:.3},{:.3}\n",
+            summary.name,
+            options.size,
+            options.runs,
+            options.seed,
+            summary.comparisons.mean,
+            summary.comparisons.std_dev,
+            summary.swaps.mean,
+            summary.swaps.std_dev,
+            summary.moves.mean,
+            summary.moves.std_dev,
+            summary.time_us.mean,
+            summary.time_us.std_dev
+        ));
+    }
+    csv
+}
+
+/// Runs the benchmark, prints the table and writes the CSV if one was requested.
+pub fn run_and_report(options: &BenchmarkOptions) -> std::io::Result<()> {
+    let summaries = run_benchmark(options);
+    let table = match options.format {
+        TableFormat::Text => format_table(options, &summaries),
+        TableFormat::Markdown => format_markdown(options, &summaries),
+        TableFormat::Csv => format_csv(options, &summaries),
+    };
+    print!("{}", table);
+    if let Some(path) = &options.csv {
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, format_csv(options, &summaries))?;
+        println!("\nCSV written to {}", path.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn benchmark_flags_are_parsed() {
+        assert_eq!(options_from_args(args(&["--size", "10"])), Ok(None));
+        let options = options_from_args(args(&["--benchmark", "--size", "12", "--runs", "3", "--seed", "7", "--csv", "out.csv"]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            options,
+            BenchmarkOptions { size: 12, runs: 3, seed: 7, csv: Some(PathBuf::from("out.csv")), format: TableFormat::Text }
+        );
+        let options = options_from_args(args(&["--benchmark", "--format", "md"])).unwrap().unwrap();
+        assert_eq!(options.format, TableFormat::Markdown);
+        assert!(options_from_args(args(&["--benchmark", "--format", "html"])).is_err());
+        assert!(options_from_args(args(&["--benchmark", "--runs", "0"])).is_err());
+        assert!(options_from_args(args(&["--benchmark", "--size", "many"])).is_err());
+    }
+
+    #[test]
+    fn summary_uses_sample_standard_deviation() {
+        let summary = Summary::of(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        assert_eq!(summary.mean, 5.0);
+        assert!((summary.std_dev - 2.138).abs() < 0.001);
+        assert_eq!(Summary::of(&[3.0]).std_dev, 0.0);
+    }
+
+    #[test]
+    fn same_seed_gives_the_same_counts() {
+        let options = BenchmarkOptions { size: 12, runs: 3, seed: 42, csv: None, format: TableFormat::Markdown };
+        let first = run_benchmark(&options);
+        let second = run_benchmark(&options);
+        assert_eq!(first.len(), second.len());
+        for (a, b) in first.iter().zip(&second) {
+            assert_eq!(a.comparisons, b.comparisons, "{}", a.name);
+            assert_eq!(a.swaps, b.swaps, "{}", a.name);
+            assert_eq!(a.moves, b.moves, "{}", a.name);
+        }
+        let markdown = format_markdown(&options, &first);
+        assert!(markdown.contains("| Algorithm "), "{}", markdown);
+        assert_eq!(markdown.lines().filter(|line| line.starts_with('|')).count(), first.len() + 2);
+    }
+}
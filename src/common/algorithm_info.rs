@@ -0,0 +1,72 @@
+/// Facts about an algorithm, shown on the cheat sheet card
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AlgorithmInfo {
+    pub name: &'static str,
+    pub best: &'static str,    // Time complexity on the most favourable input
+    pub average: &'static str, // Time complexity on random input
+    pub worst: &'static str,   // Time complexity on the least favourable input
+    pub space: &'static str,   // Extra memory beyond the array
+    pub stable: bool,          // Equal values keep their relative order
+    pub in_place: bool,        // Sorts within the array instead of building a copy
+}
+
+impl AlgorithmInfo {
+    /// The cheat sheet card, every line padded to the same width so a centered dialog keeps the columns aligned
+    pub fn card_text(&self) -> String {
+        let yes_no = |flag: bool| if flag { "Yes" } else { "No" };
+        let lines = [
+            format!("Time, best:      {}", self.best),
+            format!("Time, average:   {}", self.average),
+            format!("Time, worst:     {}", self.worst),
+            format!("Extra space:     {}", self.space),
+            format!("Stable:          {}", yes_no(self.stable)),
+            format!("In-place:        {}", yes_no(self.in_place)),
+        ];
+        let width = lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+        lines
+            .iter()
+            .map(|line| format!("{:<width$}", line, width = width))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::array_manager::ArrayData;
+    use crate::common::settings::Settings;
+    use crate::factory::ALGORITHMS;
+    use crate::stability::{is_stable_empirically, Verdict};
+
+    #[test]
+    fn every_algorithm_has_plausible_info() {
+        let array_data = ArrayData::new(vec![3, 1, 2], "Info".to_string());
+        for entry in ALGORITHMS {
+            let info = (entry.create)(&array_data, &Settings::default()).info();
+            for text in [info.name, info.best, info.average, info.worst, info.space] {
+                assert!(!text.is_empty(), "{} has empty info", entry.settings_key);
+            }
+            assert!(info.card_text().lines().all(|line| line.len() <= 52), "{}", info.card_text());
+        }
+    }
+
+    #[test]
+    fn stable_flag_matches_an_empirical_run() {
+        let array_data = ArrayData::new(vec![3, 1, 2], "Info".to_string());
+        for entry in ALGORITHMS {
+            let visualizer = (entry.create)(&array_data, &Settings::default());
+            // Searches and games do not sort by themselves, bogo sort shuffles until it happens to be sorted
+            // and stalin sort leaves the values it removes where they were
+            let sorts = entry.settings_key.ends_with("Sort") && !["BogoSort", "StalinSort"].contains(&entry.settings_key);
+            if !sorts || !visualizer.supports_custom_order() {
+                continue;
+            }
+            assert_eq!(
+                visualizer.info().stable,
+                is_stable_empirically(entry.create, &Settings::default()) == Verdict::Stable,
+                "{} stability",
+                entry.settings_key
+            );
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::common::paths::config_file;
+use std::fs::{create_dir_all, File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+const TRANSCRIPT_FILE: &str = "announce_transcript.txt";
+
+// Tests run visualizers too; keep their transcripts out of the working directory
+pub fn transcript_path() -> PathBuf {
+    if cfg!(test) {
+        std::env::temp_dir().join("togisoft_test_announce_transcript.txt")
+    } else {
+        config_file(TRANSCRIPT_FILE)
+    }
+}
+
+/// Writes one plain text line per operation to a transcript file,
+/// so a run can be followed with a screen reader or read back later.
+pub struct Announcer {
+    file: Option<File>, // None when announce mode is off or the file can't be opened
+    last_line: String,  // Last announced operation, repeated frames are skipped
+}
+
+impl Announcer {
+    /// Opens the transcript in append mode and writes a header for the run.
+    pub fn new(enabled: bool, title: &str) -> Self {
+        let path = transcript_path();
+        if enabled && let Some(dir) = path.parent() {
+            let _ = create_dir_all(dir);
+        }
+        let file = if enabled {
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .ok()
+        } else {
+            None
+        };
+        let mut announcer = Self { file, last_line: String::new() };
+        announcer.write_line(&format!("=== {} ===", title));
+        announcer
+    }
+
+    /// Announces an operation unless it is the same as the previous one.
+    pub fn announce(&mut self, operation: &str) {
+        if self.file.is_none() || operation == self.last_line {
+            return;
+        }
+        self.last_line = operation.to_string();
+        self.write_line(operation);
+    }
+
+    fn write_line(&mut self, line: &str) {
+        if let Some(file) = self.file.as_mut() {
+            // A failed write must never interrupt the visualization
+            let _ = writeln!(file, "{}", line);
+        }
+    }
+}
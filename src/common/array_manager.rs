@@ -5,41 +5,331 @@ use crossterm::{
     ExecutableCommand, QueueableCommand,
     cursor::MoveTo,
 };
+use std::collections::HashSet;
+use std::ops::{Range, RangeInclusive};
 use std::io::{stdout, Write};
+use std::fs;
+use std::path::Path;
 use std::time::Duration;
-use rand::Rng;
-use crate::common::cleanup_terminal;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+use crate::common::{centered_x, cleanup_terminal};
+use crate::common::base_visualizer::FixedRange;
+use crate::common::common_visualizer::VisualizerDrawer;
+use crate::common::clipboard::{copy_markdown, copy_values, markdown_table, parse_values};
+use crate::common::dialog::{show_error, show_info, show_question, show_success};
+use crate::common::element::{IntegerElement, SortElement, MAX_DECIMALS};
+use crate::common::enums::PresetKind;
+use crate::common::paths::config_file;
+use crate::common::settings::{Settings, DEFAULT_RANDOM_MAX, DEFAULT_RANDOM_MIN, MAX_RANDOM_VALUE};
+use crate::common::theme;
+use crate::factory::{worst_case_index, WORST_CASES};
+use crate::sort_algorithms::comparison_estimates;
+
+// Width of the first manual entry field, which also takes a pasted list
+const PASTE_BOX_WIDTH: u16 = 30;
+// Longest list the first manual entry field accepts, 50 three-digit values with separators
+const MAX_PASTE_LENGTH: usize = 250;
+
+// Most buckets and rows of the distribution histogram in the details screen
+const HISTOGRAM_MAX_BUCKETS: usize = 20;
+const HISTOGRAM_ROWS: u16 = 4;
+
+// Distinct values a few-unique preset draws from
+const FEW_UNIQUE_VALUES: usize = 4;
+
+// Largest array the details screen runs the sort preview on
+const SORT_PREVIEW_MAX_SIZE: usize = 500;
+
+// File in the config directory that "Save Arrays" writes and "Load Arrays" reads
+pub const ARRAYS_FILE: &str = "arrays.json";
 
 // Represents a single array with metadata for visualization and management
+// Values are integers unless typed or piped in with decimal places or a minus sign, see StoredArray
 #[derive(Debug, Clone)]
-pub struct ArrayData {
-    pub data: Vec<u32>,      // The actual array data
+pub struct ArrayData<T: SortElement = u32> {
+    pub data: Vec<T>,        // The actual array data
     pub name: String,        // User-defined name for the array
     pub size: usize,         // Number of elements in the array
-    pub min_value: u32,      // Minimum value in the array
-    pub max_value: u32,      // Maximum value in the array
+    pub min_value: T,        // Minimum value in the array
+    pub max_value: T,        // Maximum value in the array
+    pub sort_range: Option<Range<usize>>, // Elements a sort works on, the rest stays in place; None sorts everything
+    pub seed: Option<u64>,   // Seed the values were generated from, None for arrays typed in or derived
+    pub value_range: RangeInclusive<u32>, // Range random values were drawn from, regenerating draws from it again
 }
 
-impl ArrayData {
+impl<T: SortElement> ArrayData<T> {
     // Constructs a new ArrayData instance, calculating min/max values
-    pub fn new(data: Vec<u32>, name: String) -> Self {
+    pub fn new(data: Vec<T>, name: String) -> Self {
         let size = data.len();
-        let min_value = *data.iter().min().unwrap_or(&0);
-        let max_value = *data.iter().max().unwrap_or(&0);
+        let min_value = data.iter().copied().min_by(|a, b| a.order(*b)).unwrap_or_default();
+        let max_value = data.iter().copied().max_by(|a, b| a.order(*b)).unwrap_or_default();
         Self {
             data,
             name,
             size,
             min_value,
             max_value,
+            sort_range: None,
+            seed: None,
+            value_range: DEFAULT_RANDOM_MIN..=DEFAULT_RANDOM_MAX,
+        }
+    }
+
+    // The sort range's elements as an array of their own, and the elements around them that stay fixed
+    // None when the whole array is sorted
+    pub fn sort_slice(&self) -> Option<(ArrayData<T>, FixedRange<T>)> {
+        let range = self.sort_range.clone().filter(|range| range.end <= self.data.len() && range.len() >= 2)?;
+        let slice = ArrayData::new(
+            self.data[range.clone()].to_vec(),
+            format!("{}[{}..{}]", self.name, range.start, range.end),
+        );
+        let fixed = FixedRange { before: self.data[..range.start].to_vec(), after: self.data[range.end..].to_vec() };
+        Some((slice, fixed))
+    }
+
+    // Arithmetic mean of the values, 0.0 for an empty array
+    pub fn mean(&self) -> f64 {
+        if self.data.is_empty() {
+            return 0.0;
+        }
+        self.data.iter().map(|v| v.to_f64()).sum::<f64>() / self.data.len() as f64
+    }
+
+    // Middle value, or the average of the two middle values for an even size
+    pub fn median(&self) -> f64 {
+        if self.data.is_empty() {
+            return 0.0;
+        }
+        let mut sorted = self.data.clone();
+        sorted.sort_unstable_by(|a, b| a.order(*b));
+        let mid = sorted.len() / 2;
+        if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1].to_f64() + sorted[mid].to_f64()) / 2.0
+        } else {
+            sorted[mid].to_f64()
+        }
+    }
+
+    // Most frequent value, the smallest one wins a tie
+    pub fn mode(&self) -> Option<T> {
+        let mut sorted = self.data.clone();
+        sorted.sort_unstable_by(|a, b| a.order(*b));
+        // Equal values are neighbours once sorted, the first longest run is the smallest mode
+        sorted
+            .chunk_by(|a, b| a.order(*b).is_eq())
+            .fold(None, |best: Option<&[T]>, run| match best {
+                Some(best) if best.len() >= run.len() => Some(best),
+                _ => Some(run),
+            })
+            .map(|run| run[0])
+    }
+
+    // Population standard deviation, 0.0 for an empty array
+    pub fn std_dev(&self) -> f64 {
+        if self.data.is_empty() {
+            return 0.0;
+        }
+        let mean = self.mean();
+        let variance = self
+            .data
+            .iter()
+            .map(|v| (v.to_f64() - mean).powi(2))
+            .sum::<f64>()
+            / self.data.len() as f64;
+        variance.sqrt()
+    }
+
+    // A random element, what a search looks for unless told otherwise; the default for an empty array
+    pub fn random_value(&self) -> T {
+        if self.data.is_empty() {
+            return T::default();
+        }
+        self.data[rand::rng().random_range(0..self.data.len())]
+    }
+}
+
+impl ArrayData {
+    // Random values from 1 to 100, the same size and seed always give the same values
+    pub fn generate(size: usize, seed: u64, name: String) -> Self {
+        Self::generate_in(size, seed, DEFAULT_RANDOM_MIN..=DEFAULT_RANDOM_MAX, name)
+    }
+
+    // Random values from the given range, the same size, seed and range always give the same values
+    pub fn generate_in(size: usize, seed: u64, value_range: RangeInclusive<u32>, name: String) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let data = (0..size).map(|_| rng.random_range(value_range.clone())).collect();
+        Self { seed: Some(seed), value_range, ..Self::new(data, name) }
+    }
+
+    // Builds the array again from its seed and size, None if it was not generated from a seed
+    pub fn regenerate(&self) -> Option<ArrayData> {
+        let seed = self.seed?;
+        Some(ArrayData::generate_in(self.size, seed, self.value_range.clone(), format!("{} (regenerated)", self.name)))
+    }
+}
+
+impl<T: IntegerElement> ArrayData<T> {
+    // Counts per equal-width bucket from min to max, one bucket per value when the range is small
+    // Returns the bucket width together with the counts
+    pub fn histogram(&self, max_buckets: usize) -> (u32, Vec<usize>) {
+        if self.data.is_empty() || max_buckets == 0 {
+            return (1, Vec::new());
+        }
+        let values = self.max_value.offset_from(self.min_value) as usize + 1;
+        let bucket_width = values.div_ceil(max_buckets);
+        let mut counts = vec![0; values.div_ceil(bucket_width)];
+        for &value in &self.data {
+            counts[value.offset_from(self.min_value) as usize / bucket_width] += 1;
+        }
+        (bucket_width as u32, counts)
+    }
+}
+
+impl ArrayData<f64> {
+    // Counts per equal-width bucket from min to max, `max_buckets` of them unless every value is the same
+    // Returns the bucket width together with the counts
+    pub fn histogram(&self, max_buckets: usize) -> (f64, Vec<usize>) {
+        if self.data.is_empty() || max_buckets == 0 {
+            return (0.0, Vec::new());
+        }
+        let range = self.max_value - self.min_value;
+        if range == 0.0 {
+            return (0.0, vec![self.data.len()]);
+        }
+        let bucket_width = range / max_buckets as f64;
+        let mut counts = vec![0; max_buckets];
+        for &value in &self.data {
+            let bucket = ((value - self.min_value) / bucket_width) as usize;
+            counts[bucket.min(max_buckets - 1)] += 1;
+        }
+        (bucket_width, counts)
+    }
+}
+
+// An array in the manager's list: integers, signed integers if any value was given with a minus sign,
+// or real numbers if any value was given with decimal places.
+// Every algorithm sorts integers and all but the games sort signed ones, the comparison sorts and searches sort real numbers too.
+#[derive(Debug, Clone)]
+pub enum StoredArray {
+    Integers(ArrayData),
+    Signed(ArrayData<i32>),
+    Reals(ArrayData<f64>),
+}
+
+impl From<ArrayData> for StoredArray {
+    fn from(array_data: ArrayData) -> Self {
+        StoredArray::Integers(array_data)
+    }
+}
+
+impl From<ArrayData<i32>> for StoredArray {
+    fn from(array_data: ArrayData<i32>) -> Self {
+        StoredArray::Signed(array_data)
+    }
+}
+
+impl From<ArrayData<f64>> for StoredArray {
+    fn from(array_data: ArrayData<f64>) -> Self {
+        StoredArray::Reals(array_data)
+    }
+}
+
+impl StoredArray {
+    // Reads typed or pasted values, as real numbers if any of them has a decimal point
+    // and as signed integers if any of them is negative
+    pub fn parse(text: &str, name: String) -> Option<StoredArray> {
+        if text.contains('.') {
+            parse_values(text).map(|values| ArrayData::<f64>::new(values, name).into())
+        } else if text.contains('-') {
+            parse_values(text).map(|values| ArrayData::<i32>::new(values, name).into())
+        } else {
+            parse_values::<u32>(text).map(|values| ArrayData::new(values, name).into())
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            StoredArray::Integers(array_data) => &array_data.name,
+            StoredArray::Signed(array_data) => &array_data.name,
+            StoredArray::Reals(array_data) => &array_data.name,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        match self {
+            StoredArray::Integers(array_data) => array_data.size,
+            StoredArray::Signed(array_data) => array_data.size,
+            StoredArray::Reals(array_data) => array_data.size,
+        }
+    }
+
+    pub fn sort_range(&self) -> Option<&Range<usize>> {
+        match self {
+            StoredArray::Integers(array_data) => array_data.sort_range.as_ref(),
+            StoredArray::Signed(array_data) => array_data.sort_range.as_ref(),
+            StoredArray::Reals(array_data) => array_data.sort_range.as_ref(),
+        }
+    }
+
+    pub fn set_sort_range(&mut self, sort_range: Option<Range<usize>>) {
+        match self {
+            StoredArray::Integers(array_data) => array_data.sort_range = sort_range,
+            StoredArray::Signed(array_data) => array_data.sort_range = sort_range,
+            StoredArray::Reals(array_data) => array_data.sort_range = sort_range,
+        }
+    }
+
+    // Every value as the user typed it, e.g. "-3" or "2.5"
+    pub fn value_texts(&self) -> Vec<String> {
+        match self {
+            StoredArray::Integers(array_data) => array_data.data.iter().map(u32::to_string).collect(),
+            StoredArray::Signed(array_data) => array_data.data.iter().map(i32::to_string).collect(),
+            StoredArray::Reals(array_data) => array_data.data.iter().map(f64::to_string).collect(),
+        }
+    }
+
+    // "Range: 1-100" with the smallest and largest value, and a short preview of the values
+    // Signed ranges read "-5 to 20", a dash would look like a minus sign
+    fn summary(&self) -> (String, String) {
+        match self {
+            StoredArray::Integers(array_data) => (
+                format!("{}-{}", array_data.min_value, array_data.max_value),
+                display_array_preview(&array_data.data),
+            ),
+            StoredArray::Signed(array_data) => (
+                format!("{} to {}", array_data.min_value, array_data.max_value),
+                display_array_preview(&array_data.data),
+            ),
+            StoredArray::Reals(array_data) => (
+                format!("{}-{}", array_data.min_value, array_data.max_value),
+                display_array_preview(&array_data.data),
+            ),
         }
     }
 }
 
 // Manages a collection of arrays and tracks the currently selected array
 pub struct ArrayManager {
-    arrays: Vec<ArrayData>,          // Collection of all arrays
+    arrays: Vec<StoredArray>,        // Collection of all arrays
     selected_index: Option<usize>,  // Index of the currently selected array (if any)
+    last_deleted: Option<DeletedArray>, // Most recently deleted array, kept for a single undo
+}
+
+// One array as stored in the arrays file, min/max/size are recomputed on load
+#[derive(Serialize, Deserialize)]
+struct SavedArray {
+    name: String,
+    data: Vec<Number>,
+}
+
+// An array removed from the manager, with enough context to put it back
+struct DeletedArray {
+    index: usize,
+    array_data: StoredArray,
+    was_selected: bool,
 }
 
 impl ArrayManager {
@@ -48,16 +338,29 @@ impl ArrayManager {
         Self {
             arrays: Vec::new(),
             selected_index: None,
+            last_deleted: None,
         }
     }
 
     // Adds a new array to the manager
-    pub fn add_array(&mut self, array_data: ArrayData) {
-        self.arrays.push(array_data);
+    pub fn add_array(&mut self, array_data: impl Into<StoredArray>) {
+        self.arrays.push(array_data.into());
+    }
+
+    // Returns the number of arrays in the manager
+    pub fn len(&self) -> usize {
+        self.arrays.len()
+    }
+
+    // Marks the array at the given index as the one used for sorting
+    pub fn select_array(&mut self, index: usize) {
+        if index < self.arrays.len() {
+            self.selected_index = Some(index);
+        }
     }
 
     // Returns an immutable reference to the currently selected array
-    pub fn get_selected_array(&self) -> Option<&ArrayData> {
+    pub fn get_selected_array(&self) -> Option<&StoredArray> {
         if let Some(index) = self.selected_index {
             self.arrays.get(index)
         } else {
@@ -66,7 +369,7 @@ impl ArrayManager {
     }
 
     // Returns a mutable reference to the currently selected array
-    pub fn get_selected_array_mut(&mut self) -> Option<&mut ArrayData> {
+    pub fn get_selected_array_mut(&mut self) -> Option<&mut StoredArray> {
         if let Some(index) = self.selected_index {
             self.arrays.get_mut(index)
         } else {
@@ -77,7 +380,8 @@ impl ArrayManager {
     // Removes an array at the specified index and updates selection if necessary
     pub fn remove_array(&mut self, index: usize) {
         if index < self.arrays.len() {
-            self.arrays.remove(index);
+            let array_data = self.arrays.remove(index);
+            let was_selected = self.selected_index == Some(index);
             if let Some(selected) = self.selected_index {
                 if selected == index {
                     self.selected_index = None;  // Deselect if the removed array was selected
@@ -85,7 +389,87 @@ impl ArrayManager {
                     self.selected_index = Some(selected - 1);  // Adjust selection index
                 }
             }
+            // Only the latest deletion can be undone
+            self.last_deleted = Some(DeletedArray { index, array_data, was_selected });
+        }
+    }
+
+    // Removes several arrays at once, highest index first so the remaining indices stay valid
+    pub fn remove_arrays(&mut self, indices: &HashSet<usize>) {
+        let mut sorted: Vec<usize> = indices.iter().copied().collect();
+        sorted.sort_unstable_by(|a, b| b.cmp(a));
+        for index in sorted {
+            self.remove_array(index);
+        }
+    }
+
+    // Returns the name of the array that "Undo Delete" would restore
+    pub fn last_deleted_name(&self) -> Option<&str> {
+        self.last_deleted.as_ref().map(|deleted| deleted.array_data.name())
+    }
+
+    // Puts the last deleted array back at its previous index, returns the restored index
+    pub fn restore_last_deleted(&mut self) -> Option<usize> {
+        let deleted = self.last_deleted.take()?;
+        let index = deleted.index.min(self.arrays.len());
+        self.arrays.insert(index, deleted.array_data);
+        if deleted.was_selected {
+            self.selected_index = Some(index);
+        } else if let Some(selected) = self.selected_index
+            && selected >= index
+        {
+            self.selected_index = Some(selected + 1);  // Shift selection past the restored array
+        }
+        Some(index)
+    }
+
+    // Puts an edited array in place of the one at the given index, selection stays as it is
+    pub fn replace_array(&mut self, index: usize, array_data: impl Into<StoredArray>) {
+        if let Some(slot) = self.arrays.get_mut(index) {
+            *slot = array_data.into();
+        }
+    }
+
+    // Writes every array's name and values to a JSON file, creating its directory if needed
+    pub fn save_to_file(&self, path: &Path) -> Result<(), String> {
+        let saved: Vec<SavedArray> = self
+            .arrays
+            .iter()
+            .map(|array| SavedArray {
+                name: array.name().to_string(),
+                data: match array {
+                    StoredArray::Integers(array_data) => array_data.data.iter().map(|&value| value.into()).collect(),
+                    StoredArray::Signed(array_data) => array_data.data.iter().map(|&value| value.into()).collect(),
+                    StoredArray::Reals(array_data) => array_data.data.iter().filter_map(|&value| Number::from_f64(value)).collect(),
+                },
+            })
+            .collect();
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
         }
+        let json = serde_json::to_string_pretty(&saved).map_err(|error| format!("Could not encode the arrays: {}", error))?;
+        fs::write(path, json).map_err(|error| format!("Could not write {}: {}", path.display(), error))
+    }
+
+    // Replaces the arrays with the ones in a file written by save_to_file, returns how many were loaded.
+    // The values are read back as if typed in, so an entry without a name and a list of values,
+    // or with a value out of range or with too many decimal places, is skipped. Nothing stays selected.
+    pub fn load_from_file(&mut self, path: &Path) -> Result<usize, String> {
+        let contents = fs::read_to_string(path).map_err(|error| format!("Could not read {}: {}", path.display(), error))?;
+        let Ok(Value::Array(entries)) = serde_json::from_str::<Value>(&contents) else {
+            return Err(format!("{} is not a list of arrays", path.display()));
+        };
+        self.arrays = entries
+            .into_iter()
+            .filter_map(|entry| serde_json::from_value::<SavedArray>(entry).ok())
+            .filter_map(|saved| {
+                let text: Vec<String> = saved.data.iter().map(Number::to_string).collect();
+                StoredArray::parse(&text.join(" "), saved.name)
+            })
+            .collect();
+        self.selected_index = None;
+        self.last_deleted = None;
+        Ok(self.arrays.len())
     }
 }
 
@@ -97,6 +481,7 @@ pub fn array_management_screen(manager: &mut ArrayManager) -> bool {
 
     let mut menu_selection = 0usize;    // Tracks which menu option is highlighted
     let mut array_selection = 0usize;   // Tracks which array is highlighted (for array-specific operations)
+    let mut marked: HashSet<usize> = HashSet::new();   // Arrays marked for multi-delete
 
     loop {
         // Clear screen and draw UI
@@ -121,7 +506,14 @@ pub fn array_management_screen(manager: &mut ArrayManager) -> bool {
             "3. Select Array for Sorting",
             "4. View Array Details",
             "5. Delete Array",
-            "6. Back to Main Menu"
+            "6. Undo Delete",
+            "7. Generate Worst Case for an Algorithm",
+            "8. Generate Preset Array",
+            "9. Set Sort Range of Selected Array",
+            "10. Edit Selected Array",
+            "11. Save Arrays",
+            "12. Load Arrays",
+            "13. Back to Main Menu"
         ];
         let menu_y = title_y + 3;
         for (i, option) in menu_options.iter().enumerate() {
@@ -129,10 +521,10 @@ pub fn array_management_screen(manager: &mut ArrayManager) -> bool {
             stdout.queue(MoveTo(option_x, menu_y + i as u16)).unwrap();
             if i == menu_selection {
                 // Highlight selected menu option
-                stdout.queue(SetForegroundColor(Color::Black)).unwrap();
-                stdout.queue(SetBackgroundColor(Color::White)).unwrap();
+                stdout.queue(SetForegroundColor(theme::highlight().0)).unwrap();
+                stdout.queue(SetBackgroundColor(theme::highlight().1)).unwrap();
             } else {
-                stdout.queue(SetForegroundColor(Color::White)).unwrap();
+                stdout.queue(SetForegroundColor(theme::text())).unwrap();
                 stdout.queue(SetBackgroundColor(Color::Reset)).unwrap();
             }
             stdout.queue(Print(format!(" {} ", option))).unwrap();
@@ -153,23 +545,34 @@ pub fn array_management_screen(manager: &mut ArrayManager) -> bool {
             let no_arrays_msg = "No arrays created yet. Generate one to get started!";
             let msg_x = (width.saturating_sub(no_arrays_msg.len() as u16)) / 2;
             stdout.queue(MoveTo(msg_x, arrays_y + 2)).unwrap();
-            stdout.queue(SetForegroundColor(Color::DarkGrey)).unwrap();
+            stdout.queue(SetForegroundColor(theme::dim())).unwrap();
             stdout.queue(Print(no_arrays_msg)).unwrap();
             stdout.queue(ResetColor).unwrap();
         } else {
             // List all arrays with metadata and preview
             for (i, array_data) in manager.arrays.iter().enumerate() {
                 let y_pos = arrays_y + 2 + i as u16;
-                let array_info = format!(
-                    "{}: \"{}\" [Size: {}, Range: {}-{}]",
+                let (value_range, preview) = array_data.summary();
+                let mut array_info = format!(
+                    "{}: \"{}\" [Size: {}, Range: {}]",
                     i + 1,
-                    array_data.name,
-                    array_data.size,
-                    array_data.min_value,
-                    array_data.max_value
+                    array_data.name(),
+                    array_data.size(),
+                    value_range
                 );
+                if let Some(range) = array_data.sort_range() {
+                    array_info.push_str(&format!(" Sort: [{}, {})", range.start, range.end));
+                }
                 stdout.queue(MoveTo(8, y_pos)).unwrap();
 
+                // Show checkboxes while in delete mode
+                if menu_selection == 4 {
+                    let checkbox = if marked.contains(&i) { "[x] " } else { "[ ] " };
+                    stdout.queue(SetForegroundColor(Color::Red)).unwrap();
+                    stdout.queue(Print(checkbox)).unwrap();
+                    stdout.queue(ResetColor).unwrap();
+                }
+
                 // Highlight if this array is selected for sorting
                 if manager.selected_index == Some(i) {
                     stdout.queue(SetForegroundColor(Color::Green)).unwrap();
@@ -183,22 +586,27 @@ pub fn array_management_screen(manager: &mut ArrayManager) -> bool {
                     stdout.queue(SetBackgroundColor(Color::DarkGrey)).unwrap();
                     stdout.queue(SetForegroundColor(Color::White)).unwrap();
                 } else {
-                    stdout.queue(SetForegroundColor(Color::White)).unwrap();
+                    stdout.queue(SetForegroundColor(theme::text())).unwrap();
                 }
                 stdout.queue(Print(array_info)).unwrap();
                 stdout.queue(ResetColor).unwrap();
 
                 // Show preview of array data
-                let preview = display_array_preview(&array_data.data);
                 stdout.queue(MoveTo(12, y_pos + 1)).unwrap();
-                stdout.queue(SetForegroundColor(Color::DarkGrey)).unwrap();
+                stdout.queue(SetForegroundColor(theme::dim())).unwrap();
                 stdout.queue(Print(preview)).unwrap();
                 stdout.queue(ResetColor).unwrap();
             }
         }
 
         // --- Instructions ---
-        let instructions = if (menu_selection >= 2 && menu_selection <= 4) && !manager.arrays.is_empty() {
+        let instructions = if menu_selection == 4 && !manager.arrays.is_empty() {
+            vec![
+                "Use ↑/↓ to select array, ENTER to delete it",
+                "SPACE to mark arrays, D to delete all marked",
+                "Press LEFT arrow to go back to menu",
+            ]
+        } else if (menu_selection >= 2 && menu_selection <= 4) && !manager.arrays.is_empty() {
             vec![
                 "Use ↑/↓ to select array, ENTER to choose",
                 "Press LEFT arrow to go back to menu",
@@ -213,9 +621,748 @@ pub fn array_management_screen(manager: &mut ArrayManager) -> bool {
         };
         let inst_y = height.saturating_sub(instructions.len() as u16 + 2);
         for (i, instruction) in instructions.iter().enumerate() {
-            let inst_x = (width.saturating_sub(instruction.len() as u16)) / 2;
-            stdout.queue(MoveTo(inst_x, inst_y + i as u16)).unwrap();
-            stdout.queue(SetForegroundColor(Color::DarkGrey)).unwrap();
+            let inst_x = (width.saturating_sub(instruction.len() as u16)) / 2;
+            stdout.queue(MoveTo(inst_x, inst_y + i as u16)).unwrap();
+            stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+            stdout.queue(Print(*instruction)).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
+
+        stdout.flush().unwrap();
+
+        // --- Handle Input ---
+        if poll(Duration::from_millis(100)).unwrap_or(false) {
+            match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
+                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                    match key_event.code {
+                        KeyCode::Up => {
+                            if (menu_selection >= 2 && menu_selection <= 4) && !manager.arrays.is_empty() {
+                                // Navigate array list
+                                array_selection = if array_selection > 0 {
+                                    array_selection - 1
+                                } else {
+                                    manager.arrays.len() - 1
+                                };
+                            } else {
+                                // Navigate menu
+                                menu_selection = if menu_selection > 0 {
+                                    menu_selection - 1
+                                } else {
+                                    menu_options.len() - 1
+                                };
+                            }
+                        },
+                        KeyCode::Down => {
+                            if (menu_selection >= 2 && menu_selection <= 4) && !manager.arrays.is_empty() {
+                                // Navigate array list
+                                array_selection = (array_selection + 1) % manager.arrays.len();
+                            } else {
+                                // Navigate menu
+                                menu_selection = (menu_selection + 1) % menu_options.len();
+                            }
+                        },
+                        KeyCode::Left => {
+                            // Exit array selection mode
+                            if menu_selection >= 2 && menu_selection <= 4 {
+                                menu_selection = if menu_selection > 0 {
+                                    menu_selection - 1
+                                } else {
+                                    menu_options.len() - 1
+                                };
+                            }
+                        },
+                        KeyCode::Right => {
+                            // Move forward in menu
+                            menu_selection = (menu_selection + 1) % menu_options.len();
+                        },
+                        KeyCode::Enter => {
+                            match menu_selection {
+                                0 => {
+                                    // Generate New Random Array
+                                    if let Some(array) = generate_random_array_dialog() {
+                                        manager.add_array(array);
+                                    }
+                                },
+                                1 => {
+                                    // Enter Array Manually
+                                    if let Some(array) = manual_array_dialog() {
+                                        manager.add_array(array);
+                                    }
+                                },
+                                2 => {
+                                    // Select Array for Sorting
+                                    if !manager.arrays.is_empty() {
+                                        manager.selected_index = Some(array_selection);
+                                        show_selection_confirmation(&manager.arrays[array_selection]);
+                                    }
+                                },
+                                3 => {
+                                    // View Array Details, an array rebuilt from its seed is added to the list
+                                    if !manager.arrays.is_empty() {
+                                        if let Some(array) = show_stored_array_details(&manager.arrays[array_selection]) {
+                                            manager.add_array(array);
+                                        }
+                                    }
+                                },
+                                4 => {
+                                    // Delete Array
+                                    if !manager.arrays.is_empty() {
+                                        if confirm_delete(&manager.arrays[array_selection]) {
+                                            manager.remove_array(array_selection);
+                                            marked.clear();
+                                            if array_selection >= manager.arrays.len() && !manager.arrays.is_empty() {
+                                                array_selection = manager.arrays.len() - 1;
+                                            }
+                                        }
+                                    }
+                                },
+                                5 => {
+                                    // Undo Delete
+                                    match manager.last_deleted_name().map(str::to_string) {
+                                        Some(name) => {
+                                            if let Some(index) = manager.restore_last_deleted() {
+                                                array_selection = index;
+                                                marked.clear();
+                                                show_success(
+                                                    "Array Restored",
+                                                    &format!("\"{}\" was restored at position {}.", name, index + 1),
+                                                );
+                                            }
+                                        }
+                                        None => show_info("Undo Delete", "There is no deleted array to restore."),
+                                    }
+                                },
+                                6 => {
+                                    // Generate Worst Case for an Algorithm
+                                    if let Some(array) = worst_case_dialog() {
+                                        manager.add_array(array);
+                                    }
+                                },
+                                7 => {
+                                    // Generate Preset Array
+                                    if let Some(array) = preset_dialog() {
+                                        manager.add_array(array);
+                                    }
+                                },
+                                8 => {
+                                    // Set Sort Range of Selected Array
+                                    match manager.selected_index {
+                                        Some(index) => {
+                                            if let Some(range) = sort_range_dialog(&manager.arrays[index]) {
+                                                manager.arrays[index].set_sort_range(range);
+                                            }
+                                        }
+                                        None => show_info("Set Sort Range", "Select an array for sorting first."),
+                                    }
+                                },
+                                9 => {
+                                    // Edit Selected Array
+                                    match manager.selected_index {
+                                        Some(index) => {
+                                            if let Some(array) = edit_array_dialog(&manager.arrays[index]) {
+                                                manager.replace_array(index, array);
+                                            }
+                                        }
+                                        None => show_info("Edit Array", "Select an array for sorting first."),
+                                    }
+                                },
+                                10 => {
+                                    // Save Arrays
+                                    let path = config_file(ARRAYS_FILE);
+                                    match manager.save_to_file(&path) {
+                                        Ok(()) => show_success(
+                                            "Arrays Saved",
+                                            &format!("{} arrays were saved to\n{}", manager.len(), path.display()),
+                                        ),
+                                        Err(error) => show_error("Save Arrays", &error),
+                                    }
+                                },
+                                11 => {
+                                    // Load Arrays, replacing the current list
+                                    match manager.load_from_file(&config_file(ARRAYS_FILE)) {
+                                        Ok(count) => {
+                                            array_selection = 0;
+                                            marked.clear();
+                                            show_success("Arrays Loaded", &format!("{} arrays were loaded.", count));
+                                        }
+                                        Err(error) => show_error("Load Arrays", &error),
+                                    }
+                                },
+                                12 => {
+                                    // Back to Main Menu
+                                    cleanup_terminal();
+                                    return false;
+                                },
+                                _ => {}
+                            }
+                        },
+                        KeyCode::Char(' ') if menu_selection == 4 && !manager.arrays.is_empty() => {
+                            // Toggle the mark on the highlighted array
+                            toggle_mark(&mut marked, array_selection);
+                        },
+                        KeyCode::Char('d') | KeyCode::Char('D')
+                            if menu_selection == 4 && !marked.is_empty() && confirm_delete_marked(marked.len()) =>
+                        {
+                            // Delete all marked arrays after a single confirmation
+                            manager.remove_arrays(&marked);
+                            marked.clear();
+                            array_selection = array_selection.min(manager.arrays.len().saturating_sub(1));
+                        },
+                        KeyCode::Esc => {
+                            cleanup_terminal();
+                            return false;
+                        },
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+// Dialog for generating a new random array: prompts for size, value range and name
+// The range starts from the one used last time and is saved for the next array
+pub fn generate_random_array_dialog() -> Option<ArrayData> {
+    let mut stdout = stdout();
+    let settings = Settings::load();
+    // Size, min value, max value and name, TAB cycles through them in this order
+    let mut fields = [String::new(), settings.random_min.to_string(), settings.random_max.to_string(), String::new()];
+    let labels = ["Array Size (2-50):", "Min Value:", &format!("Max Value (≤{}):", MAX_RANDOM_VALUE), "Array Name:"];
+    let max_lengths = [2, 4, 4, 18];
+    let mut input_mode = 0;                 // Index of the field being edited
+    let mut cursor_pos = 0usize;
+    let mut error: Option<String> = None;
+
+    loop {
+        let (width, height) = size().unwrap();
+        stdout.execute(Clear(ClearType::All)).unwrap();
+        let top = (height / 2).saturating_sub(11);
+
+        // --- Title ---
+        let title = "Generate New Random Array";
+        let title_x = (width.saturating_sub(title.len() as u16)) / 2;
+        stdout.queue(MoveTo(title_x, top)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+        stdout.queue(Print(title)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        // --- Inputs ---
+        for (i, (label, field)) in labels.iter().zip(&fields).enumerate() {
+            let label_y = top + 3 + i as u16 * 3;
+            stdout.queue(MoveTo((width / 2).saturating_sub(28), label_y)).unwrap();
+            stdout.queue(SetForegroundColor(Color::Cyan)).unwrap();
+            stdout.queue(Print(label)).unwrap();
+            stdout.queue(ResetColor).unwrap();
+            let cursor = if input_mode == i { cursor_pos } else { 0 };
+            draw_input_box(&mut stdout, (width / 2).saturating_sub(10), label_y + 1, 20, field, cursor, input_mode == i);
+        }
+
+        if let Some(error) = &error {
+            stdout.queue(MoveTo(centered_x(width, error), top + 15)).unwrap();
+            stdout.queue(SetForegroundColor(Color::Red)).unwrap();
+            stdout.queue(Print(error)).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
+
+        // --- Instructions ---
+        let instructions = [
+            "Press TAB to switch between fields",
+            "Press ENTER when ready to generate",
+            "Press ESC to cancel"
+        ];
+        for (i, instruction) in instructions.iter().enumerate() {
+            let inst_x = (width.saturating_sub(instruction.len() as u16)) / 2;
+            stdout.queue(MoveTo(inst_x, top + 17 + i as u16)).unwrap();
+            stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+            stdout.queue(Print(*instruction)).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
+
+        stdout.flush().unwrap();
+
+        // --- Handle Input ---
+        if poll(Duration::from_millis(50)).unwrap_or(false) {
+            match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
+                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                    match key_event.code {
+                        KeyCode::Tab => {
+                            // Size → min → max → name, then back to size
+                            input_mode = (input_mode + 1) % fields.len();
+                            cursor_pos = fields[input_mode].len();
+                        },
+                        KeyCode::Char(c) => {
+                            // Numbers take digits only, the name any character
+                            let numeric = input_mode < 3;
+                            if (!numeric || c.is_ascii_digit()) && fields[input_mode].len() < max_lengths[input_mode] {
+                                fields[input_mode].insert(cursor_pos, c);
+                                cursor_pos += 1;
+                                error = None;
+                            }
+                        },
+                        KeyCode::Backspace => {
+                            // Handle backspace
+                            if cursor_pos > 0 {
+                                cursor_pos -= 1;
+                                fields[input_mode].remove(cursor_pos);
+                                error = None;
+                            }
+                        },
+                        KeyCode::Enter => {
+                            // Generate array if input is valid
+                            let array_size = match fields[0].trim().parse::<usize>() {
+                                Ok(array_size) if (2..=50).contains(&array_size) => array_size,
+                                _ => {
+                                    error = Some("Array size must be from 2 to 50".to_string());
+                                    continue;
+                                }
+                            };
+                            let value_range = match parse_value_range(&fields[1], &fields[2]) {
+                                Ok(value_range) => value_range,
+                                Err(message) => {
+                                    error = Some(message);
+                                    continue;
+                                }
+                            };
+                            let array_name = if fields[3].trim().is_empty() {
+                                format!("Array_{}", array_size)
+                            } else {
+                                fields[3].trim().to_string()
+                            };
+                            // Remembered as the range the next array starts with
+                            let mut settings = Settings::load();
+                            settings.random_min = *value_range.start();
+                            settings.random_max = *value_range.end();
+                            settings.save();
+                            // Seeded, so the details screen can rebuild it exactly
+                            return Some(ArrayData::generate_in(array_size, rand::rng().random(), value_range, array_name));
+                        },
+                        KeyCode::Esc => {
+                            return None;
+                        },
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+// Reads the min and max fields of the generate dialog, min must be below max and max at most MAX_RANDOM_VALUE
+pub fn parse_value_range(min: &str, max: &str) -> Result<RangeInclusive<u32>, String> {
+    let (Ok(min), Ok(max)) = (min.trim().parse::<u32>(), max.trim().parse::<u32>()) else {
+        return Err("Min and max value must be whole numbers".to_string());
+    };
+    if max > MAX_RANDOM_VALUE {
+        return Err(format!("Max value must be at most {}", MAX_RANDOM_VALUE));
+    }
+    if min >= max {
+        return Err("Min value must be below max value".to_string());
+    }
+    Ok(min..=max)
+}
+
+// Size a worst-case input starts with, ←/→ change it within the random array limits
+const WORST_CASE_DEFAULT_SIZE: usize = 16;
+const WORST_CASE_MIN_SIZE: usize = 2;
+const WORST_CASE_MAX_SIZE: usize = 50;
+
+// Dialog for picking an algorithm and generating the input that triggers its worst case
+// Starts on the last visualized algorithm when it has a worst case
+fn worst_case_dialog() -> Option<ArrayData> {
+    let mut stdout = stdout();
+    let mut selection = Settings::load()
+        .last_visualizer
+        .and_then(|key| worst_case_index(&key))
+        .unwrap_or(0);
+    let mut array_size = WORST_CASE_DEFAULT_SIZE;
+
+    loop {
+        let (width, height) = size().unwrap();
+        stdout.execute(Clear(ClearType::All)).unwrap();
+
+        // --- Title ---
+        let title = "Generate Worst Case for an Algorithm";
+        let title_x = (width.saturating_sub(title.len() as u16)) / 2;
+        stdout.queue(MoveTo(title_x, 2)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+        stdout.queue(Print(title)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        // --- Size ---
+        let size_text = format!("Array Size: ◄ {} ►", array_size);
+        stdout.queue(MoveTo((width.saturating_sub(size_text.chars().count() as u16)) / 2, 4)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Cyan)).unwrap();
+        stdout.queue(Print(size_text)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        // --- Algorithms ---
+        let list_y = 6;
+        for (i, worst_case) in WORST_CASES.iter().enumerate() {
+            stdout.queue(MoveTo(8, list_y + i as u16)).unwrap();
+            if i == selection {
+                stdout.queue(SetForegroundColor(theme::highlight().0)).unwrap();
+                stdout.queue(SetBackgroundColor(theme::highlight().1)).unwrap();
+            } else {
+                stdout.queue(SetForegroundColor(theme::text())).unwrap();
+            }
+            stdout.queue(Print(format!(" {} ", worst_case.name))).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
+
+        // --- Description and preview of the highlighted input ---
+        let worst_case = &WORST_CASES[selection];
+        let details_y = list_y + WORST_CASES.len() as u16 + 1;
+        stdout.queue(MoveTo(8, details_y)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+        stdout.queue(Print(worst_case.description)).unwrap();
+        stdout.queue(MoveTo(8, details_y + 1)).unwrap();
+        stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+        stdout.queue(Print(display_array_preview(&(worst_case.generate)(array_size)))).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        // --- Instructions ---
+        let instructions = [
+            "Use ↑/↓ to choose the algorithm, ←/→ to change the size",
+            "Press ENTER to generate the array",
+            "Press ESC to cancel",
+        ];
+        let inst_y = height.saturating_sub(instructions.len() as u16 + 2);
+        for (i, instruction) in instructions.iter().enumerate() {
+            let inst_x = (width.saturating_sub(instruction.chars().count() as u16)) / 2;
+            stdout.queue(MoveTo(inst_x, inst_y + i as u16)).unwrap();
+            stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+            stdout.queue(Print(*instruction)).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
+
+        stdout.flush().unwrap();
+
+        // --- Handle Input ---
+        if poll(Duration::from_millis(50)).unwrap_or(false) {
+            match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
+                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                    match key_event.code {
+                        KeyCode::Up => {
+                            selection = if selection > 0 { selection - 1 } else { WORST_CASES.len() - 1 };
+                        },
+                        KeyCode::Down => {
+                            selection = (selection + 1) % WORST_CASES.len();
+                        },
+                        KeyCode::Left => {
+                            array_size = array_size.saturating_sub(1).max(WORST_CASE_MIN_SIZE);
+                        },
+                        KeyCode::Right => {
+                            array_size = (array_size + 1).min(WORST_CASE_MAX_SIZE);
+                        },
+                        KeyCode::Enter => {
+                            let name = format!("Worst {}", worst_case.name);
+                            return Some(ArrayData::new((worst_case.generate)(array_size), name));
+                        },
+                        KeyCode::Esc => {
+                            return None;
+                        },
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+// Values 1 to 100 in the preset's distribution, a new draw every call
+pub fn generate_preset(size: usize, kind: PresetKind) -> Vec<u32> {
+    let mut rng = rand::rng();
+    let mut sorted: Vec<u32> = (0..size).map(|_| rng.random_range(DEFAULT_RANDOM_MIN..=DEFAULT_RANDOM_MAX)).collect();
+    sorted.sort_unstable();
+    match kind {
+        PresetKind::Sorted => sorted,
+        PresetKind::Reversed => {
+            sorted.reverse();
+            sorted
+        }
+        PresetKind::NearlySorted => {
+            // About one swap per ten elements, always at least one
+            for _ in 0..(size / 10).max(1) {
+                if size >= 2 {
+                    sorted.swap(rng.random_range(0..size), rng.random_range(0..size));
+                }
+            }
+            sorted
+        }
+        PresetKind::FewUnique => {
+            let values: Vec<u32> = (0..FEW_UNIQUE_VALUES).map(|_| rng.random_range(DEFAULT_RANDOM_MIN..=DEFAULT_RANDOM_MAX)).collect();
+            (0..size).map(|_| values[rng.random_range(0..values.len())]).collect()
+        }
+    }
+}
+
+// Dialog for picking a preset distribution and size, the preview shows the exact array that is added
+fn preset_dialog() -> Option<ArrayData> {
+    let mut stdout = stdout();
+    let mut selection = 0usize;
+    let mut array_size = WORST_CASE_DEFAULT_SIZE;
+    let mut preview = generate_preset(array_size, PresetKind::ALL[selection]);
+
+    loop {
+        let (width, height) = size().unwrap();
+        stdout.execute(Clear(ClearType::All)).unwrap();
+
+        // --- Title ---
+        let title = "Generate Preset Array";
+        let title_x = (width.saturating_sub(title.len() as u16)) / 2;
+        stdout.queue(MoveTo(title_x, 2)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+        stdout.queue(Print(title)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        // --- Size ---
+        let size_text = format!("Array Size: ◄ {} ►", array_size);
+        stdout.queue(MoveTo((width.saturating_sub(size_text.chars().count() as u16)) / 2, 4)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Cyan)).unwrap();
+        stdout.queue(Print(size_text)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        // --- Presets ---
+        let list_y = 6;
+        for (i, kind) in PresetKind::ALL.iter().enumerate() {
+            stdout.queue(MoveTo(8, list_y + i as u16)).unwrap();
+            if i == selection {
+                stdout.queue(SetForegroundColor(theme::highlight().0)).unwrap();
+                stdout.queue(SetBackgroundColor(theme::highlight().1)).unwrap();
+            } else {
+                stdout.queue(SetForegroundColor(theme::text())).unwrap();
+            }
+            stdout.queue(Print(format!(" {} ", kind.label()))).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
+
+        // --- Description and preview of the highlighted preset ---
+        let kind = PresetKind::ALL[selection];
+        let details_y = list_y + PresetKind::ALL.len() as u16 + 1;
+        stdout.queue(MoveTo(8, details_y)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+        stdout.queue(Print(kind.description())).unwrap();
+        stdout.queue(MoveTo(8, details_y + 1)).unwrap();
+        stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+        stdout.queue(Print(display_array_preview(&preview))).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        // --- Instructions ---
+        let instructions = [
+            "Use ↑/↓ to choose the preset, ←/→ to change the size, R for new values",
+            "Press ENTER to generate the array",
+            "Press ESC to cancel",
+        ];
+        let inst_y = height.saturating_sub(instructions.len() as u16 + 2);
+        for (i, instruction) in instructions.iter().enumerate() {
+            let inst_x = (width.saturating_sub(instruction.chars().count() as u16)) / 2;
+            stdout.queue(MoveTo(inst_x, inst_y + i as u16)).unwrap();
+            stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+            stdout.queue(Print(*instruction)).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
+
+        stdout.flush().unwrap();
+
+        // --- Handle Input ---
+        if poll(Duration::from_millis(50)).unwrap_or(false) {
+            match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
+                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                    match key_event.code {
+                        KeyCode::Up => {
+                            selection = if selection > 0 { selection - 1 } else { PresetKind::ALL.len() - 1 };
+                        },
+                        KeyCode::Down => {
+                            selection = (selection + 1) % PresetKind::ALL.len();
+                        },
+                        KeyCode::Left => {
+                            array_size = array_size.saturating_sub(1).max(WORST_CASE_MIN_SIZE);
+                        },
+                        KeyCode::Right => {
+                            array_size = (array_size + 1).min(WORST_CASE_MAX_SIZE);
+                        },
+                        KeyCode::Char('r') | KeyCode::Char('R') => {}, // Only new values
+                        KeyCode::Enter => {
+                            let name = format!("{}_{}", kind.name_prefix(), array_size);
+                            return Some(ArrayData::new(preview, name));
+                        },
+                        KeyCode::Esc => {
+                            return None;
+                        },
+                        _ => continue,
+                    }
+                    // Every change draws new values for the preview
+                    preview = generate_preset(array_size, PresetKind::ALL[selection]);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+// Reads a sort range typed as "lo hi", "lo..hi" or "lo,hi", the half-open range [lo, hi)
+// Empty input or the whole array clear the range, which gives None
+pub fn parse_sort_range(text: &str, len: usize) -> Result<Option<Range<usize>>, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(None);
+    }
+    let bounds: Vec<&str> = text
+        .split(|c: char| c == ',' || c == '.' || c.is_whitespace())
+        .filter(|bound| !bound.is_empty())
+        .collect();
+    let [lo, hi] = bounds.as_slice() else {
+        return Err("Enter two indices, e.g. 3 8".to_string());
+    };
+    let (Ok(lo), Ok(hi)) = (lo.parse::<usize>(), hi.parse::<usize>()) else {
+        return Err("Indices must be non-negative numbers".to_string());
+    };
+    if hi > len {
+        return Err(format!("The range must end at {} or before", len));
+    }
+    if hi < lo + 2 {
+        return Err("The range needs at least two elements".to_string());
+    }
+    Ok((lo > 0 || hi < len).then_some(lo..hi))
+}
+
+// Dialog for limiting the sorts to a slice of the array, the rest stays in place
+// Returns the new range, None if cancelled
+fn sort_range_dialog(array_data: &StoredArray) -> Option<Option<Range<usize>>> {
+    let mut stdout = stdout();
+    let mut input = array_data
+        .sort_range()
+        .map_or(String::new(), |range| format!("{} {}", range.start, range.end));
+    let mut error: Option<String> = None;
+
+    loop {
+        let (width, height) = size().unwrap();
+        stdout.execute(Clear(ClearType::All)).unwrap();
+
+        // --- Title ---
+        let title = "Set Sort Range";
+        stdout.queue(MoveTo(centered_x(width, title), 2)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+        stdout.queue(Print(title)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        // --- Array and input ---
+        let info = format!("\"{}\" has indices 0 to {}", array_data.name(), array_data.size().saturating_sub(1));
+        stdout.queue(MoveTo(centered_x(width, &info), 4)).unwrap();
+        stdout.queue(SetForegroundColor(theme::text())).unwrap();
+        stdout.queue(Print(&info)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        let label = "Range [lo, hi): ";
+        let input_x = (width / 2).saturating_sub(20);
+        let input_y = height / 2 - 2;
+        stdout.queue(MoveTo(input_x, input_y)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Cyan)).unwrap();
+        stdout.queue(Print(label)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+        draw_input_box(&mut stdout, input_x + label.len() as u16, input_y, 20, &input, input.len(), true);
+
+        if let Some(error) = &error {
+            stdout.queue(MoveTo(input_x, input_y + 2)).unwrap();
+            stdout.queue(SetForegroundColor(Color::Red)).unwrap();
+            stdout.queue(Print(error)).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
+
+        // --- Instructions ---
+        let instructions = [
+            "Sorts then work on these elements only, the others stay in place",
+            "Type two indices like 3 8, leave it empty to sort the whole array",
+            "Press ENTER to apply, ESC to cancel",
+        ];
+        let inst_y = height.saturating_sub(instructions.len() as u16 + 2);
+        for (i, instruction) in instructions.iter().enumerate() {
+            stdout.queue(MoveTo(centered_x(width, instruction), inst_y + i as u16)).unwrap();
+            stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+            stdout.queue(Print(*instruction)).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
+
+        stdout.flush().unwrap();
+
+        // --- Handle Input ---
+        if poll(Duration::from_millis(50)).unwrap_or(false) {
+            match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
+                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => match key_event.code {
+                    KeyCode::Char(c) if (c.is_ascii_digit() || c == ' ' || c == ',' || c == '.') && input.len() < 19 => {
+                        input.push(c);
+                    }
+                    KeyCode::Backspace => {
+                        input.pop();
+                    }
+                    KeyCode::Enter => match parse_sort_range(&input, array_data.size()) {
+                        Ok(range) => return Some(range),
+                        Err(message) => error = Some(message),
+                    },
+                    KeyCode::Esc => return None,
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+    }
+}
+
+// Dialog asking which value a search looks for, starting from a suggested value
+// Returns None on ESC, the caller decides what cancelling means
+pub fn search_target_dialog<T: SortElement>(array_data: &ArrayData<T>, algorithm: &str, suggested: T) -> Option<T> {
+    let mut stdout = stdout();
+    let mut input = suggested.to_string();
+    let mut error: Option<String> = None;
+    enable_raw_mode().unwrap();
+    stdout.execute(EnterAlternateScreen).unwrap();
+
+    loop {
+        let (width, height) = size().unwrap();
+        stdout.execute(Clear(ClearType::All)).unwrap();
+
+        // --- Title ---
+        let title = format!("{}: Choose the Target", algorithm);
+        stdout.queue(MoveTo(centered_x(width, &title), 2)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+        stdout.queue(Print(&title)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        // --- Array and input ---
+        let info = format!("\"{}\": {}", array_data.name, display_array_preview(&array_data.data));
+        stdout.queue(MoveTo(centered_x(width, &info), 4)).unwrap();
+        stdout.queue(SetForegroundColor(theme::text())).unwrap();
+        stdout.queue(Print(&info)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        let label = "Search for: ";
+        let input_x = (width / 2).saturating_sub(16);
+        let input_y = height / 2 - 2;
+        stdout.queue(MoveTo(input_x, input_y)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Cyan)).unwrap();
+        stdout.queue(Print(label)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+        draw_input_box(&mut stdout, input_x + label.len() as u16, input_y, 12, &input, input.len(), true);
+
+        if let Some(error) = &error {
+            stdout.queue(MoveTo(input_x, input_y + 2)).unwrap();
+            stdout.queue(SetForegroundColor(Color::Red)).unwrap();
+            stdout.queue(Print(error)).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
+
+        // --- Instructions ---
+        let instructions = [
+            "A value that is not in the array runs until the search gives up",
+            "Press ENTER to search, ESC to cancel",
+        ];
+        let inst_y = height.saturating_sub(instructions.len() as u16 + 2);
+        for (i, instruction) in instructions.iter().enumerate() {
+            stdout.queue(MoveTo(centered_x(width, instruction), inst_y + i as u16)).unwrap();
+            stdout.queue(SetForegroundColor(theme::dim())).unwrap();
             stdout.queue(Print(*instruction)).unwrap();
             stdout.queue(ResetColor).unwrap();
         }
@@ -223,155 +1370,106 @@ pub fn array_management_screen(manager: &mut ArrayManager) -> bool {
         stdout.flush().unwrap();
 
         // --- Handle Input ---
-        if poll(Duration::from_millis(100)).unwrap_or(false) {
+        if poll(Duration::from_millis(50)).unwrap_or(false) {
             match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
-                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                    match key_event.code {
-                        KeyCode::Up => {
-                            if (menu_selection >= 2 && menu_selection <= 4) && !manager.arrays.is_empty() {
-                                // Navigate array list
-                                array_selection = if array_selection > 0 {
-                                    array_selection - 1
-                                } else {
-                                    manager.arrays.len() - 1
-                                };
-                            } else {
-                                // Navigate menu
-                                menu_selection = if menu_selection > 0 {
-                                    menu_selection - 1
-                                } else {
-                                    menu_options.len() - 1
-                                };
-                            }
-                        },
-                        KeyCode::Down => {
-                            if (menu_selection >= 2 && menu_selection <= 4) && !manager.arrays.is_empty() {
-                                // Navigate array list
-                                array_selection = (array_selection + 1) % manager.arrays.len();
-                            } else {
-                                // Navigate menu
-                                menu_selection = (menu_selection + 1) % menu_options.len();
-                            }
-                        },
-                        KeyCode::Left => {
-                            // Exit array selection mode
-                            if menu_selection >= 2 && menu_selection <= 4 {
-                                menu_selection = if menu_selection > 0 {
-                                    menu_selection - 1
-                                } else {
-                                    menu_options.len() - 1
-                                };
-                            }
-                        },
-                        KeyCode::Right => {
-                            // Move forward in menu
-                            menu_selection = (menu_selection + 1) % menu_options.len();
-                        },
-                        KeyCode::Enter => {
-                            match menu_selection {
-                                0 => {
-                                    // Generate New Random Array
-                                    if let Some(array) = generate_random_array_dialog() {
-                                        manager.add_array(array);
-                                    }
-                                },
-                                1 => {
-                                    // Enter Array Manually
-                                    if let Some(array) = manual_array_dialog() {
-                                        manager.add_array(array);
-                                    }
-                                },
-                                2 => {
-                                    // Select Array for Sorting
-                                    if !manager.arrays.is_empty() {
-                                        manager.selected_index = Some(array_selection);
-                                        show_selection_confirmation(&manager.arrays[array_selection]);
-                                    }
-                                },
-                                3 => {
-                                    // View Array Details
-                                    if !manager.arrays.is_empty() {
-                                        show_array_details(&manager.arrays[array_selection]);
-                                    }
-                                },
-                                4 => {
-                                    // Delete Array
-                                    if !manager.arrays.is_empty() {
-                                        if confirm_delete(&manager.arrays[array_selection]) {
-                                            manager.remove_array(array_selection);
-                                            if array_selection >= manager.arrays.len() && !manager.arrays.is_empty() {
-                                                array_selection = manager.arrays.len() - 1;
-                                            }
-                                        }
-                                    }
-                                },
-                                5 => {
-                                    // Back to Main Menu
-                                    cleanup_terminal();
-                                    return false;
-                                },
-                                _ => {}
-                            }
-                        },
-                        KeyCode::Esc => {
-                            cleanup_terminal();
-                            return false;
-                        },
-                        _ => {}
+                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => match key_event.code {
+                    KeyCode::Char(c) if (c.is_ascii_digit() || c == '.' || (c == '-' && T::SIGNED)) && input.len() < 11 => {
+                        input.push(c);
+                        error = None;
                     }
-                }
+                    KeyCode::Backspace => {
+                        input.pop();
+                        error = None;
+                    }
+                    KeyCode::Enter => match T::parse(&input) {
+                        Some(target) => return Some(target),
+                        None => error = Some(format!("\"{}\" is not a value this array can hold", input)),
+                    },
+                    KeyCode::Esc => return None,
+                    _ => {}
+                },
                 _ => {}
             }
         }
     }
 }
 
-// Dialog for generating a new random array: prompts for size and name
-fn generate_random_array_dialog() -> Option<ArrayData> {
+// Builds the edited array from the text of every element, keeping the name and sort range.
+// The values decide the kind of array like typed ones do, `Err` names the first element that is not a number.
+pub fn edited_array(original: &StoredArray, texts: &[String]) -> Result<StoredArray, String> {
+    if let Some(position) = texts.iter().position(|text| text.trim().is_empty()) {
+        return Err(format!("Element {} has no value", position));
+    }
+    let Some(mut edited) = StoredArray::parse(&texts.join(" "), original.name().to_string()) else {
+        // Every element can be valid on its own when a negative value meets one with decimal places
+        return Err(match texts.iter().find(|text| StoredArray::parse(text, String::new()).is_none()) {
+            Some(entry) => format!("\"{}\" is not a number with at most {} decimal places", entry.trim(), MAX_DECIMALS),
+            None => "Values with decimal places can not be negative".to_string(),
+        });
+    };
+    edited.set_sort_range(original.sort_range().cloned());
+    Ok(edited)
+}
+
+// Dialog for changing single values of an array, every element can be picked with the arrow keys
+// Returns the rebuilt array on ENTER, None on ESC
+fn edit_array_dialog(array_data: &StoredArray) -> Option<StoredArray> {
     let mut stdout = stdout();
-    let mut input_string = String::new();   // Stores array size input
-    let mut name_string = String::new();    // Stores array name input
-    let mut input_mode = 0;                 // 0: size input, 1: name input
-    let mut cursor_pos = 0usize;
+    let mut texts = array_data.value_texts();
+    let mut cursor = 0usize;
+    let mut typing = false; // The first key typed on an element replaces its value, later ones extend it
+    let mut error: Option<String> = None;
 
     loop {
         let (width, height) = size().unwrap();
         stdout.execute(Clear(ClearType::All)).unwrap();
 
         // --- Title ---
-        let title = "Generate New Random Array";
-        let title_x = (width.saturating_sub(title.len() as u16)) / 2;
-        stdout.queue(MoveTo(title_x, height / 2 - 8)).unwrap();
+        let title = format!("Edit Array \"{}\"", array_data.name());
+        stdout.queue(MoveTo(centered_x(width, &title), 2)).unwrap();
         stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
-        stdout.queue(Print(title)).unwrap();
+        stdout.queue(Print(&title)).unwrap();
         stdout.queue(ResetColor).unwrap();
 
-        // --- Size Input ---
-        let size_label = "Array Size (2-50):";
-        stdout.queue(MoveTo(width / 2 - 28, height / 2 - 5)).unwrap();
-        stdout.queue(SetForegroundColor(Color::Cyan)).unwrap();
-        stdout.queue(Print(size_label)).unwrap();
-        stdout.queue(ResetColor).unwrap();
-        draw_input_box(&mut stdout, width / 2 - 10, height / 2 - 4, 20, &input_string, cursor_pos, input_mode == 0);
+        // --- Values, as a grid of equal cells ---
+        let cell_width = texts.iter().map(|text| text.len()).max().unwrap_or(1).max(4) + 2;
+        let per_row = ((width as usize).saturating_sub(8) / cell_width).max(1);
+        for (i, text) in texts.iter().enumerate() {
+            let x = 4 + (i % per_row * cell_width) as u16;
+            let y = 5 + (i / per_row * 2) as u16;
+            stdout.queue(MoveTo(x, y)).unwrap();
+            if i == cursor {
+                stdout.queue(SetForegroundColor(theme::highlight().0)).unwrap();
+                stdout.queue(SetBackgroundColor(theme::highlight().1)).unwrap();
+            } else {
+                stdout.queue(SetForegroundColor(theme::text())).unwrap();
+            }
+            stdout.queue(Print(format!("{:>width$}", text, width = cell_width - 1))).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
 
-        // --- Name Input ---
-        let name_label = "Array Name:";
-        stdout.queue(MoveTo(width / 2 - 28, height / 2 - 2)).unwrap();
+        let rows = texts.len().div_ceil(per_row) as u16;
+        let status = format!("Element {} of {}", cursor, texts.len());
+        stdout.queue(MoveTo(4, 6 + rows * 2)).unwrap();
         stdout.queue(SetForegroundColor(Color::Cyan)).unwrap();
-        stdout.queue(Print(name_label)).unwrap();
+        stdout.queue(Print(status)).unwrap();
         stdout.queue(ResetColor).unwrap();
-        draw_input_box(&mut stdout, width / 2 - 10, height / 2 - 1, 20, &name_string, if input_mode == 1 { cursor_pos } else { 0 }, input_mode == 1);
+        if let Some(error) = &error {
+            stdout.queue(MoveTo(4, 7 + rows * 2)).unwrap();
+            stdout.queue(SetForegroundColor(Color::Red)).unwrap();
+            stdout.queue(Print(error)).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
 
         // --- Instructions ---
         let instructions = [
-            "Press TAB to switch between fields",
-            "Press ENTER when ready to generate",
-            "Press ESC to cancel"
+            "Use the arrow keys to pick an element and type its new value",
+            "Press ENTER to save the array, ESC to cancel",
         ];
+        let inst_y = height.saturating_sub(instructions.len() as u16 + 2);
         for (i, instruction) in instructions.iter().enumerate() {
-            let inst_x = (width.saturating_sub(instruction.len() as u16)) / 2;
-            stdout.queue(MoveTo(inst_x, height / 2 + 2 + i as u16)).unwrap();
-            stdout.queue(SetForegroundColor(Color::DarkGrey)).unwrap();
+            stdout.queue(MoveTo(centered_x(width, instruction), inst_y + i as u16)).unwrap();
+            stdout.queue(SetForegroundColor(theme::dim())).unwrap();
             stdout.queue(Print(*instruction)).unwrap();
             stdout.queue(ResetColor).unwrap();
         }
@@ -382,58 +1480,38 @@ fn generate_random_array_dialog() -> Option<ArrayData> {
         if poll(Duration::from_millis(50)).unwrap_or(false) {
             match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
                 Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                    let last = texts.len().saturating_sub(1);
                     match key_event.code {
-                        KeyCode::Tab => {
-                            // Switch between size and name fields
-                            input_mode = (input_mode + 1) % 2;
-                            cursor_pos = if input_mode == 0 {
-                                input_string.len()
-                            } else {
-                                name_string.len()
-                            };
-                        },
-                        KeyCode::Char(c) => {
-                            // Handle character input
-                            if input_mode == 0 && c.is_ascii_digit() && input_string.len() < 2 {
-                                input_string.insert(cursor_pos, c);
-                                cursor_pos += 1;
-                            } else if input_mode == 1 && name_string.len() < 18 {
-                                name_string.insert(cursor_pos, c);
-                                cursor_pos += 1;
-                            }
-                        },
-                        KeyCode::Backspace => {
-                            // Handle backspace
-                            if input_mode == 0 && cursor_pos > 0 {
-                                cursor_pos -= 1;
-                                input_string.remove(cursor_pos);
-                            } else if input_mode == 1 && cursor_pos > 0 {
-                                cursor_pos -= 1;
-                                name_string.remove(cursor_pos);
+                        KeyCode::Left => cursor = cursor.saturating_sub(1),
+                        KeyCode::Right => cursor = (cursor + 1).min(last),
+                        KeyCode::Up => cursor = cursor.saturating_sub(per_row),
+                        KeyCode::Down => cursor = (cursor + per_row).min(last),
+                        KeyCode::Char(c) if (c.is_ascii_digit() || c == '-' || c == '.') && !texts.is_empty() => {
+                            if !typing {
+                                texts[cursor].clear();
                             }
-                        },
-                        KeyCode::Enter => {
-                            // Generate array if input is valid
-                            if let Ok(array_size) = input_string.trim().parse::<usize>() {
-                                if array_size >= 2 && array_size <= 50 {
-                                    let array_name = if name_string.trim().is_empty() {
-                                        format!("Array_{}", array_size)
-                                    } else {
-                                        name_string.trim().to_string()
-                                    };
-                                    let mut rng = rand::thread_rng();
-                                    let data: Vec<u32> = (0..array_size)
-                                        .map(|_| rng.gen_range(1..=100))
-                                        .collect();
-                                    return Some(ArrayData::new(data, array_name));
-                                }
+                            if texts[cursor].len() < 12 {
+                                texts[cursor].push(c);
                             }
+                            typing = true;
+                            error = None;
+                            continue;
+                        }
+                        KeyCode::Backspace if !texts.is_empty() => {
+                            texts[cursor].pop();
+                            typing = true;
+                            error = None;
+                            continue;
+                        }
+                        KeyCode::Enter => match edited_array(array_data, &texts) {
+                            Ok(array) => return Some(array),
+                            Err(message) => error = Some(message),
                         },
-                        KeyCode::Esc => {
-                            return None;
-                        },
+                        KeyCode::Esc => return None,
                         _ => {}
                     }
+                    // Moving on finishes the value being typed
+                    typing = false;
                 }
                 _ => {}
             }
@@ -442,12 +1520,13 @@ fn generate_random_array_dialog() -> Option<ArrayData> {
 }
 
 // Dialog for entering a new array manually: prompts for size, name, and values
-fn manual_array_dialog() -> Option<ArrayData> {
+// Values with a decimal point make an array of real numbers, negative values one of signed integers
+fn manual_array_dialog() -> Option<StoredArray> {
     let mut stdout = stdout();
     let mut mode: i32 = 0; // 0: size, 1: name, 2: values
     let mut array_size: usize = 0;
     let mut name: String = String::new();
-    let mut values: Vec<u32> = Vec::new();
+    let mut values: Vec<String> = Vec::new(); // typed or pasted values, parsed together once complete
     let mut current_index: usize = 0;
     let mut active_input: String = String::new();
     let mut cursor_pos: usize = 0;
@@ -469,14 +1548,16 @@ fn manual_array_dialog() -> Option<ArrayData> {
 
         match mode {
             0 => {
-                // Size input
-                let label = "Array Size (2-50): ";
+                // Size input, or a pasted list of values
+                let label = "Array Size (2-50) or Values: ";
                 stdout.queue(MoveTo(size_x, height / 2 as u16 - 4)).unwrap();
                 stdout.queue(SetForegroundColor(Color::Cyan)).unwrap();
                 stdout.queue(Print(label)).unwrap();
                 stdout.queue(ResetColor).unwrap();
                 let input_x = size_x + label.len() as u16;
-                draw_input_box(&mut stdout, input_x, height / 2 as u16 - 4, 5, &active_input, cursor_pos, true);
+                // A long list scrolls so its end stays in view
+                let skipped = active_input.len().saturating_sub(PASTE_BOX_WIDTH as usize - 1);
+                draw_input_box(&mut stdout, input_x, height / 2 as u16 - 4, PASTE_BOX_WIDTH, &active_input[skipped..], cursor_pos.saturating_sub(skipped), true);
             },
             1 => {
                 // Name input, show size
@@ -529,13 +1610,18 @@ fn manual_array_dialog() -> Option<ArrayData> {
 
         // --- Instructions ---
         let instructions: Vec<&str> = match mode {
-            0 | 1 => vec![
+            0 => vec![
+                "Type a size, or paste values like 5, 3, 8 or 2.5, 0.75 to use them as they are",
+                "Press ENTER to proceed",
+                "Press ESC to cancel"
+            ],
+            1 => vec![
                 "Press TAB to switch to next field",
                 "Press ENTER to proceed",
                 "Press ESC to cancel"
             ],
             2 => vec![
-                "Enter numbers only",
+                "Enter numbers only, decimal points and minus signs are allowed",
                 "Press ENTER for next value",
                 "Press ESC to cancel"
             ],
@@ -545,7 +1631,7 @@ fn manual_array_dialog() -> Option<ArrayData> {
         for (i, instruction) in instructions.iter().enumerate() {
             let inst_x = (width.saturating_sub(instruction.len() as u16)) / 2;
             stdout.queue(MoveTo(inst_x, inst_y + i as u16)).unwrap();
-            stdout.queue(SetForegroundColor(Color::DarkGrey)).unwrap();
+            stdout.queue(SetForegroundColor(theme::dim())).unwrap();
             stdout.queue(Print(*instruction)).unwrap();
             stdout.queue(ResetColor).unwrap();
         }
@@ -560,12 +1646,10 @@ fn manual_array_dialog() -> Option<ArrayData> {
                         KeyCode::Tab => {
                             match mode {
                                 0 => {
-                                    if let Ok(s) = active_input.trim().parse::<usize>() {
-                                        if s >= 2 && s <= 50 {
-                                            array_size = s;
-                                            active_input.clear();
-                                            mode = 1;
-                                        }
+                                    if let Some(s) = accept_size_or_values(&active_input, &mut values) {
+                                        array_size = s;
+                                        active_input.clear();
+                                        mode = 1;
                                     }
                                 },
                                 1 => {
@@ -574,6 +1658,10 @@ fn manual_array_dialog() -> Option<ArrayData> {
                                     } else {
                                         active_input.trim().to_string()
                                     };
+                                    // Pasted values need no further entry
+                                    if values.len() == array_size {
+                                        return StoredArray::parse(&values.join(" "), name);
+                                    }
                                     values.clear();
                                     current_index = 0;
                                     active_input.clear();
@@ -586,7 +1674,7 @@ fn manual_array_dialog() -> Option<ArrayData> {
                         KeyCode::Char(c) => {
                             match mode {
                                 0 => {
-                                    if c.is_ascii_digit() && active_input.len() < 2 {
+                                    if (c.is_ascii_digit() || c == ',' || c == ' ' || c == '.' || c == '-') && active_input.len() < MAX_PASTE_LENGTH {
                                         active_input.insert(cursor_pos, c);
                                         cursor_pos += 1;
                                     }
@@ -598,7 +1686,7 @@ fn manual_array_dialog() -> Option<ArrayData> {
                                     }
                                 },
                                 2 => {
-                                    if c.is_ascii_digit() && active_input.len() < 10 {
+                                    if (c.is_ascii_digit() || c == '.' || c == '-') && active_input.len() < 10 {
                                         active_input.insert(cursor_pos, c);
                                         cursor_pos += 1;
                                     }
@@ -615,13 +1703,11 @@ fn manual_array_dialog() -> Option<ArrayData> {
                         KeyCode::Enter => {
                             match mode {
                                 0 => {
-                                    if let Ok(s) = active_input.trim().parse::<usize>() {
-                                        if s >= 2 && s <= 50 {
-                                            array_size = s;
-                                            active_input.clear();
-                                            mode = 1;
-                                            cursor_pos = 0;
-                                        }
+                                    if let Some(s) = accept_size_or_values(&active_input, &mut values) {
+                                        array_size = s;
+                                        active_input.clear();
+                                        mode = 1;
+                                        cursor_pos = 0;
                                     }
                                 },
                                 1 => {
@@ -630,6 +1716,10 @@ fn manual_array_dialog() -> Option<ArrayData> {
                                     } else {
                                         active_input.trim().to_string()
                                     };
+                                    // Pasted values need no further entry
+                                    if values.len() == array_size {
+                                        return StoredArray::parse(&values.join(" "), name);
+                                    }
                                     values.clear();
                                     current_index = 0;
                                     active_input.clear();
@@ -640,11 +1730,11 @@ fn manual_array_dialog() -> Option<ArrayData> {
                                     let input_copy = active_input.trim().to_string();
                                     active_input.clear();
                                     cursor_pos = 0;
-                                    if let Ok(val) = input_copy.parse::<u32>() {
-                                        values.push(val);
+                                    if is_value(&input_copy) {
+                                        values.push(input_copy);
                                         current_index += 1;
                                         if current_index == array_size {
-                                            return Some(ArrayData::new(values, name));
+                                            return StoredArray::parse(&values.join(" "), name);
                                         }
                                     }
                                     // If invalid, stay on current input (cleared)
@@ -664,6 +1754,30 @@ fn manual_array_dialog() -> Option<ArrayData> {
     }
 }
 
+// Reads the first field of the manual entry: a size, or a whole pasted list whose
+// values are stored in `values`. Returns the array size, `None` if the input is invalid.
+fn accept_size_or_values(input: &str, values: &mut Vec<String>) -> Option<usize> {
+    values.clear();
+    let size = if input.contains(',') {
+        StoredArray::parse(input, String::new())?;
+        *values = input.split(|c: char| c == ',' || c.is_whitespace()).filter(|entry| !entry.is_empty()).map(str::to_string).collect();
+        values.len()
+    } else {
+        input.trim().parse::<usize>().ok()?
+    };
+    if (2..=50).contains(&size) {
+        Some(size)
+    } else {
+        values.clear();
+        None
+    }
+}
+
+// Returns true if the manual entry takes `entry` as a value: an integer, negative or not, or a real number with a decimal point
+fn is_value(entry: &str) -> bool {
+    StoredArray::parse(entry, String::new()).is_some()
+}
+
 // Renders an input box with border, content, and cursor
 fn draw_input_box(stdout: &mut std::io::Stdout, x: u16, y: u16, width: u16, text: &str, cursor_pos: usize, active: bool) {
     // Draw border
@@ -700,14 +1814,14 @@ fn draw_input_box(stdout: &mut std::io::Stdout, x: u16, y: u16, width: u16, text
 }
 
 // Shows a confirmation message after selecting an array for sorting
-fn show_selection_confirmation(array_data: &ArrayData) {
+fn show_selection_confirmation(array_data: &StoredArray) {
     let mut stdout = stdout();
     let (width, height) = size().unwrap();
     stdout.execute(Clear(ClearType::All)).unwrap();
 
     // --- Title ---
     let title = "Array Selected for Sorting!";
-    let title_x = (width.saturating_sub(title.len() as u16)) / 2;
+    let title_x = centered_x(width, title);
     stdout.queue(MoveTo(title_x, height / 2 - 3)).unwrap();
     stdout.queue(SetForegroundColor(Color::Green)).unwrap();
     stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
@@ -715,18 +1829,18 @@ fn show_selection_confirmation(array_data: &ArrayData) {
     stdout.queue(ResetColor).unwrap();
 
     // --- Info ---
-    let info = format!("Selected: \"{}\" (Size: {})", array_data.name, array_data.size);
-    let info_x = (width.saturating_sub(info.len() as u16)) / 2;
+    let info = format!("Selected: \"{}\" (Size: {})", array_data.name(), array_data.size());
+    let info_x = centered_x(width, &info);
     stdout.queue(MoveTo(info_x, height / 2 - 1)).unwrap();
-    stdout.queue(SetForegroundColor(Color::White)).unwrap();
+    stdout.queue(SetForegroundColor(theme::text())).unwrap();
     stdout.queue(Print(info)).unwrap();
     stdout.queue(ResetColor).unwrap();
 
     // --- Instruction ---
     let instruction = "Press any key to continue...";
-    let inst_x = (width.saturating_sub(instruction.len() as u16)) / 2;
+    let inst_x = centered_x(width, instruction);
     stdout.queue(MoveTo(inst_x, height / 2 + 1)).unwrap();
-    stdout.queue(SetForegroundColor(Color::DarkGrey)).unwrap();
+    stdout.queue(SetForegroundColor(theme::dim())).unwrap();
     stdout.queue(Print(instruction)).unwrap();
     stdout.queue(ResetColor).unwrap();
     stdout.flush().unwrap();
@@ -741,31 +1855,100 @@ fn show_selection_confirmation(array_data: &ArrayData) {
     }
 }
 
+// What the details screen shows beyond the statistics, only integer arrays have all of it
+struct DetailExtras {
+    distribution_title: String,
+    counts: Vec<usize>,                                  // values under each histogram bar
+    estimates: Result<Vec<(&'static str, u32)>, String>, // predicted comparisons, or why there are none
+    regenerated: Option<(ArrayData, String, Color)>,     // the array rebuilt from its seed, with its message
+}
+
+// Displays detailed information about a stored array of any kind
+fn show_stored_array_details(stored: &StoredArray) -> Option<ArrayData> {
+    match stored {
+        StoredArray::Integers(array_data) => {
+            let regenerated = array_data.regenerate().map(|array| {
+                let message = regenerate_message(array_data, &array);
+                let color = if array.data == array_data.data { Color::Green } else { Color::Red };
+                (array, message, color)
+            });
+            show_array_details(array_data, integer_extras(array_data, array_data, regenerated))
+        }
+        StoredArray::Signed(array_data) => {
+            // Shifted up so the smallest value is 0, every sort meets the same order, counts and
+            // digits on them since counting and radix sort make the same shift themselves
+            let shifted: Vec<u32> = array_data.data.iter().map(|value| value.offset_from(array_data.min_value)).collect();
+            let shifted = ArrayData::new(shifted, array_data.name.clone());
+            show_array_details(array_data, integer_extras(array_data, &shifted, None))
+        }
+        StoredArray::Reals(array_data) => {
+            let (bucket_width, counts) = array_data.histogram(HISTOGRAM_MAX_BUCKETS);
+            let distribution_title = if bucket_width == 0.0 {
+                "Value Distribution (every value is the same):".to_string()
+            } else {
+                format!("Value Distribution (bars {:.3} wide):", bucket_width)
+            };
+            let extras = DetailExtras {
+                distribution_title,
+                counts,
+                estimates: Err("Predictions need an array of integers".to_string()),
+                regenerated: None,
+            };
+            show_array_details(array_data, extras)
+        }
+    }
+}
+
+// Histogram and sort preview of an integer array, the preview runs on `estimated`
+fn integer_extras<T: IntegerElement>(
+    array_data: &ArrayData<T>,
+    estimated: &ArrayData,
+    regenerated: Option<(ArrayData, String, Color)>,
+) -> DetailExtras {
+    let (bucket_width, counts) = array_data.histogram(HISTOGRAM_MAX_BUCKETS);
+    let distribution_title = if bucket_width == 1 {
+        "Value Distribution (one bar per value):".to_string()
+    } else {
+        format!("Value Distribution ({} values per bar):", bucket_width)
+    };
+    let estimates = if array_data.size > SORT_PREVIEW_MAX_SIZE {
+        Err(format!("Skipped for arrays larger than {} elements", SORT_PREVIEW_MAX_SIZE))
+    } else {
+        Ok(comparison_estimates(estimated))
+    };
+    DetailExtras { distribution_title, counts, estimates, regenerated }
+}
+
 // Displays detailed information about an array
-fn show_array_details(array_data: &ArrayData) {
+// R rebuilds a generated array from its seed and returns the copy once the screen closes
+fn show_array_details<T: SortElement>(array_data: &ArrayData<T>, extras: DetailExtras) -> Option<ArrayData> {
     let mut stdout = stdout();
     let (width, height) = size().unwrap();
     stdout.execute(Clear(ClearType::All)).unwrap();
 
     // --- Title ---
     let title = format!("Array Details: \"{}\"", array_data.name);
-    let title_x = (width.saturating_sub(title.len() as u16)) / 2;
-    stdout.queue(MoveTo(title_x, height / 2 - 8)).unwrap();
+    let title_x = centered_x(width, &title);
+    stdout.queue(MoveTo(title_x, height / 2 - 9)).unwrap();
     stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
     stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
     stdout.queue(Print(title)).unwrap();
     stdout.queue(ResetColor).unwrap();
 
     // --- Details ---
+    let mode = array_data.mode().map_or("-".to_string(), |value| value.to_string());
     let details = [
         format!("Size: {}", array_data.size),
         format!("Min Value: {}", array_data.min_value),
         format!("Max Value: {}", array_data.max_value),
         format!("Range: {} - {}", array_data.min_value, array_data.max_value),
+        format!("Mean: {:.2} | Median: {:.1}", array_data.mean(), array_data.median()),
+        format!("Mode: {} | Std Dev: {:.2}", mode, array_data.std_dev()),
+        array_data.seed.map_or("Seed: - (not generated)".to_string(), |seed| format!("Seed: {}", seed)),
     ];
     for (i, detail) in details.iter().enumerate() {
-        let detail_x = (width.saturating_sub(detail.len() as u16)) / 2;
-        stdout.queue(MoveTo(detail_x, height / 2 - 5 + i as u16)).unwrap();
+        let detail_x = centered_x(width, detail);
+        stdout.queue(MoveTo(detail_x, height / 2 - 7 + i as u16)).unwrap();
         stdout.queue(SetForegroundColor(Color::Cyan)).unwrap();
         stdout.queue(Print(detail)).unwrap();
         stdout.queue(ResetColor).unwrap();
@@ -773,7 +1956,7 @@ fn show_array_details(array_data: &ArrayData) {
 
     // --- Array Content ---
     let array_title = "Array Content:";
-    let array_title_x = (width.saturating_sub(array_title.len() as u16)) / 2;
+    let array_title_x = centered_x(width, array_title);
     stdout.queue(MoveTo(array_title_x, height / 2)).unwrap();
     stdout.queue(SetForegroundColor(Color::Green)).unwrap();
     stdout.queue(Print(array_title)).unwrap();
@@ -781,34 +1964,84 @@ fn show_array_details(array_data: &ArrayData) {
 
     let content = display_array_full(&array_data.data, width as usize - 4);
     for (i, line) in content.iter().enumerate() {
-        let line_x = (width.saturating_sub(line.len() as u16)) / 2;
+        let line_x = centered_x(width, line);
         stdout.queue(MoveTo(line_x, height / 2 + 2 + i as u16)).unwrap();
-        stdout.queue(SetForegroundColor(Color::White)).unwrap();
+        stdout.queue(SetForegroundColor(theme::text())).unwrap();
         stdout.queue(Print(line)).unwrap();
         stdout.queue(ResetColor).unwrap();
     }
 
+    // --- Distribution ---
+    let histogram_y = height / 2 + 3 + content.len() as u16;
+    let labels = (array_data.min_value.to_string(), array_data.max_value.to_string());
+    draw_distribution(&mut stdout, &extras.distribution_title, &extras.counts, labels, width, histogram_y);
+
+    // --- Sort Preview ---
+    let preview_y = histogram_y + HISTOGRAM_ROWS + 3;
+    draw_sort_preview(&mut stdout, &extras.estimates, width, preview_y, height.saturating_sub(3));
+
     // --- Instruction ---
-    let instruction = "Press any key to continue...";
-    let inst_x = (width.saturating_sub(instruction.len() as u16)) / 2;
+    let instruction = if extras.regenerated.is_some() {
+        "R: Regenerate identically | C: Copy to clipboard | M: Copy preview as Markdown | Any other key to continue..."
+    } else {
+        "C: Copy to clipboard | M: Copy preview as Markdown | Any other key to continue..."
+    };
+    let inst_x = (width.saturating_sub(instruction.chars().count() as u16)) / 2;
     stdout.queue(MoveTo(inst_x, height - 2)).unwrap();
-    stdout.queue(SetForegroundColor(Color::DarkGrey)).unwrap();
+    stdout.queue(SetForegroundColor(theme::dim())).unwrap();
     stdout.queue(Print(instruction)).unwrap();
     stdout.queue(ResetColor).unwrap();
     stdout.flush().unwrap();
 
-    // Wait for keypress
+    // Wait for keypress, C copies the values and R rebuilds them, both keep the screen open
+    let mut regenerated = None;
     loop {
         if poll(Duration::from_millis(100)).unwrap_or(false) {
-            if let Ok(_) = read() {
-                break;
-            }
+            let (message, color) = match read() {
+                Ok(Event::Key(key_event)) if key_event.kind != KeyEventKind::Press => continue,
+                Ok(Event::Key(key_event)) if matches!(key_event.code, KeyCode::Char('c') | KeyCode::Char('C')) => {
+                    (copy_values(&array_data.data), Color::Green)
+                }
+                Ok(Event::Key(key_event)) if matches!(key_event.code, KeyCode::Char('m') | KeyCode::Char('M')) => {
+                    let Ok(estimates) = &extras.estimates else {
+                        continue;
+                    };
+                    (copy_markdown(&sort_preview_markdown(estimates), "the sort preview"), Color::Green)
+                }
+                Ok(Event::Key(key_event)) if matches!(key_event.code, KeyCode::Char('r') | KeyCode::Char('R')) => {
+                    let Some((array, message, color)) = &extras.regenerated else {
+                        continue;
+                    };
+                    regenerated = Some(array.clone());
+                    (message.clone(), *color)
+                }
+                _ => break,
+            };
+            let message_x = (width.saturating_sub(message.chars().count() as u16)) / 2;
+            stdout.queue(MoveTo(0, height - 3)).unwrap();
+            stdout.queue(Clear(ClearType::CurrentLine)).unwrap();
+            stdout.queue(MoveTo(message_x, height - 3)).unwrap();
+            stdout.queue(SetForegroundColor(color)).unwrap();
+            stdout.queue(Print(message)).unwrap();
+            stdout.queue(ResetColor).unwrap();
+            stdout.flush().unwrap();
         }
     }
+    regenerated
+}
+
+// Whether the array rebuilt from the seed matches the stored one
+fn regenerate_message(array_data: &ArrayData, regenerated: &ArrayData) -> String {
+    let seed = array_data.seed.unwrap_or_default();
+    if regenerated.data == array_data.data {
+        format!("✓ Seed {} gives the same {} values, added as \"{}\"", seed, regenerated.size, regenerated.name)
+    } else {
+        format!("✗ Seed {} gives different values, the array was changed since, added as \"{}\"", seed, regenerated.name)
+    }
 }
 
 // Prompts for confirmation before deleting an array
-fn confirm_delete(array_data: &ArrayData) -> bool {
+fn confirm_delete(array_data: &StoredArray) -> bool {
     let mut stdout = stdout();
     let (width, height) = size().unwrap();
 
@@ -825,10 +2058,10 @@ fn confirm_delete(array_data: &ArrayData) -> bool {
         stdout.queue(ResetColor).unwrap();
 
         // --- Question ---
-        let question = format!("Delete array \"{}\"?", array_data.name);
+        let question = format!("Delete array \"{}\"?", array_data.name());
         let question_x = (width.saturating_sub(question.len() as u16)) / 2;
         stdout.queue(MoveTo(question_x, height / 2 - 2)).unwrap();
-        stdout.queue(SetForegroundColor(Color::White)).unwrap();
+        stdout.queue(SetForegroundColor(theme::text())).unwrap();
         stdout.queue(Print(question)).unwrap();
         stdout.queue(ResetColor).unwrap();
 
@@ -836,7 +2069,7 @@ fn confirm_delete(array_data: &ArrayData) -> bool {
         let options = "Press Y to confirm, N to cancel";
         let options_x = (width.saturating_sub(options.len() as u16)) / 2;
         stdout.queue(MoveTo(options_x, height / 2)).unwrap();
-        stdout.queue(SetForegroundColor(Color::DarkGrey)).unwrap();
+        stdout.queue(SetForegroundColor(theme::dim())).unwrap();
         stdout.queue(Print(options)).unwrap();
         stdout.queue(ResetColor).unwrap();
         stdout.flush().unwrap();
@@ -858,7 +2091,102 @@ fn confirm_delete(array_data: &ArrayData) -> bool {
 }
 
 // Returns a short preview of the array for the list view
-fn display_array_preview(arr: &[u32]) -> String {
+// Marks an array for multi-delete, or unmarks it if it was already marked
+fn toggle_mark(marked: &mut HashSet<usize>, index: usize) {
+    if !marked.remove(&index) {
+        marked.insert(index);
+    }
+}
+
+// Draws predicted comparison counts of every sort in three columns, between `y` and `max_y`
+// Draws a histogram of the values, uniform data gives an even skyline and clustered data a few peaks
+fn draw_distribution(stdout: &mut std::io::Stdout, title: &str, counts: &[usize], labels: (String, String), width: u16, y: u16) {
+    let title_x = (width.saturating_sub(title.len() as u16)) / 2;
+    stdout.queue(MoveTo(title_x, y)).unwrap();
+    stdout.queue(SetForegroundColor(Color::Green)).unwrap();
+    stdout.queue(Print(title)).unwrap();
+    stdout.queue(ResetColor).unwrap();
+
+    let rows = VisualizerDrawer::histogram_rows(counts, HISTOGRAM_ROWS as usize);
+    let histogram_width = rows.first().map_or(0, |row| row.chars().count()) as u16;
+    let x = (width.saturating_sub(histogram_width)) / 2;
+    // Queued on the same terminal output, flushed with the rest of the screen
+    VisualizerDrawer::new().draw_histogram(&rows, x, y + 1);
+
+    // Smallest value under the first bar, largest under the last
+    let (min_label, max_label) = labels;
+    stdout.queue(MoveTo(x, y + 1 + HISTOGRAM_ROWS)).unwrap();
+    stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+    stdout.queue(Print(min_label)).unwrap();
+    stdout.queue(MoveTo((x + histogram_width).saturating_sub(max_label.len() as u16), y + 1 + HISTOGRAM_ROWS)).unwrap();
+    stdout.queue(Print(max_label)).unwrap();
+    stdout.queue(ResetColor).unwrap();
+}
+
+fn draw_sort_preview(stdout: &mut std::io::Stdout, estimates: &Result<Vec<(&str, u32)>, String>, width: u16, y: u16, max_y: u16) {
+    if y >= max_y {
+        return;
+    }
+
+    let title = "Sort Preview (predicted comparisons):";
+    let title_x = (width.saturating_sub(title.len() as u16)) / 2;
+    stdout.queue(MoveTo(title_x, y)).unwrap();
+    stdout.queue(SetForegroundColor(Color::Green)).unwrap();
+    stdout.queue(Print(title)).unwrap();
+    stdout.queue(ResetColor).unwrap();
+
+    let estimates = match estimates {
+        Ok(estimates) => estimates,
+        Err(message) => {
+            let message_x = (width.saturating_sub(message.len() as u16)) / 2;
+            stdout.queue(MoveTo(message_x, y + 1)).unwrap();
+            stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+            stdout.queue(Print(message)).unwrap();
+            stdout.queue(ResetColor).unwrap();
+            return;
+        }
+    };
+
+    let column_width = 22u16;
+    let columns = 3u16;
+    let rows = (estimates.len() as u16).div_ceil(columns);
+    let start_x = (width.saturating_sub(column_width * columns)) / 2;
+    for (i, (name, comparisons)) in estimates.iter().enumerate() {
+        let row = i as u16 % rows;
+        let column = i as u16 / rows;
+        let row_y = y + 1 + row;
+        if row_y >= max_y {
+            continue;
+        }
+        stdout.queue(MoveTo(start_x + column * column_width, row_y)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Cyan)).unwrap();
+        stdout.queue(Print(format!("{:<10}", name))).unwrap();
+        stdout.queue(SetForegroundColor(theme::text())).unwrap();
+        stdout.queue(Print(format!("{:>8}", comparisons))).unwrap();
+        stdout.queue(ResetColor).unwrap();
+    }
+}
+
+// The sort preview as a Markdown table, one row per algorithm
+fn sort_preview_markdown(estimates: &[(&str, u32)]) -> String {
+    let rows: Vec<Vec<String>> = estimates
+        .iter()
+        .map(|(name, comparisons)| vec![name.to_string(), comparisons.to_string()])
+        .collect();
+    markdown_table(&["Algorithm", "Predicted comparisons"], &rows)
+}
+
+// Asks once before deleting every marked array
+fn confirm_delete_marked(count: usize) -> bool {
+    let message = format!(
+        "Delete {} marked array{}?\n\nOnly the last removed one can be restored\nwith 'Undo Delete'.",
+        count,
+        if count == 1 { "" } else { "s" }
+    );
+    show_question("Confirm Delete", &message, vec!["Yes", "No"]) == 0
+}
+
+fn display_array_preview<T: SortElement>(arr: &[T]) -> String {
     if arr.len() <= 8 {
         format!("[{}]", arr.iter().map(|x| format!("{:2}", x)).collect::<Vec<_>>().join(", "))
     } else {
@@ -868,7 +2196,7 @@ fn display_array_preview(arr: &[u32]) -> String {
 }
 
 // Returns the full array content, split into lines if necessary
-fn display_array_full(arr: &[u32], max_width: usize) -> Vec<String> {
+fn display_array_full<T: SortElement>(arr: &[T], max_width: usize) -> Vec<String> {
     let mut lines = Vec::new();
     let mut current_line = String::from("[");
     let mut first_on_line = true;
@@ -893,4 +2221,228 @@ fn display_array_full(arr: &[u32], max_width: usize) -> Vec<String> {
         lines.push(current_line);
     }
     lines
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::enums::SelectionState;
+
+    fn array(values: &[u32]) -> ArrayData {
+        ArrayData::new(values.to_vec(), "Test".to_string())
+    }
+
+    #[test]
+    fn sort_range_parses_half_open_bounds() {
+        assert_eq!(parse_sort_range("3 8", 10), Ok(Some(3..8)));
+        assert_eq!(parse_sort_range("3..8", 10), Ok(Some(3..8)));
+        assert_eq!(parse_sort_range(" 0, 4 ", 10), Ok(Some(0..4)));
+        // Empty input and the whole array sort everything
+        assert_eq!(parse_sort_range("", 10), Ok(None));
+        assert_eq!(parse_sort_range("0 10", 10), Ok(None));
+        assert!(parse_sort_range("3 11", 10).is_err());
+        assert!(parse_sort_range("4 5", 10).is_err());
+        assert!(parse_sort_range("8 3", 10).is_err());
+        assert!(parse_sort_range("3", 10).is_err());
+    }
+
+    #[test]
+    fn sort_slice_keeps_the_rest_in_place() {
+        let mut data = array(&[9, 8, 7, 6, 5]);
+        assert!(data.sort_slice().is_none());
+        data.sort_range = Some(1..4);
+        let (slice, fixed) = data.sort_slice().unwrap();
+        assert_eq!(slice.data, vec![8, 7, 6]);
+        assert_eq!(fixed.start(), 1);
+        assert_eq!(fixed.full_array(&[6, 7, 8]), vec![9, 6, 7, 8, 5]);
+        let states = fixed.full_states(&[SelectionState::Sorted; 3]);
+        assert!(states[0] == SelectionState::OutsideRange && states[4] == SelectionState::OutsideRange);
+        assert!(states[1..4] == [SelectionState::Sorted; 3]);
+    }
+
+    #[test]
+    fn arrays_round_trip_through_a_file() {
+        let path = std::env::temp_dir().join(format!("togisoft_test_arrays_{}.json", std::process::id()));
+        let mut manager = ArrayManager::new();
+        manager.add_array(array(&[3, 1, 2]));
+        manager.add_array(ArrayData::<i32>::new(vec![-4, 7], "Signed".to_string()));
+        manager.add_array(ArrayData::<f64>::new(vec![2.5, 1.0], "Reals".to_string()));
+        manager.select_array(1);
+        manager.save_to_file(&path).unwrap();
+
+        let mut loaded = ArrayManager::new();
+        loaded.add_array(array(&[9]));
+        loaded.select_array(0);
+        assert_eq!(loaded.load_from_file(&path), Ok(3));
+        assert!(loaded.get_selected_array().is_none());
+        let StoredArray::Integers(integers) = &loaded.arrays[0] else {
+            panic!("whole numbers load as integers");
+        };
+        assert_eq!(integers.data, vec![3, 1, 2]);
+        assert_eq!((integers.min_value, integers.max_value, integers.size), (1, 3, 3));
+        assert!(matches!(&loaded.arrays[1], StoredArray::Signed(signed) if signed.min_value == -4));
+        assert!(matches!(&loaded.arrays[2], StoredArray::Reals(reals) if reals.data == [2.5, 1.0]));
+
+        // Damaged, overflowing and overly precise entries are skipped, a file that is not a list is an error
+        fs::write(
+            &path,
+            r#"[{"name": "Ok", "data": [5, 6]}, {"name": "Bad"}, 4, {"name": "Big", "data": [5000000000]},
+                {"name": "Fine", "data": [1.2345]}, {"name": "Low", "data": [-3000000000]}]"#,
+        )
+        .unwrap();
+        assert_eq!(loaded.load_from_file(&path), Ok(1));
+        assert_eq!(loaded.arrays[0].name(), "Ok");
+        fs::write(&path, "{}").unwrap();
+        assert!(loaded.load_from_file(&path).is_err());
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn statistics_on_known_input() {
+        let data = array(&[2, 4, 4, 4, 5, 5, 7, 9]);
+        assert_eq!(data.mean(), 5.0);
+        assert_eq!(data.median(), 4.5);
+        assert_eq!(data.mode(), Some(4));
+        assert_eq!(data.std_dev(), 2.0);
+    }
+
+    #[test]
+    fn value_range_needs_min_below_max_within_the_ceiling() {
+        assert_eq!(parse_value_range("1", "100"), Ok(1..=100));
+        assert_eq!(parse_value_range(" 0", "9999 "), Ok(0..=9999));
+        assert!(parse_value_range("5", "5").is_err());
+        assert!(parse_value_range("50", "10").is_err());
+        assert!(parse_value_range("1", "10000").is_err());
+        assert!(parse_value_range("", "10").is_err());
+
+        let generated = ArrayData::generate_in(30, 7, 1000..=1010, "Wide".to_string());
+        assert!(generated.data.iter().all(|value| (1000..=1010).contains(value)));
+        assert_eq!(generated.regenerate().unwrap().data, generated.data);
+    }
+
+    #[test]
+    fn presets_have_their_distribution() {
+        for size in [2, 10, 50] {
+            let sorted = generate_preset(size, PresetKind::Sorted);
+            assert_eq!(sorted.len(), size);
+            assert!(sorted.is_sorted());
+            assert!(generate_preset(size, PresetKind::Reversed).windows(2).all(|pair| pair[0] >= pair[1]));
+            // A few swaps leave most elements in place
+            let mut nearly = generate_preset(size, PresetKind::NearlySorted);
+            let out_of_order = nearly.windows(2).filter(|pair| pair[0] > pair[1]).count();
+            assert!(out_of_order <= 4 * (size / 10).max(1), "{:?}", nearly);
+            nearly.sort_unstable();
+            assert_eq!(nearly.len(), size);
+            let mut unique = generate_preset(size, PresetKind::FewUnique);
+            unique.sort_unstable();
+            unique.dedup();
+            assert!(unique.len() <= FEW_UNIQUE_VALUES);
+        }
+    }
+
+    #[test]
+    fn edited_values_rebuild_the_array() {
+        let mut original = array(&[5, 9, 2]);
+        original.sort_range = Some(0..2);
+        let original = StoredArray::from(original);
+        let texts = |values: &[&str]| values.iter().map(|value| value.to_string()).collect::<Vec<_>>();
+        let Ok(StoredArray::Integers(edited)) = edited_array(&original, &texts(&["5", "40", "2"])) else {
+            panic!("whole numbers stay integers");
+        };
+        assert_eq!(edited.data, vec![5, 40, 2]);
+        assert_eq!((edited.min_value, edited.max_value, edited.size), (2, 40, 3));
+        assert_eq!((edited.name.as_str(), edited.sort_range.clone()), ("Test", Some(0..2)));
+
+        let signed = edited_array(&original, &texts(&["-1", "3", "2"])).unwrap();
+        assert!(matches!(&signed, StoredArray::Signed(signed) if signed.min_value == -1));
+        assert_eq!(signed.value_texts(), texts(&["-1", "3", "2"]));
+        assert!(edited_array(&original, &texts(&["5", "", "2"])).is_err());
+        assert!(edited_array(&original, &texts(&["5", "1-", "2"])).is_err());
+        assert!(edited_array(&original, &texts(&["-1", "2.5", "2"])).is_err());
+
+        let mut manager = ArrayManager::new();
+        manager.add_array(original);
+        manager.replace_array(0, edited);
+        manager.replace_array(3, array(&[1]));
+        assert_eq!(manager.arrays[0].value_texts(), texts(&["5", "40", "2"]));
+        assert_eq!(manager.len(), 1);
+    }
+
+    #[test]
+    fn random_value_is_an_element() {
+        let data = array(&[4, 8, 15]);
+        assert!((0..20).all(|_| data.data.contains(&data.random_value())));
+        assert_eq!(array(&[]).random_value(), 0);
+    }
+
+    #[test]
+    fn median_of_odd_size_is_middle_value() {
+        assert_eq!(array(&[9, 1, 5]).median(), 5.0);
+    }
+
+    #[test]
+    fn mode_prefers_smallest_value_on_tie() {
+        assert_eq!(array(&[7, 3, 7, 3, 1]).mode(), Some(3));
+    }
+
+    #[test]
+    fn histogram_buckets_cover_min_to_max() {
+        let (bucket_width, counts) = array(&[1, 2, 2, 3, 5]).histogram(20);
+        assert_eq!(bucket_width, 1);
+        assert_eq!(counts, [1, 2, 1, 0, 1]);
+
+        let (bucket_width, counts) = array(&[1, 50, 51, 100]).histogram(10);
+        assert_eq!(bucket_width, 10);
+        assert_eq!(counts.len(), 10);
+        assert_eq!(counts[0], 1);
+        assert_eq!(counts[4] + counts[5], 2);
+        assert_eq!(counts[9], 1);
+    }
+
+    #[test]
+    fn statistics_on_empty_array() {
+        let data = array(&[]);
+        assert_eq!(data.mean(), 0.0);
+        assert_eq!(data.median(), 0.0);
+        assert_eq!(data.mode(), None);
+        assert_eq!(data.std_dev(), 0.0);
+    }
+
+    #[test]
+    fn decimal_points_make_an_array_of_real_numbers() {
+        let Some(StoredArray::Reals(reals)) = StoredArray::parse("2.5, 1, 0.75", "Reals".to_string()) else {
+            panic!("not parsed as real numbers");
+        };
+        assert_eq!((reals.min_value, reals.max_value, reals.median()), (0.75, 2.5, 1.0));
+        assert_eq!(reals.histogram(2), (0.875, vec![2, 1]));
+        assert!(matches!(StoredArray::parse("5 3 8", String::new()), Some(StoredArray::Integers(_))));
+        assert!(StoredArray::parse("2.5, x", String::new()).is_none());
+        assert_eq!(accept_size_or_values("2.5, 1", &mut Vec::new()), Some(2));
+        assert!(is_value("0.5") && !is_value("1.2.3"));
+    }
+
+    #[test]
+    fn minus_signs_make_an_array_of_signed_integers() {
+        let Some(StoredArray::Signed(signed)) = StoredArray::parse("-5, 3, -12, 0", "Signed".to_string()) else {
+            panic!("not parsed as signed integers");
+        };
+        assert_eq!((signed.min_value, signed.max_value), (-12, 3));
+        assert_eq!(signed.histogram(20), (1, [vec![1], vec![0; 6], vec![1, 0, 0, 0, 0, 1, 0, 0, 1]].concat()));
+        assert!(StoredArray::parse("-2.5, 1", String::new()).is_none());
+        assert!(is_value("-7") && !is_value("7-"));
+    }
+
+    #[test]
+    fn generated_array_rebuilds_from_its_seed() {
+        let generated = ArrayData::generate(10, 42, "Seeded".to_string());
+        let rebuilt = generated.regenerate().unwrap();
+        assert_eq!(rebuilt.data, generated.data);
+        assert_eq!(rebuilt.seed, Some(42));
+        assert!(regenerate_message(&generated, &rebuilt).starts_with("✓ Seed 42"));
+
+        let mut edited = generated.clone();
+        edited.data[0] += 1;
+        assert!(regenerate_message(&edited, &rebuilt).starts_with("✗ Seed 42"));
+        assert!(array(&[3, 1, 2]).regenerate().is_none());
+    }
+}
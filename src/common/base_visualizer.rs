@@ -1,13 +1,84 @@
-use crate::common::{SelectionState, TeachingQuestion};
+use crate::common::algorithm_info::AlgorithmInfo;
+use crate::common::common_visualizer::VisualizerDrawer;
+use crate::common::element::SortElement;
+use crate::common::{RenderMode, SelectionState, SortOrder, TeachingQuestion, ValueFormat};
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::Range;
+use std::rc::Rc;
 use std::time::Duration;
 
-// Base trait that all visualizers must implement
-pub trait SortVisualizer {
+// Orders two values, every comparison a sort makes goes through one of these
+// Shared rather than owned so a snapshot of the state can keep using it
+pub type Comparator<T = u32> = Rc<dyn Fn(T, T) -> Ordering>;
+
+// Most steps that can be taken back, the oldest snapshot is dropped beyond this
+pub const MAX_STEP_HISTORY: usize = 500;
+
+// Copies of a whole visualizer taken before each step, so step_back can restore them
+// Implemented for every visualizer that derives Clone
+pub trait Snapshot {
+    fn snapshot(&self) -> Box<dyn Any>;
+    fn restore(&mut self, snapshot: Box<dyn Any>);
+}
+
+impl<T: Clone + 'static> Snapshot for T {
+    fn snapshot(&self) -> Box<dyn Any> {
+        Box::new(self.clone())
+    }
+
+    fn restore(&mut self, snapshot: Box<dyn Any>) {
+        if let Ok(snapshot) = snapshot.downcast::<T>() {
+            *self = *snapshot;
+        }
+    }
+}
+
+// Snapshots of the steps taken so far, newest last
+// Cloning gives an empty history, so a snapshot never holds the snapshots before it
+#[derive(Default)]
+pub struct StepHistory {
+    snapshots: VecDeque<Box<dyn Any>>,
+}
+
+impl Clone for StepHistory {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl StepHistory {
+    fn push(&mut self, snapshot: Box<dyn Any>) {
+        if self.snapshots.len() == MAX_STEP_HISTORY {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(snapshot);
+    }
+
+    fn pop(&mut self) -> Option<Box<dyn Any>> {
+        self.snapshots.pop_back()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+    }
+}
+
+// Base trait that all visualizers must implement, over the element type they sort
+pub trait SortVisualizer<T: SortElement = u32>: Snapshot {
     // Returns the current state of the array
-    fn get_array(&self) -> &[u32];
+    fn get_array(&self) -> &[T];
+
+    // Returns the array for in-place edits
+    fn get_array_mut(&mut self) -> &mut [T];
 
     // Returns the original array
-    fn get_original_array(&self) -> &[u32];
+    fn get_original_array(&self) -> &[T];
 
     // Returns the selection states
     fn get_states(&self) -> &[SelectionState];
@@ -17,6 +88,11 @@ pub trait SortVisualizer {
     fn get_swaps(&self) -> u32;
     fn get_speed(&self) -> Duration;
 
+    // Returns the single-position writes, see `VisualizerState::moves`
+    fn get_moves(&self) -> u32 {
+        self.state().moves
+    }
+
     // Returns status information
     fn is_running(&self) -> bool;
     fn is_paused(&self) -> bool;
@@ -33,6 +109,13 @@ pub trait SortVisualizer {
     // Advances one step
     fn step(&mut self) -> bool;
 
+    // Advances one step and reports what it did, told apart by the operation counters it advanced
+    fn step_with_kind(&mut self) -> (bool, StepKind) {
+        let before = self.state().operation_counts();
+        let more = self.step();
+        (more, self.state().step_kind_since(before))
+    }
+
     // Resets the visualizer
     fn reset(&mut self);
 
@@ -42,15 +125,143 @@ pub trait SortVisualizer {
     // Returns a visualizer-specific title
     fn get_title(&self) -> &str;
 
+    // Returns the algorithm name taken from the title, e.g. "In-Place Merge Sort"
+    fn display_name(&self) -> String {
+        let name = self
+            .get_title()
+            .trim_start_matches("TOGISOFT ")
+            .trim_end_matches(" VISUALIZER");
+        title_case(name)
+    }
+
     // Returns a visualizer-specific intro text
     fn get_intro_text(&self) -> &str;
 
-    // Returns visualizer-specific legend items
-    fn get_legend_items(&self) -> Vec<(&str, crossterm::style::Color)>;
+    // Returns visualizer-specific legend items, each drawn in the color theme's color of its state
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)>;
 
     // Returns the current operation description
     fn get_current_operation(&self) -> String;
 
+    // Returns why the current step happens, shown under the operation when the hint is on
+    fn get_step_hint(&self) -> Option<&str> {
+        None
+    }
+
+    // Returns the elements that would change the outcome of the next comparison if swapped
+    // with the element it is about, empty unless a comparison comes next
+    fn decision_changers(&self) -> Vec<usize> {
+        Vec::new()
+    }
+
+    // Returns the subrange length at or below which a hybrid sort switches to insertion sort,
+    // None for algorithms without such a threshold
+    fn insertion_cutoff(&self) -> Option<usize> {
+        None
+    }
+
+    // Changes the insertion sort threshold for the rest of the run, [ and ] tune it live
+    fn set_insertion_cutoff(&mut self, _cutoff: usize) {}
+
+    // Returns the shared visualizer state
+    fn state(&self) -> &VisualizerState<T>;
+    fn state_mut(&mut self) -> &mut VisualizerState<T>;
+
+    // Returns the name saved as the last visualizer, e.g. "BubbleSort"
+    fn settings_key(&self) -> &str;
+
+    // Handles visualizer-specific input before the common controls
+    // Returns true if the key was consumed (e.g. bubble sort's swap confirmation)
+    fn handle_extra_input(&mut self, _key_code: crossterm::event::KeyCode) -> bool {
+        false
+    }
+
+    // Returns true while a visualizer-specific prompt blocks auto-stepping
+    fn has_extra_state(&self) -> bool {
+        false
+    }
+
+    // Called after the teaching mode is toggled
+    fn on_teaching_mode_changed(&mut self) {}
+
+    // Saves the visualizer as it is before a step, the runner calls this before every step the user sees
+    fn record_step(&mut self) {
+        let snapshot = self.snapshot();
+        self.state_mut().step_history.push(snapshot);
+    }
+
+    // Returns to the visualizer as it was before the last recorded step, false if there is none
+    // The array, highlights, phase and counters go back, view settings changed since stay as they are
+    fn step_back(&mut self) -> bool {
+        let mut history = std::mem::take(&mut self.state_mut().step_history);
+        let Some(snapshot) = history.pop() else {
+            return false;
+        };
+        let mut state = self.state().clone();
+        self.restore(snapshot);
+        state.rewind_to(self.state());
+        state.step_history = history;
+        *self.state_mut() = state;
+        true
+    }
+
+    // Called once the algorithm has finished
+    fn on_completed(&mut self) {
+        self.mark_all_sorted();
+    }
+
+    // Replaces a single value mid-run; override to recompute fields derived from the array
+    // Returns an error message if the visualizer cannot accept the edit right now
+    fn edit_value(&mut self, index: usize, value: T) -> Result<(), String> {
+        match self.get_array_mut().get_mut(index) {
+            Some(slot) => {
+                *slot = value;
+                Ok(())
+            }
+            None => Err(format!("Index {} is out of range", index)),
+        }
+    }
+
+    // Compares array[i] with array[j] using the active ordering and counts the comparison
+    fn compare(&mut self, i: usize, j: usize) -> Ordering {
+        let (a, b) = (self.get_array()[i], self.get_array()[j]);
+        self.state_mut().compare_values(a, b)
+    }
+
+    // Returns false for algorithms that order by the values themselves (counting, radix, ...)
+    // and therefore always sort ascending
+    fn supports_custom_order(&self) -> bool {
+        true
+    }
+
+    // Returns false if the current values are not the ones being sorted, e.g. while the output
+    // is written over the input; --verify then leaves the run alone (counting sort, ...)
+    fn verifiable(&self) -> bool {
+        true
+    }
+
+    // Returns the index ranges the algorithm guarantees are in their final place at this step,
+    // drawn as a bracket under the chart and checked by --verify. The default collects the runs
+    // marked Sorted; override to state the invariant directly, or when Sorted marks elements
+    // that may still move (insertion sort's prefix, a found search target, ...)
+    fn sorted_regions(&self) -> Vec<Range<usize>> {
+        sorted_runs(self.get_states())
+    }
+
+    // Returns the complexities, stability and memory use shown on the cheat sheet card
+    fn info(&self) -> AlgorithmInfo;
+
+    // Returns the name of the current phase, read from the "Phase: ..." statistics line
+    // Override for visualizers whose statistics show the phase under another name
+    fn phase_label(&self) -> Option<String> {
+        self.get_statistics_strings()
+            .into_iter()
+            .find_map(|line| line.strip_prefix("Phase: ").map(str::to_string))
+    }
+
+    // Draws visualizer-specific extras such as buckets or piles
+    fn draw_extra(&self, _drawer: &mut VisualizerDrawer, _width: u16, _height: u16) {}
+
     // Returns the status message
     fn get_status(&self) -> &str {
         if self.is_completed() {
@@ -83,6 +294,7 @@ pub trait SortVisualizer {
             format!("Array Size: {}", self.get_array().len()),
             format!("Comparisons: {}", self.get_comparisons()),
             format!("Swaps: {}", self.get_swaps()),
+            format!("Moves: {}", self.get_moves()),
             format!("Speed: {}ms", self.get_speed().as_millis()),
             format!("Progress: {:.1}%", self.get_progress()),
             if self.is_teaching_mode() {
@@ -95,49 +307,323 @@ pub trait SortVisualizer {
     }
 }
 
+// Contiguous runs of elements marked Sorted, e.g. [S, S, N, S] -> [0..2, 3..4]
+pub fn sorted_runs(states: &[SelectionState]) -> Vec<Range<usize>> {
+    let mut runs = Vec::new();
+    let mut start = None;
+    for (i, state) in states.iter().enumerate() {
+        match (*state == SelectionState::Sorted, start) {
+            (true, None) => start = Some(i),
+            (false, Some(run_start)) => {
+                runs.push(run_start..i);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(run_start) = start {
+        runs.push(run_start..states.len());
+    }
+    runs
+}
+
+// "IN-PLACE MERGE SORT" -> "In-Place Merge Sort"
+fn title_case(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut capitalize = true;
+    for c in text.chars() {
+        if capitalize {
+            result.extend(c.to_uppercase());
+        } else {
+            result.extend(c.to_lowercase());
+        }
+        capitalize = c == ' ' || c == '-';
+    }
+    result
+}
+
+// Shortest step delay auto-play may use while reduced motion is on (ms)
+pub const REDUCED_MOTION_MIN_SPEED: u64 = 600;
+
+// What a step did in terms of the operation counters, a step that changed the array counts as a change
+// even if it compared too
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StepKind {
+    Change,     // swapped or moved at least one value
+    Comparison, // only compared values
+    Other,      // neither, e.g. moving to the next pass
+}
+
+// A lecture note attached to a bar, drawn above it as text, an arrow or both
+#[derive(Clone, Debug, PartialEq)]
+pub struct Annotation<T = u32> {
+    pub text: String,
+    pub value: T, // value the note was written on, followed when the element moves
+    pub arrow: bool, // an arrow points down at the bar, with or without text
+}
+
+// Elements around a sorted slice that stay in place, drawn dimmed on either side of it
+#[derive(Clone, Debug, PartialEq)]
+pub struct FixedRange<T = u32> {
+    pub before: Vec<T>, // elements left of the slice
+    pub after: Vec<T>,  // elements right of the slice
+}
+
+impl<T: SortElement> FixedRange<T> {
+    // Index in the full array of the slice's first element
+    pub fn start(&self) -> usize {
+        self.before.len()
+    }
+
+    // The full array with `slice` in its place
+    pub fn full_array(&self, slice: &[T]) -> Vec<T> {
+        [self.before.as_slice(), slice, self.after.as_slice()].concat()
+    }
+
+    // States for the full array, the fixed elements marked as outside the range
+    pub fn full_states(&self, states: &[SelectionState]) -> Vec<SelectionState> {
+        let mut full = vec![SelectionState::OutsideRange; self.before.len()];
+        full.extend_from_slice(states);
+        full.extend(std::iter::repeat_n(SelectionState::OutsideRange, self.after.len()));
+        full
+    }
+}
+
 // Common visualizer behaviors
-pub struct VisualizerState {
+#[derive(Clone)]
+pub struct VisualizerState<T: SortElement = u32> {
     pub is_running: bool,
     pub is_paused: bool,
     pub completed: bool,
     pub teaching_mode: bool,
+    pub auto_pause_on_swap: bool,
+    pub reduced_motion: bool,
+    pub render_mode: RenderMode, // how the drawer shows the array, cycled with V
+    pub value_format: ValueFormat,
+    pub hide_values: bool, // bars are drawn alike and unlabeled, inspecting and copying are off
     pub speed: Duration,
+    // Operation counters, counted the same way by every algorithm so they can be compared:
+    // a comparison orders two values, a swap exchanges the values of two positions,
+    // and a move writes one value into one position of the array or of a buffer (a shift,
+    // a placement, a copy to or from scratch space). A held key written back counts as a move
+    // only if it left its position.
     pub comparisons: u32,
     pub swaps: u32,
+    pub moves: u32,
     pub awaiting_question: Option<usize>,
     pub questions: Vec<TeachingQuestion>,
+    pub sort_order: SortOrder,
+    comparator: Comparator<T>,
+    pub watched_pair: Option<(T, T)>,
+    pub watched_comparisons: u32,
+    pub annotations: HashMap<usize, Annotation<T>>, // notes keyed by the index they are drawn at
+    pub annotations_follow: bool, // notes move with their element instead of staying at the index
+    pub show_sorted_regions: bool, // draw the sorted regions bracket under the chart
+    pub victory_sweep: Option<usize>, // sorted bar lit by the completion animation, set by the runner every frame
+    pub progress_milestones: Vec<u32>, // percentages auto-play pauses at once, in ascending order
+    pub compare_delay: u32, // auto-play waits this percentage of the speed after a comparison step
+    pub change_delay: u32, // and this percentage after a step that swapped or moved values
+    pub show_step_hint: bool, // explain why the current step happens under the operation line
+    pub fixed_range: Option<FixedRange<T>>, // only a slice of the array is sorted, the rest is drawn around it
+    pub auto_reset_after: Option<Duration>, // a completed run replays after this dwell, None waits for a key
+    pub show_decision_changers: bool, // while paused, mark the elements that would flip the next comparison
+    pub show_counter_overlay: bool, // the counters are drawn in large figures over the chart, for recordings
+    pub current_streak: u32, // teaching questions answered right in a row
+    pub best_streak: u32, // longest streak with this algorithm, kept in the settings by the runner
+    pub max_steps: Option<u64>, // auto-play pauses each time this many more steps pass without completing
+    pub steps_taken: u64, // steps of this run, auto-play and manual
+    pub seed: Option<u64>, // seed the array was generated from, recorded in the run history
+    pub practice: bool, // practice mode: the name, intro and telltale statistics stay hidden until the user guesses
+    pub step_history: StepHistory, // snapshots step_back restores, cleared by a reset
+    milestones_passed: usize, // milestones progress already crossed in this run
 }
 
-impl VisualizerState {
+impl<T: SortElement> VisualizerState<T> {
     // Creates a new VisualizerState
     pub fn new(questions: Vec<TeachingQuestion>, default_speed: Duration) -> Self {
         Self {
             is_running: false,
             is_paused: false,
             completed: false,
-            teaching_mode: true,
+            // Visualizers enable this from Settings, it is never switched on implicitly
+            teaching_mode: false,
+            auto_pause_on_swap: false,
+            reduced_motion: false,
+            render_mode: RenderMode::Bars,
+            value_format: ValueFormat::Decimal,
+            hide_values: false,
             speed: default_speed,
             comparisons: 0,
             swaps: 0,
+            moves: 0,
             awaiting_question: None,
             questions,
+            sort_order: SortOrder::Ascending,
+            comparator: SortOrder::Ascending.comparator(),
+            watched_pair: None,
+            watched_comparisons: 0,
+            annotations: HashMap::new(),
+            annotations_follow: true,
+            show_sorted_regions: true,
+            victory_sweep: None,
+            progress_milestones: Vec::new(),
+            milestones_passed: 0,
+            compare_delay: 100,
+            change_delay: 100,
+            show_step_hint: false,
+            fixed_range: None,
+            auto_reset_after: None,
+            show_decision_changers: false,
+            show_counter_overlay: false,
+            current_streak: 0,
+            best_streak: 0,
+            max_steps: None,
+            steps_taken: 0,
+            seed: None,
+            practice: false,
+            step_history: StepHistory::default(),
         }
     }
 
-    // Increases the speed
+    // A value as the user sees it, in the chosen value format, e.g. "2A" in hex
+    pub fn value_text(&self, value: T) -> String {
+        value.format(self.value_format)
+    }
+
+    // Compares two values using the active ordering and counts the comparison
+    pub fn compare_values(&mut self, a: T, b: T) -> Ordering {
+        self.comparisons += 1;
+        if self.watched_pair.is_some_and(|(x, y)| (a, b) == (x, y) || (a, b) == (y, x)) {
+            self.watched_comparisons += 1;
+        }
+        (self.comparator)(a, b)
+    }
+
+    // Elements that would flip the decision about `array[current]` if they were swapped into its place
+    // `decides` turns the ordering of a value at `current` against `other` into the decision,
+    // the indices in `skip` take part in the comparison and are left out. Nothing is counted.
+    pub fn decision_changers(
+        &self,
+        array: &[T],
+        current: usize,
+        other: T,
+        skip: &[usize],
+        decides: impl Fn(Ordering) -> bool,
+    ) -> Vec<usize> {
+        let Some(&value) = array.get(current) else {
+            return Vec::new();
+        };
+        let decision = decides((self.comparator)(value, other));
+        (0..array.len())
+            .filter(|k| !skip.contains(k) && decides((self.comparator)(array[*k], other)) != decision)
+            .collect()
+    }
+
+    // Starts counting comparisons between the two values, in either order
+    pub fn watch_pair(&mut self, a: T, b: T) {
+        self.watched_pair = Some((a, b));
+        self.watched_comparisons = 0;
+    }
+
+    // Stops the value-pair watch
+    pub fn clear_watch(&mut self) {
+        self.watched_pair = None;
+        self.watched_comparisons = 0;
+    }
+
+    // Sets the note on `index`, empty text removes it unless an arrow is left
+    pub fn annotate(&mut self, index: usize, value: T, text: &str) {
+        let text = text.trim();
+        let arrow = self.annotations.get(&index).is_some_and(|annotation| annotation.arrow);
+        if text.is_empty() && !arrow {
+            self.annotations.remove(&index);
+        } else {
+            self.annotations.insert(index, Annotation { text: text.to_string(), value, arrow });
+        }
+    }
+
+    // Puts an arrow on `index` or takes it away, a note without text or arrow is removed
+    pub fn toggle_arrow(&mut self, index: usize, value: T) {
+        let text = self.annotations.get(&index).map_or(String::new(), |annotation| annotation.text.clone());
+        let arrow = !self.annotations.get(&index).is_some_and(|annotation| annotation.arrow);
+        if text.is_empty() && !arrow {
+            self.annotations.remove(&index);
+        } else {
+            self.annotations.insert(index, Annotation { text, value, arrow });
+        }
+    }
+
+    // Moves each note whose value left its index to the nearest free index holding that value.
+    // A value that is nowhere in the array (e.g. a held insertion key) keeps its note in place
+    // until it shows up again.
+    pub fn follow_annotations(&mut self, array: &[T]) {
+        if !self.annotations_follow {
+            return;
+        }
+        let mut taken: HashSet<usize> = self.annotations.keys().copied().collect();
+        let mut lost: Vec<usize> = self
+            .annotations
+            .iter()
+            .filter(|(index, annotation)| array.get(**index) != Some(&annotation.value))
+            .map(|(index, _)| *index)
+            .collect();
+        lost.sort_unstable();
+
+        for index in lost {
+            let value = self.annotations[&index].value;
+            let target = (0..array.len())
+                .filter(|candidate| array[*candidate] == value && !taken.contains(candidate))
+                .min_by_key(|candidate| candidate.abs_diff(index));
+            if let Some(target) = target {
+                let annotation = self.annotations.remove(&index).unwrap();
+                self.annotations.insert(target, annotation);
+                taken.remove(&index);
+                taken.insert(target);
+            }
+        }
+    }
+
+    // Switches to one of the ordering presets
+    pub fn set_sort_order(&mut self, sort_order: SortOrder) {
+        self.sort_order = sort_order;
+        self.set_comparator(sort_order.comparator());
+    }
+
+    // Replaces the comparison function, `sort_order` is left for the caller to describe it
+    pub fn set_comparator(&mut self, comparator: Comparator<T>) {
+        self.comparator = comparator;
+    }
+
+    // Increases the speed, in 50 ms steps down to 100 ms and by halving below that
     pub fn increase_speed(&mut self, min_speed: u64) {
-        self.speed = Duration::from_millis(
-            (self.speed.as_millis() as u64)
-                .saturating_sub(50)
-                .max(min_speed),
-        );
+        let speed = self.speed.as_millis() as u64;
+        let faster = if speed > 100 { speed - 50 } else { speed / 2 };
+        self.speed = Duration::from_millis(faster.max(self.min_speed(min_speed)));
+    }
+
+    // Turns reduced motion on or off, slowing the current speed down to its floor if needed
+    pub fn set_reduced_motion(&mut self, reduced_motion: bool) {
+        self.reduced_motion = reduced_motion;
+        let floor = Duration::from_millis(self.min_speed(0));
+        if self.speed < floor {
+            self.speed = floor;
+        }
+    }
+
+    // Fastest allowed step delay, reduced motion raises the floor
+    fn min_speed(&self, min_speed: u64) -> u64 {
+        if self.reduced_motion {
+            min_speed.max(REDUCED_MOTION_MIN_SPEED)
+        } else {
+            min_speed
+        }
     }
 
-    // Decreases the speed
+    // Decreases the speed, doubling up to 100 ms and in 50 ms steps above that
     pub fn decrease_speed(&mut self, max_speed: u64) {
-        self.speed = Duration::from_millis(
-            (self.speed.as_millis() as u64 + 50).min(max_speed),
-        );
+        let speed = self.speed.as_millis() as u64;
+        let slower = if speed >= 100 { speed + 50 } else { (speed * 2).min(100) };
+        self.speed = Duration::from_millis(slower.min(max_speed));
     }
 
     // Toggles teaching mode
@@ -145,6 +631,93 @@ impl VisualizerState {
         self.teaching_mode = !self.teaching_mode;
     }
 
+    // Toggles pausing after every swap
+    pub fn toggle_auto_pause_on_swap(&mut self) {
+        self.auto_pause_on_swap = !self.auto_pause_on_swap;
+    }
+
+    // Comparisons and array changes, compared before and after a step to tell what it did
+    pub fn operation_counts(&self) -> (u32, u32) {
+        (self.comparisons, self.array_changes())
+    }
+
+    // What a step did since the counts were `before`
+    pub fn step_kind_since(&self, before: (u32, u32)) -> StepKind {
+        let (comparisons, changes) = self.operation_counts();
+        if changes > before.1 {
+            StepKind::Change
+        } else if comparisons > before.0 {
+            StepKind::Comparison
+        } else {
+            StepKind::Other
+        }
+    }
+
+    // Delay auto-play waits after a step of `kind`, reduced motion keeps its floor
+    pub fn delay_after(&self, kind: StepKind) -> Duration {
+        let percent = match kind {
+            StepKind::Change => self.change_delay,
+            StepKind::Comparison => self.compare_delay,
+            StepKind::Other => 100,
+        };
+        let delay = self.speed * percent / 100;
+        delay.max(Duration::from_millis(self.min_speed(0)))
+    }
+
+    // Swaps and moves together, grows with every step that changes the array
+    pub fn array_changes(&self) -> u32 {
+        self.swaps + self.moves
+    }
+
+    // Pauses a running sort if a swap or move happened since `changes_before`
+    // A pending question takes priority, the run keeps going once it is answered
+    pub fn pause_after_swap(&mut self, changes_before: u32) {
+        if self.auto_pause_on_swap
+            && self.is_running
+            && !self.completed
+            && self.array_changes() > changes_before
+            && self.awaiting_question.is_none()
+        {
+            self.is_paused = true;
+        }
+    }
+
+    // Marks every milestone up to `progress` (percent) as passed, so none triggers twice
+    // Returns the highest milestone newly passed, progress can jump over several in one step
+    pub fn pass_milestones(&mut self, progress: f64) -> Option<u32> {
+        let mut passed = None;
+        while let Some(&milestone) = self.progress_milestones.get(self.milestones_passed) {
+            if progress < milestone as f64 {
+                break;
+            }
+            passed = Some(milestone);
+            self.milestones_passed += 1;
+        }
+        passed
+    }
+
+    // Pauses auto-play when progress crosses a milestone, returns the milestone it paused at
+    pub fn pause_at_milestone(&mut self, progress: f64) -> Option<u32> {
+        let milestone = self.pass_milestones(progress)?;
+        if !self.is_running || self.completed {
+            return None;
+        }
+        self.is_paused = true;
+        Some(milestone)
+    }
+
+    // Counts a step, a running sort is paused every time it reaches another multiple of the step limit
+    // Returns true if it paused, the sort may never terminate
+    pub fn count_step(&mut self) -> bool {
+        self.steps_taken += 1;
+        let at_limit = self.max_steps.is_some_and(|max_steps| self.steps_taken.is_multiple_of(max_steps));
+        if !at_limit || !self.is_running || self.completed {
+            return false;
+        }
+        self.is_paused = true;
+        true
+    }
+
     // Toggles play/pause
     pub fn toggle_play_pause(&mut self) {
         if self.is_running {
@@ -162,7 +735,28 @@ impl VisualizerState {
         self.completed = false;
         self.comparisons = 0;
         self.swaps = 0;
+        self.moves = 0;
+        self.watched_comparisons = 0;
         self.awaiting_question = None;
+        self.victory_sweep = None;
+        self.milestones_passed = 0;
+        self.steps_taken = 0;
+        self.step_history.clear();
+    }
+
+    // Takes the progress of the run from a snapshot's state, a running run is left paused there
+    pub fn rewind_to(&mut self, saved: &VisualizerState<T>) {
+        self.completed = saved.completed;
+        self.is_running = saved.is_running;
+        self.is_paused = saved.is_running;
+        self.comparisons = saved.comparisons;
+        self.swaps = saved.swaps;
+        self.moves = saved.moves;
+        self.watched_comparisons = saved.watched_comparisons;
+        self.awaiting_question = saved.awaiting_question;
+        self.victory_sweep = None;
+        self.milestones_passed = saved.milestones_passed;
+        self.steps_taken = saved.steps_taken;
     }
 
     // Marks the process as completed
@@ -183,4 +777,181 @@ impl VisualizerState {
     pub fn clear_question(&mut self) {
         self.awaiting_question = None;
     }
+
+    // Counts an answer towards the streak, a wrong one starts it over
+    // Returns true if the streak is a new best
+    pub fn record_answer(&mut self, correct: bool) -> bool {
+        if !correct {
+            self.current_streak = 0;
+            return false;
+        }
+        self.current_streak += 1;
+        let new_best = self.current_streak > self.best_streak;
+        self.best_streak = self.best_streak.max(self.current_streak);
+        new_best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::array_manager::ArrayData;
+    use crate::common::settings::Settings;
+    use crate::sort_algorithms::QuickSortVisualizer;
+
+    #[test]
+    fn step_back_rewinds_every_step_until_a_reset() {
+        let array_data = ArrayData::new(vec![5, 3, 8, 1, 9, 2], "Back".to_string());
+        let mut visualizer = QuickSortVisualizer::with_settings(&array_data, &Settings::default());
+        let mut frames = Vec::new();
+        loop {
+            frames.push((visualizer.get_array().to_vec(), visualizer.get_states().to_vec(), visualizer.state().comparisons));
+            visualizer.record_step();
+            if !visualizer.step() {
+                break;
+            }
+        }
+        for (array, states, comparisons) in frames.iter().rev() {
+            assert!(visualizer.step_back());
+            assert_eq!(visualizer.get_array(), array.as_slice());
+            assert!(visualizer.get_states() == states.as_slice());
+            assert_eq!(visualizer.state().comparisons, *comparisons);
+        }
+        assert!(!visualizer.step_back());
+
+        for _ in 0..MAX_STEP_HISTORY + 1 {
+            visualizer.record_step();
+        }
+        assert_eq!(std::iter::from_fn(|| visualizer.step_back().then_some(())).count(), MAX_STEP_HISTORY);
+        visualizer.record_step();
+        visualizer.reset();
+        assert!(!visualizer.step_back());
+    }
+
+    #[test]
+    fn notes_follow_their_element_or_stay_at_the_index() {
+        let mut state = VisualizerState::<u32>::new(Vec::new(), Duration::from_millis(100));
+        state.annotate(0, 5, "pivot ");
+        state.annotate(2, 9, "");
+        assert_eq!(state.annotations.len(), 1);
+
+        // 5 moved from index 0 to index 2
+        state.follow_annotations(&[3, 1, 5]);
+        assert_eq!(state.annotations[&2].text, "pivot");
+
+        // A value that is nowhere to be seen keeps its note in place
+        state.follow_annotations(&[3, 1, 7]);
+        assert!(state.annotations.contains_key(&2));
+
+        state.annotations_follow = false;
+        state.follow_annotations(&[5, 1, 3]);
+        assert!(state.annotations.contains_key(&2));
+    }
+
+    #[test]
+    fn arrows_and_labels_share_an_annotation() {
+        let mut state = VisualizerState::<u32>::new(Vec::new(), Duration::from_millis(100));
+        state.toggle_arrow(1, 4);
+        state.annotate(1, 4, "pivot");
+        assert!(state.annotations[&1].arrow);
+
+        // Clearing the text keeps the arrow, removing the arrow then drops the note
+        state.annotate(1, 4, "");
+        assert!(state.annotations[&1].arrow);
+        state.toggle_arrow(1, 4);
+        assert!(state.annotations.is_empty());
+    }
+
+    #[test]
+    fn wrong_answers_restart_the_streak() {
+        let mut state = VisualizerState::<u32>::new(Vec::new(), Duration::from_millis(100));
+        state.best_streak = 2;
+        assert!(!state.record_answer(true));
+        assert!(!state.record_answer(true));
+        assert!(state.record_answer(true));
+        assert_eq!((state.current_streak, state.best_streak), (3, 3));
+        assert!(!state.record_answer(false));
+        assert_eq!((state.current_streak, state.best_streak), (0, 3));
+    }
+
+    #[test]
+    fn step_limit_pauses_a_running_sort_at_each_multiple() {
+        let mut state = VisualizerState::<u32>::new(Vec::new(), Duration::from_millis(100));
+        state.max_steps = Some(3);
+        state.toggle_play_pause();
+        assert!(!state.count_step());
+        assert!(!state.count_step());
+        assert!(state.count_step());
+        assert!(state.is_paused);
+        state.toggle_play_pause();
+        assert!(!state.count_step());
+        state.reset_state();
+        assert_eq!(state.steps_taken, 0);
+        // Manual steps are counted without pausing anything
+        for _ in 0..3 {
+            assert!(!state.count_step());
+        }
+    }
+
+    #[test]
+    fn decision_changers_land_on_the_other_side() {
+        let mut state = VisualizerState::<u32>::new(Vec::new(), Duration::from_millis(100));
+        // 7 against 4 swaps, every value not above 4 would not
+        let array = [7, 4, 1, 9, 4, 6];
+        assert_eq!(state.decision_changers(&array, 0, 4, &[0, 1], Ordering::is_gt), vec![2, 4]);
+        // Descending keeps 7 before 4, only a value below 4 would swap
+        state.set_sort_order(SortOrder::Descending);
+        assert_eq!(state.decision_changers(&array, 0, 4, &[0, 1], Ordering::is_gt), vec![2]);
+    }
+
+    #[test]
+    fn steps_are_paced_by_what_they_did() {
+        let mut state = VisualizerState::<u32>::new(Vec::new(), Duration::from_millis(200));
+        state.compare_delay = 50;
+        state.change_delay = 300;
+        let before = state.operation_counts();
+        state.comparisons += 1;
+        assert_eq!(state.step_kind_since(before), StepKind::Comparison);
+        state.swaps += 1;
+        assert_eq!(state.step_kind_since(before), StepKind::Change);
+        assert_eq!(state.step_kind_since(state.operation_counts()), StepKind::Other);
+
+        assert_eq!(state.delay_after(StepKind::Comparison), Duration::from_millis(100));
+        assert_eq!(state.delay_after(StepKind::Change), Duration::from_millis(600));
+        assert_eq!(state.delay_after(StepKind::Other), Duration::from_millis(200));
+        // Reduced motion never goes below its floor
+        state.set_reduced_motion(true);
+        assert_eq!(state.delay_after(StepKind::Comparison), Duration::from_millis(REDUCED_MOTION_MIN_SPEED));
+    }
+
+    #[test]
+    fn milestones_pause_auto_play_once() {
+        let mut state = VisualizerState::<u32>::new(Vec::new(), Duration::from_millis(100));
+        state.progress_milestones = vec![25, 50, 75];
+        state.toggle_play_pause();
+        assert_eq!(state.pause_at_milestone(10.0), None);
+        assert_eq!(state.pause_at_milestone(30.0), Some(25));
+        assert!(state.is_paused);
+
+        // Progress jumping over two milestones pauses once, at the later one
+        state.toggle_play_pause();
+        assert_eq!(state.pause_at_milestone(80.0), Some(75));
+        state.toggle_play_pause();
+        assert_eq!(state.pause_at_milestone(90.0), None);
+        assert!(!state.is_paused);
+
+        // A reset arms them again
+        state.reset_state();
+        assert_eq!(state.pass_milestones(60.0), Some(50));
+        assert!(!state.is_paused);
+    }
+
+    #[test]
+    fn sorted_runs_split_at_unsorted_elements() {
+        use SelectionState::{Normal, Sorted};
+
+        assert_eq!(sorted_runs(&[Sorted, Sorted, Normal, Sorted]), vec![0..2, 3..4]);
+        assert_eq!(sorted_runs(&[Normal, Normal]), Vec::<Range<usize>>::new());
+        assert_eq!(sorted_runs(&[Normal, Sorted, Sorted]), vec![1..3]);
+    }
 }
@@ -0,0 +1,105 @@
+use crate::common::element::SortElement;
+
+/// Copies `text` to the system clipboard.
+/// Returns a message for the user if there is no clipboard to copy to.
+#[cfg(feature = "clipboard")]
+pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_text(text))
+        .map_err(|error| format!("Clipboard unavailable: {}", error))
+}
+
+/// Without the `clipboard` feature there is never a clipboard to copy to.
+#[cfg(not(feature = "clipboard"))]
+pub fn copy_to_clipboard(_text: &str) -> Result<(), String> {
+    Err("Clipboard support is not built in (build with --features clipboard)".to_string())
+}
+
+/// Joins values the way the manual entry accepts them back, e.g. "5, 3, 8" or "2.5, 0.75"
+pub fn format_values<T: SortElement>(values: &[T]) -> String {
+    values.iter().map(T::to_string).collect::<Vec<String>>().join(", ")
+}
+
+/// Reads a comma or space separated list of values, `None` if any entry is not a `T`
+pub fn parse_values<T: SortElement>(text: &str) -> Option<Vec<T>> {
+    text.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|entry| !entry.is_empty())
+        .map(T::parse)
+        .collect()
+}
+
+/// A pipe-delimited Markdown table for GitHub issues, docs or course notes.
+/// The first column is left-aligned, every other column holds numbers and is right-aligned.
+/// Cells are padded to the column width so the table also reads well as plain text.
+pub fn markdown_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let widths: Vec<usize> = (0..header.len())
+        .map(|column| {
+            rows.iter()
+                .filter_map(|row| row.get(column))
+                .map(|cell| cell.chars().count())
+                .chain([header[column].chars().count(), 3])
+                .max()
+                .unwrap_or(3)
+        })
+        .collect();
+    let line = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+    let pad = |column: usize, text: &str| {
+        if column == 0 {
+            format!("{:<width$}", text, width = widths[column])
+        } else {
+            format!("{:>width$}", text, width = widths[column])
+        }
+    };
+    let mut lines = vec![line(header.iter().enumerate().map(|(column, text)| pad(column, text)).collect())];
+    lines.push(line(
+        widths
+            .iter()
+            .enumerate()
+            .map(|(column, &width)| if column == 0 { "-".repeat(width) } else { format!("{}:", "-".repeat(width - 1)) })
+            .collect(),
+    ));
+    for row in rows {
+        lines.push(line((0..header.len()).map(|column| pad(column, row.get(column).map_or("", String::as_str))).collect()));
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Copies values as a comma-separated list and describes the outcome for a status line
+pub fn copy_values<T: SortElement>(values: &[T]) -> String {
+    match copy_to_clipboard(&format_values(values)) {
+        Ok(()) => format!("Copied {} values to the clipboard", values.len()),
+        Err(message) => message,
+    }
+}
+
+/// Copies a Markdown table and describes the outcome for a status line, `what` names the table
+pub fn copy_markdown(table: &str, what: &str) -> String {
+    match copy_to_clipboard(table) {
+        Ok(()) => format!("Copied {} to the clipboard as a Markdown table", what),
+        Err(message) => message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markdown_tables_right_align_numbers() {
+        let rows = vec![vec!["Bubble".to_string(), "190".to_string()], vec!["Merge".to_string(), "48".to_string()]];
+        assert_eq!(
+            markdown_table(&["Algorithm", "Comparisons"], &rows),
+            "| Algorithm | Comparisons |\n| --------- | ----------: |\n| Bubble    |         190 |\n| Merge     |          48 |\n"
+        );
+    }
+
+    #[test]
+    fn formatted_values_parse_back() {
+        let values = vec![5, 3, 8, 100, 1];
+        assert_eq!(parse_values(&format_values(&values)), Some(values));
+        assert_eq!(parse_values::<u32>("1,2  3,\n4"), Some(vec![1, 2, 3, 4]));
+        assert_eq!(parse_values::<u32>("1, two, 3"), None);
+        assert_eq!(parse_values::<u32>("1, 2.5"), None);
+        assert_eq!(parse_values::<f64>(&format_values(&[2.5, 0.75, 3.0])), Some(vec![2.5, 0.75, 3.0]));
+    }
+}
@@ -1,143 +1,812 @@
 use crossterm::{cursor::MoveTo, style::{Attribute, Color, Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor}, terminal::{size, Clear, ClearType}, ExecutableCommand, QueueableCommand};
-use std::io::{stdout, Write};
-use crate::common::enums::{SelectionState, TeachingQuestion};
+use std::io::{stdout, Stdout, Write};
+use crate::common::base_visualizer::VisualizerState;
+use crate::common::element::SortElement;
+use crate::common::enums::{ColorTheme, RenderMode, SelectionState, TeachingQuestion};
+use crate::common::theme::{self, Palette};
 use crossterm::event::{poll, read};
-use std::time::Duration;
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+// Characters of a lecture note drawn above its bar, longer notes are cut
+pub const MAX_NOTE_WIDTH: usize = 16;
+
+// Rows of the tallest bar in a secondary bar row such as a merge buffer
+const BUFFER_BAR_HEIGHT: usize = 5;
+
+// Rows of the faint original array drawn above the bars, a row is left free above the tallest bar
+const GHOST_ROWS: usize = 2;
+
+// Figures of the counter overlay, three rows of half blocks for each digit
+const BIG_DIGITS: [[&str; 3]; 10] = [
+    ["█▀█", "█ █", "█▄█"],
+    ["▀█ ", " █ ", "▄█▄"],
+    ["▀▀█", "█▀▀", "█▄▄"],
+    ["▀▀█", " ▀█", "▄▄█"],
+    ["█ █", "▀▀█", "  █"],
+    ["█▀▀", "▀▀█", "▄▄█"],
+    ["█▀▀", "█▀█", "█▄█"],
+    ["▀▀█", "  █", "  █"],
+    ["█▀█", "█▀█", "█▄█"],
+    ["█▀█", "▀▀█", "▄▄█"],
+];
 
 // Common drawing functions
-pub struct VisualizerDrawer;
+// One drawer is created per run and kept across frames. It takes the theme colors once and
+// remembers the bars it drew, so bars that did not change are not drawn again.
+pub struct VisualizerDrawer {
+    stdout: Stdout,
+    palette: Palette,          // neutral colors of the theme the run started with
+    color_theme: ColorTheme,   // colors of the bars in each selection state, taken when the run started
+    layout: (u16, u16, usize), // terminal width, height and first bar row of the frame being drawn
+    bar_layout: Option<((usize, u16), BarLayout)>, // array length and width, and the bar_layout for them
+    bars: Option<BarFrame>,    // bars left on screen by the last frame, None draws every bar
+    bar_row: String,           // one row of a bar, reused while the bar width stays the same
+}
+
+// x position of the first bar, bar width and spacing between bars
+type BarLayout = (usize, usize, usize);
+
+// Bars of one frame and the layout they were drawn with
+#[derive(Clone, PartialEq)]
+struct BarFrame {
+    layout: (u16, u16, usize), // terminal width, height and the first bar row
+    axis: Option<usize>, // row of the zero axis when some values are negative
+    bars: Vec<(usize, usize, Color, Color)>, // top row, height, foreground and background of every bar
+}
+
+impl BarFrame {
+    // Bars that differ from `previous`, every bar if there is none or the layout changed
+    fn changed_bars(&self, previous: Option<&BarFrame>) -> Vec<usize> {
+        match previous {
+            Some(previous) if previous.layout == self.layout && previous.axis == self.axis && previous.bars.len() == self.bars.len() => (0..self.bars.len())
+                .filter(|&i| previous.bars[i] != self.bars[i])
+                .collect(),
+            _ => (0..self.bars.len()).collect(),
+        }
+    }
+}
+
+impl Default for VisualizerDrawer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl VisualizerDrawer {
+    // Creates a drawer with the colors of the current theme
+    pub fn new() -> Self {
+        Self {
+            stdout: stdout(),
+            palette: theme::palette(),
+            color_theme: theme::color_theme(),
+            layout: (0, 0, 0),
+            bar_layout: None,
+            bars: None,
+            bar_row: String::new(),
+        }
+    }
+
+    // Neutral colors the drawer uses
+    pub fn palette(&self) -> Palette {
+        self.palette
+    }
+
+    // Writes everything queued to the terminal
+    pub fn flush(&mut self) {
+        self.stdout.flush().unwrap();
+    }
+
+    // Forgets the bars on screen, the next frame clears everything and draws every bar
+    // Anything that draws over the visualizer, like a dialog, needs the full redraw
+    pub fn invalidate(&mut self) {
+        self.bars = None;
+    }
+
+    // Clears the screen for a new frame
+    // Rows holding bars of the last frame are kept when the terminal size did not change,
+    // draw_array_bars then only draws the bars that changed
+    pub fn clear_screen(&mut self, width: u16, height: u16, array_start_y: usize) {
+        self.layout = (width, height, array_start_y);
+        let bar_rows = array_start_y..=array_start_y + Self::max_bar_height(height);
+        match &self.bars {
+            Some(frame) if frame.layout == self.layout => {
+                for y in (0..height).filter(|&y| !bar_rows.contains(&(y as usize))) {
+                    self.stdout.queue(MoveTo(0, y)).unwrap();
+                    self.stdout.queue(Clear(ClearType::CurrentLine)).unwrap();
+                }
+            }
+            _ => {
+                self.bars = None;
+                self.stdout.execute(Clear(ClearType::All)).unwrap();
+            }
+        }
+    }
+
+    // Rows of the tallest bar below the first bar row
+    fn max_bar_height(height: u16) -> usize {
+        (height as usize).saturating_sub(20).min(20)
+    }
+
     // Draws the title
-    pub fn draw_title(stdout: &mut std::io::Stdout, title: &str) {
+    pub fn draw_title(&mut self, title: &str) {
         let (width, _) = size().unwrap();
         let title_x = (width.saturating_sub(title.len() as u16)) / 2;
-        stdout.queue(MoveTo(title_x, 1)).unwrap();
-        stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
-        stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
-        stdout.queue(SetBackgroundColor(Color::DarkBlue)).unwrap();
-        stdout.queue(Print(title)).unwrap();
-        stdout.queue(ResetColor).unwrap();
+        self.stdout.queue(MoveTo(title_x, 1)).unwrap();
+        self.stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
+        self.stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+        self.stdout.queue(SetBackgroundColor(Color::DarkBlue)).unwrap();
+        self.stdout.queue(Print(title)).unwrap();
+        self.stdout.queue(ResetColor).unwrap();
     }
 
-    // Draws the array as a bar graph
-    pub fn draw_array_bars(
-        stdout: &mut std::io::Stdout,
-        array: &[u32],
+    // Draws the array as a bar graph in the layout given to the last clear_screen
+    // In the Gradient render mode bars in the Normal state are colored by their value instead
+    // Labels are written in its `value_format`, cut to their lowest digits if wider than a bar slot,
+    // three characters always fit like the decimal labels always did
+    // Notes are kept by the visualizer's own index, which sits `note_offset` bars into `array`
+    pub fn draw_array_bars<T: SortElement>(
+        &mut self,
+        array: &[T],
         states: &[SelectionState],
-        width: u16,
-        height: u16,
-        array_start_y: usize,
+        state: &VisualizerState<T>,
+        note_offset: usize,
     ) {
-        let max_value = *array.iter().max().unwrap_or(&1) as f64;
+        let (width, height, array_start_y) = self.layout;
+        let value_gradient = state.render_mode == RenderMode::Gradient && !state.hide_values;
+        let highlight_only = state.render_mode == RenderMode::HighlightOnly;
+        let value_format = state.value_format;
+        let max_value = array.iter().map(|value| value.to_f64()).fold(0.0, f64::max);
+        let min_value = array.iter().map(|value| value.to_f64()).fold(f64::INFINITY, f64::min);
         let array_len = array.len();
+        let max_bar_height = Self::max_bar_height(height);
+        // Bars of the last frame are still on screen if clear_screen kept their rows.
+        // Only bars that changed are drawn again, unless notes go over the rows or the bar count changed.
+        let previous = self.bars.take();
+        let rows_kept = previous.is_some();
+        let overlaid = !state.annotations.is_empty() || state.show_counter_overlay;
+        let kept = previous.filter(|previous| !overlaid && previous.bars.len() == array_len);
+        if rows_kept && kept.is_none() {
+            for y in array_start_y..=array_start_y + max_bar_height {
+                self.stdout.queue(MoveTo(0, y as u16)).unwrap();
+                self.stdout.queue(Clear(ClearType::CurrentLine)).unwrap();
+            }
+        }
         if array_len == 0 {
             return;
         }
         // Calculate bar sizes
-        let available_width = (width as usize).saturating_sub(4);
-        let bar_width = if available_width / array_len >= 3 {
-            3
-        } else if available_width / array_len >= 2 {
-            2
-        } else {
-            1
+        let (start_x, bar_width, spacing) = self.cached_bar_layout(array_len, width);
+
+        // Rows a bar covers below the first bar row, as its top row and its height.
+        // Bars grow up from the bottom row, with negative values up and down from a zero axis instead.
+        // Hidden values give every bar the full height and a "?" label
+        let axis = (min_value < 0.0 && !state.hide_values).then(|| Self::axis_row(min_value, max_value, max_bar_height + 1));
+        let bar_rows_of = |value: T| {
+            if state.hide_values {
+                (0, max_bar_height + 1)
+            } else if axis.is_some() {
+                Self::axis_bar_rows(value.to_f64(), min_value, max_value, max_bar_height + 1)
+            } else {
+                let height = ((value.to_f64() / max_value) * max_bar_height as f64) as usize + 1;
+                (max_bar_height + 1 - height, height)
+            }
         };
-        let spacing = if bar_width >= 2 { 1 } else { 0 };
-        let total_width_needed = array_len * bar_width + (array_len - 1) * spacing;
-        let start_x = (width as usize - total_width_needed) / 2;
-        let max_bar_height = (height as usize).saturating_sub(20).min(20);
 
+        let frame = BarFrame {
+            layout: self.layout,
+            axis,
+            bars: array
+                .iter()
+                .zip(states)
+                .map(|(&value, &selection)| {
+                    let (fg_color, bg_color) = if value_gradient && selection == SelectionState::Normal {
+                        (Self::value_color(value.to_f64(), min_value, max_value), Color::Reset)
+                    } else if highlight_only {
+                        Self::highlight_only_colors(self.color_theme, selection)
+                    } else {
+                        theme::state_colors(self.color_theme, selection)
+                    };
+                    let (top, height) = bar_rows_of(value);
+                    (top, height, fg_color, bg_color)
+                })
+                .collect(),
+        };
+        let changed = frame.changed_bars(kept.as_ref());
+        let kept_rows = kept.is_some();
+        let blank = " ".repeat(bar_width);
+        let axis_segment = "─".repeat(bar_width);
+        // The zero axis runs under the whole chart, bars drawn later cover it
+        if let Some(axis) = axis.filter(|_| !kept_rows) {
+            let chart_width = array_len * (bar_width + spacing) - spacing;
+            self.stdout.queue(MoveTo(start_x as u16, (array_start_y + axis) as u16)).unwrap();
+            self.stdout.queue(SetForegroundColor(self.palette.dim)).unwrap();
+            self.stdout.queue(Print("─".repeat(chart_width))).unwrap();
+            self.stdout.queue(ResetColor).unwrap();
+        }
         for (i, &value) in array.iter().enumerate() {
-            let bar_height = ((value as f64 / max_value) * max_bar_height as f64) as usize + 1;
             let x = start_x + i * (bar_width + spacing);
-            let (fg_color, bg_color) = Self::get_state_colors(states[i]);
-            // Draw the bar from bottom to top
-            for h in 0..bar_height {
-                let y = array_start_y + max_bar_height - h;
-                stdout.queue(MoveTo(x as u16, y as u16)).unwrap();
-                stdout.queue(SetForegroundColor(fg_color)).unwrap();
-                stdout.queue(SetBackgroundColor(bg_color)).unwrap();
-                if bar_width == 1 {
-                    stdout.queue(Print("█")).unwrap();
-                } else {
-                    stdout.queue(Print("█".repeat(bar_width))).unwrap();
+            if changed.binary_search(&i).is_ok() {
+                let (top, bar_height, fg_color, bg_color) = frame.bars[i];
+                // Rows kept from the last frame may still hold a longer bar here, the axis is drawn back under it
+                if kept_rows {
+                    for y in (0..=max_bar_height).filter(|y| !(top..top + bar_height).contains(y)) {
+                        self.stdout.queue(MoveTo(x as u16, (array_start_y + y) as u16)).unwrap();
+                        if Some(y) == axis {
+                            self.stdout.queue(SetForegroundColor(self.palette.dim)).unwrap();
+                            self.stdout.queue(Print(&axis_segment)).unwrap();
+                            self.stdout.queue(ResetColor).unwrap();
+                        } else {
+                            self.stdout.queue(Print(&blank)).unwrap();
+                        }
+                    }
                 }
-                stdout.queue(ResetColor).unwrap();
+                self.draw_bar(x, bar_width, bar_height, fg_color, bg_color, array_start_y + top + bar_height - 1);
             }
             // Draw the value
-            let value_str = value.to_string();
-            let value_x = x + (bar_width.saturating_sub(value_str.len())) / 2;
-            stdout.queue(MoveTo(value_x as u16, (array_start_y + max_bar_height + 1) as u16)).unwrap();
-            stdout.queue(SetForegroundColor(Color::White)).unwrap();
-            stdout.queue(Print(value_str)).unwrap();
-            stdout.queue(ResetColor).unwrap();
+            let value_str = if state.hide_values {
+                "?".to_string()
+            } else {
+                value.fit(value_format, (bar_width + spacing).max(3))
+            };
+            let value_x = x + (bar_width.saturating_sub(value_str.chars().count())) / 2;
+            self.stdout.queue(MoveTo(value_x as u16, (array_start_y + max_bar_height + 1) as u16)).unwrap();
+            self.stdout.queue(SetForegroundColor(self.palette.text)).unwrap();
+            self.stdout.queue(Print(value_str)).unwrap();
+            self.stdout.queue(ResetColor).unwrap();
             // Draw the index
             let index_str = i.to_string();
             let index_x = x + (bar_width.saturating_sub(index_str.len())) / 2;
-            stdout.queue(MoveTo(index_x as u16, (array_start_y + max_bar_height + 2) as u16)).unwrap();
-            stdout.queue(SetForegroundColor(Color::DarkGrey)).unwrap();
-            stdout.queue(Print(index_str)).unwrap();
-            stdout.queue(ResetColor).unwrap();
+            self.stdout.queue(MoveTo(index_x as u16, (array_start_y + max_bar_height + 2) as u16)).unwrap();
+            self.stdout.queue(SetForegroundColor(self.palette.dim)).unwrap();
+            self.stdout.queue(Print(index_str)).unwrap();
+            self.stdout.queue(ResetColor).unwrap();
+        }
+
+        // Lecture notes go just above their bars, after every bar so none is drawn over
+        // An arrow takes the row above the bar and pushes its text one row up
+        for (&i, annotation) in &state.annotations {
+            let i = i + note_offset;
+            let Some(&value) = array.get(i) else {
+                continue;
+            };
+            let (top, _) = bar_rows_of(value);
+            let x = start_x + i * (bar_width + spacing);
+            let mut note_y = (array_start_y + top).saturating_sub(1);
+            if annotation.arrow {
+                self.stdout.queue(MoveTo((x + bar_width / 2) as u16, note_y as u16)).unwrap();
+                self.stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+                self.stdout.queue(Print("↓")).unwrap();
+                note_y = note_y.saturating_sub(1);
+            }
+            let note: String = annotation.text.chars().take(MAX_NOTE_WIDTH).collect();
+            let note_x = (x + bar_width / 2).saturating_sub(note.chars().count() / 2);
+            self.stdout.queue(MoveTo(note_x as u16, note_y as u16)).unwrap();
+            self.stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+            self.stdout.queue(Print(note)).unwrap();
+            self.stdout.queue(ResetColor).unwrap();
+        }
+        if state.show_counter_overlay {
+            self.draw_counter_overlay(state, start_x, array_start_y);
+        }
+        if !overlaid {
+            self.bars = Some(frame);
+        }
+    }
+
+    // Draws the comparisons and swaps in large figures over the top left of the chart,
+    // and the moves for the algorithms that make any
+    fn draw_counter_overlay<T: SortElement>(&mut self, state: &VisualizerState<T>, x: usize, y: usize) {
+        let mut counters = vec![("COMPARISONS", state.comparisons), ("SWAPS", state.swaps)];
+        if state.moves > 0 {
+            counters.push(("MOVES", state.moves));
+        }
+        let mut x = x;
+        for (label, count) in counters {
+            let rows = Self::big_number(count);
+            self.stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
+            self.stdout.queue(SetForegroundColor(self.palette.text)).unwrap();
+            self.stdout.queue(MoveTo(x as u16, y as u16)).unwrap();
+            self.stdout.queue(Print(label)).unwrap();
+            self.stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+            for (row, text) in rows.iter().enumerate() {
+                self.stdout.queue(MoveTo(x as u16, (y + 1 + row) as u16)).unwrap();
+                self.stdout.queue(Print(text)).unwrap();
+            }
+            self.stdout.queue(ResetColor).unwrap();
+            x += label.len().max(rows[0].chars().count()) + 3;
+        }
+    }
+
+    // `number` in the three rows of BIG_DIGITS, a space between digits
+    pub fn big_number(number: u32) -> [String; 3] {
+        let digits: Vec<usize> = number.to_string().bytes().map(|digit| (digit - b'0') as usize).collect();
+        std::array::from_fn(|row| {
+            digits
+                .iter()
+                .map(|&digit| BIG_DIGITS[digit][row])
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+    }
+
+    // Draws the array in the state's render mode, `original` is the array the run started from
+    pub fn draw_array<T: SortElement>(
+        &mut self,
+        array: &[T],
+        original: &[T],
+        states: &[SelectionState],
+        state: &VisualizerState<T>,
+        note_offset: usize,
+    ) {
+        if state.render_mode == RenderMode::Original && !state.hide_values {
+            self.draw_ghost_array(original);
+        }
+        self.draw_array_bars(array, states, state, note_offset);
+    }
+
+    // Draws the array the run started from as faint bars above the current ones, each over its index
+    fn draw_ghost_array<T: SortElement>(&mut self, original: &[T]) {
+        let (width, _, array_start_y) = self.layout;
+        let Some(top_y) = array_start_y.checked_sub(GHOST_ROWS + 1) else {
+            return;
+        };
+        let (start_x, bar_width, spacing) = self.cached_bar_layout(original.len(), width);
+        self.stdout.queue(SetForegroundColor(self.palette.dim)).unwrap();
+        for (row, blocks) in Self::ghost_rows(original, GHOST_ROWS).iter().enumerate() {
+            for (i, &block) in blocks.iter().enumerate() {
+                if block != ' ' {
+                    let x = start_x + i * (bar_width + spacing);
+                    self.stdout.queue(MoveTo(x as u16, (top_y + row) as u16)).unwrap();
+                    self.stdout.queue(Print(block.to_string().repeat(bar_width))).unwrap();
+                }
+            }
+        }
+        if start_x >= 10 {
+            self.stdout.queue(MoveTo((start_x - 10) as u16, (top_y + GHOST_ROWS - 1) as u16)).unwrap();
+            self.stdout.queue(Print("original")).unwrap();
+        }
+        self.stdout.queue(ResetColor).unwrap();
+    }
+
+    // Block character of every value in each of `rows` rows, top row first, the largest value fills every row
+    // With negative values the bars grow in whole rows up and down from a zero axis like the current ones
+    pub fn ghost_rows<T: SortElement>(values: &[T], rows: usize) -> Vec<Vec<char>> {
+        const BLOCKS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+        let lowest = values.iter().map(|value| value.to_f64()).fold(0.0, f64::min);
+        let highest = values.iter().map(|value| value.to_f64()).fold(0.0, f64::max);
+        if lowest < 0.0 {
+            let axis = Self::axis_row(lowest, highest, rows);
+            let spans: Vec<(usize, usize)> = values.iter().map(|value| Self::axis_bar_rows(value.to_f64(), lowest, highest, rows)).collect();
+            return (0..rows)
+                .map(|row| {
+                    spans
+                        .iter()
+                        .map(|&(top, height)| match (top..top + height).contains(&row) {
+                            true => '█',
+                            false if row == axis => '─',
+                            false => ' ',
+                        })
+                        .collect()
+                })
+                .collect();
+        }
+        // Values are scaled by the largest one, all-zero arrays by 1
+        let max_value = Some(highest).filter(|&max| max > 0.0).unwrap_or(1.0);
+        (0..rows)
+            .map(|row| {
+                let floor = (rows - 1 - row) * 8;
+                values
+                    .iter()
+                    .map(|&value| {
+                        let eighths = (value.to_f64() * (rows * 8) as f64 / max_value).ceil() as usize;
+                        BLOCKS[eighths.saturating_sub(floor).min(8)]
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    // Row of the zero axis in a chart of `rows` rows counted from the top, the rows above it hold the positive values.
+    // Negative values always keep at least the row below it.
+    fn axis_row(lowest: f64, highest: f64, rows: usize) -> usize {
+        let span = (highest.max(0.0) - lowest.min(0.0)).max(1.0);
+        let axis = (highest.max(0.0) / span * rows.saturating_sub(1) as f64).round() as usize;
+        if lowest < 0.0 { axis.min(rows.saturating_sub(2)) } else { axis }
+    }
+
+    // Top row and height of a bar in a chart with a zero axis, `lowest` and `highest` are the chart's extreme values.
+    // Zero and positive values grow up from the axis row, negative values down from the row below it.
+    fn axis_bar_rows(value: f64, lowest: f64, highest: f64, rows: usize) -> (usize, usize) {
+        let span = (highest.max(0.0) - lowest.min(0.0)).max(1.0);
+        let axis = Self::axis_row(lowest, highest, rows);
+        let height = (value.abs() / span * rows.saturating_sub(1) as f64) as usize;
+        if value >= 0.0 {
+            let height = (height + 1).min(axis + 1);
+            (axis + 1 - height, height)
+        } else {
+            (axis + 1, height.max(1).min(rows.saturating_sub(axis + 1)))
+        }
+    }
+
+    // Draws one bar upwards from `bottom_y`
+    fn draw_bar(&mut self, x: usize, bar_width: usize, bar_height: usize, fg_color: Color, bg_color: Color, bottom_y: usize) {
+        if self.bar_row.chars().count() != bar_width {
+            self.bar_row = "█".repeat(bar_width);
+        }
+        for h in 0..bar_height {
+            self.stdout.queue(MoveTo(x as u16, (bottom_y - h) as u16)).unwrap();
+            if self.color_theme == ColorTheme::HighContrast {
+                self.stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
+            }
+            self.stdout.queue(SetForegroundColor(fg_color)).unwrap();
+            self.stdout.queue(SetBackgroundColor(bg_color)).unwrap();
+            self.stdout.queue(Print(&self.bar_row)).unwrap();
+            self.stdout.queue(SetAttribute(Attribute::Reset)).unwrap();
+        }
+    }
+
+    // bar_layout for the current terminal width, computed again only when the length or width changes
+    fn cached_bar_layout(&mut self, array_len: usize, width: u16) -> BarLayout {
+        match self.bar_layout {
+            Some((key, layout)) if key == (array_len, width) => layout,
+            _ => {
+                let layout = Self::bar_layout(array_len, width);
+                self.bar_layout = Some(((array_len, width), layout));
+                layout
+            }
+        }
+    }
+
+    // Returns the x position of the first bar, the bar width and the spacing between bars
+    pub fn bar_layout(array_len: usize, width: u16) -> BarLayout {
+        let available_width = (width as usize).saturating_sub(4);
+        let bar_width = if available_width / array_len.max(1) >= 3 {
+            3
+        } else if available_width / array_len.max(1) >= 2 {
+            2
+        } else {
+            1
+        };
+        let spacing = if bar_width >= 2 { 1 } else { 0 };
+        let total_width_needed = array_len * bar_width + array_len.saturating_sub(1) * spacing;
+        let start_x = (width as usize).saturating_sub(total_width_needed) / 2;
+        (start_x, bar_width, spacing)
+    }
+
+    // Draws a marker below the index row pointing at a single bar
+    pub fn draw_index_marker(
+        &mut self,
+        array_len: usize,
+        index: usize,
+        width: u16,
+        height: u16,
+        array_start_y: usize,
+    ) {
+        if index >= array_len {
+            return;
+        }
+        let (start_x, bar_width, spacing) = self.cached_bar_layout(array_len, width);
+        let max_bar_height = Self::max_bar_height(height);
+        let x = start_x + index * (bar_width + spacing) + bar_width.saturating_sub(1) / 2;
+        self.stdout.queue(MoveTo(x as u16, (array_start_y + max_bar_height + 3) as u16)).unwrap();
+        self.stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+        self.stdout.queue(Print("^")).unwrap();
+        self.stdout.queue(ResetColor).unwrap();
+    }
+
+    // Draws a green bracket below the index row under every sorted region
+    pub fn draw_sorted_regions(
+        &mut self,
+        regions: &[Range<usize>],
+        array_len: usize,
+        width: u16,
+        height: u16,
+        array_start_y: usize,
+    ) {
+        let (start_x, bar_width, spacing) = self.cached_bar_layout(array_len, width);
+        let max_bar_height = Self::max_bar_height(height);
+        self.stdout.queue(SetForegroundColor(Color::Green)).unwrap();
+        for region in regions.iter().filter(|region| !region.is_empty() && region.end <= array_len) {
+            let span = region.len() * (bar_width + spacing) - spacing;
+            let bracket = if span == 1 {
+                "─".to_string()
+            } else {
+                format!("└{}┘", "─".repeat(span - 2))
+            };
+            let x = start_x + region.start * (bar_width + spacing);
+            self.stdout.queue(MoveTo(x as u16, (array_start_y + max_bar_height + 3) as u16)).unwrap();
+            self.stdout.queue(Print(bracket)).unwrap();
         }
+        self.stdout.queue(ResetColor).unwrap();
     }
 
-    // Returns colors based on state
-    pub fn get_state_colors(state: SelectionState) -> (Color, Color) {
+    // Colors in the highlight only mode: bars the current operation acts on keep their color, the rest are gray
+    pub fn highlight_only_colors(color_theme: ColorTheme, state: SelectionState) -> (Color, Color) {
         match state {
-            SelectionState::Normal => (Color::Cyan, Color::Reset),
-            SelectionState::Sorted => (Color::Green, Color::DarkGreen),
-            SelectionState::CurrentMin => (Color::Yellow, Color::DarkYellow),
-            SelectionState::Comparing => (Color::Magenta, Color::DarkMagenta),
-            SelectionState::Selected => (Color::White, Color::DarkBlue),
-            SelectionState::Swapping => (Color::Red, Color::DarkRed),
-            SelectionState::PartitionLeft | SelectionState::PartitionRight => (Color::Blue, Color::DarkBlue),
+            SelectionState::Comparing
+            | SelectionState::Swapping
+            | SelectionState::CurrentMin
+            | SelectionState::Selected
+            | SelectionState::WouldChange
+            | SelectionState::Removed
+            | SelectionState::OutsideRange => theme::state_colors(color_theme, state),
+            SelectionState::Normal
+            | SelectionState::Sorted
+            | SelectionState::PartitionLeft
+            | SelectionState::PartitionRight => (Color::Grey, Color::Reset),
+        }
+    }
+
+    // Lays out counts as `rows` lines of block characters, top line first, one two-column bar per count
+    // The tallest bar fills every row, any non-zero count shows at least an eighth of a row
+    pub fn histogram_rows(counts: &[usize], rows: usize) -> Vec<String> {
+        const BLOCKS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+        let max_count = counts.iter().copied().max().unwrap_or(0).max(1);
+        let eighths: Vec<usize> = counts.iter().map(|&count| (count * rows * 8).div_ceil(max_count)).collect();
+        (0..rows)
+            .map(|row| {
+                let floor = (rows - 1 - row) * 8;
+                eighths
+                    .iter()
+                    .map(|&height| {
+                        let block = BLOCKS[height.saturating_sub(floor).min(8)];
+                        format!("{}{}", block, block)
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect()
+    }
+
+    // Draws rows from `histogram_rows` with their top left corner at (x, y)
+    pub fn draw_histogram(&mut self, rows: &[String], x: u16, y: u16) {
+        self.stdout.queue(SetForegroundColor(Color::Cyan)).unwrap();
+        for (i, row) in rows.iter().enumerate() {
+            self.stdout.queue(MoveTo(x, y + i as u16)).unwrap();
+            self.stdout.queue(Print(row)).unwrap();
         }
+        self.stdout.queue(ResetColor).unwrap();
+    }
+
+    // Maps a value onto a blue (smallest) to red (largest) hue ramp
+    pub fn value_color(value: f64, min_value: f64, max_value: f64) -> Color {
+        let range = max_value - min_value;
+        let t = if range <= 0.0 {
+            0.0
+        } else {
+            ((value - min_value) / range).clamp(0.0, 1.0)
+        };
+        // Hue 240 is blue, 0 is red, the way passes through cyan, green and yellow
+        let hue = 240.0 * (1.0 - t);
+        let sector = hue / 60.0;
+        let x = ((1.0 - (sector % 2.0 - 1.0).abs()) * 255.0).round() as u8;
+        let (r, g, b) = match sector as u32 {
+            0 => (255, x, 0),
+            1 => (x, 255, 0),
+            2 => (0, 255, x),
+            3 => (0, x, 255),
+            _ => (x, 0, 255),
+        };
+        Color::Rgb { r, g, b }
     }
 
     // Draws the legend
     pub fn draw_legend(
-        stdout: &mut std::io::Stdout,
-        items: &[(&str, Color)],
+        &mut self,
+        items: &[(&str, SelectionState)],
         width: u16,
         height: u16,
         array_start_y: usize,
     ) {
-        let max_bar_height = (height as usize).saturating_sub(20).min(20);
+        let max_bar_height = Self::max_bar_height(height);
         let legend_y = array_start_y + max_bar_height + 4;
         let legend_width = items.len() * 15;
         let legend_start_x = (width as usize - legend_width) / 2;
-        for (i, (label, color)) in items.iter().enumerate() {
+        for (i, (label, state)) in items.iter().enumerate() {
             let x = legend_start_x + i * 15;
-            stdout.queue(MoveTo(x as u16, legend_y as u16)).unwrap();
-            stdout.queue(SetForegroundColor(*color)).unwrap();
-            stdout.queue(Print("██")).unwrap();
-            stdout.queue(ResetColor).unwrap();
-            stdout.queue(Print(format!(" {}", label))).unwrap();
+            let (color, _) = theme::state_colors(self.color_theme, *state);
+            self.stdout.queue(MoveTo(x as u16, legend_y as u16)).unwrap();
+            self.stdout.queue(SetForegroundColor(color)).unwrap();
+            self.stdout.queue(Print("██")).unwrap();
+            self.stdout.queue(ResetColor).unwrap();
+            self.stdout.queue(Print(format!(" {}", label))).unwrap();
+        }
+    }
+
+    // Draws buckets or piles as labeled rows below the legend, the values already formatted
+    pub fn draw_buckets(
+        &mut self,
+        label: &str,
+        buckets: &[Vec<String>],
+        highlighted: Option<usize>,
+        width: u16,
+        height: u16,
+        array_start_y: usize,
+    ) {
+        let max_bar_height = Self::max_bar_height(height);
+        let start_y = array_start_y + max_bar_height + 6;
+        let last_y = (height as usize).saturating_sub(13);
+        let max_line = (width as usize).saturating_sub(10);
+        for (i, bucket) in buckets.iter().enumerate() {
+            let y = start_y + i;
+            if y >= last_y && i + 1 < buckets.len() {
+                // Out of room: summarize the remaining rows
+                self.stdout.queue(MoveTo(5, y as u16)).unwrap();
+                self.stdout.queue(SetForegroundColor(self.palette.dim)).unwrap();
+                self.stdout.queue(Print(format!("... +{} more", buckets.len() - i))).unwrap();
+                self.stdout.queue(ResetColor).unwrap();
+                break;
+            }
+            let values = bucket.join(" ");
+            let mut line = format!("{} {}: [{}]", label, i + 1, values);
+            if line.len() > max_line {
+                line.truncate(max_line.saturating_sub(4));
+                line.push_str(" ...");
+            }
+            self.stdout.queue(MoveTo(5, y as u16)).unwrap();
+            if highlighted == Some(i) {
+                self.stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
+                self.stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+            } else {
+                self.stdout.queue(SetForegroundColor(Color::Cyan)).unwrap();
+            }
+            self.stdout.queue(Print(line)).unwrap();
+            self.stdout.queue(ResetColor).unwrap();
+        }
+    }
+
+    // Draws a guessed sequence as one row below the legend, each entry colored by its outcome:
+    // green when it matched, red when it missed and white while it is still unchecked
+    pub fn draw_guess_row(
+        &mut self,
+        label: &str,
+        guesses: &[(String, Option<bool>)],
+        width: u16,
+        height: u16,
+        array_start_y: usize,
+    ) {
+        let max_bar_height = Self::max_bar_height(height);
+        let y = (array_start_y + max_bar_height + 6) as u16;
+        let max_line = (width as usize).saturating_sub(10);
+
+        self.stdout.queue(MoveTo(5, y)).unwrap();
+        self.stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+        self.stdout.queue(Print(format!("{}:", label))).unwrap();
+        let mut used = label.len() + 1;
+        for (text, outcome) in guesses {
+            if used + text.len() + 1 > max_line {
+                self.stdout.queue(SetForegroundColor(self.palette.dim)).unwrap();
+                self.stdout.queue(Print(" ...")).unwrap();
+                break;
+            }
+            let color = match outcome {
+                Some(true) => Color::Green,
+                Some(false) => Color::Red,
+                None => self.palette.text,
+            };
+            self.stdout.queue(SetForegroundColor(color)).unwrap();
+            self.stdout.queue(Print(format!(" {}", text))).unwrap();
+            used += text.len() + 1;
+        }
+        self.stdout.queue(ResetColor).unwrap();
+    }
+
+    // Draws a secondary row of short bars below the legend, each slot under the main bar with the same index
+    // `slots` has one entry per array index, None leaves the slot empty
+    pub fn draw_buffer_row<T: SortElement>(
+        &mut self,
+        label: &str,
+        slots: &[Option<(T, Color)>],
+        max_value: T,
+        width: u16,
+        height: u16,
+        array_start_y: usize,
+    ) {
+        let max_bar_height = Self::max_bar_height(height);
+        let label_y = array_start_y + max_bar_height + 6;
+        let bottom_y = label_y + BUFFER_BAR_HEIGHT;
+        if bottom_y >= (height as usize).saturating_sub(13) {
+            return;
+        }
+        self.stdout.queue(MoveTo(5, label_y as u16)).unwrap();
+        self.stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+        self.stdout.queue(Print(label)).unwrap();
+
+        let (start_x, bar_width, spacing) = self.cached_bar_layout(slots.len(), width);
+        let max_value = Some(max_value.to_f64()).filter(|&max| max > 0.0).unwrap_or(1.0);
+        for (i, slot) in slots.iter().enumerate() {
+            let Some((value, color)) = slot else {
+                continue;
+            };
+            let x = start_x + i * (bar_width + spacing);
+            let bar_height = (value.to_f64() * BUFFER_BAR_HEIGHT as f64 / max_value).ceil().max(1.0) as usize;
+            self.stdout.queue(SetForegroundColor(*color)).unwrap();
+            for h in 0..bar_height {
+                self.stdout.queue(MoveTo(x as u16, (bottom_y - h) as u16)).unwrap();
+                self.stdout.queue(Print("█".repeat(bar_width))).unwrap();
+            }
+        }
+        self.stdout.queue(ResetColor).unwrap();
+    }
+
+    // Draws colored lines of text below the legend, one per row, for panels such as a run stack
+    pub fn draw_text_rows(
+        &mut self,
+        rows: &[(String, Color)],
+        width: u16,
+        height: u16,
+        array_start_y: usize,
+    ) {
+        let max_bar_height = Self::max_bar_height(height);
+        let start_y = array_start_y + max_bar_height + 6;
+        let last_y = (height as usize).saturating_sub(13);
+        let max_line = (width as usize).saturating_sub(10);
+        for (i, (text, color)) in rows.iter().enumerate() {
+            let y = start_y + i;
+            if y >= last_y {
+                break;
+            }
+            let line: String = text.chars().take(max_line).collect();
+            self.stdout.queue(MoveTo(5, y as u16)).unwrap();
+            self.stdout.queue(SetForegroundColor(*color)).unwrap();
+            self.stdout.queue(Print(line)).unwrap();
+            self.stdout.queue(ResetColor).unwrap();
         }
     }
 
     // Draws the statistics
     pub fn draw_statistics(
-        stdout: &mut std::io::Stdout,
+        &mut self,
         stats: &[String],
         width: u16,
         height: u16,
     ) {
         let stats_y = height.saturating_sub(12);
-        for (i, stat) in stats.iter().enumerate() {
-            let x = 5 + (i % 3) * 25;
-            let y = stats_y + (i / 3) as u16;
-            stdout.queue(MoveTo(x as u16, y)).unwrap();
-            stdout.queue(SetForegroundColor(Color::Cyan)).unwrap();
-            stdout.queue(Print(stat)).unwrap();
-            stdout.queue(ResetColor).unwrap();
+        for (x, row, text) in Self::layout_statistics(stats, width) {
+            self.stdout.queue(MoveTo(x, stats_y + row)).unwrap();
+            self.stdout.queue(SetForegroundColor(Color::Cyan)).unwrap();
+            self.stdout.queue(Print(text)).unwrap();
+            self.stdout.queue(ResetColor).unwrap();
         }
     }
 
+    /// Computes where each statistic goes as `(x, row, text)`.
+    /// The column width follows the longest stat and the column count follows the
+    /// terminal width, so stats never overlap or run past the right edge.
+    pub fn layout_statistics(stats: &[String], width: u16) -> Vec<(u16, u16, String)> {
+        const LEFT_MARGIN: usize = 5;
+        const COLUMN_GAP: usize = 3;
+
+        let available = (width as usize).saturating_sub(LEFT_MARGIN);
+        if available == 0 {
+            return Vec::new();
+        }
+
+        let longest = stats.iter().map(|s| s.chars().count()).max().unwrap_or(0);
+        let column_width = (longest + COLUMN_GAP).min(available).max(1);
+        // The last column does not need a trailing gap
+        let columns = if column_width >= available {
+            1
+        } else {
+            ((available + COLUMN_GAP) / column_width).max(1)
+        };
+
+        stats
+            .iter()
+            .enumerate()
+            .map(|(i, stat)| {
+                let x = LEFT_MARGIN + (i % columns) * column_width;
+                let max_len = (width as usize - x).min(column_width);
+                let text: String = stat.chars().take(max_len).collect();
+                (x as u16, (i / columns) as u16, text)
+            })
+            .collect()
+    }
+
     // Draws the controls
     pub fn draw_controls(
-        stdout: &mut std::io::Stdout,
+        &mut self,
         status: &str,
         controls: &str,
         width: u16,
@@ -145,48 +814,48 @@ impl VisualizerDrawer {
     ) {
         let controls_y = height.saturating_sub(4);
         // Status
-        stdout.queue(MoveTo(5, controls_y)).unwrap();
-        stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
+        self.stdout.queue(MoveTo(5, controls_y)).unwrap();
+        self.stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
         let status_color = match status {
             "COMPLETED!" => Color::Green,
             "RUNNING..." => Color::Yellow,
             "PAUSED" => Color::Red,
             "AWAITING SWAP CONFIRMATION" => Color::Magenta,
             "WAITING FOR QUESTION" => Color::Blue,
-            _ => Color::White,
+            _ => self.palette.text,
         };
-        stdout.queue(SetForegroundColor(status_color)).unwrap();
-        stdout.queue(Print(format!("Status: {}", status))).unwrap();
-        stdout.queue(ResetColor).unwrap();
+        self.stdout.queue(SetForegroundColor(status_color)).unwrap();
+        self.stdout.queue(Print(format!("Status: {}", status))).unwrap();
+        self.stdout.queue(ResetColor).unwrap();
         // Controls
         let controls_x = (width.saturating_sub(controls.len() as u16)) / 2;
-        stdout.queue(MoveTo(controls_x, controls_y + 1)).unwrap();
-        stdout.queue(SetForegroundColor(Color::DarkGrey)).unwrap();
-        stdout.queue(Print(controls)).unwrap();
-        stdout.queue(ResetColor).unwrap();
+        self.stdout.queue(MoveTo(controls_x, controls_y + 1)).unwrap();
+        self.stdout.queue(SetForegroundColor(self.palette.dim)).unwrap();
+        self.stdout.queue(Print(controls)).unwrap();
+        self.stdout.queue(ResetColor).unwrap();
     }
 
     // Draws the current operation info
     pub fn draw_operation_info(
-        stdout: &mut std::io::Stdout,
+        &mut self,
         message: &str,
         width: u16,
         height: u16,
         color: Color,
     ) {
         let op_x = (width.saturating_sub(message.len() as u16)) / 2;
-        stdout.queue(MoveTo(op_x, height.saturating_sub(6))).unwrap();
-        stdout.queue(SetForegroundColor(color)).unwrap();
+        self.stdout.queue(MoveTo(op_x, height.saturating_sub(6))).unwrap();
+        self.stdout.queue(SetForegroundColor(color)).unwrap();
         if color == Color::Green {
-            stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
+            self.stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
         }
-        stdout.queue(Print(message)).unwrap();
-        stdout.queue(ResetColor).unwrap();
+        self.stdout.queue(Print(message)).unwrap();
+        self.stdout.queue(ResetColor).unwrap();
     }
 
     // Draws a question
     pub fn draw_question(
-        stdout: &mut std::io::Stdout,
+        &mut self,
         question: &TeachingQuestion,
         width: u16,
         height: u16,
@@ -194,26 +863,26 @@ impl VisualizerDrawer {
         let q_text = format!("QUESTION: {}", question.text);
         let q_x = (width.saturating_sub(q_text.len() as u16)) / 2;
         let q_y = height.saturating_sub(10);
-        stdout.queue(MoveTo(q_x, q_y)).unwrap();
-        stdout.queue(SetForegroundColor(Color::Magenta)).unwrap();
-        stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
-        stdout.queue(Print(q_text)).unwrap();
-        stdout.queue(ResetColor).unwrap();
+        self.stdout.queue(MoveTo(q_x, q_y)).unwrap();
+        self.stdout.queue(SetForegroundColor(Color::Magenta)).unwrap();
+        self.stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
+        self.stdout.queue(Print(q_text)).unwrap();
+        self.stdout.queue(ResetColor).unwrap();
         for (i, option) in question.options.iter().enumerate() {
             let opt_text = format!("{}: {}", i + 1, option);
             let opt_x = (width.saturating_sub(opt_text.len() as u16)) / 2;
             let opt_y = q_y + (i as u16 + 1);
-            stdout.queue(MoveTo(opt_x, opt_y)).unwrap();
-            stdout.queue(SetForegroundColor(Color::White)).unwrap();
-            stdout.queue(Print(opt_text)).unwrap();
-            stdout.queue(ResetColor).unwrap();
+            self.stdout.queue(MoveTo(opt_x, opt_y)).unwrap();
+            self.stdout.queue(SetForegroundColor(self.palette.text)).unwrap();
+            self.stdout.queue(Print(opt_text)).unwrap();
+            self.stdout.queue(ResetColor).unwrap();
         }
         let inst_y = q_y + (question.options.len() as u16 + 2);
         let inst_x = (width.saturating_sub("Press 1,2, or 3.".len() as u16)) / 2;
-        stdout.queue(MoveTo(inst_x, inst_y)).unwrap();
-        stdout.queue(SetForegroundColor(Color::DarkGrey)).unwrap();
-        stdout.queue(Print("Press 1,2, or 3.")).unwrap();
-        stdout.queue(ResetColor).unwrap();
+        self.stdout.queue(MoveTo(inst_x, inst_y)).unwrap();
+        self.stdout.queue(SetForegroundColor(self.palette.dim)).unwrap();
+        self.stdout.queue(Print("Press 1,2, or 3.")).unwrap();
+        self.stdout.queue(ResetColor).unwrap();
     }
 }
 
@@ -242,10 +911,12 @@ pub fn show_intro_screen(intro_text: &str) {
 }
 
 // Shows feedback for question answers
+// Waits for a key, or with `auto_advance` continues by itself once that much time has passed
 pub fn show_question_feedback(
     correct: bool,
     question: &TeachingQuestion,
     answer: usize,
+    auto_advance: Option<Duration>,
 ) {
     let mut stdout = stdout();
     let (width, height) = size().unwrap();
@@ -264,16 +935,175 @@ pub fn show_question_feedback(
     stdout.queue(SetForegroundColor(if correct { Color::Green } else { Color::Red })).unwrap();
     stdout.queue(Print(feedback)).unwrap();
     stdout.queue(ResetColor).unwrap();
-    stdout.queue(MoveTo(5, (fb_y + 2) as u16)).unwrap();
-    stdout.queue(SetForegroundColor(Color::DarkGrey)).unwrap();
-    stdout.queue(Print("Press any key to continue...")).unwrap();
-    stdout.queue(ResetColor).unwrap();
-    stdout.flush().unwrap();
-    // Wait for any key press
+    let started = Instant::now();
     loop {
+        let prompt = match auto_advance {
+            Some(delay) => format!(
+                "Continuing in {} s, press any key to continue now...",
+                delay.saturating_sub(started.elapsed()).as_secs() + 1
+            ),
+            None => "Press any key to continue...".to_string(),
+        };
+        stdout.queue(MoveTo(5, (fb_y + 2) as u16)).unwrap();
+        stdout.queue(Clear(ClearType::CurrentLine)).unwrap();
+        stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+        stdout.queue(Print(prompt)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+        stdout.flush().unwrap();
+        // Wait for any key press or the end of the auto-advance delay
         if poll(Duration::from_millis(100)).unwrap_or(false) {
             let _ = read();
             break;
         }
+        if auto_advance.is_some_and(|delay| started.elapsed() >= delay) {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BarFrame, VisualizerDrawer};
+    use crate::common::enums::{ColorTheme, SelectionState, ValueFormat};
+    use crate::common::theme;
+    use crossterm::style::Color;
+
+    fn sample_stats() -> Vec<String> {
+        vec![
+            "Array Size: 20".to_string(),
+            "Comparisons: 1234".to_string(),
+            "Swaps: 56".to_string(),
+            "Phase: Building Max Heap".to_string(),
+            "Progress: 42.0%".to_string(),
+            "Teaching: ON".to_string(),
+            "Current Index: 7".to_string(),
+        ]
+    }
+
+    #[test]
+    fn statistics_layout_never_overlaps_or_overflows() {
+        let stats = sample_stats();
+        for width in [0u16, 3, 5, 6, 10, 20, 40, 80, 120, 200, 400] {
+            let layout = VisualizerDrawer::layout_statistics(&stats, width);
+            if width <= 5 {
+                assert!(layout.is_empty(), "width {width}");
+                continue;
+            }
+            assert_eq!(layout.len(), stats.len(), "width {width}");
+
+            for (x, _, text) in &layout {
+                let end = *x as usize + text.chars().count();
+                assert!(end <= width as usize, "width {width}: '{text}' ends at {end}");
+            }
+
+            for (i, (x1, row1, text1)) in layout.iter().enumerate() {
+                for (x2, row2, _) in layout.iter().skip(i + 1) {
+                    if row1 != row2 {
+                        continue;
+                    }
+                    let (a, b) = (*x1 as usize, *x2 as usize);
+                    let a_end = a + text1.chars().count();
+                    assert!(a_end < b || b < a, "width {width}: stats overlap on row {row1}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn statistics_layout_uses_more_columns_on_wide_terminals() {
+        let stats = sample_stats();
+        let rows = |width| {
+            VisualizerDrawer::layout_statistics(&stats, width)
+                .iter()
+                .map(|(_, row, _)| *row)
+                .max()
+                .unwrap()
+        };
+        assert!(rows(200) < rows(60));
+        assert_eq!(rows(20), stats.len() as u16 - 1);
+    }
+
+    #[test]
+    fn histogram_rows_scale_to_the_tallest_bar() {
+        let rows = VisualizerDrawer::histogram_rows(&[4, 0, 1, 2], 2);
+        assert_eq!(rows, ["██         ", "██    ▄▄ ██"]);
+    }
+
+    #[test]
+    fn value_gradient_runs_from_blue_to_red() {
+        assert_eq!(VisualizerDrawer::value_color(1.0, 1.0, 100.0), Color::Rgb { r: 0, g: 0, b: 255 });
+        assert_eq!(VisualizerDrawer::value_color(100.0, 1.0, 100.0), Color::Rgb { r: 255, g: 0, b: 0 });
+        // A flat array has nothing to rank, every bar gets the low end
+        assert_eq!(VisualizerDrawer::value_color(7.0, 7.0, 7.0), Color::Rgb { r: 0, g: 0, b: 255 });
+    }
+
+    #[test]
+    fn bar_labels_keep_the_lowest_digits_of_wide_values() {
+        assert_eq!(ValueFormat::Hex.fit(999, 3), "3E7");
+        assert_eq!(ValueFormat::Binary.fit(5, 4), "101");
+        assert_eq!(ValueFormat::Binary.fit(42, 4), "…010");
+        assert_eq!(ValueFormat::Binary.next(), ValueFormat::Decimal);
+    }
+
+    #[test]
+    fn big_numbers_put_each_digit_side_by_side() {
+        let rows = VisualizerDrawer::big_number(40);
+        assert_eq!(rows, ["█ █ █▀█", "▀▀█ █ █", "  █ █▄█"].map(String::from));
+        assert_eq!(VisualizerDrawer::big_number(7)[1], "  █");
+    }
+
+    #[test]
+    fn highlight_only_grays_out_idle_bars() {
+        assert_eq!(VisualizerDrawer::highlight_only_colors(ColorTheme::Classic, SelectionState::Normal), (Color::Grey, Color::Reset));
+        assert_eq!(VisualizerDrawer::highlight_only_colors(ColorTheme::Classic, SelectionState::Sorted), (Color::Grey, Color::Reset));
+        for color_theme in ColorTheme::ALL {
+            for active in [SelectionState::Comparing, SelectionState::Swapping] {
+                assert_eq!(VisualizerDrawer::highlight_only_colors(color_theme, active), theme::state_colors(color_theme, active));
+            }
+        }
+    }
+
+    #[test]
+    fn ghost_rows_scale_to_the_largest_value() {
+        let rows = VisualizerDrawer::ghost_rows(&[8, 4, 1, 0], 2);
+        assert_eq!(rows[0], ['█', ' ', ' ', ' ']);
+        assert_eq!(rows[1], ['█', '█', '▂', ' ']);
+        // Negative values hang below the axis of the current bars, in whole rows
+        let rows = VisualizerDrawer::ghost_rows(&[4, -4, 0], 3);
+        assert_eq!(rows, [vec!['█', ' ', ' '], vec!['█', '─', '█'], vec![' ', '█', ' ']]);
+    }
+
+    #[test]
+    fn negative_bars_grow_down_from_the_zero_axis() {
+        // -50..=50 over 21 rows puts the axis on the middle row
+        assert_eq!(VisualizerDrawer::axis_row(-50.0, 50.0, 21), 10);
+        assert_eq!(VisualizerDrawer::axis_bar_rows(50.0, -50.0, 50.0, 21), (0, 11));
+        assert_eq!(VisualizerDrawer::axis_bar_rows(0.0, -50.0, 50.0, 21), (10, 1));
+        assert_eq!(VisualizerDrawer::axis_bar_rows(-50.0, -50.0, 50.0, 21), (11, 10));
+        assert_eq!(VisualizerDrawer::axis_bar_rows(-1.0, -50.0, 50.0, 21), (11, 1));
+        // A single small negative value still gets a row below the axis
+        assert_eq!(VisualizerDrawer::axis_row(-1.0, 100.0, 21), 19);
+        assert_eq!(VisualizerDrawer::axis_bar_rows(-1.0, -1.0, 100.0, 21), (20, 1));
+    }
+
+    #[test]
+    fn only_changed_bars_are_drawn_again() {
+        let frame = |heights: &[usize]| BarFrame {
+            layout: (80, 40, 5),
+            axis: None,
+            bars: heights.iter().map(|&height| (10 - height, height, Color::White, Color::Reset)).collect(),
+        };
+        let previous = frame(&[3, 5, 2, 7]);
+        assert_eq!(frame(&[3, 2, 5, 7]).changed_bars(Some(&previous)), vec![1, 2]);
+        assert!(previous.changed_bars(Some(&previous)).is_empty());
+        // A new state color redraws the bar too
+        let mut compared = previous.clone();
+        compared.bars[3].2 = Color::Yellow;
+        assert_eq!(compared.changed_bars(Some(&previous)), vec![3]);
+        // Without a kept frame or after a resize every bar is drawn
+        assert_eq!(previous.changed_bars(None), vec![0, 1, 2, 3]);
+        let mut resized = previous.clone();
+        resized.layout = (100, 40, 5);
+        assert_eq!(resized.changed_bars(Some(&previous)).len(), 4);
     }
 }
@@ -7,6 +7,7 @@ use crossterm::{
 };
 use std::io::{stdout, Write};
 use std::time::Duration;
+use crate::common::theme;
 
 // Represents the type of dialog, which affects colors, icons, and default titles
 #[derive(Clone, Copy)]
@@ -112,7 +113,7 @@ impl Dialog {
     fn print_controls_after(&self, stdout: &mut std::io::Stdout) {
         let controls = "←/→ TAB nav, ENTER select, ESC cancel";
         stdout.execute(Clear(ClearType::CurrentLine)).unwrap();
-        stdout.execute(SetForegroundColor(Color::DarkGrey)).unwrap();
+        stdout.execute(SetForegroundColor(theme::dim())).unwrap();
         print!("{}", controls);
         stdout.flush().unwrap();
         println!(); // Move to the next line
@@ -200,7 +201,7 @@ impl Dialog {
         if !self.title.is_empty() && self.title != self.dialog_type.get_title() {
             let custom_title_x = dialog_x + (self.width.saturating_sub(self.title.len() as u16)) / 2;
             stdout.queue(MoveTo(custom_title_x, dialog_y + 2)).unwrap();
-            stdout.queue(SetForegroundColor(Color::White)).unwrap();
+            stdout.queue(SetForegroundColor(theme::text())).unwrap();
             stdout.queue(Print(&self.title)).unwrap();
             stdout.queue(ResetColor).unwrap();
         }
@@ -210,7 +211,7 @@ impl Dialog {
         for (i, line) in self.message.iter().enumerate() {
             let line_x = dialog_x + (self.width.saturating_sub(line.len() as u16)) / 2;
             stdout.queue(MoveTo(line_x, message_start_y + i as u16)).unwrap();
-            stdout.queue(SetForegroundColor(Color::White)).unwrap();
+            stdout.queue(SetForegroundColor(theme::text())).unwrap();
             stdout.queue(Print(line)).unwrap();
             stdout.queue(ResetColor).unwrap();
         }
@@ -240,7 +241,7 @@ impl Dialog {
         let controls = "←/→ TAB nav, ENTER select, ESC cancel";
         let controls_x = dialog_x + (self.width.saturating_sub(controls.len() as u16)) / 2;
         stdout.queue(MoveTo(controls_x, dialog_y + self.height - 3)).unwrap();
-        stdout.queue(SetForegroundColor(Color::DarkGrey)).unwrap();
+        stdout.queue(SetForegroundColor(theme::dim())).unwrap();
         stdout.queue(Print(controls)).unwrap();
         stdout.queue(ResetColor).unwrap();
 
@@ -291,11 +292,15 @@ pub fn show_question(title: &str, message: &str, buttons: Vec<&str>) -> usize {
 }
 
 // Specific helper functions for common use cases
-pub fn show_no_array_selected() {
-    show_warning(
+/// Asks whether a new array should be generated because none is selected.
+/// Returns `true` if the user chose to generate one now.
+pub fn show_no_array_selected() -> bool {
+    let result = show_question(
         "No Array Selected",
-        "Please select an array first from the Array Manager.\n\nGo to 'Generate/Select Array' option to create\nor select an array for sorting."
+        "No array selected — please create/select one in\nArray Management first.\n\nGenerate a random array now?",
+        vec!["Generate", "Cancel"]
     );
+    result == 0
 }
 
 pub fn confirm_exit() -> bool {
@@ -0,0 +1,170 @@
+use crate::common::enums::ValueFormat;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+/// Most decimal places a real value may be typed with, more would not fit under a bar.
+pub const MAX_DECIMALS: usize = 3;
+
+/// A value the visualizers can sort: ordered, displayable and drawable as a bar.
+/// Comparison sorts, searches and the drawer work on any of them.
+pub trait SortElement: Copy + PartialEq + PartialOrd + Default + Display + Debug + 'static {
+    /// Whether values can be negative, the inputs accept a leading '-' then.
+    const SIGNED: bool = false;
+
+    /// The value as a number for bar heights, colors and statistics.
+    fn to_f64(self) -> f64;
+
+    /// Total order of the values, the one ascending sorts arrange them in.
+    fn order(self, other: Self) -> Ordering;
+
+    /// Reads a value typed or pasted by the user, None if it is not one.
+    fn parse(text: &str) -> Option<Self>;
+
+    /// Writes the value in the chosen format, e.g. "2A" in hex.
+    fn format(self, format: ValueFormat) -> String;
+
+    /// Writes the value into at most `width` characters for a bar label.
+    fn fit(self, format: ValueFormat, width: usize) -> String;
+}
+
+/// A value with integer digits, the only kind radix and counting sort accept.
+pub trait IntegerElement: SortElement + Ord + Hash {
+    /// How far the value lies above `base`, the number digits and count slots are taken from.
+    fn offset_from(self, base: Self) -> u32;
+}
+
+impl SortElement for u32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn order(self, other: Self) -> Ordering {
+        self.cmp(&other)
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        text.trim().parse().ok()
+    }
+
+    fn format(self, format: ValueFormat) -> String {
+        format.format(self)
+    }
+
+    fn fit(self, format: ValueFormat, width: usize) -> String {
+        format.fit(self, width)
+    }
+}
+
+impl IntegerElement for u32 {
+    fn offset_from(self, base: Self) -> u32 {
+        self - base
+    }
+}
+
+/// Negative values keep their sign in every format, e.g. "-2A" in hex
+impl SortElement for i32 {
+    const SIGNED: bool = true;
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn order(self, other: Self) -> Ordering {
+        self.cmp(&other)
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        text.trim().parse().ok()
+    }
+
+    fn format(self, format: ValueFormat) -> String {
+        let digits = format.format(self.unsigned_abs());
+        if self < 0 { format!("-{}", digits) } else { digits }
+    }
+
+    /// The sign is always kept, the digits are cut like those of u32, e.g. "-…10"
+    fn fit(self, format: ValueFormat, width: usize) -> String {
+        if self < 0 {
+            format!("-{}", format.fit(self.unsigned_abs(), width.saturating_sub(1).max(1)))
+        } else {
+            format.fit(self as u32, width)
+        }
+    }
+}
+
+impl IntegerElement for i32 {
+    // `base` is never above the value, so the distance is the difference
+    fn offset_from(self, base: Self) -> u32 {
+        self.abs_diff(base)
+    }
+}
+
+/// Real numbers are always written in decimal, the number bases only apply to integers.
+impl SortElement for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn order(self, other: Self) -> Ordering {
+        self.total_cmp(&other)
+    }
+
+    /// Accepts non-negative numbers with at most MAX_DECIMALS decimal places, e.g. "2.5" or ".75"
+    fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let (whole, fraction) = text.split_once('.').unwrap_or((text, ""));
+        let digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+        if whole.len() + fraction.len() == 0 || !digits(whole) || !digits(fraction) || fraction.len() > MAX_DECIMALS {
+            return None;
+        }
+        text.parse().ok()
+    }
+
+    fn format(self, _format: ValueFormat) -> String {
+        self.to_string()
+    }
+
+    /// Drops decimal places until the value fits, the whole part is always kept, e.g. "13" for 12.75
+    fn fit(self, _format: ValueFormat, width: usize) -> String {
+        let text = self.to_string();
+        if text.len() <= width {
+            return text;
+        }
+        (0..MAX_DECIMALS)
+            .rev()
+            .map(|places| format!("{:.*}", places, self))
+            .find(|text| text.len() <= width)
+            .unwrap_or_else(|| format!("{:.0}", self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn real_values_parse_and_fit_under_a_bar() {
+        assert_eq!(f64::parse("2.5"), Some(2.5));
+        assert_eq!(f64::parse(".75"), Some(0.75));
+        assert_eq!(f64::parse("3"), Some(3.0));
+        assert_eq!(f64::parse("1.2345"), None);
+        assert_eq!(f64::parse("-2"), None);
+        assert_eq!(f64::parse("."), None);
+        assert_eq!(f64::parse("inf"), None);
+        assert_eq!(12.75.fit(ValueFormat::Hex, 3), "13");
+        assert_eq!(12.75.fit(ValueFormat::Decimal, 4), "12.8");
+        assert_eq!(0.5.format(ValueFormat::Binary), "0.5");
+        assert_eq!(2.0.format(ValueFormat::Decimal), "2");
+    }
+
+    #[test]
+    fn negative_values_keep_their_sign() {
+        assert_eq!(i32::parse("-12"), Some(-12));
+        assert_eq!(u32::parse("-12"), None);
+        assert_eq!((-42).format(ValueFormat::Hex), "-2A");
+        assert_eq!((-6).fit(ValueFormat::Binary, 3), "-…0");
+        assert_eq!((-7).offset_from(-10), 3);
+        assert_eq!(i32::MAX.offset_from(i32::MIN), u32::MAX);
+    }
+}
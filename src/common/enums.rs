@@ -1,3 +1,8 @@
+use crate::common::base_visualizer::Comparator;
+use crate::common::element::SortElement;
+use std::rc::Rc;
+use serde::{Deserialize, Serialize};
+
 /// Represents the visual state of an element in a sorting visualization.
 /// Each state can be used to apply different colors or styles to elements
 /// during the sorting process, making it easier to track the algorithm's progress.
@@ -26,8 +31,342 @@ pub enum SelectionState {
 
     /// State for elements on the right side of a partition (e.g., in quicksort).
     PartitionRight,
+
+    /// State for elements thrown out of the array (e.g., in stalin sort).
+    Removed,
+
+    /// State for elements outside the range being sorted, they stay where they are.
+    OutsideRange,
+
+    /// State for elements that would flip the next comparison if swapped with the element it is about.
+    WouldChange,
+}
+
+impl SelectionState {
+    /// Human-readable name of the state, e.g. for the inspection line.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SelectionState::Normal => "Normal",
+            SelectionState::Sorted => "Sorted",
+            SelectionState::CurrentMin => "Current Min",
+            SelectionState::Comparing => "Comparing",
+            SelectionState::Selected => "Selected",
+            SelectionState::Swapping => "Swapping",
+            SelectionState::PartitionLeft => "Partition Left",
+            SelectionState::PartitionRight => "Partition Right",
+            SelectionState::Removed => "Removed",
+            SelectionState::OutsideRange => "Outside Range",
+            SelectionState::WouldChange => "Would Change Decision",
+        }
+    }
+}
+
+
+/// Ordering the comparison sorts arrange the array by.
+/// Every preset is a total preorder, values with equal keys may end up in any order.
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum SortOrder {
+    /// Smallest value first.
+    #[default]
+    Ascending,
+
+    /// Largest value first.
+    Descending,
+
+    /// By the last decimal digit, e.g. 41 before 12.
+    LastDigit,
+
+    /// By the decimal text, e.g. 10 before 9.
+    AsText,
+
+    /// By the distance from 50, an "absolute value" around the middle of the default range.
+    DistanceFromMiddle,
+}
+
+impl SortOrder {
+    /// Every preset, in the order the settings menu cycles through them.
+    pub const ALL: [SortOrder; 5] = [
+        SortOrder::Ascending,
+        SortOrder::Descending,
+        SortOrder::LastDigit,
+        SortOrder::AsText,
+        SortOrder::DistanceFromMiddle,
+    ];
+
+    /// Human-readable name of the ordering.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SortOrder::Ascending => "Ascending",
+            SortOrder::Descending => "Descending",
+            SortOrder::LastDigit => "By Last Digit",
+            SortOrder::AsText => "As Text",
+            SortOrder::DistanceFromMiddle => "Distance From 50",
+        }
+    }
+
+    /// The preset after this one, wrapping around.
+    pub fn next(&self) -> SortOrder {
+        let index = SortOrder::ALL.iter().position(|order| order == self).unwrap_or(0);
+        SortOrder::ALL[(index + 1) % SortOrder::ALL.len()]
+    }
+
+    /// Builds the comparison function of the preset.
+    /// Digits and distances of real values are taken from their whole part and their value.
+    pub fn comparator<T: SortElement>(&self) -> Comparator<T> {
+        match self {
+            SortOrder::Ascending => Rc::new(|a: T, b: T| a.order(b)),
+            SortOrder::Descending => Rc::new(|a: T, b: T| b.order(a)),
+            SortOrder::LastDigit => Rc::new(|a: T, b: T| last_digit(a).cmp(&last_digit(b))),
+            SortOrder::AsText => Rc::new(|a: T, b: T| a.to_string().cmp(&b.to_string())),
+            SortOrder::DistanceFromMiddle => Rc::new(|a: T, b: T| (a.to_f64() - 50.0).abs().total_cmp(&(b.to_f64() - 50.0).abs())),
+        }
+    }
+}
+
+// Last decimal digit of the whole part, e.g. 2 for 42, for 12.5 and for -2
+fn last_digit<T: SortElement>(value: T) -> u64 {
+    value.to_f64().trunc().abs() as u64 % 10
+}
+
+/// Number base element values are displayed in.
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum ValueFormat {
+    /// Base 10, e.g. 42.
+    #[default]
+    Decimal,
+
+    /// Base 16, e.g. 2A.
+    Hex,
+
+    /// Base 2, e.g. 101010.
+    Binary,
+}
+
+impl ValueFormat {
+    /// Every format, in the order the X key cycles through them.
+    pub const ALL: [ValueFormat; 3] = [ValueFormat::Decimal, ValueFormat::Hex, ValueFormat::Binary];
+
+    /// Human-readable name of the format.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ValueFormat::Decimal => "Decimal",
+            ValueFormat::Hex => "Hex",
+            ValueFormat::Binary => "Binary",
+        }
+    }
+
+    /// The format after this one, wrapping around.
+    pub fn next(&self) -> ValueFormat {
+        let index = ValueFormat::ALL.iter().position(|format| format == self).unwrap_or(0);
+        ValueFormat::ALL[(index + 1) % ValueFormat::ALL.len()]
+    }
+
+    /// Formats a value without a prefix, e.g. "2A" in hex.
+    pub fn format(&self, value: u32) -> String {
+        match self {
+            ValueFormat::Decimal => value.to_string(),
+            ValueFormat::Hex => format!("{:X}", value),
+            ValueFormat::Binary => format!("{:b}", value),
+        }
+    }
+
+    /// Formats a value into at most `width` characters, a longer one keeps
+    /// its lowest digits behind a "…", e.g. "…10" for 6 in binary.
+    pub fn fit(&self, value: u32, width: usize) -> String {
+        let text = self.format(value);
+        if text.len() <= width || width == 0 {
+            return text;
+        }
+        format!("…{}", &text[text.len() - (width - 1)..])
+    }
+}
+
+/// Background the terminal is assumed to have, picks the neutral text and highlight colors.
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum TerminalTheme {
+    /// Light text on a dark background.
+    #[default]
+    Dark,
+
+    /// Dark text on a light background.
+    Light,
+
+    /// Detected from the terminal, dark if it can't be told.
+    Auto,
+}
+
+impl TerminalTheme {
+    /// Every theme, in the order the settings menu cycles through them.
+    pub const ALL: [TerminalTheme; 3] = [TerminalTheme::Dark, TerminalTheme::Light, TerminalTheme::Auto];
+
+    /// Human-readable name of the theme.
+    pub fn label(&self) -> &'static str {
+        match self {
+            TerminalTheme::Dark => "Dark",
+            TerminalTheme::Light => "Light",
+            TerminalTheme::Auto => "Auto",
+        }
+    }
+
+    /// The theme after this one, wrapping around.
+    pub fn next(&self) -> TerminalTheme {
+        let index = TerminalTheme::ALL.iter().position(|theme| theme == self).unwrap_or(0);
+        TerminalTheme::ALL[(index + 1) % TerminalTheme::ALL.len()]
+    }
+}
+
+/// Colors the bars of a visualizer are drawn in for each selection state.
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum ColorTheme {
+    /// The original colors, bright bars on dark backgrounds.
+    #[default]
+    Classic,
+
+    /// Bold bright colors only, never a dark color on a dark background.
+    HighContrast,
+
+    /// The accent colors of the Solarized palette.
+    Solarized,
+}
+
+impl ColorTheme {
+    /// Every color theme, in the order the settings menu cycles through them.
+    pub const ALL: [ColorTheme; 3] = [ColorTheme::Classic, ColorTheme::HighContrast, ColorTheme::Solarized];
+
+    /// Human-readable name of the color theme.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColorTheme::Classic => "Classic",
+            ColorTheme::HighContrast => "High Contrast",
+            ColorTheme::Solarized => "Solarized",
+        }
+    }
+
+    /// The color theme after this one, wrapping around.
+    pub fn next(&self) -> ColorTheme {
+        let index = ColorTheme::ALL.iter().position(|theme| theme == self).unwrap_or(0);
+        ColorTheme::ALL[(index + 1) % ColorTheme::ALL.len()]
+    }
+}
+
+/// Algorithm bucket sort runs on each of its buckets.
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum BucketInnerSort {
+    /// Insertion sort, fast on the few elements a bucket usually holds.
+    #[default]
+    Insertion,
+
+    /// Bucket sort again, splitting the bucket's value range until every sub-bucket is sorted.
+    Bucket,
+}
+
+impl BucketInnerSort {
+    /// Every inner sort, in the order the settings menu cycles through them.
+    pub const ALL: [BucketInnerSort; 2] = [BucketInnerSort::Insertion, BucketInnerSort::Bucket];
+
+    /// Human-readable name of the inner sort.
+    pub fn label(&self) -> &'static str {
+        match self {
+            BucketInnerSort::Insertion => "Insertion Sort",
+            BucketInnerSort::Bucket => "Bucket Sort (recursive)",
+        }
+    }
+
+    /// The inner sort after this one, wrapping around.
+    pub fn next(&self) -> BucketInnerSort {
+        let index = BucketInnerSort::ALL.iter().position(|inner| inner == self).unwrap_or(0);
+        BucketInnerSort::ALL[(index + 1) % BucketInnerSort::ALL.len()]
+    }
+}
+
+/// How the array is drawn, V in a visualizer cycles through the modes.
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum RenderMode {
+    /// Bars colored by what the algorithm does with them.
+    #[default]
+    Bars,
+
+    /// Idle bars colored blue to red by their value.
+    Gradient,
+
+    /// Bars with the array the run started from drawn faintly above them.
+    Original,
+
+    /// Every bar gray except the ones being compared, swapped or picked, for projectors and recordings.
+    HighlightOnly,
+}
+
+impl RenderMode {
+    /// Every render mode, in the order V cycles through them.
+    pub const ALL: [RenderMode; 4] = [RenderMode::Bars, RenderMode::Gradient, RenderMode::Original, RenderMode::HighlightOnly];
+
+    /// Human-readable name of the render mode.
+    pub fn label(&self) -> &'static str {
+        match self {
+            RenderMode::Bars => "Bars",
+            RenderMode::Gradient => "Gradient",
+            RenderMode::Original => "Original",
+            RenderMode::HighlightOnly => "Highlight Only",
+        }
+    }
+
+    /// The render mode after this one, wrapping around.
+    pub fn next(&self) -> RenderMode {
+        let index = RenderMode::ALL.iter().position(|mode| mode == self).unwrap_or(0);
+        RenderMode::ALL[(index + 1) % RenderMode::ALL.len()]
+    }
+}
+
+/// Distribution of a preset array, for showing how algorithms react to ordered input.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PresetKind {
+    /// Already in ascending order.
+    Sorted,
+
+    /// In descending order.
+    Reversed,
+
+    /// Sorted, then a few pairs swapped.
+    NearlySorted,
+
+    /// Random values drawn from a handful of distinct ones.
+    FewUnique,
 }
 
+impl PresetKind {
+    /// Every preset, in the order the preset dialog lists them.
+    pub const ALL: [PresetKind; 4] = [PresetKind::Sorted, PresetKind::Reversed, PresetKind::NearlySorted, PresetKind::FewUnique];
+
+    /// Human-readable name of the preset.
+    pub fn label(&self) -> &'static str {
+        match self {
+            PresetKind::Sorted => "Already Sorted",
+            PresetKind::Reversed => "Reversed",
+            PresetKind::NearlySorted => "Nearly Sorted",
+            PresetKind::FewUnique => "Few Unique",
+        }
+    }
+
+    /// Start of the generated array's name, e.g. "Reversed" for "Reversed_20".
+    pub fn name_prefix(&self) -> &'static str {
+        match self {
+            PresetKind::Sorted => "Sorted",
+            PresetKind::Reversed => "Reversed",
+            PresetKind::NearlySorted => "NearlySorted",
+            PresetKind::FewUnique => "FewUnique",
+        }
+    }
+
+    /// What the preset shows off, for the preset dialog.
+    pub fn description(&self) -> &'static str {
+        match self {
+            PresetKind::Sorted => "Best case of adaptive sorts such as insertion and tim sort",
+            PresetKind::Reversed => "Worst case of insertion sort, every element moves all the way",
+            PresetKind::NearlySorted => "Sorted with a few pairs swapped, adaptive sorts stay fast",
+            PresetKind::FewUnique => "Many duplicates, hard on quick sort, easy for counting sort",
+        }
+    }
+}
 
 // Simple question structure for teaching
 #[derive(Clone)]
@@ -0,0 +1,214 @@
+use crate::common::base_visualizer::SortVisualizer;
+use crate::common::element::SortElement;
+use crate::common::paths::config_file;
+use std::fs::{create_dir_all, write};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const FORMAT_HEADER: &str = "# togisoft frame 1";
+const BAR_WIDTH: usize = 3;
+const CHART_HEIGHT: usize = 10;
+
+/// Writes the current frame with its annotations to a new file in the config directory.
+/// Returns the path it was written to, `frame_text` describes the format.
+pub fn export_frame<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &V) -> Result<PathBuf, String> {
+    let millis = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_millis());
+    let path = config_file(&format!("frame_{}_{}.txt", visualizer.settings_key(), millis));
+    if let Some(dir) = path.parent() {
+        let _ = create_dir_all(dir);
+    }
+    write(&path, frame_text(visualizer)).map_err(|error| format!("Could not export the frame: {}", error))?;
+    Ok(path)
+}
+
+/// The frame in the export format for course material.
+///
+/// F in a visualizer freezes the current frame. The arrow keys and N / A then place labels and
+/// arrows on bars, and F writes the frame to `frame_<algorithm>_<milliseconds>.txt` in the
+/// config directory. The file is UTF-8 text in four parts, so it can be pasted as it is or
+/// turned into an image by a script:
+///
+/// ```text
+/// # togisoft frame 1
+/// algorithm: Bubble Sort
+/// status: PAUSED
+/// operation: Comparing 5 and 3
+/// statistics: Array Size: 6 | Comparisons: 1 | ...
+///
+/// ## bars
+/// index  value  state  column
+/// 0  5  Comparing  0
+/// ...
+///
+/// ## annotations
+/// index  value  arrow  column  text
+/// 1  3  yes  4  pivot
+///
+/// ## chart
+///    ↓
+/// ███
+/// ███ ███
+///  5   3  ...
+/// ```
+///
+/// - The header lines are `key: value`, `statistics` joins the statistics panel with ` | `.
+/// - `## bars` and `## annotations` are tab-separated tables with a header row, shown with spaces above.
+///   `state` is the label shown when inspecting a bar. `arrow` is `yes` or `no`, and `text` is
+///   empty for an arrow without a label.
+/// - `column` is where the bar starts in the chart, counted in characters from the left.
+///   Every bar is `BAR_WIDTH` characters wide with a space between bars.
+/// - `## chart` is the frame drawn in monospaced text, `CHART_HEIGHT` rows for the tallest bar.
+///   The rows above it hold the labels, then the arrows, and the value row comes below it.
+///
+/// The version in the first line goes up if the layout changes.
+pub fn frame_text<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &V) -> String {
+    let state = visualizer.state();
+    let array = visualizer.get_array();
+    let states = visualizer.get_states();
+    let mut lines = vec![
+        FORMAT_HEADER.to_string(),
+        format!("algorithm: {}", visualizer.display_name()),
+        format!("status: {}", visualizer.get_status()),
+        format!("operation: {}", visualizer.get_current_operation()),
+        format!("statistics: {}", visualizer.get_statistics_strings().join(" | ")),
+        String::new(),
+        "## bars".to_string(),
+        "index\tvalue\tstate\tcolumn".to_string(),
+    ];
+    for (i, &value) in array.iter().enumerate() {
+        let label = states.get(i).map_or("Normal", |selection| selection.label());
+        lines.push(format!("{}\t{}\t{}\t{}", i, value, label, column(i)));
+    }
+
+    let mut annotated: Vec<usize> = state.annotations.keys().copied().filter(|&i| i < array.len()).collect();
+    annotated.sort_unstable();
+    lines.push(String::new());
+    lines.push("## annotations".to_string());
+    lines.push("index\tvalue\tarrow\tcolumn\ttext".to_string());
+    for &i in &annotated {
+        let annotation = &state.annotations[&i];
+        let arrow = if annotation.arrow { "yes" } else { "no" };
+        lines.push(format!("{}\t{}\t{}\t{}\t{}", i, array[i], arrow, column(i), annotation.text));
+    }
+
+    lines.push(String::new());
+    lines.push("## chart".to_string());
+    lines.extend(chart_lines(visualizer));
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+// Left edge of bar `index` in the chart
+fn column(index: usize) -> usize {
+    index * (BAR_WIDTH + 1)
+}
+
+// Labels, arrows, the bars from the top row down, then the values
+fn chart_lines<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &V) -> Vec<String> {
+    let state = visualizer.state();
+    let array = visualizer.get_array();
+    let max_value = array.iter().map(|value| value.to_f64()).fold(1.0, f64::max);
+    let heights: Vec<usize> = array
+        .iter()
+        .map(|value| (value.to_f64() * CHART_HEIGHT as f64 / max_value).ceil() as usize)
+        .collect();
+
+    let mut label_row = Row::new();
+    let mut arrow_row = Row::new();
+    let mut annotated: Vec<usize> = state.annotations.keys().copied().filter(|&i| i < array.len()).collect();
+    annotated.sort_unstable();
+    for i in annotated {
+        let annotation = &state.annotations[&i];
+        let center = column(i) + BAR_WIDTH / 2;
+        if !annotation.text.is_empty() {
+            let start = center.saturating_sub(annotation.text.chars().count() / 2);
+            label_row.put(start, &annotation.text);
+        }
+        if annotation.arrow {
+            arrow_row.put(center, "↓");
+        }
+    }
+
+    let mut lines: Vec<String> = [label_row, arrow_row]
+        .into_iter()
+        .filter(|row| !row.is_empty())
+        .map(Row::finish)
+        .collect();
+    for level in (1..=CHART_HEIGHT).rev() {
+        let mut row = Row::new();
+        for (i, &height) in heights.iter().enumerate() {
+            if height >= level {
+                row.put(column(i), &"█".repeat(BAR_WIDTH));
+            }
+        }
+        lines.push(row.finish());
+    }
+    let mut value_row = Row::new();
+    for (i, value) in array.iter().enumerate() {
+        let text = value.to_string();
+        let start = (column(i) + BAR_WIDTH / 2).saturating_sub(text.len() / 2);
+        value_row.put(start, &text);
+    }
+    lines.push(value_row.finish());
+    lines
+}
+
+// One line of the chart, written at character columns
+struct Row {
+    cells: Vec<char>,
+}
+
+impl Row {
+    fn new() -> Self {
+        Self { cells: Vec::new() }
+    }
+
+    // Writes `text` from `start`, later text wins where two overlap
+    fn put(&mut self, start: usize, text: &str) {
+        for (offset, c) in text.chars().enumerate() {
+            let at = start + offset;
+            if self.cells.len() <= at {
+                self.cells.resize(at + 1, ' ');
+            }
+            self.cells[at] = c;
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.cells.iter().all(|&c| c == ' ')
+    }
+
+    fn finish(self) -> String {
+        self.cells.into_iter().collect::<String>().trim_end().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::array_manager::ArrayData;
+    use crate::common::settings::Settings;
+    use crate::sort_algorithms::insertion_sort::InsertionSortVisualizer;
+
+    #[test]
+    fn frame_lists_bars_and_annotation_positions() {
+        let array_data = ArrayData::new(vec![5, 10, 2], "Frame".to_string());
+        let mut visualizer = InsertionSortVisualizer::with_settings(&array_data, &Settings::default());
+        visualizer.state_mut().annotate(1, 10, "max");
+        visualizer.state_mut().toggle_arrow(2, 2);
+
+        let text = frame_text(&visualizer);
+        assert!(text.starts_with(FORMAT_HEADER));
+        assert!(text.contains("algorithm: Insertion Sort"));
+        assert!(text.contains("\n1\t10\tNormal\t4\n"));
+        assert!(text.contains("\n1\t10\tno\t4\tmax\n"));
+        assert!(text.contains("\n2\t2\tyes\t8\t\n"));
+
+        let chart: Vec<&str> = text.split("## chart\n").nth(1).unwrap().lines().collect();
+        assert_eq!(chart[0], "    max");
+        assert_eq!(chart[1], "         ↓");
+        // The tallest bar reaches the top row, the value row comes last
+        assert_eq!(chart[2], "    ███");
+        assert_eq!(chart[2 + CHART_HEIGHT], " 5  10   2");
+    }
+}
@@ -1,11 +1,18 @@
-use crossterm::style::ResetColor;
+use crossterm::style::{Print, ResetColor};
 use crossterm::terminal::{disable_raw_mode, LeaveAlternateScreen};
 use crossterm::ExecutableCommand;
 use std::io::{stdout, Write};
 use rand::prelude::SliceRandom;
-use crate::common::array_manager::{ArrayData, ArrayManager};
+use crate::common::array_manager::{generate_random_array_dialog, ArrayManager, StoredArray};
 use crate::common::dialog::show_no_array_selected;
 use crate::common::enums::TeachingQuestion;
+use unicode_width::UnicodeWidthStr;
+
+/// Saves the current window title on the terminal's title stack (xterm window ops).
+/// Terminals without a title stack ignore it.
+pub const PUSH_WINDOW_TITLE: &str = "\x1b[22;0t";
+/// Restores the window title saved by `PUSH_WINDOW_TITLE`.
+pub const POP_WINDOW_TITLE: &str = "\x1b[23;0t";
 
 /// Executes a sorting function on the currently selected array in the manager.
 ///
@@ -15,18 +22,50 @@ use crate::common::enums::TeachingQuestion;
 ///
 /// # Behavior
 /// - If an array is selected, applies the sorting function to it.
-/// - If no array is selected, shows a dialog informing the user to select an array first.
-pub fn run_sort<F>(array_manager: &mut ArrayManager, mut sort_fn: F)
+/// - If no array is selected, explains why and offers to generate one right away.
+///
+/// # Returns
+/// `true` if the sorting function was run.
+pub fn run_sort<F>(array_manager: &mut ArrayManager, sort_fn: F) -> bool
+where
+    F: FnMut(&mut StoredArray),
+{
+    run_sort_with(array_manager, sort_fn, offer_array_generation)
+}
+
+/// Same as [`run_sort`], but `on_missing` decides what happens when no array is selected.
+/// It returns `true` if it selected an array, in which case the sort runs on it.
+pub fn run_sort_with<F, G>(array_manager: &mut ArrayManager, mut sort_fn: F, mut on_missing: G) -> bool
 where
-    F: FnMut(&mut ArrayData),
+    F: FnMut(&mut StoredArray),
+    G: FnMut(&mut ArrayManager) -> bool,
 {
-    // Check if an array is selected
-    if let Some(array) = array_manager.get_selected_array_mut() {
-        // Apply the sorting function to the selected array
-        sort_fn(array);
-    } else {
-        // Show a dialog if no array is selected
-        show_no_array_selected();
+    if array_manager.get_selected_array().is_none() && !on_missing(array_manager) {
+        return false;
+    }
+
+    // Apply the sorting function to the selected array
+    match array_manager.get_selected_array_mut() {
+        Some(array) => {
+            sort_fn(array);
+            true
+        }
+        None => false,
+    }
+}
+
+// Shows the "no array selected" dialog and optionally opens the generate dialog
+fn offer_array_generation(array_manager: &mut ArrayManager) -> bool {
+    if !show_no_array_selected() {
+        return false;
+    }
+    match generate_random_array_dialog() {
+        Some(array) => {
+            array_manager.add_array(array);
+            array_manager.select_array(array_manager.len() - 1);
+            true
+        }
+        None => false,
     }
 }
 
@@ -35,6 +74,7 @@ where
 /// # Effects
 /// - Resets all terminal colors and styles.
 /// - Exits the alternate screen (if one was entered).
+/// - Restores the window title saved when a visualizer started.
 /// - Disables raw mode (restores normal terminal input handling).
 /// - Flushes the output to ensure all changes are applied.
 pub fn cleanup_terminal() {
@@ -46,6 +86,9 @@ pub fn cleanup_terminal() {
     // Exit the alternate screen (if one was entered)
     stdout.execute(LeaveAlternateScreen).unwrap();
 
+    // Bring back the window title saved by a visualizer (ignored if none was saved)
+    stdout.execute(Print(POP_WINDOW_TITLE)).unwrap();
+
     // Disable raw mode (restore normal terminal input handling)
     disable_raw_mode().unwrap();
 
@@ -53,6 +96,14 @@ pub fn cleanup_terminal() {
     stdout.flush().unwrap();
 }
 
+/// Returns the column that centers `text` on a line `width` columns wide.
+///
+/// Measures the displayed width rather than the byte length, so names with
+/// accented or wide (e.g. CJK) characters are centered like ASCII ones.
+pub fn centered_x(width: u16, text: &str) -> u16 {
+    let text_width = u16::try_from(text.width()).unwrap_or(u16::MAX);
+    width.saturating_sub(text_width) / 2
+}
 
 // Function to randomize the position of the correct answer for each question
 pub fn randomize_questions(mut questions: Vec<TeachingQuestion>) -> Vec<TeachingQuestion> {
@@ -88,4 +139,54 @@ pub fn randomize_questions(mut questions: Vec<TeachingQuestion>) -> Vec<Teaching
     }
 
     questions
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::array_manager::ArrayData;
+
+    #[test]
+    fn centering_uses_the_displayed_width() {
+        assert_eq!(centered_x(20, "abcd"), 8);
+        // Two wide characters take four columns but six bytes
+        assert_eq!(centered_x(20, "日本"), 8);
+        assert_eq!(centered_x(20, "Größe"), 7);
+        assert_eq!(centered_x(3, "配列データ"), 0);
+    }
+
+    #[test]
+    fn run_sort_without_selected_array_does_not_sort() {
+        let mut array_manager = ArrayManager::new();
+        let mut sorted = false;
+        let mut asked = false;
+
+        let ran = run_sort_with(&mut array_manager, |_| sorted = true, |_| {
+            asked = true;
+            false
+        });
+
+        assert!(!ran);
+        assert!(asked);
+        assert!(!sorted);
+    }
+
+    #[test]
+    fn run_sort_uses_array_created_by_missing_handler() {
+        let mut array_manager = ArrayManager::new();
+        let mut sorted_name = None;
+
+        let ran = run_sort_with(
+            &mut array_manager,
+            |array| sorted_name = Some(array.name().to_string()),
+            |manager| {
+                manager.add_array(ArrayData::new(vec![3, 1, 2], "Generated".to_string()));
+                manager.select_array(0);
+                true
+            },
+        );
+
+        assert!(ran);
+        assert_eq!(sorted_name.as_deref(), Some("Generated"));
+    }
+}
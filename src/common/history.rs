@@ -0,0 +1,276 @@
+use crate::common::paths::config_file;
+use crate::common::theme;
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{poll, read, Event, KeyCode, KeyEventKind},
+    execute,
+    style::{Attribute, Color, Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor},
+    terminal::{disable_raw_mode, enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+    QueueableCommand,
+};
+use std::fs::{create_dir_all, read_to_string, rename, OpenOptions};
+use std::io::{stdout, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const HISTORY_FILE: &str = "run_history.csv";
+// The full file is moved here when it reaches MAX_HISTORY_ENTRIES, replacing the previous one
+const OLD_HISTORY_FILE: &str = "run_history.old.csv";
+const HEADER: &str = "timestamp,algorithm,size,seed,comparisons,swaps,moves,elapsed_ms";
+const MAX_HISTORY_ENTRIES: usize = 1000;
+
+/// One completed run, as appended to the history file
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    pub timestamp: u64,     // Seconds since the Unix epoch when the run completed
+    pub algorithm: String,  // Display name, e.g. "Quick Sort"
+    pub size: usize,        // Number of elements sorted
+    pub seed: Option<u64>,  // Seed the array was generated from, None for arrays typed in or derived
+    pub comparisons: u32,
+    pub swaps: u32,
+    pub moves: u32,
+    pub elapsed_ms: u64,    // Time from the first step to completion, pauses included
+}
+
+impl HistoryEntry {
+    /// The entry as one CSV line, commas in the algorithm name are dropped
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{}",
+            self.timestamp,
+            self.algorithm.replace(',', ""),
+            self.size,
+            self.seed.map_or(String::new(), |seed| seed.to_string()),
+            self.comparisons,
+            self.swaps,
+            self.moves,
+            self.elapsed_ms
+        )
+    }
+
+    /// Reads a line written by `to_csv`, None for the header or a damaged line
+    pub fn from_csv(line: &str) -> Option<Self> {
+        let fields: Vec<&str> = line.trim().split(',').collect();
+        let [timestamp, algorithm, size, seed, comparisons, swaps, moves, elapsed_ms] = fields[..] else {
+            return None;
+        };
+        Some(Self {
+            timestamp: timestamp.parse().ok()?,
+            algorithm: algorithm.to_string(),
+            size: size.parse().ok()?,
+            seed: if seed.is_empty() { None } else { Some(seed.parse().ok()?) },
+            comparisons: comparisons.parse().ok()?,
+            swaps: swaps.parse().ok()?,
+            moves: moves.parse().ok()?,
+            elapsed_ms: elapsed_ms.parse().ok()?,
+        })
+    }
+}
+
+/// Seconds since the Unix epoch, the timestamp of a new entry
+pub fn now_timestamp() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Adds a run to the end of the history file in the config directory.
+/// A file holding MAX_HISTORY_ENTRIES runs is rotated to `run_history.old.csv` first, so the history never grows past two files.
+pub fn append(entry: &HistoryEntry) -> Result<(), String> {
+    let path = config_file(HISTORY_FILE);
+    if let Some(dir) = path.parent() {
+        let _ = create_dir_all(dir);
+    }
+    let existing = read_to_string(&path).map_or(0, |text| text.lines().filter(|line| HistoryEntry::from_csv(line).is_some()).count());
+    if existing >= MAX_HISTORY_ENTRIES {
+        rename(&path, config_file(OLD_HISTORY_FILE)).map_err(|error| format!("Could not rotate the run history: {}", error))?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|error| format!("Could not open the run history: {}", error))?;
+    let header = if existing == 0 || existing >= MAX_HISTORY_ENTRIES {
+        format!("{}\n", HEADER)
+    } else {
+        String::new()
+    };
+    writeln!(file, "{}{}", header, entry.to_csv()).map_err(|error| format!("Could not write the run history: {}", error))
+}
+
+/// Every run in the rotated and the current history file, oldest first
+pub fn load() -> Vec<HistoryEntry> {
+    [OLD_HISTORY_FILE, HISTORY_FILE]
+        .iter()
+        .filter_map(|name| read_to_string(config_file(name)).ok())
+        .flat_map(|text| text.lines().filter_map(HistoryEntry::from_csv).collect::<Vec<_>>())
+        .collect()
+}
+
+/// "YYYY-MM-DD HH:MM" in UTC for a timestamp in seconds since the Unix epoch
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64;
+    let minutes = timestamp % 86_400 / 60;
+    // Civil date from days since 1970-01-01, counted in 400 year eras starting in March
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02} {:02}:{:02}", year, month, day, minutes / 60, minutes % 60)
+}
+
+// Algorithms that appear in the history in the order they were first run, the filter cycles through them
+fn algorithm_names(entries: &[HistoryEntry]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for entry in entries {
+        if !names.contains(&entry.algorithm) {
+            names.push(entry.algorithm.clone());
+        }
+    }
+    names
+}
+
+/// Shows the run history newest first, ←/→ filters it to one algorithm
+pub fn show_history() {
+    let entries = load();
+    let names = algorithm_names(&entries);
+    // 0 shows every algorithm, i shows names[i - 1]
+    let mut filter = 0usize;
+    let mut scroll = 0usize;
+
+    let mut stdout = stdout();
+    enable_raw_mode().unwrap();
+    execute!(stdout, EnterAlternateScreen, Hide).unwrap();
+
+    loop {
+        let (width, height) = size().unwrap_or((80, 24));
+        let visible: Vec<&HistoryEntry> = entries
+            .iter()
+            .rev()
+            .filter(|entry| filter == 0 || entry.algorithm == names[filter - 1])
+            .collect();
+        let body_height = height.saturating_sub(9) as usize;
+        scroll = scroll.min(visible.len().saturating_sub(body_height));
+
+        stdout.queue(Clear(ClearType::All)).unwrap();
+
+        // --- Title ---
+        let title = " 📜 RUN HISTORY ";
+        stdout.queue(MoveTo(width.saturating_sub(title.len() as u16) / 2, 1)).unwrap();
+        stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+        stdout.queue(SetBackgroundColor(Color::DarkBlue)).unwrap();
+        stdout.queue(Print(title)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        // --- Filter ---
+        let algorithm = if filter == 0 { "All" } else { names[filter - 1].as_str() };
+        stdout.queue(MoveTo(2, 3)).unwrap();
+        stdout.queue(SetForegroundColor(theme::text())).unwrap();
+        stdout.queue(Print(format!("Algorithm: {} ({} runs)", algorithm, visible.len()))).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        // --- Table ---
+        stdout.queue(MoveTo(2, 5)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Cyan)).unwrap();
+        stdout.queue(Print(format!(
+            "{:<17} {:<20} {:>5} {:>20} {:>11} {:>9} {:>9} {:>10}",
+            "Completed (UTC)", "Algorithm", "Size", "Seed", "Comparisons", "Swaps", "Moves", "Time"
+        ))).unwrap();
+        stdout.queue(ResetColor).unwrap();
+        if visible.is_empty() {
+            stdout.queue(MoveTo(2, 6)).unwrap();
+            stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+            stdout.queue(Print("No runs recorded yet, every completed visualization is added here")).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
+        for (row, entry) in visible.iter().skip(scroll).take(body_height).enumerate() {
+            stdout.queue(MoveTo(2, 6 + row as u16)).unwrap();
+            stdout.queue(SetForegroundColor(theme::text())).unwrap();
+            let name: String = entry.algorithm.chars().take(20).collect();
+            stdout.queue(Print(format!(
+                "{:<17} {:<20} {:>5} {:>20} {:>11} {:>9} {:>9} {:>9.1}s",
+                format_timestamp(entry.timestamp),
+                name,
+                entry.size,
+                entry.seed.map_or("-".to_string(), |seed| seed.to_string()),
+                entry.comparisons,
+                entry.swaps,
+                entry.moves,
+                entry.elapsed_ms as f64 / 1000.0
+            ))).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
+
+        // --- Instructions ---
+        let instructions = "↑/↓ PgUp/PgDn: Scroll | ←/→: Filter Algorithm | ESC: Back";
+        stdout.queue(MoveTo(width.saturating_sub(instructions.chars().count() as u16) / 2, height.saturating_sub(2))).unwrap();
+        stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+        stdout.queue(Print(instructions)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+        stdout.flush().unwrap();
+
+        // --- Handle Input ---
+        if !poll(Duration::from_millis(100)).unwrap_or(false) {
+            continue;
+        }
+        let Ok(Event::Key(key_event)) = read() else {
+            continue;
+        };
+        if key_event.kind != KeyEventKind::Press {
+            continue;
+        }
+        match key_event.code {
+            KeyCode::Up => scroll = scroll.saturating_sub(1),
+            KeyCode::Down => scroll += 1,
+            KeyCode::PageUp => scroll = scroll.saturating_sub(body_height.max(1)),
+            KeyCode::PageDown => scroll += body_height.max(1),
+            KeyCode::Right => {
+                filter = (filter + 1) % (names.len() + 1);
+                scroll = 0;
+            }
+            KeyCode::Left => {
+                filter = (filter + names.len()) % (names.len() + 1);
+                scroll = 0;
+            }
+            KeyCode::Esc => break,
+            _ => {}
+        }
+    }
+
+    execute!(stdout, ResetColor, Show, LeaveAlternateScreen).unwrap();
+    disable_raw_mode().unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_round_trip_through_csv() {
+        let entry = HistoryEntry {
+            timestamp: 1_700_000_000,
+            algorithm: "Quick Sort".to_string(),
+            size: 20,
+            seed: Some(42),
+            comparisons: 61,
+            swaps: 30,
+            moves: 0,
+            elapsed_ms: 5120,
+        };
+        assert_eq!(HistoryEntry::from_csv(&entry.to_csv()), Some(entry.clone()));
+        let typed = HistoryEntry { seed: None, ..entry };
+        assert_eq!(HistoryEntry::from_csv(&typed.to_csv()), Some(typed));
+        assert_eq!(HistoryEntry::from_csv(HEADER), None);
+        assert_eq!(HistoryEntry::from_csv("1,Bubble Sort,5"), None);
+    }
+
+    #[test]
+    fn timestamps_format_as_utc_dates() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_700_000_000), "2023-11-14 22:13");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00");
+    }
+}
@@ -5,6 +5,9 @@ use crossterm::cursor::{MoveTo, Show};
 use crossterm::event::{Event, KeyCode};
 use crossterm::style::{Color, Print, ResetColor, SetBackgroundColor, SetForegroundColor};
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode, size, LeaveAlternateScreen, Clear, ClearType};
+use crate::common::settings::Settings;
+use crate::common::theme;
+use crate::factory;
 
 #[derive(Debug, Clone)]
 pub struct MenuOption {
@@ -13,28 +16,18 @@ pub struct MenuOption {
     pub category: String,
 }
 
-/// Displays an interactive categorized menu for the algorithm visualizer and returns the selected option.
-///
-/// # Returns
-/// A number representing the selected menu option.
-pub fn print_menu_banner() -> u32 {
-    // Enable raw mode for direct keyboard input handling
-    enable_raw_mode().unwrap();
-
-    // Get a handle to standard output
-    let mut stdout = stdout();
-
-    // Enter alternate screen and clear it
-    execute!(stdout, terminal::EnterAlternateScreen, Clear(ClearType::All)).unwrap();
-
-    // Define menu categories and options
-    let categories = vec![
+/// Menu categories and their options, algorithm ids match `factory::ALGORITHMS`
+pub fn menu_categories() -> Vec<(&'static str, Vec<MenuOption>)> {
+    vec![
         ("START", vec![
             MenuOption { id: 1, name: "Generate Array List".to_string(), category: "start".to_string() },
+            MenuOption { id: 33, name: "Resume Suspended Run".to_string(), category: "start".to_string() },
         ]),
         ("SEARCH ALGORITHMS", vec![
             MenuOption { id: 2, name: "Linear Search".to_string(), category: "search".to_string() },
             MenuOption { id: 3, name: "Binary Search".to_string(), category: "search".to_string() },
+            MenuOption { id: 27, name: "Jump Search".to_string(), category: "search".to_string() },
+            MenuOption { id: 28, name: "Interpolation Search".to_string(), category: "search".to_string() },
         ]),
         ("SORTING ALGORITHMS", vec![
             MenuOption { id: 4, name: "Bubble Sort".to_string(), category: "sort".to_string() },
@@ -50,19 +43,76 @@ pub fn print_menu_banner() -> u32 {
             MenuOption { id: 14, name: "Quick Sort".to_string(), category: "sort".to_string() },
             MenuOption { id: 15, name: "Radix Sort".to_string(), category: "sort".to_string() },
             MenuOption { id: 16, name: "Selection Sort".to_string(), category: "sort".to_string() },
-            MenuOption { id: 17, name: "Shell Sort".to_string(), category: "search".to_string() },
-            MenuOption { id: 18, name: "Tim Sort".to_string(), category: "search".to_string() },
+            MenuOption { id: 17, name: "Shell Sort".to_string(), category: "sort".to_string() },
+            MenuOption { id: 18, name: "Tim Sort".to_string(), category: "sort".to_string() },
+            MenuOption { id: 19, name: "Odd-Even Sort".to_string(), category: "sort".to_string() },
+            MenuOption { id: 20, name: "Cycle Sort".to_string(), category: "sort".to_string() },
+            MenuOption { id: 21, name: "Patience Sort".to_string(), category: "sort".to_string() },
+            MenuOption { id: 22, name: "In-Place Merge Sort".to_string(), category: "sort".to_string() },
+        ]),
+        ("JOKE ALGORITHMS", vec![
+            MenuOption { id: 23, name: "Bogosort".to_string(), category: "joke".to_string() },
+            MenuOption { id: 24, name: "Stalin Sort".to_string(), category: "joke".to_string() },
+        ]),
+        ("PUZZLES", vec![
+            MenuOption { id: 25, name: "Comparison Challenge".to_string(), category: "puzzle".to_string() },
+            MenuOption { id: 26, name: "Pick Order Game".to_string(), category: "puzzle".to_string() },
+            MenuOption { id: 36, name: "Practice Mode".to_string(), category: "puzzle".to_string() },
         ]),
         ("⚙️ SETTINGS & OTHERS", vec![
             MenuOption { id: 31, name: "Settings".to_string(), category: "settings".to_string() },
+            MenuOption { id: 32, name: "Glossary".to_string(), category: "settings".to_string() },
+            MenuOption { id: 34, name: "Size Sweep".to_string(), category: "settings".to_string() },
+            MenuOption { id: 35, name: "Run History".to_string(), category: "settings".to_string() },
+            MenuOption { id: 37, name: "Stability Report".to_string(), category: "settings".to_string() },
         ]),
-    ];
+    ]
+}
 
-    let mut selected_category = 0usize;
-    let mut selected_option = 0usize;
+/// Displays an interactive categorized menu for the algorithm visualizer and returns the selected option.
+///
+/// # Returns
+/// A number representing the selected menu option.
+pub fn print_menu_banner() -> u32 {
+    print_menu_banner_at(1)
+}
+
+/// Same as `print_menu_banner`, but opens with the given option highlighted.
+pub fn print_menu_banner_at(initial_option_id: u32) -> u32 {
+    // Enable raw mode for direct keyboard input handling
+    enable_raw_mode().unwrap();
+
+    // Get a handle to standard output
+    let mut stdout = stdout();
+
+    // Enter alternate screen and clear it
+    execute!(stdout, terminal::EnterAlternateScreen, Clear(ClearType::All)).unwrap();
+
+    // Define menu categories and options
+    let all_categories = menu_categories();
+
+    let mut settings = Settings::load();
+    let mut favorites_only = false;
+
+    // Start on the requested option if it exists
+    let (mut selected_category, mut selected_option) = all_categories
+        .iter()
+        .enumerate()
+        .find_map(|(cat_idx, (_, options))| {
+            options
+                .iter()
+                .position(|option| option.id == initial_option_id)
+                .map(|opt_idx| (cat_idx, opt_idx))
+        })
+        .unwrap_or((0, 0));
 
     // Main menu loop
     loop {
+        // Apply the favorites filter to the algorithm categories
+        let categories = visible_categories(&all_categories, &settings.favorites, favorites_only);
+        selected_category = selected_category.min(categories.len() - 1);
+        selected_option = selected_option.min(categories[selected_category].1.len() - 1);
+
         // Get current terminal dimensions
         let (width, height) = size().unwrap();
 
@@ -98,12 +148,13 @@ pub fn print_menu_banner() -> u32 {
 
         // --- Draw Options (Right Side) ---
         let current_category = &categories[selected_category];
-        draw_category_header(&mut stdout, option_x, content_start_y, &current_category.0);
+        draw_category_header(&mut stdout, option_x, content_start_y, current_category.0);
 
         for (i, option) in current_category.1.iter().enumerate() {
             let y = content_start_y + 3 + (i as u16);
             let is_selected = i == selected_option;
-            let option_text = format!("{}. {}", option.id, option.name);
+            let star = if settings.favorites.contains(&option.name) { "★ " } else { "" };
+            let option_text = format!("{}. {}{}", option.id, star, option.name);
             draw_option_item(&mut stdout, &option_text, option_x, y, is_selected, option_width as usize);
         }
 
@@ -116,7 +167,7 @@ pub fn print_menu_banner() -> u32 {
 
         // --- Draw Border Box ---
         // Calculate dynamic height based on the maximum number of options in any category
-        let max_options = categories.iter().map(|(_, opts)| opts.len()).max().unwrap_or(0) as u16;
+        let max_options = all_categories.iter().map(|(_, opts)| opts.len()).max().unwrap_or(0) as u16;
         let border_height = max_options + 5; // 2 for header, 1 for separator, 2 for padding
         draw_border_box(&mut stdout, start_x - 2, content_start_y - 2, total_content_width + 4, border_height);
 
@@ -161,6 +212,18 @@ pub fn print_menu_banner() -> u32 {
                             };
                             selected_option = 0;
                         },
+                        KeyCode::Char('f') | KeyCode::Char('F') => {
+                            // Star or unstar the highlighted algorithm
+                            let option = &categories[selected_category].1[selected_option];
+                            if factory::find_by_menu_id(option.id).is_some() {
+                                settings.toggle_favorite(&option.name);
+                                settings.save();
+                            }
+                        },
+                        KeyCode::Char('v') | KeyCode::Char('V') => {
+                            favorites_only = !favorites_only;
+                            selected_option = 0;
+                        },
                         KeyCode::Enter => {
                             let selected_id = categories[selected_category].1[selected_option].id;
                             cleanup_terminal(&mut stdout);
@@ -197,6 +260,30 @@ pub fn print_menu_banner() -> u32 {
     }
 }
 
+/// Returns the categories to show, keeping only starred algorithms when `favorites_only` is set.
+/// Categories without algorithms (start, settings) are always shown.
+fn visible_categories<'a>(
+    categories: &'a [(&'a str, Vec<MenuOption>)],
+    favorites: &[String],
+    favorites_only: bool,
+) -> Vec<(&'a str, Vec<&'a MenuOption>)> {
+    categories
+        .iter()
+        .map(|(name, options)| {
+            let options = options
+                .iter()
+                .filter(|option| {
+                    !favorites_only
+                        || factory::find_by_menu_id(option.id).is_none()
+                        || favorites.contains(&option.name)
+                })
+                .collect::<Vec<_>>();
+            (*name, options)
+        })
+        .filter(|(_, options)| !options.is_empty())
+        .collect()
+}
+
 fn draw_title(stdout: &mut std::io::Stdout, width: u16, y: u16) {
     let title = "TOGISOFT ALGORITHM VISUALIZER";
     let title_len = title.len() as u16;
@@ -227,7 +314,7 @@ fn draw_title(stdout: &mut std::io::Stdout, width: u16, y: u16) {
 }
 
 fn draw_navigation_help(stdout: &mut std::io::Stdout, width: u16, y: u16) {
-    let help_text = "↑↓ Navigate | W/S Categories | Enter Select | Esc/Q Exit";
+    let help_text = "↑↓ Navigate | W/S Categories | F Favorite | V Favorites Only | Enter Select | Esc/Q Exit";
     let x = (width.saturating_sub(help_text.len() as u16)) / 2;
 
     stdout.queue(MoveTo(x, y)).unwrap();
@@ -242,7 +329,7 @@ fn draw_category_header(stdout: &mut std::io::Stdout, x: u16, y: u16, title: &st
     stdout.queue(Print(title)).unwrap();
 
     stdout.queue(MoveTo(x, y + 1)).unwrap();
-    stdout.queue(SetForegroundColor(Color::DarkGrey)).unwrap();
+    stdout.queue(SetForegroundColor(theme::dim())).unwrap();
     stdout.queue(Print("─".repeat(title.chars().count().min(30)))).unwrap();
 
     stdout.queue(ResetColor).unwrap();
@@ -259,11 +346,11 @@ fn draw_category_item(
     stdout.queue(MoveTo(x, y)).unwrap();
 
     if is_selected {
-        stdout.queue(SetForegroundColor(Color::Black)).unwrap();
-        stdout.queue(SetBackgroundColor(Color::White)).unwrap();
+        stdout.queue(SetForegroundColor(theme::highlight().0)).unwrap();
+        stdout.queue(SetBackgroundColor(theme::highlight().1)).unwrap();
         stdout.queue(Print("▶ ")).unwrap();
     } else {
-        stdout.queue(SetForegroundColor(Color::White)).unwrap();
+        stdout.queue(SetForegroundColor(theme::text())).unwrap();
         stdout.queue(SetBackgroundColor(Color::Reset)).unwrap();
         stdout.queue(Print("  ")).unwrap();
     }
@@ -301,7 +388,7 @@ fn draw_option_item(
         stdout.queue(SetBackgroundColor(Color::Green)).unwrap();
         stdout.queue(Print("● ")).unwrap();
     } else {
-        stdout.queue(SetForegroundColor(Color::White)).unwrap();
+        stdout.queue(SetForegroundColor(theme::text())).unwrap();
         stdout.queue(SetBackgroundColor(Color::Reset)).unwrap();
         stdout.queue(Print("  ")).unwrap();
     }
@@ -344,7 +431,7 @@ fn draw_description(stdout: &mut std::io::Stdout, width: u16, y: u16, descriptio
 fn draw_border_box(stdout: &mut std::io::Stdout, x: u16, y: u16, width: u16, height: u16) {
     // Top border
     stdout.queue(MoveTo(x, y)).unwrap();
-    stdout.queue(SetForegroundColor(Color::DarkGrey)).unwrap();
+    stdout.queue(SetForegroundColor(theme::dim())).unwrap();
     stdout.queue(Print("┌")).unwrap();
     stdout.queue(Print("─".repeat((width - 2) as usize))).unwrap();
     stdout.queue(Print("┐")).unwrap();
@@ -406,7 +493,21 @@ pub fn get_option_description(option_id: u32) -> String {
         16 => "Visualize selection sort - finds minimum element and places it at beginning".to_string(),
         17 => "Visualize shell sort - generalized insertion sort with diminishing gaps".to_string(),
         18 => "Visualize tim sort - hybrid stable sorting algorithm derived from merge sort".to_string(),
+        19 => "Visualize odd-even sort - alternating odd/even pair passes, ideal for parallel hardware".to_string(),
+        20 => "Visualize cycle sort - places each element directly in its final slot with minimal writes".to_string(),
+        21 => "Visualize patience sort - deals elements onto solitaire-style piles, then merges the piles".to_string(),
+        22 => "Visualize in-place merge sort - merges runs with rotations using O(1) extra memory".to_string(),
+        23 => "Joke algorithm: shuffles until sorted, gives up after 1000 shuffles - an educational curiosity".to_string(),
+        24 => "Joke algorithm: removes out-of-order elements, leaving a shorter sequence - an educational curiosity".to_string(),
+        25 => "Puzzle: sort hidden values yourself, choosing every comparison - aim for log2(n!) comparisons".to_string(),
+        26 => "Puzzle: guess the order selection sort picks the elements in, then watch it and see your score".to_string(),
+        27 => "Visualize jump search - jumps through a sorted array in blocks of √n, then scans one block".to_string(),
+        28 => "Visualize interpolation search - probes where the target should be in sorted, evenly spread data".to_string(),
         31 => "Configure application settings - speed, colors, array size, and display options".to_string(),
+        32 => "Browse every algorithm's intro, teaching questions and explanations, searchable with /".to_string(),
+        34 => "Chart an algorithm's comparisons against n over growing arrays, optionally against a second one".to_string(),
+        33 => "Continue the run suspended with Z exactly where it stopped".to_string(),
+        37 => "Sort tagged duplicate values with every sort and report which ones keep equal keys in order".to_string(),
         99 => "Exit the application and return to terminal".to_string(),
         _ => "Unknown option - please select a valid menu item".to_string(),
     }
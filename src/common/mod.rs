@@ -1,12 +1,22 @@
+pub mod algorithm_info;
+pub mod announcer;
 pub mod array_manager;
 pub mod base_visualizer;
+pub mod clipboard;
 pub mod common_visualizer;
 pub mod dialog;
+pub mod element;
 pub mod enums;
+pub mod frame_export;
 pub mod helper;
+pub mod history;
 pub mod menu;
+pub mod onboarding;
+pub mod paths;
 pub mod runner;
 pub mod settings;
+pub mod theme;
+pub mod verifier;
 pub mod welcome_banner;
 
 pub use array_manager::*;
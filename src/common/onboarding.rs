@@ -0,0 +1,68 @@
+use crate::common::array_manager::{generate_random_array_dialog, ArrayManager};
+use crate::common::dialog::{show_info, show_question, show_success, show_warning};
+
+/// Menu option highlighted when the tour hands over to the main menu (Bubble Sort).
+pub const ONBOARDING_ALGORITHM_OPTION: u32 = 4;
+
+/// Walks a first-time user through creating an array, selecting it and picking an algorithm.
+///
+/// # Returns
+/// `true` if the user finished the tour and an array is selected, so the main menu should
+/// open on the sorting algorithms; `false` if the tour was skipped or cancelled.
+pub fn run_onboarding(array_manager: &mut ArrayManager) -> bool {
+    let choice = show_question(
+        "Welcome!",
+        "Looks like this is your first time here.\n\n\
+         Every visualization runs on an array you create,\n\
+         so let's get you started:\n\n\
+         1. Create an array\n\
+         2. Select it for sorting\n\
+         3. Pick an algorithm",
+        vec!["Start Tour", "Skip"],
+    );
+    if choice != 0 {
+        return false;
+    }
+
+    // Step 1: create an array
+    show_info(
+        "Step 1 of 3: Create an Array",
+        "Arrays live in '1. Generate Array List'.\n\n\
+         Let's generate a random one now.\n\
+         Choose a size and a name in the next dialog.",
+    );
+    let array = match generate_random_array_dialog() {
+        Some(array) => array,
+        None => {
+            show_warning(
+                "Tour Cancelled",
+                "No array was created.\n\n\
+                 Create one any time from '1. Generate Array List'.",
+            );
+            return false;
+        }
+    };
+
+    // Step 2: select it
+    let name = array.name.clone();
+    array_manager.add_array(array);
+    array_manager.select_array(array_manager.len() - 1);
+    show_success(
+        "Step 2 of 3: Select It",
+        &format!(
+            "'{}' is now selected for sorting.\n\n\
+             To switch arrays later, use\n\
+             'Select Array for Sorting' in Array Management.",
+            name
+        ),
+    );
+
+    // Step 3: pick an algorithm
+    show_info(
+        "Step 3 of 3: Pick an Algorithm",
+        "The main menu will open on SORTING ALGORITHMS.\n\n\
+         Use ↑/↓ to choose one and press ENTER to start.\n\
+         ESC in a visualizer brings you back to the menu.",
+    );
+    true
+}
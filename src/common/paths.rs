@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// Environment variable that overrides where settings and exports are stored.
+pub const CONFIG_DIR_ENV: &str = "TOGISOFT_CONFIG_DIR";
+
+const CONFIG_DIR_FLAG: &str = "--config-dir";
+const APP_DIR_NAME: &str = "togisoft_algorithm_visualizer";
+
+// Set once from the command line, takes priority over everything else
+static CONFIG_DIR_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Uses `dir` for settings and exports for the rest of the process.
+pub fn set_config_dir(dir: PathBuf) {
+    let _ = CONFIG_DIR_OVERRIDE.set(dir);
+}
+
+/// Returns the directory holding settings and exports.
+///
+/// Resolved in order: the `--config-dir` flag, the `TOGISOFT_CONFIG_DIR` environment
+/// variable, the platform config directory, and finally the working directory.
+pub fn config_dir() -> PathBuf {
+    if let Some(dir) = CONFIG_DIR_OVERRIDE.get() {
+        return dir.clone();
+    }
+    if let Some(dir) = std::env::var_os(CONFIG_DIR_ENV).filter(|dir| !dir.is_empty()) {
+        return PathBuf::from(dir);
+    }
+    dirs::config_dir()
+        .map(|dir| dir.join(APP_DIR_NAME))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// Path of a file inside the config directory.
+pub fn config_file(name: &str) -> PathBuf {
+    config_dir().join(name)
+}
+
+/// Reads `--config-dir <dir>` or `--config-dir=<dir>` from the command line arguments.
+pub fn config_dir_from_args<I: IntoIterator<Item = String>>(args: I) -> Option<PathBuf> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == CONFIG_DIR_FLAG {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(dir) = arg.strip_prefix("--config-dir=") {
+            return Some(PathBuf::from(dir));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn reads_config_dir_flag() {
+        assert_eq!(config_dir_from_args(args(&["app", "--config-dir", "/tmp/a"])), Some(PathBuf::from("/tmp/a")));
+        assert_eq!(config_dir_from_args(args(&["app", "--config-dir=/tmp/b"])), Some(PathBuf::from("/tmp/b")));
+    }
+
+    #[test]
+    fn missing_config_dir_flag_or_value() {
+        assert_eq!(config_dir_from_args(args(&["app"])), None);
+        assert_eq!(config_dir_from_args(args(&["app", "--config-dir"])), None);
+    }
+}
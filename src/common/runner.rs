@@ -1,77 +1,661 @@
-use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
+use crate::common::announcer::Announcer;
+use crate::common::array_manager::ArrayData;
+use crate::common::base_visualizer::{FixedRange, SortVisualizer, StepKind};
+use crate::common::clipboard::copy_values;
+use crate::common::frame_export::export_frame;
 use crate::common::common_visualizer::{show_intro_screen, show_question_feedback, VisualizerDrawer};
-use crate::common::helper::cleanup_terminal;
+use crate::common::dialog;
+use crate::common::element::{SortElement, MAX_DECIMALS};
+use crate::common::enums::{RenderMode, SelectionState, SortOrder, ValueFormat};
+use crate::common::helper::{cleanup_terminal, PUSH_WINDOW_TITLE};
+use crate::common::history::{self, HistoryEntry};
+use crate::common::settings::{Settings, MAX_RANDOM_VALUE, MIN_SPEED};
+use crate::common::verifier::{self, Divergence};
+use crate::sort_algorithms::{in_quadratic_family, quadratic_family_text};
 use crossterm::{
     event::{poll, read, Event, KeyCode, KeyEventKind},
-    terminal::{enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen},
-    ExecutableCommand, QueueableCommand,
+    style::{Color, Print},
+    terminal::{enable_raw_mode, size, EnterAlternateScreen, SetTitle},
+    ExecutableCommand,
 };
-use std::io::{stdout, Write};
-use std::time::Duration;
+use std::io::stdout;
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+
+// Longest lecture note that can be typed
+const MAX_NOTE_LENGTH: usize = 40;
+
+// Characters of a value typed in the edit mode, enough for -MAX_RANDOM_VALUE with MAX_DECIMALS decimals
+const EDIT_MAX_LENGTH: usize = 6 + MAX_DECIMALS;
+
+// Key events further apart than this end a held step key
+const STEP_HOLD_GAP: Duration = Duration::from_millis(250);
+// Every this much holding doubles the steps taken per key event
+const STEP_HOLD_DOUBLING: Duration = Duration::from_millis(600);
+// Most steps a single key event may take while the step key is held
+const MAX_STEPS_PER_EVENT: u32 = 16;
+// Most auto-play steps between two frames, a frame that falls behind drops the rest
+const MAX_STEPS_PER_FRAME: u32 = 100;
+// How long "Settings saved" stays on screen after a change was saved
+const SAVED_NOTICE_DURATION: Duration = Duration::from_millis(1500);
+// Time the completion sweep takes to light every bar once, left to right
+const VICTORY_SWEEP_DURATION: Duration = Duration::from_millis(1200);
+// Largest insertion sort threshold [ and ] tune a hybrid sort to, tim sort's longest min run
+const MAX_INSERTION_CUTOFF: usize = 64;
+// Shown instead of the algorithm's own title in practice mode
+const PRACTICE_TITLE: &str = "MYSTERY ALGORITHM";
+// Practice mode controls, everything that names or gives away the algorithm is left out
+const PRACTICE_CONTROLS: &str = "SPACE: Start/Pause | S: Step | R: Reset | +/-: Speed | ESC: Guess";
+
+// Tracks how long the step key has been held to accelerate stepping
+struct StepHold {
+    started: Instant,
+    last_event: Instant,
+}
+
+impl StepHold {
+    fn new() -> Self {
+        let now = Instant::now();
+        Self { started: now, last_event: now - STEP_HOLD_GAP }
+    }
+
+    // Registers a step key event and returns how many steps it should take.
+    // Terminals report a held key either as Repeat events or as a stream of
+    // Press events, so any event close enough to the last one extends the hold.
+    fn register(&mut self, now: Instant) -> u32 {
+        if now.duration_since(self.last_event) >= STEP_HOLD_GAP {
+            self.started = now;
+        }
+        self.last_event = now;
+        let doublings = now.duration_since(self.started).as_millis() / STEP_HOLD_DOUBLING.as_millis();
+        1u32.checked_shl(doublings as u32).unwrap_or(u32::MAX).min(MAX_STEPS_PER_EVENT)
+    }
+}
+
+// Paces auto-play independently of the frame rate: time passing between frames
+// is spent on as many steps as the step delay fits into it
+struct StepClock {
+    last_tick: Instant,
+    budget: Duration,
+}
+
+impl StepClock {
+    fn new() -> Self {
+        Self { last_tick: Instant::now(), budget: Duration::ZERO }
+    }
+
+    // Returns true if the time passed up to `now` covers `delay`, which is then spent on the next step
+    // Each step waits its own delay, so the caller asks again after every step
+    fn step_due(&mut self, now: Instant, delay: Duration) -> bool {
+        self.budget += now.duration_since(self.last_tick);
+        self.last_tick = now;
+        if self.budget < delay.max(Duration::from_nanos(1)) {
+            return false;
+        }
+        self.budget -= delay;
+        true
+    }
+
+    // A frame that fell behind drops the steps it could not take
+    fn drop_backlog(&mut self) {
+        self.budget = Duration::ZERO;
+    }
+
+    // Auto-play is stopped, time spent paused must not turn into a burst of steps
+    fn idle(&mut self, now: Instant) {
+        self.last_tick = now;
+        self.budget = Duration::ZERO;
+    }
+}
+
+// Steps in an unchanged phase before the speed ramp kicks in
+const RAMP_START_STEPS: u32 = 10;
+// Every this many further steps in the same phase double the ramp
+const RAMP_DOUBLING_STEPS: u32 = 10;
+// Fastest the ramp makes auto-play, as a multiple of the chosen speed
+const MAX_RAMP: u32 = 8;
+
+// Speeds auto-play up during long stretches of one phase and back down when the phase changes,
+// so the transitions between phases play at the chosen speed
+struct SpeedRamp {
+    phase: Option<String>,
+    steps_in_phase: u32,
+}
+
+impl SpeedRamp {
+    fn new() -> Self {
+        Self { phase: None, steps_in_phase: 0 }
+    }
+
+    // Registers the phase after a step
+    fn record(&mut self, phase: Option<String>) {
+        if phase == self.phase {
+            self.steps_in_phase = self.steps_in_phase.saturating_add(1);
+        } else {
+            self.phase = phase;
+            self.steps_in_phase = 0;
+        }
+    }
+
+    // How many times faster than the chosen speed auto-play currently runs
+    fn factor(&self) -> u32 {
+        if self.phase.is_none() || self.steps_in_phase < RAMP_START_STEPS {
+            return 1;
+        }
+        let doublings = (self.steps_in_phase - RAMP_START_STEPS) / RAMP_DOUBLING_STEPS + 1;
+        1u32.checked_shl(doublings).unwrap_or(u32::MAX).min(MAX_RAMP)
+    }
+}
+
+// State of the "what-if" editor while a single value is being changed
+struct EditMode {
+    index: usize,
+    input: String,
+    error: Option<String>,
+}
+
+// State of the lecture note editor while a note is being written
+struct NoteMode {
+    index: usize,
+    input: String,
+}
+
+// How the user left a visualizer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RunOutcome {
+    Exited,    // ESC, the run is over
+    Suspended, // Z, the run can be resumed from the main menu
+}
+
+// Counters of a completed run, kept to compare the next run against
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunSummary {
+    pub algorithm: String,
+    pub comparisons: u32,
+    pub swaps: u32,
+    pub moves: u32,
+}
+
+impl RunSummary {
+    fn of<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &V) -> Self {
+        let state = visualizer.state();
+        Self { algorithm: visualizer.display_name(), comparisons: state.comparisons, swaps: state.swaps, moves: state.moves }
+    }
+
+    // e.g. "+30% comparisons, -10 swaps, +0 moves vs last run (Bubble Sort)"
+    pub fn diff_text(&self, previous: &RunSummary) -> String {
+        let comparisons = if previous.comparisons == 0 {
+            format!("{:+}", self.comparisons as i64)
+        } else {
+            let change = (self.comparisons as f64 - previous.comparisons as f64) / previous.comparisons as f64 * 100.0;
+            format!("{:+.0}%", change)
+        };
+        format!(
+            "{} comparisons, {:+} swaps, {:+} moves vs last run ({})",
+            comparisons,
+            self.swaps as i64 - previous.swaps as i64,
+            self.moves as i64 - previous.moves as i64,
+            previous.algorithm
+        )
+    }
+}
+
+// Runs a new visualizer and hands it back if the user suspended it
+// `settings` are the ones the visualizer was built with, the run reuses them instead of loading again
+pub fn run_suspendable<T: SortElement, V: SortVisualizer<T> + 'static>(
+    mut visualizer: V,
+    settings: Settings,
+    last_run: &mut Option<RunSummary>,
+) -> Option<Box<dyn SortVisualizer<T>>> {
+    match run_visualizer(&mut visualizer, settings, last_run) {
+        RunOutcome::Suspended => Some(Box::new(visualizer)),
+        RunOutcome::Exited => None,
+    }
+}
+
+// Same as run_suspendable for a visualizer built by the factory
+pub fn run_suspendable_boxed<T: SortElement>(
+    mut visualizer: Box<dyn SortVisualizer<T>>,
+    settings: Settings,
+    last_run: &mut Option<RunSummary>,
+) -> Option<Box<dyn SortVisualizer<T>>> {
+    match run_visualizer(visualizer.as_mut(), settings, last_run) {
+        RunOutcome::Suspended => Some(visualizer),
+        RunOutcome::Exited => None,
+    }
+}
+
+/// A run suspended with Z, resumed from the main menu. Any kind of array can be suspended.
+pub enum SuspendedRun {
+    Integers(Box<dyn SortVisualizer>),
+    Signed(Box<dyn SortVisualizer<i32>>),
+    Reals(Box<dyn SortVisualizer<f64>>),
+}
+
+impl SuspendedRun {
+    /// Continues the run where it stopped, returns it again if it was suspended once more
+    pub fn resume(mut self, last_run: &mut Option<RunSummary>) -> Option<SuspendedRun> {
+        let outcome = match &mut self {
+            SuspendedRun::Integers(visualizer) => resume_visualizer(visualizer.as_mut(), last_run),
+            SuspendedRun::Signed(visualizer) => resume_visualizer(visualizer.as_mut(), last_run),
+            SuspendedRun::Reals(visualizer) => resume_visualizer(visualizer.as_mut(), last_run),
+        };
+        (outcome == RunOutcome::Suspended).then_some(self)
+    }
+}
 
 // General visualizer runner
 // Common input handling and render loop for each sorting algorithm
-pub fn run_visualizer<V: SortVisualizer>(visualizer: &mut V, state: &mut VisualizerState) {
-    let mut stdout = stdout();
+pub fn run_visualizer<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &mut V, settings: Settings, last_run: &mut Option<RunSummary>) -> RunOutcome {
+    enable_raw_mode().unwrap();
+    stdout().execute(EnterAlternateScreen).unwrap();
+
+    // Show intro screen, practice mode keeps the algorithm a secret and asks no questions about it
+    if visualizer.state().practice {
+        visualizer.state_mut().teaching_mode = false;
+    } else {
+        show_intro_screen(visualizer.get_intro_text());
+    }
+
+    visualizer.state_mut().set_reduced_motion(settings.reduced_motion);
+    visualizer.state_mut().render_mode = settings.render_mode;
+    visualizer.state_mut().value_format = settings.value_format;
+    visualizer.state_mut().annotations_follow = settings.notes_follow_elements;
+    visualizer.state_mut().show_sorted_regions = settings.show_sorted_regions;
+    visualizer.state_mut().progress_milestones = settings.progress_milestones.clone();
+    visualizer.state_mut().compare_delay = settings.compare_delay;
+    visualizer.state_mut().change_delay = settings.change_delay;
+    visualizer.state_mut().auto_reset_after =
+        (settings.auto_reset_after > 0).then(|| Duration::from_secs(settings.auto_reset_after as u64));
+    visualizer.state_mut().max_steps = (settings.max_steps > 0).then_some(settings.max_steps);
+    visualizer.state_mut().best_streak = settings.best_streaks.get(visualizer.settings_key()).copied().unwrap_or(0);
+    if visualizer.supports_custom_order() {
+        visualizer.state_mut().set_sort_order(settings.sort_order);
+    }
+    run_loop(visualizer, settings, last_run)
+}
+
+// Continues a suspended run where it stopped, without the intro or reapplying settings
+pub fn resume_visualizer<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &mut V, last_run: &mut Option<RunSummary>) -> RunOutcome {
     enable_raw_mode().unwrap();
-    stdout.execute(EnterAlternateScreen).unwrap();
+    stdout().execute(EnterAlternateScreen).unwrap();
+    run_loop(visualizer, Settings::load(), last_run)
+}
+
+// Input handling and render loop shared by new and resumed runs
+// Every completed run replaces `last_run`, the completion screen shows the difference to the one before
+// The settings are loaded once by the caller, every change made during the run is saved from them
+fn run_loop<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &mut V, mut settings: Settings, last_run: &mut Option<RunSummary>) -> RunOutcome {
+    let mut stdout = stdout();
+    // Keeps the bars of the last frame, unchanged bars are not drawn again
+    let mut drawer = VisualizerDrawer::default();
+    // The transcript would name the algorithm in practice mode
+    let mut announcer = Announcer::new(settings.announce_mode && !visualizer.state().practice, visualizer.get_title());
+    let mut edit_mode: Option<EditMode> = None;
+    let mut note_mode: Option<NoteMode> = None;
+    let mut inspect_index: Option<usize> = None;
+    // First value picked for a comparison watch, W on a second value completes the pair
+    let mut watch_first: Option<T> = None;
+    let mut step_hold = StepHold::new();
+    let mut step_clock = StepClock::new();
+    // What the last auto-play step did, it decides how long auto-play waits before the next one
+    let mut last_step = StepKind::Other;
+    // Only consulted with the speed ramp setting on
+    let mut speed_ramp = SpeedRamp::new();
+    // Drawing runs at a fixed rate, the speed setting only decides how many steps each frame shows
+    let frame_interval = Duration::from_secs(1) / settings.frame_rate;
+    // Comparison with the previous run, shown while this run stays completed
+    let mut run_diff: Option<String> = None;
+    // Outcome of the last C press, cleared by the next key
+    let mut clipboard_notice: Option<String> = None;
+    // F froze the frame for annotating, the next F exports it
+    let mut frozen = false;
+    // Where the last frame was exported to, cleared by the next key
+    let mut export_notice: Option<String> = None;
+    // Progress milestone auto-play last paused at, cleared by the next key
+    let mut milestone_notice: Option<String> = None;
+    // Explanation of the last answered question, shown until the run moves on, with the step it was answered at
+    let mut held_explanation: Option<(u64, String)> = None;
+    // Set when auto-play paused at the step limit, cleared by the next key
+    let mut step_limit_notice: Option<String> = None;
+    // When the settings were last saved, shown briefly as a notice
+    let mut saved_at: Option<Instant> = None;
+    // Element --verify found out of place, the run only pauses again for a different one
+    let mut divergence: Option<Divergence<T>> = None;
+    let mut was_completed = visualizer.state().completed;
+    // When the completion sweep started, any key ends it early
+    let mut victory_started: Option<Instant> = None;
+    // When the run completed, an auto reset replays it once its dwell has passed
+    let mut completed_at: Option<Instant> = None;
+    let mut run_started: Option<Instant> = None;
 
-    // Show intro screen
-    show_intro_screen(visualizer.get_intro_text());
+    // The title shows the algorithm and progress while the terminal is in the background,
+    // cleanup_terminal puts the saved one back
+    stdout.execute(Print(PUSH_WINDOW_TITLE)).unwrap();
+    let mut window_title = String::new();
 
     loop {
+        // Notes move with their elements, whatever moved them
+        follow_notes(visualizer);
+
+        // Catches divergences left by manual steps and edits
+        if verify_step(visualizer, &mut divergence) {
+            inspect_index = divergence.map(|divergence| divergence.index);
+        }
+
+        // The cursor only lives while the run is stopped, a frozen frame ends with it
+        if !can_inspect(visualizer) {
+            inspect_index = None;
+            frozen = false;
+        }
+
+        // The history times a run from its first step, a reset starts the clock again
+        if visualizer.state().steps_taken == 0 {
+            run_started = None;
+        } else if run_started.is_none() {
+            run_started = Some(Instant::now());
+        }
+
+        // A held explanation stays until the run resumes or takes another step
+        if held_explanation
+            .as_ref()
+            .is_some_and(|(step, _)| can_auto_step(visualizer) || visualizer.state().steps_taken != *step)
+        {
+            held_explanation = None;
+        }
+
+        // Record the run once it completes, however it got there
+        let completed = visualizer.state().completed;
+        if completed && !was_completed {
+            let summary = RunSummary::of(visualizer);
+            record_history(visualizer, run_started.map_or(Duration::ZERO, |started| started.elapsed()));
+            run_diff = last_run.replace(summary.clone()).map(|previous| summary.diff_text(&previous));
+            // Reduced motion has no flashing highlights, the sweep included
+            victory_started = (settings.victory_animation && !visualizer.state().reduced_motion).then(Instant::now);
+            completed_at = Some(Instant::now());
+        } else if !completed {
+            run_diff = None;
+            victory_started = None;
+            completed_at = None;
+        }
+        was_completed = completed;
+        let sweep = victory_started.and_then(|started| victory_sweep_index(started.elapsed(), visualizer.get_array().len()));
+        if sweep.is_none() {
+            victory_started = None;
+        }
+        visualizer.state_mut().victory_sweep = sweep;
+
+        // A completed practice run goes straight to the guess once the sweep has played
+        if completed && sweep.is_none() && visualizer.state().practice {
+            cleanup_terminal();
+            return RunOutcome::Exited;
+        }
+
+        // Unattended displays replay a completed run by themselves
+        let replay_in = completed_at
+            .zip(visualizer.state().auto_reset_after)
+            .map(|(completed_at, dwell)| dwell.saturating_sub(completed_at.elapsed()));
+        if replay_in == Some(Duration::ZERO) {
+            visualizer.reset();
+            visualizer.state_mut().toggle_play_pause();
+            continue;
+        }
+
         // Draw the screen
-        draw_screen(&mut stdout, visualizer, state);
+        let ramp_notice = (settings.speed_ramp && can_auto_step(visualizer) && speed_ramp.factor() > 1)
+            .then(|| format!("Speed Ramp: {}x", speed_ramp.factor()));
+        let saved_notice = saved_at
+            .is_some_and(|saved_at| saved_at.elapsed() < SAVED_NOTICE_DURATION)
+            .then(|| "✓ Settings saved".to_string());
+        let notices: Vec<String> = run_diff
+            .iter()
+            .chain(&clipboard_notice)
+            .chain(&export_notice)
+            .chain(&milestone_notice)
+            .chain(&step_limit_notice)
+            .chain(held_explanation.as_ref().map(|(_, explanation)| explanation))
+            .cloned()
+            .chain(frozen.then(|| "Frame frozen: ←/→ pick a bar, N: Label, A: Arrow, F: Export".to_string()))
+            .chain(divergence.map(|divergence| divergence.describe()))
+            .chain(replay_in.map(|remaining| format!("Replaying in {} s", remaining.as_secs() + 1)))
+            .chain(ramp_notice)
+            .chain(saved_notice)
+            .collect();
+        draw_screen(&mut drawer, visualizer, edit_mode.as_ref(), note_mode.as_ref(), inspect_index, watch_first, &notices);
+        announcer.announce(&visualizer.get_current_operation());
+        update_window_title(&mut stdout, visualizer, &mut window_title);
 
-        // Process input
-        if poll(Duration::from_millis(50)).unwrap_or(false) {
+        // Process input until the next frame is due
+        if poll(frame_interval).unwrap_or(false) {
+            // A key can open a screen that draws over the bars
+            drawer.invalidate();
             match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
+                // Held step key, other keys only act on press
+                Event::Key(key_event) if key_event.kind == KeyEventKind::Repeat
+                    && matches!(key_event.code, KeyCode::Char('s') | KeyCode::Char('S'))
+                    && edit_mode.is_none() && note_mode.is_none() && visualizer.state().awaiting_question.is_none()
+                    && !visualizer.has_extra_state() => {
+                    step_held(visualizer, &mut step_hold);
+                }
                 Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                    clipboard_notice = None;
+                    milestone_notice = None;
+                    step_limit_notice = None;
+                    export_notice = None;
+
+                    // A key during the completion sweep only skips it
+                    if victory_started.take().is_some() {
+                        visualizer.state_mut().victory_sweep = None;
+                        continue;
+                    }
+
+                    // Handle value editing
+                    if let Some(edit) = edit_mode.as_mut() {
+                        if handle_edit_input(visualizer, edit, key_event.code) {
+                            edit_mode = None;
+                        }
+                        continue;
+                    }
+
+                    // Handle note writing
+                    if let Some(note) = note_mode.as_mut() {
+                        if handle_note_input(visualizer, note, key_event.code) {
+                            note_mode = None;
+                        }
+                        continue;
+                    }
+
                     // Handle question answer if a question is pending
-                    if let Some(q_index) = state.awaiting_question {
-                        match key_event.code {
-                            KeyCode::Char('1') => handle_question_answer(visualizer, state, q_index, 0, &mut stdout),
-                            KeyCode::Char('2') => handle_question_answer(visualizer, state, q_index, 1, &mut stdout),
-                            KeyCode::Char('3') => handle_question_answer(visualizer, state, q_index, 2, &mut stdout),
+                    if let Some(q_index) = visualizer.state().awaiting_question {
+                        let answer = match key_event.code {
+                            KeyCode::Char('1') => 0,
+                            KeyCode::Char('2') => 1,
+                            KeyCode::Char('3') => 2,
                             _ => continue,
+                        };
+                        if let Some(explanation) = handle_question_answer(visualizer, q_index, answer, &mut settings)
+                            && settings.hold_explanation
+                        {
+                            // The presenter elaborates on the explanation before SPACE resumes the run
+                            if visualizer.state().is_running {
+                                visualizer.state_mut().is_paused = true;
+                            }
+                            held_explanation = Some((visualizer.state().steps_taken, explanation));
                         }
                         continue;
                     }
 
+                    // Handle visualizer-specific prompts
+                    if visualizer.handle_extra_input(key_event.code) {
+                        continue;
+                    }
+
                     // Handle normal controls
                     match key_event.code {
                         KeyCode::Char(' ') => {
-                            if state.completed {
+                            if visualizer.state().completed {
                                 visualizer.reset();
-                                state.reset_state();
                             } else {
-                                state.toggle_play_pause();
+                                visualizer.state_mut().toggle_play_pause();
                             }
                         }
                         KeyCode::Char('r') | KeyCode::Char('R') => {
                             visualizer.reset();
-                            state.reset_state();
                         }
                         KeyCode::Char('s') | KeyCode::Char('S') => {
-                            if !state.completed && !state.is_running {
-                                if !visualizer.step() {
-                                    state.mark_completed();
-                                    visualizer.mark_all_sorted();
+                            step_held(visualizer, &mut step_hold);
+                        }
+                        KeyCode::Char('e') | KeyCode::Char('E') if can_edit(visualizer) => {
+                            let index = inspect_index.take().unwrap_or(0);
+                            edit_mode = Some(EditMode { index, input: String::new(), error: None });
+                        }
+                        KeyCode::Char('n') | KeyCode::Char('N') if can_inspect(visualizer) => {
+                            let index = inspect_index.take().unwrap_or(0);
+                            let input = visualizer
+                                .state()
+                                .annotations
+                                .get(&index)
+                                .map_or(String::new(), |annotation| annotation.text.clone());
+                            note_mode = Some(NoteMode { index, input });
+                        }
+                        KeyCode::Char('a') | KeyCode::Char('A') if can_inspect(visualizer) => {
+                            let index = inspect_index.unwrap_or(0);
+                            inspect_index = Some(index);
+                            let value = visualizer.get_array()[index];
+                            visualizer.state_mut().toggle_arrow(index, value);
+                        }
+                        KeyCode::Char('f') | KeyCode::Char('F') if frozen => {
+                            export_notice = Some(match export_frame(visualizer) {
+                                Ok(path) => format!("Frame exported to {}", path.display()),
+                                Err(message) => message,
+                            });
+                            frozen = false;
+                        }
+                        KeyCode::Char('f') | KeyCode::Char('F') => {
+                            // Freeze the frame, the cursor picks the bars to annotate
+                            if visualizer.state().is_running {
+                                visualizer.state_mut().is_paused = true;
+                            }
+                            if can_inspect(visualizer) {
+                                frozen = true;
+                                inspect_index = Some(inspect_index.unwrap_or(0));
+                            }
+                        }
+                        KeyCode::Left if inspect_index.is_none() && can_step_back(visualizer) => {
+                            // Rewinds one step, a running sort stays paused there
+                            visualizer.step_back();
+                        }
+                        KeyCode::Left if can_inspect(visualizer) => {
+                            inspect_index = Some(inspect_index.map_or(0, |index| index.saturating_sub(1)));
+                        }
+                        KeyCode::Right if can_inspect(visualizer) => {
+                            let last = visualizer.get_array().len() - 1;
+                            inspect_index = Some(inspect_index.map_or(0, |index| (index + 1).min(last)));
+                        }
+                        KeyCode::Char('y') | KeyCode::Char('Y') if !visualizer.state().practice => {
+                            let state = visualizer.state_mut();
+                            state.show_step_hint = !state.show_step_hint;
+                        }
+                        KeyCode::Char('v') | KeyCode::Char('V') => {
+                            let render_mode = visualizer.state().render_mode.next();
+                            visualizer.state_mut().render_mode = render_mode;
+                            settings.render_mode = render_mode;
+                            save_settings(&settings, &mut saved_at);
+                        }
+                        KeyCode::Char('[') | KeyCode::Char(']') if !visualizer.state().completed && !visualizer.state().practice => {
+                            // Only affects this run, the default lives in the settings menu
+                            if let Some(cutoff) = visualizer.insertion_cutoff() {
+                                let cutoff = if key_event.code == KeyCode::Char('[') {
+                                    cutoff.saturating_sub(1)
+                                } else {
+                                    (cutoff + 1).min(MAX_INSERTION_CUTOFF)
+                                };
+                                visualizer.set_insertion_cutoff(cutoff);
+                            }
+                        }
+                        KeyCode::Char('l') | KeyCode::Char('L') if can_compare_family(visualizer) => {
+                            // Same array, all four simple quadratic sorts, run headlessly
+                            let array_data = ArrayData::new(visualizer.get_original_array().to_vec(), "Family".to_string());
+                            let text = quadratic_family_text(&array_data, &settings, visualizer.settings_key());
+                            dialog::show_info("Simple Quadratic Sorts", &text);
+                        }
+                        KeyCode::Char('i') | KeyCode::Char('I') if visualizer.state().completed && !visualizer.state().practice => {
+                            let info = visualizer.info();
+                            dialog::show_info(&format!("Cheat Sheet: {}", info.name), &info.card_text());
+                        }
+                        KeyCode::Char('k') | KeyCode::Char('K') => {
+                            let state = visualizer.state_mut();
+                            state.show_counter_overlay = !state.show_counter_overlay;
+                        }
+                        KeyCode::Char('o') | KeyCode::Char('O') => {
+                            let state = visualizer.state_mut();
+                            state.show_decision_changers = !state.show_decision_changers;
+                        }
+                        KeyCode::Char('w') | KeyCode::Char('W') => {
+                            match inspect_index.map(|index| visualizer.get_array()[index]) {
+                                Some(value) => match watch_first.take() {
+                                    Some(first) => visualizer.state_mut().watch_pair(first, value),
+                                    None => watch_first = Some(value),
+                                },
+                                // Outside inspection W ends the watch
+                                None => {
+                                    watch_first = None;
+                                    visualizer.state_mut().clear_watch();
                                 }
                             }
                         }
-                        KeyCode::Char('t') | KeyCode::Char('T') => {
-                            state.toggle_teaching_mode();
+                        KeyCode::Char('c') | KeyCode::Char('C') if !visualizer.state().hide_values => {
+                            clipboard_notice = Some(copy_values(visualizer.get_array()));
+                        }
+                        KeyCode::Char('t') | KeyCode::Char('T') if !visualizer.state().practice => {
+                            // Only affects this run, the default lives in the settings menu
+                            visualizer.state_mut().toggle_teaching_mode();
+                            visualizer.on_teaching_mode_changed();
+                        }
+                        KeyCode::Char('p') | KeyCode::Char('P') => {
+                            visualizer.state_mut().toggle_auto_pause_on_swap();
+                        }
+                        KeyCode::Char('g') | KeyCode::Char('G') => {
+                            // Shortcut between the plain bars and the gradient
+                            let render_mode = match visualizer.state().render_mode {
+                                RenderMode::Gradient => RenderMode::Bars,
+                                _ => RenderMode::Gradient,
+                            };
+                            visualizer.state_mut().render_mode = render_mode;
+                            settings.render_mode = render_mode;
+                            save_settings(&settings, &mut saved_at);
+                        }
+                        KeyCode::Char('x') | KeyCode::Char('X') => {
+                            let value_format = visualizer.state().value_format.next();
+                            visualizer.state_mut().value_format = value_format;
+                            settings.value_format = value_format;
+                            save_settings(&settings, &mut saved_at);
                         }
                         KeyCode::Char('+') => {
-                            state.increase_speed(50);
+                            visualizer.state_mut().increase_speed(MIN_SPEED);
+                            remember_algorithm_speed(visualizer, &mut settings);
+                            save_settings(&settings, &mut saved_at);
                         }
                         KeyCode::Char('-') => {
-                            state.decrease_speed(2000);
+                            visualizer.state_mut().decrease_speed(2000);
+                            remember_algorithm_speed(visualizer, &mut settings);
+                            save_settings(&settings, &mut saved_at);
+                        }
+                        KeyCode::Char('z') | KeyCode::Char('Z') if !visualizer.state().completed && !visualizer.state().practice => {
+                            // Keep the run paused so resuming shows exactly this frame
+                            if visualizer.state().is_running {
+                                visualizer.state_mut().is_paused = true;
+                            }
+                            save_last_visualizer(visualizer, &mut settings);
+                            cleanup_terminal();
+                            return RunOutcome::Suspended;
                         }
                         KeyCode::Esc => {
+                            if !visualizer.state().practice {
+                                save_last_visualizer(visualizer, &mut settings);
+                            }
                             cleanup_terminal();
-                            return;
+                            return RunOutcome::Exited;
                         }
                         _ => {}
                     }
@@ -81,105 +665,699 @@ pub fn run_visualizer<V: SortVisualizer>(visualizer: &mut V, state: &mut Visuali
         }
 
         // Auto-step if running and not paused
-        if state.is_running && !state.is_paused && !state.completed && state.awaiting_question.is_none() {
-            std::thread::sleep(state.speed);
-            if !visualizer.step() {
-                state.mark_completed();
-                visualizer.mark_all_sorted();
+        let now = Instant::now();
+        if can_auto_step(visualizer) {
+            let mut steps = 0;
+            loop {
+                let delay = visualizer.state().delay_after(last_step);
+                let delay = if settings.speed_ramp { delay / speed_ramp.factor() } else { delay };
+                if !step_clock.step_due(now, delay) {
+                    break;
+                }
+                steps += 1;
+                if steps > MAX_STEPS_PER_FRAME {
+                    step_clock.drop_backlog();
+                    break;
+                }
+                let changes_before = visualizer.state().array_changes();
+                visualizer.record_step();
+                let (more, kind) = visualizer.step_with_kind();
+                last_step = kind;
+                if more {
+                    visualizer.state_mut().pause_after_swap(changes_before);
+                    let progress = visualizer.get_progress();
+                    if let Some(milestone) = visualizer.state_mut().pause_at_milestone(progress) {
+                        milestone_notice = Some(format!("Paused at the {}% milestone, SPACE continues", milestone));
+                    }
+                    if visualizer.state_mut().count_step() {
+                        step_limit_notice = Some(format!(
+                            "Step limit reached — possible non-termination after {} steps, SPACE continues",
+                            visualizer.state().steps_taken
+                        ));
+                    }
+                } else {
+                    finish(visualizer);
+                }
+                if settings.speed_ramp {
+                    speed_ramp.record(visualizer.phase_label());
+                }
+                follow_notes(visualizer);
+                // Steps between frames are never drawn, the transcript still gets each of them
+                announcer.announce(&visualizer.get_current_operation());
+                if verify_step(visualizer, &mut divergence) {
+                    inspect_index = divergence.map(|divergence| divergence.index);
+                }
+                if !can_auto_step(visualizer) {
+                    break;
+                }
+            }
+        } else {
+            step_clock.idle(now);
+        }
+    }
+}
+
+// The completion screen of bubble, cocktail, gnome and insertion sort compares the four
+fn can_compare_family<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &V) -> bool {
+    visualizer.state().completed && !visualizer.state().practice && in_quadratic_family(visualizer.settings_key())
+}
+
+// Auto-play steps while running unless a pause, question or prompt stops it
+fn can_auto_step<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &V) -> bool {
+    let state = visualizer.state();
+    state.is_running && !state.is_paused && !state.completed
+        && state.awaiting_question.is_none() && !visualizer.has_extra_state()
+}
+
+// With --verify, checks the elements marked sorted against the sorted result
+// Returns true for a new divergence, the run is paused so the flagged index can be inspected
+fn verify_step<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &mut V, divergence: &mut Option<Divergence<T>>) -> bool {
+    if !verifier::is_enabled() || !visualizer.verifiable() {
+        return false;
+    }
+    let comparator = visualizer.state().sort_order.comparator();
+    let found = verifier::verify_partial(visualizer.get_array(), &visualizer.sorted_regions(), &comparator);
+    let is_new = found.is_some_and(|found| divergence.map(|previous| previous.index) != Some(found.index));
+    *divergence = found;
+    if is_new && visualizer.state().is_running {
+        visualizer.state_mut().is_paused = true;
+    }
+    is_new
+}
+
+// Lets the notes follow the elements the last step moved
+fn follow_notes<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &mut V) {
+    if visualizer.state().annotations.is_empty() {
+        return;
+    }
+    let array = visualizer.get_array().to_vec();
+    visualizer.state_mut().follow_annotations(&array);
+}
+
+// Advances a single step while the visualizer is stopped, returns false once nothing is left to step
+fn step_once<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &mut V) -> bool {
+    if visualizer.state().completed || visualizer.state().is_running {
+        return false;
+    }
+    visualizer.record_step();
+    if !visualizer.step() {
+        finish(visualizer);
+        return false;
+    }
+    // Milestones stepped past by hand don't pause auto-play later
+    let progress = visualizer.get_progress();
+    visualizer.state_mut().pass_milestones(progress);
+    visualizer.state_mut().count_step();
+    true
+}
+
+// Left steps back while no bar is inspected and there is a step to go back to
+fn can_step_back<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &V) -> bool {
+    let state = visualizer.state();
+    !state.step_history.is_empty() && state.awaiting_question.is_none() && !visualizer.has_extra_state()
+}
+
+// Steps faster the longer the step key is held.
+// Teaching mode stays at one step per event since any step may ask a question.
+fn step_held<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &mut V, step_hold: &mut StepHold) {
+    let steps = if visualizer.state().teaching_mode {
+        1
+    } else {
+        step_hold.register(Instant::now())
+    };
+    for _ in 0..steps {
+        if !step_once(visualizer) || visualizer.state().awaiting_question.is_some() || visualizer.has_extra_state() {
+            break;
+        }
+    }
+}
+
+// Sets the window title to e.g. "Radix Sort — 42%", only writing it when the text changes
+fn update_window_title<T: SortElement, V: SortVisualizer<T> + ?Sized>(stdout: &mut std::io::Stdout, visualizer: &V, window_title: &mut String) {
+    let progress = if visualizer.state().completed { 100.0 } else { visualizer.get_progress() };
+    let name = if visualizer.state().practice { "Mystery Algorithm".to_string() } else { visualizer.display_name() };
+    let title = format!("{} — {:.0}%", name, progress);
+    if *window_title != title {
+        stdout.execute(SetTitle(&title)).unwrap();
+        *window_title = title;
+    }
+}
+
+// Appends the completed run to the history file, a run that cannot be recorded still completes
+fn record_history<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &V, elapsed: Duration) {
+    let state = visualizer.state();
+    let _ = history::append(&HistoryEntry {
+        timestamp: history::now_timestamp(),
+        algorithm: visualizer.display_name(),
+        size: visualizer.get_array().len(),
+        seed: state.seed,
+        comparisons: state.comparisons,
+        swaps: state.swaps,
+        moves: state.moves,
+        elapsed_ms: elapsed.as_millis() as u64,
+    });
+}
+
+// Saves the settings and starts the "Settings saved" notice
+fn save_settings(settings: &Settings, saved_at: &mut Option<Instant>) {
+    settings.save();
+    *saved_at = Some(Instant::now());
+}
+
+// Remembers the visualizer so the main menu can offer it again, saves only if it changed
+fn save_last_visualizer<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &V, settings: &mut Settings) {
+    if settings.last_visualizer.as_deref() != Some(visualizer.settings_key()) {
+        settings.last_visualizer = Some(visualizer.settings_key().to_string());
+        settings.save();
+    }
+}
+
+// Remembers the adjusted speed as this algorithm's preferred speed
+fn remember_algorithm_speed<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &V, settings: &mut Settings) {
+    settings
+        .algorithm_speeds
+        .insert(visualizer.settings_key().to_string(), visualizer.state().speed.as_millis() as u64);
+}
+
+// Bar the completion sweep lights `elapsed` after it started, None once it has passed every bar
+fn victory_sweep_index(elapsed: Duration, len: usize) -> Option<usize> {
+    if elapsed >= VICTORY_SWEEP_DURATION {
+        return None;
+    }
+    let index = elapsed.as_millis() * len as u128 / VICTORY_SWEEP_DURATION.as_millis();
+    (index < len as u128).then_some(index as usize)
+}
+
+// Marks the run as completed and lets the visualizer update its final view
+fn finish<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &mut V) {
+    visualizer.state_mut().mark_completed();
+    visualizer.on_completed();
+}
+
+// Values can only be edited while they are visible, the algorithm is stopped and nothing else waits for input
+fn can_edit<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &V) -> bool {
+    let state = visualizer.state();
+    !state.completed
+        && !state.hide_values
+        && (!state.is_running || state.is_paused)
+        && state.awaiting_question.is_none()
+        && !visualizer.has_extra_state()
+        && !visualizer.get_array().is_empty()
+}
+
+// Elements can be inspected whenever their values are visible, the run is stopped and nothing else waits for input
+fn can_inspect<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &V) -> bool {
+    let state = visualizer.state();
+    (!state.is_running || state.is_paused || state.completed)
+        && !state.hide_values
+        && state.awaiting_question.is_none()
+        && !visualizer.has_extra_state()
+        && !visualizer.get_array().is_empty()
+}
+
+// Handles a key while editing, returns true when the editor should close
+fn handle_edit_input<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &mut V, edit: &mut EditMode, key_code: KeyCode) -> bool {
+    let len = visualizer.get_array().len();
+    match key_code {
+        KeyCode::Left => {
+            edit.index = edit.index.saturating_sub(1);
+            edit.input.clear();
+        }
+        KeyCode::Right => {
+            edit.index = (edit.index + 1).min(len - 1);
+            edit.input.clear();
+        }
+        KeyCode::Char(c) if (c.is_ascii_digit() || c == '.' || (c == '-' && T::SIGNED)) && edit.input.len() < EDIT_MAX_LENGTH => {
+            edit.input.push(c);
+        }
+        KeyCode::Backspace => {
+            edit.input.pop();
+        }
+        KeyCode::Enter => {
+            let range = edit_range::<T>();
+            match T::parse(&edit.input) {
+                Some(value) if range.contains(&value.to_f64()) => {
+                    match visualizer.edit_value(edit.index, value) {
+                        Ok(()) => return true,
+                        Err(message) => {
+                            edit.error = Some(message);
+                            edit.input.clear();
+                        }
+                    }
+                }
+                _ => {
+                    edit.error = Some(format!("Value must be between {} and {}", range.start(), range.end()));
+                    edit.input.clear();
+                }
             }
         }
+        KeyCode::Esc => return true,
+        _ => {}
     }
+    false
+}
+
+// Values the edit mode accepts for elements of type T, every value the generate dialog can draw
+// so a generated array can be edited back to its own values; signed arrays go as far below zero
+fn edit_range<T: SortElement>() -> RangeInclusive<f64> {
+    let highest = MAX_RANDOM_VALUE as f64;
+    let lowest = if T::SIGNED { -highest } else { 0.0 };
+    lowest..=highest
+}
+
+// Handles a key while writing a note, returns true when the editor should close
+fn handle_note_input<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &mut V, note: &mut NoteMode, key_code: KeyCode) -> bool {
+    match key_code {
+        KeyCode::Char(c) if note.input.chars().count() < MAX_NOTE_LENGTH => note.input.push(c),
+        KeyCode::Backspace => {
+            note.input.pop();
+        }
+        KeyCode::Enter => {
+            let value = visualizer.get_array()[note.index];
+            visualizer.state_mut().annotate(note.index, value, &note.input);
+            return true;
+        }
+        KeyCode::Esc => return true,
+        _ => {}
+    }
+    false
 }
 
 // Draws the screen
-fn draw_screen<V: SortVisualizer>(
-    stdout: &mut std::io::Stdout,
+fn draw_screen<T: SortElement, V: SortVisualizer<T> + ?Sized>(
+    drawer: &mut VisualizerDrawer,
     visualizer: &V,
-    state: &VisualizerState,
+    edit_mode: Option<&EditMode>,
+    note_mode: Option<&NoteMode>,
+    inspect_index: Option<usize>,
+    watch_first: Option<T>,
+    notices: &[String],
 ) {
     let (width, height) = size().unwrap();
-    stdout.execute(Clear(ClearType::All)).unwrap();
+    drawer.clear_screen(width, height, 5);
+    let state = visualizer.state();
 
     // Title
-    VisualizerDrawer::draw_title(stdout, visualizer.get_title());
+    drawer.draw_title(if state.practice { PRACTICE_TITLE } else { visualizer.get_title() });
+
+    // Array bars, reduced motion keeps auto-play free of flashing highlights
+    let mut states = if state.reduced_motion && state.is_running && !state.is_paused {
+        calm_states(visualizer.get_states())
+    } else {
+        visualizer.get_states().to_vec()
+    };
+    // The completion sweep only lights bars that ended up sorted
+    if let Some(index) = state.victory_sweep
+        && states.get(index) == Some(&SelectionState::Sorted)
+    {
+        states[index] = SelectionState::Selected;
+    }
+    // While paused, the what-if overlay marks the idle elements that would flip the next comparison
+    let what_if = state.show_decision_changers && !state.completed && (!state.is_running || state.is_paused);
+    let changers = if what_if {
+        visualizer.decision_changers()
+    } else {
+        Vec::new()
+    };
+    for &i in &changers {
+        if states.get(i) == Some(&SelectionState::Normal) {
+            states[i] = SelectionState::WouldChange;
+        }
+    }
+    // A sorted slice is drawn in its place, between the dimmed elements that stay fixed
+    let offset = state.fixed_range.as_ref().map_or(0, FixedRange::start);
+    match &state.fixed_range {
+        Some(fixed) => {
+            let array = fixed.full_array(visualizer.get_array());
+            let original = fixed.full_array(visualizer.get_original_array());
+            drawer.draw_array(&array, &original, &fixed.full_states(&states), state, offset);
+        }
+        None => drawer.draw_array(visualizer.get_array(), visualizer.get_original_array(), &states, state, 0),
+    }
+
+    // Sorted regions, the inspect marker on the same row is drawn later and stays on top
+    if state.show_sorted_regions {
+        let regions: Vec<_> = visualizer
+            .sorted_regions()
+            .into_iter()
+            .map(|region| region.start + offset..region.end + offset)
+            .collect();
+        drawer.draw_sorted_regions(&regions, drawn_position(visualizer, 0).0, width, height, 5);
+    }
+
+    // Legend and visualizer-specific extras, pivots, buckets or piles would give the algorithm away in practice mode
+    if !state.practice {
+        drawer.draw_legend(
+            &visualizer.get_legend_items(),
+            width,
+            height,
+            5,
+        );
+        visualizer.draw_extra(drawer, width, height);
+    }
+
+    // Statistics, practice mode only shows the counters every algorithm has
+    let mut stats = if state.practice {
+        practice_statistics(visualizer)
+    } else {
+        visualizer.get_statistics_strings()
+    };
+    if state.sort_order != SortOrder::Ascending {
+        stats.push(format!("Order: {}", state.sort_order.label()));
+    }
+    if state.value_format != ValueFormat::Decimal {
+        stats.push(format!("Values: {}", state.value_format.label()));
+    }
+    if what_if {
+        stats.push(format!("What If: {} elements would change the decision", changers.len()));
+    }
+    if state.teaching_mode && state.best_streak > 0 {
+        stats.push(format!("Question Streak: {} (best {})", state.current_streak, state.best_streak));
+    }
+    if let Some(fixed) = &state.fixed_range {
+        let start = fixed.start();
+        stats.push(format!("Range: [{}, {})", start, start + visualizer.get_array().len()));
+    }
+    if let Some((a, b)) = state.watched_pair {
+        let format = state.value_format;
+        stats.push(format!("Watch {}↔{}: {}", a.format(format), b.format(format), state.watched_comparisons));
+    }
+    stats.extend_from_slice(notices);
+    drawer.draw_statistics(&stats, width, height);
 
-    // Array bars
-    VisualizerDrawer::draw_array_bars(
-        stdout,
-        visualizer.get_array(),
-        visualizer.get_states(),
+    // Value editor
+    if let Some(edit) = edit_mode {
+        draw_edit_mode(drawer, visualizer, edit, width, height);
+        drawer.flush();
+        return;
+    }
+
+    // Note editor
+    if let Some(note) = note_mode {
+        draw_note_mode(drawer, visualizer, note, width, height);
+        drawer.flush();
+        return;
+    }
+
+    // Controls
+    let mut controls = if state.practice { PRACTICE_CONTROLS } else { visualizer.get_controls_text() }.to_string();
+    let step_back = inspect_index.is_none() && can_step_back(visualizer);
+    if step_back {
+        controls.push_str(" | ←: Step Back");
+    }
+    if can_inspect(visualizer) {
+        controls.push_str(if step_back { " | →: Inspect" } else { " | ←/→: Inspect" });
+    }
+    if can_edit(visualizer) {
+        controls.push_str(" | E: Edit");
+    }
+    if can_inspect(visualizer) {
+        controls.push_str(" | N: Note | A: Arrow");
+    }
+    if !state.hide_values {
+        controls.push_str(" | F: Freeze");
+    }
+    if !state.completed {
+        controls.push_str(if state.auto_pause_on_swap { " | P: Swap Pause (ON)" } else { " | P: Swap Pause" });
+        if !state.practice {
+            controls.push_str(" | Z: Suspend");
+            controls.push_str(if state.show_step_hint { " | Y: Why (ON)" } else { " | Y: Why" });
+        }
+        controls.push_str(if state.show_decision_changers { " | O: What If (ON)" } else { " | O: What If" });
+    }
+    if state.completed && !state.practice {
+        controls.push_str(" | I: Cheat Sheet");
+    }
+    if can_compare_family(visualizer) {
+        controls.push_str(" | L: Compare Family");
+    }
+    if let Some(cutoff) = visualizer.insertion_cutoff().filter(|_| !state.completed && !state.practice) {
+        controls.push_str(&format!(" | [/]: Cutoff ({})", cutoff));
+    }
+    controls.push_str(&format!(" | V: View ({})", state.render_mode.label()));
+    controls.push_str(if state.show_counter_overlay { " | K: Big Counters (ON)" } else { " | K: Big Counters" });
+    controls.push_str(" | X: Base");
+    if !state.hide_values {
+        controls.push_str(" | C: Copy");
+    }
+    drawer.draw_controls(visualizer.get_status(), &controls, width, height);
+
+    // Current operation, its wording names gaps, pivots and digits
+    if state.awaiting_question.is_none() && !visualizer.has_extra_state() && !state.practice {
+        let operation = visualizer.get_current_operation();
+        let color = if state.completed { Color::Green } else { drawer.palette().text };
+        drawer.draw_operation_info(&operation, width, height, color);
+        // The hint goes on the free row between the operation and the status line
+        if state.show_step_hint && let Some(hint) = visualizer.get_step_hint() {
+            let hint: String = format!("Why: {}", hint).chars().take(width as usize).collect();
+            drawer.draw_operation_info(&hint, width, height.saturating_add(1), drawer.palette().dim);
+        }
+    }
+
+    // Inspected element
+    if let Some(index) = inspect_index {
+        draw_inspection(drawer, visualizer, index, watch_first, width, height);
+    }
+
+    // Question
+    if let Some(question) = state.awaiting_question.and_then(|q_index| state.questions.get(q_index)) {
+        drawer.draw_question(question, width, height);
+    }
+
+    drawer.flush();
+}
+
+// Counters every algorithm has, shown in practice mode instead of the algorithm's own statistics
+fn practice_statistics<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &V) -> Vec<String> {
+    vec![
+        format!("Array Size: {}", visualizer.get_array().len()),
+        format!("Comparisons: {}", visualizer.get_comparisons()),
+        format!("Swaps: {}", visualizer.get_swaps()),
+        format!("Moves: {}", visualizer.get_moves()),
+        format!("Speed: {}ms", visualizer.get_speed().as_millis()),
+        format!("Progress: {:.1}%", visualizer.get_progress()),
+    ]
+}
+
+// Drops transient highlights, only the sorted region and removed elements stay colored
+fn calm_states(states: &[SelectionState]) -> Vec<SelectionState> {
+    states
+        .iter()
+        .map(|&state| match state {
+            SelectionState::Sorted | SelectionState::Removed => state,
+            _ => SelectionState::Normal,
+        })
+        .collect()
+}
+
+// Bar count on screen and where the visualizer's `index` is among them,
+// a sorted slice has the elements that stay fixed drawn around it
+fn drawn_position<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &V, index: usize) -> (usize, usize) {
+    let len = visualizer.get_array().len();
+    match &visualizer.state().fixed_range {
+        Some(fixed) => (fixed.before.len() + len + fixed.after.len(), fixed.start() + index),
+        None => (len, index),
+    }
+}
+
+// Draws the value editor prompt, the index marker and the invariant warning
+fn draw_edit_mode<T: SortElement, V: SortVisualizer<T> + ?Sized>(
+    drawer: &mut VisualizerDrawer,
+    visualizer: &V,
+    edit: &EditMode,
+    width: u16,
+    height: u16,
+) {
+    let array = visualizer.get_array();
+    let (drawn_len, drawn_index) = drawn_position(visualizer, edit.index);
+    drawer.draw_index_marker(drawn_len, drawn_index, width, height, 5);
+
+    drawer.draw_controls(
+        "EDITING",
+        "←/→: Select Index | 0-9: Type Value | Enter: Apply | ESC: Cancel",
         width,
         height,
-        5,
     );
 
-    // Legend
-    VisualizerDrawer::draw_legend(
-        stdout,
-        &visualizer.get_legend_items(),
-        width,
-        height,
-        5,
+    let range = edit_range::<T>();
+    let prompt = format!(
+        "Edit array[{}] (currently {}) -> new value ({} to {}): {}_",
+        edit.index, visualizer.state().value_text(array[edit.index]), range.start(), range.end(), edit.input
     );
+    drawer.draw_operation_info(&prompt, width, height, Color::Yellow);
+
+    let state = visualizer.state();
+    let warning = if let Some(error) = &edit.error {
+        Some(error.as_str())
+    } else if state.comparisons > 0 || state.array_changes() > 0 {
+        Some("Warning: editing mid-sort may violate the algorithm's invariants (e.g. a partially-built heap)")
+    } else {
+        None
+    };
+    if let Some(warning) = warning {
+        drawer.draw_operation_info(warning, width, height.saturating_sub(2), Color::Red);
+    }
+}
 
-    // Statistics
-    let stats = visualizer.get_statistics_strings();
-    VisualizerDrawer::draw_statistics(stdout, &stats, width, height);
+// Draws the note editor prompt and the index marker
+fn draw_note_mode<T: SortElement, V: SortVisualizer<T> + ?Sized>(
+    drawer: &mut VisualizerDrawer,
+    visualizer: &V,
+    note: &NoteMode,
+    width: u16,
+    height: u16,
+) {
+    let (drawn_len, drawn_index) = drawn_position(visualizer, note.index);
+    drawer.draw_index_marker(drawn_len, drawn_index, width, height, 5);
 
-    // Controls
-    VisualizerDrawer::draw_controls(
-        stdout,
-        visualizer.get_status(),
-        visualizer.get_controls_text(),
+    drawer.draw_controls(
+        "WRITING NOTE",
+        "Type the note | Enter: Save (empty removes it) | ESC: Cancel",
         width,
         height,
     );
 
-    // Current operation
-    if state.awaiting_question.is_none() {
-        let operation = visualizer.get_current_operation();
-        let color = if state.completed {
-            crossterm::style::Color::Green
-        } else {
-            crossterm::style::Color::White
-        };
-        VisualizerDrawer::draw_operation_info(stdout, &operation, width, height, color);
+    let prompt = format!("Note for array[{}]: {}_", note.index, note.input);
+    drawer.draw_operation_info(&prompt, width, height, Color::Yellow);
+}
+
+// Draws the inspection cursor and a read-only line describing the element under it
+fn draw_inspection<T: SortElement, V: SortVisualizer<T> + ?Sized>(
+    drawer: &mut VisualizerDrawer,
+    visualizer: &V,
+    index: usize,
+    watch_first: Option<T>,
+    width: u16,
+    height: u16,
+) {
+    let array = visualizer.get_array();
+    let Some(value) = array.get(index) else {
+        return;
+    };
+    let (drawn_len, drawn_index) = drawn_position(visualizer, index);
+    drawer.draw_index_marker(drawn_len, drawn_index, width, height, 5);
+
+    let state = visualizer
+        .get_states()
+        .get(index)
+        .map_or("Unknown", |state| state.label());
+    let format = visualizer.state().value_format;
+    let watch_hint = match watch_first {
+        Some(first) => format!("W: Watch {}↔{}", first.format(format), value.format(format)),
+        None => "W: Watch Value".to_string(),
+    };
+    // Other bases also show the decimal value, edits are still typed in decimal
+    let shown = match format {
+        ValueFormat::Decimal => value.to_string(),
+        _ => format!("{} ({})", value.format(format), value),
+    };
+    let detail = format!("Inspecting array[{}] = {} | State: {} | {}", index, shown, state, watch_hint);
+    drawer.draw_operation_info(&detail, width, height.saturating_sub(1), Color::Magenta);
+}
+
+// Handles question answers, returns the explanation to keep on screen
+// A new best streak is saved as this algorithm's best
+fn handle_question_answer<T: SortElement, V: SortVisualizer<T> + ?Sized>(visualizer: &mut V, q_index: usize, answer: usize, settings: &mut Settings) -> Option<String> {
+    let question = visualizer.state().questions.get(q_index)?.clone();
+    let correct = answer == question.correct_index;
+    let auto_advance = (settings.feedback_seconds > 0).then(|| Duration::from_secs(settings.feedback_seconds as u64));
+    show_question_feedback(correct, &question, answer, auto_advance);
+    let state = visualizer.state_mut();
+    state.clear_question();
+    if state.record_answer(correct) {
+        let best = state.best_streak;
+        settings.best_streaks.insert(visualizer.settings_key().to_string(), best);
+        settings.save();
     }
+    Some(format!(
+        "Answer: {} — {} (SPACE resumes)",
+        question.options[question.correct_index], question.explanation
+    ))
+}
 
-    // Question
-    if let Some(q_index) = state.awaiting_question {
-        if let Some(question) = state.questions.get(q_index) {
-            VisualizerDrawer::draw_question(stdout, question, width, height);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(algorithm: &str, comparisons: u32, swaps: u32, moves: u32) -> RunSummary {
+        RunSummary { algorithm: algorithm.to_string(), comparisons, swaps, moves }
+    }
+
+    #[test]
+    fn step_clock_fits_several_fast_steps_into_one_frame() {
+        // Steps the clock allows at `now` when every step waits `delay`
+        fn steps_due(clock: &mut StepClock, now: Instant, delay: Duration) -> u32 {
+            let mut steps = 0;
+            while clock.step_due(now, delay) {
+                steps += 1;
+            }
+            steps
         }
+
+        let start = Instant::now();
+        let mut clock = StepClock { last_tick: start, budget: Duration::ZERO };
+        let frame = Duration::from_millis(33);
+        assert_eq!(steps_due(&mut clock, start + frame, Duration::from_millis(10)), 3);
+        // The 3 ms left over each frame add up, no time is lost between frames
+        let steps: u32 = (2..=10).map(|i| steps_due(&mut clock, start + frame * i, Duration::from_millis(10))).sum();
+        assert_eq!(3 + steps, 33);
+
+        // A slow step waits for as many frames as it needs
+        let mut clock = StepClock { last_tick: start, budget: Duration::ZERO };
+        assert_eq!(steps_due(&mut clock, start + frame, Duration::from_millis(600)), 0);
+        assert_eq!(steps_due(&mut clock, start + Duration::from_millis(600), Duration::from_millis(600)), 1);
+
+        // A long delay after one step and a short one after the next
+        let mut clock = StepClock { last_tick: start, budget: Duration::ZERO };
+        assert!(clock.step_due(start + Duration::from_millis(300), Duration::from_millis(300)));
+        assert!(!clock.step_due(start + Duration::from_millis(300), Duration::from_millis(50)));
+        assert!(clock.step_due(start + Duration::from_millis(350), Duration::from_millis(50)));
     }
 
-    stdout.flush().unwrap();
-}
+    #[test]
+    fn speed_ramp_accelerates_within_a_phase_and_resets_on_change() {
+        let mut ramp = SpeedRamp::new();
+        for _ in 0..RAMP_START_STEPS {
+            ramp.record(Some("Counting".to_string()));
+        }
+        assert_eq!(ramp.factor(), 1);
+        ramp.record(Some("Counting".to_string()));
+        assert_eq!(ramp.factor(), 2);
+        for _ in 0..100 {
+            ramp.record(Some("Counting".to_string()));
+        }
+        assert_eq!(ramp.factor(), MAX_RAMP);
 
-// Handles question answers
-fn handle_question_answer<V: SortVisualizer>(
-    visualizer: &V,
-    state: &mut VisualizerState,
-    q_index: usize,
-    answer: usize,
-    _stdout: &mut std::io::Stdout,
-) {
-    if let Some(question) = state.questions.get(q_index) {
-        let correct = answer == question.correct_index;
-        show_question_feedback(correct, question, answer);
-        state.clear_question();
+        ramp.record(Some("Collecting".to_string()));
+        assert_eq!(ramp.factor(), 1);
     }
-}
 
-// Extendable visualizer runner for special cases
-// For example, bubble sort's swap confirmation
-pub trait ExtendedVisualizerBehavior {
-    // Extra input handling
-    fn handle_extra_input(&mut self, key_code: KeyCode) -> bool;
+    #[test]
+    fn edit_range_covers_every_generated_value() {
+        assert_eq!(edit_range::<u32>(), 0.0..=9999.0);
+        assert_eq!(edit_range::<i32>(), -9999.0..=9999.0);
+        assert!("-9999.125".len() <= EDIT_MAX_LENGTH);
+    }
 
-    // Check for extra state
-    fn has_extra_state(&self) -> bool;
+    #[test]
+    fn victory_sweep_crosses_every_bar_once() {
+        assert_eq!(victory_sweep_index(Duration::ZERO, 4), Some(0));
+        assert_eq!(victory_sweep_index(VICTORY_SWEEP_DURATION / 2, 4), Some(2));
+        assert_eq!(victory_sweep_index(VICTORY_SWEEP_DURATION - Duration::from_millis(1), 4), Some(3));
+        assert_eq!(victory_sweep_index(VICTORY_SWEEP_DURATION, 4), None);
+        assert_eq!(victory_sweep_index(Duration::ZERO, 0), None);
+    }
 
-    // Get extra state message
-    fn get_extra_state_message(&self) -> Option<String>;
+    #[test]
+    fn run_diff_is_relative_to_the_previous_run() {
+        let previous = summary("Bubble Sort", 100, 40, 0);
+        assert_eq!(
+            summary("Quick Sort", 130, 30, 0).diff_text(&previous),
+            "+30% comparisons, -10 swaps, +0 moves vs last run (Bubble Sort)"
+        );
+        assert_eq!(
+            summary("Insertion Sort", 5, 0, 7).diff_text(&summary("Linear Search", 0, 0, 0)),
+            "+5 comparisons, +0 swaps, +7 moves vs last run (Linear Search)"
+        );
+    }
 }
@@ -8,37 +8,367 @@ use crossterm::{
         LeaveAlternateScreen,
     },
 };
-use serde::{Deserialize, Serialize};
-use std::fs::{self, File};
-use std::io::{stdout, Read, Write};
-use std::path::Path;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{stdout, Write};
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex};
 use std::time::Duration;
+use crate::common::announcer::transcript_path;
+use crate::common::dialog::show_question;
+use crate::common::paths::config_file;
+use crate::factory::is_known_settings_key;
+use crate::common::base_visualizer::REDUCED_MOTION_MIN_SPEED;
+use crate::common::enums::{BucketInnerSort, ColorTheme, RenderMode, SortOrder, TerminalTheme, ValueFormat};
+use crate::common::theme;
 
 const SETTINGS_FILE: &str = "settings.json";
 
-#[derive(Serialize, Deserialize, Default, Clone)]
+fn settings_path() -> PathBuf {
+    config_file(SETTINGS_FILE)
+}
+
+// Settings that live only in memory, `load` and `save` never touch the file while these are set.
+// Tests construct visualizers, which load and save settings, so they always start with the defaults
+static IN_MEMORY: LazyLock<Mutex<Option<Settings>>> = LazyLock::new(|| Mutex::new(cfg!(test).then(Settings::default)));
+
+/// Keeps `settings` in memory for the rest of the process instead of the settings file.
+/// `Settings::load` returns them and `save` only replaces them, for headless runs that must
+/// neither depend on nor change the user's configuration.
+pub fn keep_in_memory(settings: Settings) {
+    *IN_MEMORY.lock().unwrap() = Some(settings);
+}
+
+// Step delay used until the user picks one (ms)
+pub const DEFAULT_SPEED: u64 = 600;
+// Range a loaded speed is clamped to, below one frame several steps are shown per frame (ms)
+pub const MIN_SPEED: u64 = 1;
+pub const MAX_SPEED: u64 = 3000;
+
+// Frames drawn per second while a visualizer runs, cycled through in the settings menu
+pub const DEFAULT_FRAME_RATE: u32 = 30;
+const FRAME_RATES: [u32; 3] = [15, 30, 60];
+
+// Digit counts radix sort can be padded to, 0 shows only the passes the values need
+const RADIX_PADDINGS: [u32; 4] = [0, 3, 4, 5];
+
+// Minimum run lengths tim sort can be set to, 0 computes it from the array size like CPython
+const TIM_MIN_RUNS: [u32; 6] = [0, 4, 8, 16, 32, 64];
+
+// Range lengths quick sort can switch to insertion sort at, 0 always partitions
+const QUICK_SORT_CUTOFFS: [u32; 4] = [0, 4, 8, 16];
+
+// Seconds the answer feedback of a teaching question stays up before the run continues, 0 waits for a key
+const FEEDBACK_DELAYS: [u32; 4] = [0, 3, 5, 10];
+
+// Step delays auto-play can wait after a comparison or a swap/move, in percent of the speed
+const DELAY_FACTORS: [u32; 5] = [100, 200, 400, 25, 50];
+
+// Seconds a completed run can stay on screen before it replays, 0 is off
+const AUTO_RESET_DWELLS: [u32; 5] = [0, 5, 10, 30, 60];
+
+// Step limits a run can be capped at, 0 is off
+const STEP_LIMITS: [u64; 4] = [0, 100_000, 1_000_000, 10_000_000];
+
+// Generous enough that no sort of a supported array size gets near it
+const DEFAULT_MAX_STEPS: u64 = 1_000_000;
+
+// Progress milestone sets cycled through in the settings menu, any ascending list of 1-99 can be set in the file
+const MILESTONE_PRESETS: [&[u32]; 4] = [&[], &[50], &[25, 50, 75], &[10, 20, 30, 40, 50, 60, 70, 80, 90]];
+
+// Values random arrays are generated from until the user picks a range in the generate dialog
+pub const DEFAULT_RANDOM_MIN: u32 = 1;
+pub const DEFAULT_RANDOM_MAX: u32 = 100;
+// Largest value the generate dialog accepts, four digits keep the labels readable
+pub const MAX_RANDOM_VALUE: u32 = 9999;
+
+const REPAIR_LOG_FILE: &str = "settings_repairs.log";
+
+// Read field by field in `from_json`, missing fields are taken from `Settings::default()`
+#[derive(Serialize, Clone)]
 pub struct Settings {
     pub speed: u64, // milliseconds
-    pub teaching_mode: bool,
+    pub teaching_mode: bool, // default for every visualizer, T only changes the current run
     pub last_visualizer: Option<String>, // e.g., "BubbleSort"
+    pub algorithm_speeds: HashMap<String, u64>, // per-algorithm overrides of `speed`, keyed like last_visualizer
+    pub favorites: Vec<String>, // starred menu entries, by menu name e.g. "Quick Sort"
+    pub onboarding_done: bool, // set once the first-run tour was shown
+    pub announce_mode: bool, // write a text transcript of every operation alongside the visual
+    pub reduced_motion: bool, // slower minimum speed and no flashing highlights during auto-play
+    pub sort_order: SortOrder, // comparator preset used by the comparison sorts
+    pub render_mode: RenderMode, // how visualizers draw the array, the last one picked with V
+    pub frame_rate: u32, // frames per second, independent of the step speed
+    pub radix_padding: u32, // radix sort shows at least this many digit passes, 0 is off
+    pub value_format: ValueFormat, // base values are shown in, toggled with X in a visualizer
+    pub notes_follow_elements: bool, // lecture notes move with their element, otherwise they stay at the index
+    pub show_sorted_regions: bool, // bracket under the chart marking the elements already in their final place
+    pub speed_ramp: bool, // auto-play speeds up during long stretches of one phase
+    pub terminal_theme: TerminalTheme, // background the neutral text and highlight colors are picked for, the bars follow `theme`
+    pub victory_animation: bool, // a highlight sweeps across the bars once a run completes
+    pub tim_min_run: u32, // tim sort extends shorter runs to this length, 0 computes it from the array size
+    pub quick_sort_cutoff: u32, // quick sort insertion sorts ranges of this many elements or fewer, 0 is off
+    pub progress_milestones: Vec<u32>, // auto-play pauses once at each of these progress percentages
+    pub bucket_inner_sort: BucketInnerSort, // algorithm bucket sort runs on each bucket
+    pub compare_delay: u32, // percent of the speed auto-play waits after a step that only compared
+    pub change_delay: u32, // percent of the speed auto-play waits after a step that swapped or moved values
+    pub auto_reset_after: u32, // seconds a completed run stays on screen before it replays, 0 waits for a key
+    pub best_streaks: HashMap<String, u32>, // most teaching questions answered right in a row, keyed like last_visualizer
+    pub max_steps: u64, // auto-play pauses after this many steps without completing, 0 is off
+    pub feedback_seconds: u32, // answer feedback continues by itself after this many seconds, 0 waits for a key
+    pub hold_explanation: bool, // after the feedback the run stays paused with the explanation under the chart
+    pub practice_guesses: u32, // practice mode rounds guessed so far
+    pub practice_correct: u32, // practice mode rounds where the guess named the algorithm
+    pub random_min: u32, // smallest value the generate dialog starts with
+    pub random_max: u32, // largest value the generate dialog starts with
+    pub theme: ColorTheme, // colors the bars and legend of a visualizer are drawn in for each selection state, the text follows `terminal_theme`
+}
+
+// The single place every setting gets its default value
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            speed: DEFAULT_SPEED,
+            teaching_mode: false,
+            last_visualizer: None,
+            algorithm_speeds: HashMap::new(),
+            favorites: Vec::new(),
+            onboarding_done: false,
+            announce_mode: false,
+            reduced_motion: false,
+            sort_order: SortOrder::Ascending,
+            render_mode: RenderMode::Bars,
+            frame_rate: DEFAULT_FRAME_RATE,
+            radix_padding: 0,
+            value_format: ValueFormat::Decimal,
+            notes_follow_elements: true,
+            show_sorted_regions: true,
+            speed_ramp: false,
+            terminal_theme: TerminalTheme::Dark,
+            victory_animation: true,
+            tim_min_run: 0,
+            quick_sort_cutoff: 0,
+            progress_milestones: Vec::new(),
+            bucket_inner_sort: BucketInnerSort::Insertion,
+            compare_delay: 100,
+            change_delay: 100,
+            auto_reset_after: 0,
+            best_streaks: HashMap::new(),
+            max_steps: DEFAULT_MAX_STEPS,
+            feedback_seconds: 0,
+            hold_explanation: false,
+            practice_guesses: 0,
+            practice_correct: 0,
+            random_min: DEFAULT_RANDOM_MIN,
+            random_max: DEFAULT_RANDOM_MAX,
+            theme: ColorTheme::Classic,
+        }
+    }
 }
 
 impl Settings {
+    /// Loads the settings, a missing or unreadable file gives the defaults.
+    /// Invalid values are repaired, written back and listed in the repair log.
+    /// Settings kept in memory are returned as they are.
     pub fn load() -> Self {
-        if Path::new(SETTINGS_FILE).exists() {
-            let mut file = File::open(SETTINGS_FILE).expect("Failed to open settings file");
-            let mut contents = String::new();
-            file.read_to_string(&mut contents)
-                .expect("Failed to read settings file");
-            serde_json::from_str(&contents).unwrap_or_else(|_| Settings::default())
+        if let Some(settings) = IN_MEMORY.lock().unwrap().as_ref() {
+            return settings.clone();
+        }
+        let Ok(contents) = fs::read_to_string(settings_path()) else {
+            return Settings::default();
+        };
+        let (settings, repairs) = Settings::from_json(&contents);
+        if !repairs.is_empty() {
+            log_repairs(&repairs);
+            settings.save();
+        }
+        settings
+    }
+
+    /// Parses settings, replacing every invalid field with a usable value.
+    /// Returns the settings together with a description of each repair.
+    pub fn from_json(contents: &str) -> (Self, Vec<String>) {
+        let mut repairs = Vec::new();
+        let defaults = Settings::default();
+        let object = match serde_json::from_str::<Value>(contents) {
+            Ok(Value::Object(object)) => object,
+            _ => {
+                repairs.push("settings file is not a JSON object, using defaults".to_string());
+                return (defaults, repairs);
+            }
+        };
+
+        // Files from before the render modes only had the gradient switch
+        let legacy_render_mode = if read_field(&object, "value_gradient", false, &mut repairs) {
+            RenderMode::Gradient
+        } else {
+            defaults.render_mode
+        };
+        let mut settings = Settings {
+            speed: read_field(&object, "speed", defaults.speed, &mut repairs),
+            teaching_mode: read_field(&object, "teaching_mode", defaults.teaching_mode, &mut repairs),
+            last_visualizer: read_field(&object, "last_visualizer", defaults.last_visualizer, &mut repairs),
+            algorithm_speeds: read_field(&object, "algorithm_speeds", defaults.algorithm_speeds, &mut repairs),
+            favorites: read_field(&object, "favorites", defaults.favorites, &mut repairs),
+            onboarding_done: read_field(&object, "onboarding_done", defaults.onboarding_done, &mut repairs),
+            announce_mode: read_field(&object, "announce_mode", defaults.announce_mode, &mut repairs),
+            reduced_motion: read_field(&object, "reduced_motion", defaults.reduced_motion, &mut repairs),
+            sort_order: read_field(&object, "sort_order", defaults.sort_order, &mut repairs),
+            render_mode: read_field(&object, "render_mode", legacy_render_mode, &mut repairs),
+            frame_rate: read_field(&object, "frame_rate", defaults.frame_rate, &mut repairs),
+            radix_padding: read_field(&object, "radix_padding", defaults.radix_padding, &mut repairs),
+            value_format: read_field(&object, "value_format", defaults.value_format, &mut repairs),
+            notes_follow_elements: read_field(&object, "notes_follow_elements", defaults.notes_follow_elements, &mut repairs),
+            show_sorted_regions: read_field(&object, "show_sorted_regions", defaults.show_sorted_regions, &mut repairs),
+            speed_ramp: read_field(&object, "speed_ramp", defaults.speed_ramp, &mut repairs),
+            terminal_theme: read_field(&object, "terminal_theme", defaults.terminal_theme, &mut repairs),
+            victory_animation: read_field(&object, "victory_animation", defaults.victory_animation, &mut repairs),
+            tim_min_run: read_field(&object, "tim_min_run", defaults.tim_min_run, &mut repairs),
+            quick_sort_cutoff: read_field(&object, "quick_sort_cutoff", defaults.quick_sort_cutoff, &mut repairs),
+            feedback_seconds: read_field(&object, "feedback_seconds", defaults.feedback_seconds, &mut repairs),
+            hold_explanation: read_field(&object, "hold_explanation", defaults.hold_explanation, &mut repairs),
+            practice_guesses: read_field(&object, "practice_guesses", defaults.practice_guesses, &mut repairs),
+            practice_correct: read_field(&object, "practice_correct", defaults.practice_correct, &mut repairs),
+            random_min: read_field(&object, "random_min", defaults.random_min, &mut repairs),
+            random_max: read_field(&object, "random_max", defaults.random_max, &mut repairs),
+            theme: read_field(&object, "theme", defaults.theme, &mut repairs),
+            progress_milestones: read_field(&object, "progress_milestones", defaults.progress_milestones, &mut repairs),
+            bucket_inner_sort: read_field(&object, "bucket_inner_sort", defaults.bucket_inner_sort, &mut repairs),
+            compare_delay: read_field(&object, "compare_delay", defaults.compare_delay, &mut repairs),
+            change_delay: read_field(&object, "change_delay", defaults.change_delay, &mut repairs),
+            auto_reset_after: read_field(&object, "auto_reset_after", defaults.auto_reset_after, &mut repairs),
+            best_streaks: read_field(&object, "best_streaks", defaults.best_streaks, &mut repairs),
+            max_steps: read_field(&object, "max_steps", defaults.max_steps, &mut repairs),
+        };
+        settings.validate(&mut repairs);
+        (settings, repairs)
+    }
+
+    // Clamps speeds and drops references to algorithms that don't exist
+    fn validate(&mut self, repairs: &mut Vec<String>) {
+        let speed = self.speed.clamp(MIN_SPEED, MAX_SPEED);
+        if speed != self.speed {
+            repairs.push(format!("speed {} ms is out of range, clamped to {} ms", self.speed, speed));
+            self.speed = speed;
+        }
+
+        if !FRAME_RATES.contains(&self.frame_rate) {
+            repairs.push(format!("frame rate {} fps is not supported, reset to {} fps", self.frame_rate, DEFAULT_FRAME_RATE));
+            self.frame_rate = DEFAULT_FRAME_RATE;
+        }
+
+        if !RADIX_PADDINGS.contains(&self.radix_padding) {
+            repairs.push(format!("radix padding of {} digits is not supported, turned off", self.radix_padding));
+            self.radix_padding = 0;
+        }
+
+        if !TIM_MIN_RUNS.contains(&self.tim_min_run) {
+            repairs.push(format!("tim sort min run of {} is not supported, computed from the array size", self.tim_min_run));
+            self.tim_min_run = 0;
+        }
+        if !QUICK_SORT_CUTOFFS.contains(&self.quick_sort_cutoff) {
+            repairs.push(format!("quick sort cutoff of {} is not supported, turned off", self.quick_sort_cutoff));
+            self.quick_sort_cutoff = 0;
+        }
+        if !FEEDBACK_DELAYS.contains(&self.feedback_seconds) {
+            repairs.push(format!("feedback delay of {} s is not supported, waits for a key", self.feedback_seconds));
+            self.feedback_seconds = 0;
+        }
+
+        for (name, delay) in [("comparison", &mut self.compare_delay), ("swap/move", &mut self.change_delay)] {
+            if !DELAY_FACTORS.contains(delay) {
+                repairs.push(format!("{} delay of {}% is not supported, reset to 100%", name, delay));
+                *delay = 100;
+            }
+        }
+
+        if !AUTO_RESET_DWELLS.contains(&self.auto_reset_after) {
+            repairs.push(format!("auto reset after {} s is not supported, turned off", self.auto_reset_after));
+            self.auto_reset_after = 0;
+        }
+
+        let mut milestones: Vec<u32> = self.progress_milestones.iter().copied().filter(|milestone| (1..=99).contains(milestone)).collect();
+        milestones.sort_unstable();
+        milestones.dedup();
+        if milestones != self.progress_milestones {
+            repairs.push(format!("progress milestones {:?} sorted, keeping only 1-99%: {:?}", self.progress_milestones, milestones));
+            self.progress_milestones = milestones;
+        }
+
+        if let Some(name) = self.last_visualizer.take() {
+            if is_known_settings_key(&name) {
+                self.last_visualizer = Some(name);
+            } else {
+                repairs.push(format!("unknown last_visualizer \"{}\" removed", name));
+            }
+        }
+
+        self.algorithm_speeds.retain(|name, _| {
+            let known = is_known_settings_key(name);
+            if !known {
+                repairs.push(format!("speed for unknown algorithm \"{}\" removed", name));
+            }
+            known
+        });
+        self.best_streaks.retain(|name, _| {
+            let known = is_known_settings_key(name);
+            if !known {
+                repairs.push(format!("question streak for unknown algorithm \"{}\" removed", name));
+            }
+            known
+        });
+        for (name, speed) in self.algorithm_speeds.iter_mut() {
+            let clamped = (*speed).clamp(MIN_SPEED, MAX_SPEED);
+            if clamped != *speed {
+                repairs.push(format!("{} speed {} ms is out of range, clamped to {} ms", name, speed, clamped));
+                *speed = clamped;
+            }
+        }
+        if self.practice_correct > self.practice_guesses {
+            repairs.push(format!(
+                "practice_correct {} is more than the {} guesses, lowered to match",
+                self.practice_correct, self.practice_guesses
+            ));
+            self.practice_correct = self.practice_guesses;
+        }
+        if self.random_min >= self.random_max || self.random_max > MAX_RANDOM_VALUE {
+            repairs.push(format!(
+                "random value range {}-{} is not supported, reset to {}-{}",
+                self.random_min, self.random_max, DEFAULT_RANDOM_MIN, DEFAULT_RANDOM_MAX
+            ));
+            self.random_min = DEFAULT_RANDOM_MIN;
+            self.random_max = DEFAULT_RANDOM_MAX;
+        }
+    }
+
+    /// Returns the preferred speed for an algorithm, falling back to the global speed.
+    pub fn speed_for(&self, algorithm: &str) -> u64 {
+        self.algorithm_speeds.get(algorithm).copied().unwrap_or(self.speed)
+    }
+
+    /// Stars or unstars a menu entry.
+    pub fn toggle_favorite(&mut self, name: &str) {
+        if let Some(pos) = self.favorites.iter().position(|f| f == name) {
+            self.favorites.remove(pos);
         } else {
-            Settings::default()
+            self.favorites.push(name.to_string());
         }
     }
 
+    /// Saves the settings, creating the config directory if it doesn't exist yet.
     pub fn save(&self) {
+        if let Some(settings) = IN_MEMORY.lock().unwrap().as_mut() {
+            *settings = self.clone();
+            return;
+        }
+        let path = settings_path();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).expect("Failed to create config directory");
+        }
         let json = serde_json::to_string_pretty(self).expect("Failed to serialize settings");
-        fs::write(SETTINGS_FILE, json).expect("Failed to write settings file");
+        fs::write(path, json).expect("Failed to write settings file");
     }
 
     /// Displays an interactive settings menu using crossterm and returns the updated Settings.
@@ -52,7 +382,34 @@ impl Settings {
         // Track the currently selected menu option (0-based index)
         let mut selection = 0u32;
         // Define settings options
-        let options = ["1. Change Speed", "2. Toggle Teaching Mode", "3. Back"];
+        let options = [
+            "1. Change Speed",
+            "2. Toggle Reduced Motion (Accessibility)",
+            "3. Toggle Teaching Mode Default",
+            "4. Clear Per-Algorithm Speeds",
+            "5. Toggle Announce Transcript",
+            "6. Change Sort Order",
+            "7. Change Frame Rate",
+            "8. Change Radix Padding",
+            "9. Toggle Notes Follow Elements",
+            "10. Toggle Sorted Regions",
+            "11. Toggle Speed Ramp",
+            "12. Change Terminal Theme",
+            "13. Toggle Victory Animation",
+            "14. Change Tim Sort Min Run",
+            "15. Change Progress Milestones",
+            "16. Change Bucket Sort Inner Sort",
+            "17. Change Comparison Delay",
+            "18. Change Swap/Move Delay",
+            "19. Change Auto Reset",
+            "20. Change Step Limit",
+            "21. Change Quick Sort Cutoff",
+            "22. Change Question Feedback",
+            "23. Toggle Hold Explanation",
+            "24. Change Color Theme",
+            "25. Reset to Defaults",
+            "26. Back",
+        ];
         // Main settings loop
         loop {
             // Get current terminal dimensions
@@ -74,10 +431,122 @@ impl Settings {
             // --- Draw Current Settings ---
             let settings_info_y = title_y + 2;
             let speed_text = format!("Current Speed: {} ms", settings.speed);
+            let reduced_motion_text = if settings.reduced_motion {
+                format!(
+                    "Reduced Motion: ON (at least {} ms per step, highlights only when stepping)",
+                    REDUCED_MOTION_MIN_SPEED
+                )
+            } else {
+                "Reduced Motion: OFF".to_string()
+            };
+            let overrides_text = if settings.algorithm_speeds.is_empty() {
+                "Per-Algorithm Speeds: None (set with +/- inside a visualizer)".to_string()
+            } else {
+                let mut overrides: Vec<String> = settings
+                    .algorithm_speeds
+                    .iter()
+                    .map(|(name, speed)| format!("{} {} ms", name, speed))
+                    .collect();
+                overrides.sort();
+                format!("Per-Algorithm Speeds: {}", overrides.join(", "))
+            };
             let teaching_text = format!(
-                "Teaching Mode: {}",
+                "Teaching Mode (default for every visualizer): {}",
                 if settings.teaching_mode { "ON" } else { "OFF" }
             );
+            let announce_text = if settings.announce_mode {
+                format!("Announce Transcript: ON (writing to {})", transcript_path().display())
+            } else {
+                "Announce Transcript: OFF".to_string()
+            };
+            let sort_order_text = format!(
+                "Sort Order: {} (comparison sorts only, counting/radix/bucket stay ascending)",
+                settings.sort_order.label()
+            );
+            let frame_rate_text = format!(
+                "Frame Rate: {} fps (how often the screen is drawn, speed sets the step pace)",
+                settings.frame_rate
+            );
+            let radix_padding_text = if settings.radix_padding == 0 {
+                "Radix Padding: OFF (radix sort shows only the passes the values need)".to_string()
+            } else {
+                format!(
+                    "Radix Padding: {} digits (extra passes on higher places are for illustration only)",
+                    settings.radix_padding
+                )
+            };
+            let notes_text = if settings.notes_follow_elements {
+                "Lecture Notes (N in a visualizer): follow their element as it moves".to_string()
+            } else {
+                "Lecture Notes (N in a visualizer): stay at their index".to_string()
+            };
+            let sorted_regions_text = if settings.show_sorted_regions {
+                "Sorted Regions: ON (a bracket under the chart marks elements in their final place)".to_string()
+            } else {
+                "Sorted Regions: OFF".to_string()
+            };
+            let speed_ramp_text = if settings.speed_ramp {
+                "Speed Ramp: ON (auto-play speeds up during a long phase, slows down when it changes)".to_string()
+            } else {
+                "Speed Ramp: OFF".to_string()
+            };
+            let theme_text = match settings.terminal_theme {
+                TerminalTheme::Auto if theme::is_light(TerminalTheme::Auto, std::env::var("COLORFGBG").ok().as_deref()) => {
+                    "Terminal Theme: Auto (light background detected, sets the text colors, not the bars)".to_string()
+                }
+                TerminalTheme::Auto => "Terminal Theme: Auto (dark unless the terminal reports a light background, sets the text colors, not the bars)".to_string(),
+                terminal_theme => format!("Terminal Theme: {} background (sets the text colors, not the bars)", terminal_theme.label()),
+            };
+            let victory_text = if settings.victory_animation {
+                "Victory Animation: ON (a highlight sweeps the bars once a run completes, any key skips it)".to_string()
+            } else {
+                "Victory Animation: OFF".to_string()
+            };
+            let tim_min_run_text = if settings.tim_min_run == 0 {
+                "Tim Sort Min Run: Auto (computed from the array size like CPython, 32-64 from 64 elements)".to_string()
+            } else {
+                format!("Tim Sort Min Run: {} (shorter natural runs are extended with insertion sort)", settings.tim_min_run)
+            };
+            let milestones_text = if settings.progress_milestones.is_empty() {
+                "Progress Milestones: OFF".to_string()
+            } else {
+                let milestones: Vec<String> = settings.progress_milestones.iter().map(|milestone| format!("{}%", milestone)).collect();
+                format!("Progress Milestones: {} (auto-play pauses once at each, SPACE continues)", milestones.join(", "))
+            };
+            let step_delay_text = format!(
+                "Step Delays: {}% of the speed after a comparison, {}% after a swap or move",
+                settings.compare_delay, settings.change_delay
+            );
+            let auto_reset_text = if settings.auto_reset_after == 0 {
+                "Auto Reset: OFF (a completed run waits for a key)".to_string()
+            } else {
+                format!("Auto Reset: after {} s (a completed run replays by itself, for unattended displays)", settings.auto_reset_after)
+            };
+            let step_limit_text = if settings.max_steps == 0 {
+                "Step Limit: OFF (a run that never terminates keeps going)".to_string()
+            } else {
+                format!("Step Limit: {} steps (auto-play pauses a run that has not completed by then)", settings.max_steps)
+            };
+            let quick_sort_cutoff_text = if settings.quick_sort_cutoff == 0 {
+                "Quick Sort Cutoff: OFF (every range is partitioned)".to_string()
+            } else {
+                format!("Quick Sort Cutoff: {} (smaller ranges are insertion sorted, [ / ] tune it during a run)", settings.quick_sort_cutoff)
+            };
+            let feedback_text = if settings.feedback_seconds == 0 {
+                "Question Feedback: until a key is pressed".to_string()
+            } else {
+                format!("Question Feedback: {} s, then the run continues by itself (hands-free presentations)", settings.feedback_seconds)
+            };
+            let hold_text = if settings.hold_explanation {
+                "Hold Explanation: ON (the run stays paused with the explanation under the chart, SPACE resumes)".to_string()
+            } else {
+                "Hold Explanation: OFF".to_string()
+            };
+            let color_theme_text = match settings.theme {
+                ColorTheme::HighContrast => "Color Theme: High Contrast (bold bright bars and legend, no dark colors)".to_string(),
+                color_theme => format!("Color Theme: {} (colors of the bars and legend in each state, not the text)", color_theme.label()),
+            };
+            let bucket_inner_text = format!("Bucket Sort Inner Sort: {} (runs on each bucket after distribution)", settings.bucket_inner_sort.label());
             let last_viz_text = format!(
                 "Last Visualizer: {:?}",
                 settings.last_visualizer.as_ref().unwrap_or(&"None".to_string())
@@ -86,11 +555,74 @@ impl Settings {
             execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
             execute!(stdout, Print(&speed_text)).unwrap();
             execute!(stdout, MoveTo(5, settings_info_y + 1)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Yellow)).unwrap();
+            execute!(stdout, Print(&reduced_motion_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 2)).unwrap();
             execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
             execute!(stdout, Print(&teaching_text)).unwrap();
-            execute!(stdout, MoveTo(5, settings_info_y + 2)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 3)).unwrap();
             execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
             execute!(stdout, Print(&last_viz_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 4)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&overrides_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 5)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&announce_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 6)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&sort_order_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 7)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&frame_rate_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 8)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&radix_padding_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 9)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&notes_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 10)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&sorted_regions_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 11)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&speed_ramp_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 12)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&theme_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 13)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&victory_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 14)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&tim_min_run_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 15)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&milestones_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 16)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&bucket_inner_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 17)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&step_delay_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 18)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&auto_reset_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 19)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&step_limit_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 20)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&quick_sort_cutoff_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 21)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&feedback_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 22)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&hold_text)).unwrap();
+            execute!(stdout, MoveTo(5, settings_info_y + 23)).unwrap();
+            execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
+            execute!(stdout, Print(&color_theme_text)).unwrap();
             // --- Draw Subtitle ---
             let subtitle = "Options";
             let subtitle_x = if width > subtitle.len() as u16 {
@@ -98,7 +630,7 @@ impl Settings {
             } else {
                 0
             };
-            let subtitle_y = settings_info_y + 4;
+            let subtitle_y = settings_info_y + 25;
             execute!(stdout, MoveTo(subtitle_x, subtitle_y)).unwrap();
             execute!(stdout, SetForegroundColor(Color::Cyan)).unwrap();
             execute!(stdout, SetBackgroundColor(Color::Reset)).unwrap();
@@ -112,7 +644,7 @@ impl Settings {
             };
             let menu_select_y = subtitle_y + 2;
             execute!(stdout, MoveTo(menu_select_x, menu_select_y)).unwrap();
-            execute!(stdout, SetForegroundColor(Color::White)).unwrap();
+            execute!(stdout, SetForegroundColor(theme::text())).unwrap();
             execute!(stdout, SetBackgroundColor(Color::Reset)).unwrap();
             execute!(stdout, Print(menu_select_text)).unwrap();
             // --- Draw Menu Options ---
@@ -127,10 +659,10 @@ impl Settings {
                 execute!(stdout, MoveTo(option_x, option_y)).unwrap();
                 // Highlight the currently selected option
                 if i == selection as usize {
-                    execute!(stdout, SetForegroundColor(Color::Black)).unwrap();
-                    execute!(stdout, SetBackgroundColor(Color::White)).unwrap();
+                    execute!(stdout, SetForegroundColor(theme::highlight().0)).unwrap();
+                    execute!(stdout, SetBackgroundColor(theme::highlight().1)).unwrap();
                 } else {
-                    execute!(stdout, SetForegroundColor(Color::White)).unwrap();
+                    execute!(stdout, SetForegroundColor(theme::text())).unwrap();
                     execute!(stdout, SetBackgroundColor(Color::Reset)).unwrap();
                 }
                 // Print the option with some padding
@@ -168,11 +700,133 @@ impl Settings {
                                         }
                                     }
                                     1 => {
-                                        // Toggle Teaching Mode
-                                        settings.teaching_mode = !settings.teaching_mode;
+                                        // Toggle Reduced Motion, honored by every visualizer
+                                        settings.reduced_motion = !settings.reduced_motion;
                                         settings.save(); // Save immediately
                                     }
                                     2 => {
+                                        // Toggle Teaching Mode, the default every visualizer starts with
+                                        settings.teaching_mode = !settings.teaching_mode;
+                                        settings.save(); // Save immediately
+                                    }
+                                    3 => {
+                                        // Clear Per-Algorithm Speeds, every visualizer uses the global speed again
+                                        settings.algorithm_speeds.clear();
+                                        settings.save(); // Save immediately
+                                    }
+                                    4 => {
+                                        // Toggle Announce Transcript, one text line per operation
+                                        settings.announce_mode = !settings.announce_mode;
+                                        settings.save(); // Save immediately
+                                    }
+                                    5 => {
+                                        // Change Sort Order, cycles through the comparator presets
+                                        settings.sort_order = settings.sort_order.next();
+                                        settings.save(); // Save immediately
+                                    }
+                                    6 => {
+                                        // Change Frame Rate, cycles through the supported rates
+                                        settings.frame_rate = next_frame_rate(settings.frame_rate);
+                                        settings.save(); // Save immediately
+                                    }
+                                    7 => {
+                                        // Change Radix Padding, cycles through the padded digit counts
+                                        settings.radix_padding = next_radix_padding(settings.radix_padding);
+                                        settings.save(); // Save immediately
+                                    }
+                                    8 => {
+                                        // Toggle Notes Follow Elements, applies to the next visualizer
+                                        settings.notes_follow_elements = !settings.notes_follow_elements;
+                                        settings.save(); // Save immediately
+                                    }
+                                    9 => {
+                                        // Toggle Sorted Regions, applies to the next visualizer
+                                        settings.show_sorted_regions = !settings.show_sorted_regions;
+                                        settings.save(); // Save immediately
+                                    }
+                                    10 => {
+                                        // Toggle Speed Ramp, applies to the next visualizer
+                                        settings.speed_ramp = !settings.speed_ramp;
+                                        settings.save(); // Save immediately
+                                    }
+                                    11 => {
+                                        // Change Terminal Theme, every screen is redrawn with it right away
+                                        settings.terminal_theme = settings.terminal_theme.next();
+                                        theme::apply(settings.terminal_theme);
+                                        settings.save(); // Save immediately
+                                    }
+                                    12 => {
+                                        // Toggle Victory Animation, applies to the next visualizer
+                                        settings.victory_animation = !settings.victory_animation;
+                                        settings.save(); // Save immediately
+                                    }
+                                    13 => {
+                                        // Change Tim Sort Min Run, cycles through the run lengths
+                                        settings.tim_min_run = next_tim_min_run(settings.tim_min_run);
+                                        settings.save(); // Save immediately
+                                    }
+                                    14 => {
+                                        // Change Progress Milestones, cycles through the milestone sets
+                                        settings.progress_milestones = next_milestones(&settings.progress_milestones);
+                                        settings.save(); // Save immediately
+                                    }
+                                    15 => {
+                                        // Change Bucket Sort Inner Sort, applies to the next bucket sort run
+                                        settings.bucket_inner_sort = settings.bucket_inner_sort.next();
+                                        settings.save(); // Save immediately
+                                    }
+                                    16 => {
+                                        // Change Comparison Delay, cycles through the delay factors
+                                        settings.compare_delay = next_delay_factor(settings.compare_delay);
+                                        settings.save(); // Save immediately
+                                    }
+                                    17 => {
+                                        // Change Swap/Move Delay, cycles through the delay factors
+                                        settings.change_delay = next_delay_factor(settings.change_delay);
+                                        settings.save(); // Save immediately
+                                    }
+                                    18 => {
+                                        // Change Auto Reset, cycles through the dwell times
+                                        settings.auto_reset_after = next_auto_reset(settings.auto_reset_after);
+                                        settings.save(); // Save immediately
+                                    }
+                                    19 => {
+                                        // Change Step Limit, cycles through the limits
+                                        settings.max_steps = next_step_limit(settings.max_steps);
+                                        settings.save(); // Save immediately
+                                    }
+                                    20 => {
+                                        // Change Quick Sort Cutoff, cycles through the cutoffs
+                                        settings.quick_sort_cutoff = next_quick_sort_cutoff(settings.quick_sort_cutoff);
+                                        settings.save(); // Save immediately
+                                    }
+                                    21 => {
+                                        // Change Question Feedback, cycles through the delays
+                                        settings.feedback_seconds = next_feedback_delay(settings.feedback_seconds);
+                                        settings.save(); // Save immediately
+                                    }
+                                    22 => {
+                                        // Toggle Hold Explanation
+                                        settings.hold_explanation = !settings.hold_explanation;
+                                        settings.save(); // Save immediately
+                                    }
+                                    23 => {
+                                        // Change Color Theme, applies to the next visualizer
+                                        settings.theme = settings.theme.next();
+                                        theme::apply_colors(settings.theme);
+                                        settings.save(); // Save immediately
+                                    }
+                                    24 if confirm_reset_settings() => {
+                                        // Reset to Defaults, the first-run tour is not shown again
+                                        settings = Settings {
+                                            onboarding_done: settings.onboarding_done,
+                                            ..Settings::default()
+                                        };
+                                        theme::apply(settings.terminal_theme);
+                                        theme::apply_colors(settings.theme);
+                                        settings.save(); // Save immediately
+                                    }
+                                    25 => {
                                         // Back
                                         execute!(stdout, ResetColor).unwrap();
                                         execute!(stdout, Show, LeaveAlternateScreen).unwrap();
@@ -199,6 +853,94 @@ impl Settings {
     }
 }
 
+// Reads one field, keeping the default if it is missing and reporting it if it is invalid
+fn read_field<T: DeserializeOwned>(object: &Map<String, Value>, name: &str, default: T, repairs: &mut Vec<String>) -> T {
+    match object.get(name) {
+        None => default,
+        Some(value) => T::deserialize(value).unwrap_or_else(|_| {
+            repairs.push(format!("invalid {} {}, reset to its default", name, value));
+            default
+        }),
+    }
+}
+
+// Appends what was repaired to the repair log next to the settings file
+fn log_repairs(repairs: &[String]) {
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(config_file(REPAIR_LOG_FILE)) {
+        for repair in repairs {
+            // Logging is best effort, a repaired settings file is still usable
+            let _ = writeln!(file, "Repaired settings: {}", repair);
+        }
+    }
+}
+
+// The supported frame rate after `frame_rate`, wrapping around
+fn next_frame_rate(frame_rate: u32) -> u32 {
+    let index = FRAME_RATES.iter().position(|&rate| rate == frame_rate).unwrap_or(0);
+    FRAME_RATES[(index + 1) % FRAME_RATES.len()]
+}
+
+// The radix padding after `radix_padding`, wrapping around to off
+fn next_radix_padding(radix_padding: u32) -> u32 {
+    let index = RADIX_PADDINGS.iter().position(|&digits| digits == radix_padding).unwrap_or(0);
+    RADIX_PADDINGS[(index + 1) % RADIX_PADDINGS.len()]
+}
+
+// The tim sort min run after `tim_min_run`, wrapping around to automatic
+fn next_tim_min_run(tim_min_run: u32) -> u32 {
+    let index = TIM_MIN_RUNS.iter().position(|&length| length == tim_min_run).unwrap_or(0);
+    TIM_MIN_RUNS[(index + 1) % TIM_MIN_RUNS.len()]
+}
+
+// The quick sort cutoff after `cutoff`, wrapping around to off
+fn next_quick_sort_cutoff(cutoff: u32) -> u32 {
+    let index = QUICK_SORT_CUTOFFS.iter().position(|&length| length == cutoff).unwrap_or(0);
+    QUICK_SORT_CUTOFFS[(index + 1) % QUICK_SORT_CUTOFFS.len()]
+}
+
+// The question feedback delay after `seconds`, wrapping around to waiting for a key
+fn next_feedback_delay(seconds: u32) -> u32 {
+    let index = FEEDBACK_DELAYS.iter().position(|&delay| delay == seconds).unwrap_or(0);
+    FEEDBACK_DELAYS[(index + 1) % FEEDBACK_DELAYS.len()]
+}
+
+// The step delay factor after `delay`, wrapping around
+fn next_delay_factor(delay: u32) -> u32 {
+    let index = DELAY_FACTORS.iter().position(|&factor| factor == delay).unwrap_or(0);
+    DELAY_FACTORS[(index + 1) % DELAY_FACTORS.len()]
+}
+
+// The auto reset dwell after `seconds`, wrapping around to off
+fn next_auto_reset(seconds: u32) -> u32 {
+    let index = AUTO_RESET_DWELLS.iter().position(|&dwell| dwell == seconds).unwrap_or(0);
+    AUTO_RESET_DWELLS[(index + 1) % AUTO_RESET_DWELLS.len()]
+}
+
+// The step limit after `max_steps`, a custom limit from the file is followed by off
+fn next_step_limit(max_steps: u64) -> u64 {
+    let index = STEP_LIMITS.iter().position(|&limit| limit == max_steps);
+    STEP_LIMITS[index.map_or(0, |index| (index + 1) % STEP_LIMITS.len())]
+}
+
+// The milestone set after `milestones`, a custom set from the file is followed by off
+fn next_milestones(milestones: &[u32]) -> Vec<u32> {
+    let index = MILESTONE_PRESETS.iter().position(|&preset| preset == milestones);
+    let next = index.map_or(0, |index| (index + 1) % MILESTONE_PRESETS.len());
+    MILESTONE_PRESETS[next].to_vec()
+}
+
+/// Asks before every setting is put back to its default value
+fn confirm_reset_settings() -> bool {
+    let choice = show_question(
+        "Reset to Defaults",
+        "Restore every setting to its default value?\n\n\
+         Speed, teaching mode, per-algorithm speeds,\n\
+         favorites and accessibility options are reset.",
+        vec!["Reset", "Cancel"],
+    );
+    choice == 0
+}
+
 /// Interactive sub-menu to change speed using crossterm
 fn change_speed_menu() -> Option<u64> {
     let mut stdout = stdout();
@@ -237,7 +979,7 @@ fn change_speed_menu() -> Option<u64> {
         execute!(
             stdout,
             MoveTo(prompt_x, height / 2),
-            SetForegroundColor(Color::White),
+            SetForegroundColor(theme::text()),
             Print(&full_prompt),
             ResetColor
         )
@@ -306,4 +1048,147 @@ fn change_speed_menu() -> Option<u64> {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_json_gives_defaults() {
+        let (settings, repairs) = Settings::from_json("{ speed: fast");
+        assert_eq!(settings.speed, DEFAULT_SPEED);
+        assert_eq!(repairs.len(), 1);
+    }
+
+    #[test]
+    fn zero_speed_is_clamped() {
+        let (settings, repairs) = Settings::from_json(r#"{ "speed": 0, "algorithm_speeds": { "QuickSort": 99999 } }"#);
+        assert_eq!(settings.speed, MIN_SPEED);
+        assert_eq!(settings.speed_for("QuickSort"), MAX_SPEED);
+        assert_eq!(repairs.len(), 2);
+    }
+
+    #[test]
+    fn progress_milestones_are_sorted_into_range() {
+        let (settings, repairs) = Settings::from_json(r#"{ "progress_milestones": [75, 0, 25, 100, 25] }"#);
+        assert_eq!(settings.progress_milestones, vec![25, 75]);
+        assert_eq!(repairs.len(), 1);
+        assert_eq!(next_milestones(&[]), vec![50]);
+        assert_eq!(next_milestones(&[25, 75]), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn unsupported_step_delays_are_reset() {
+        let (settings, repairs) = Settings::from_json(r#"{ "compare_delay": 25, "change_delay": 0 }"#);
+        assert_eq!((settings.compare_delay, settings.change_delay), (25, 100));
+        assert_eq!(repairs.len(), 1);
+        assert_eq!(next_delay_factor(400), 25);
+    }
+
+    #[test]
+    fn gradient_switch_becomes_the_render_mode() {
+        let (settings, repairs) = Settings::from_json(r#"{ "value_gradient": true }"#);
+        assert_eq!(settings.render_mode, RenderMode::Gradient);
+        assert!(repairs.is_empty());
+        let (settings, _) = Settings::from_json(r#"{ "value_gradient": true, "render_mode": "Original" }"#);
+        assert_eq!(settings.render_mode, RenderMode::Original);
+        assert_eq!(RenderMode::Original.next(), RenderMode::HighlightOnly);
+        assert_eq!(RenderMode::HighlightOnly.next(), RenderMode::Bars);
+    }
+
+    #[test]
+    fn unsupported_feedback_delay_waits_for_a_key() {
+        let (settings, repairs) = Settings::from_json(r#"{ "feedback_seconds": 4, "hold_explanation": true }"#);
+        assert_eq!(settings.feedback_seconds, 0);
+        assert!(settings.hold_explanation);
+        assert_eq!(repairs.len(), 1);
+        assert_eq!(next_feedback_delay(0), 3);
+        assert_eq!(next_feedback_delay(10), 0);
+    }
+
+    #[test]
+    fn unsupported_auto_reset_is_turned_off() {
+        let (settings, repairs) = Settings::from_json(r#"{ "auto_reset_after": 7 }"#);
+        assert_eq!(settings.auto_reset_after, 0);
+        assert_eq!(repairs.len(), 1);
+        assert_eq!(next_auto_reset(0), 5);
+        assert_eq!(next_auto_reset(60), 0);
+    }
+
+    #[test]
+    fn step_limit_cycles_from_a_custom_value() {
+        let (settings, repairs) = Settings::from_json(r#"{ "max_steps": 5000 }"#);
+        assert_eq!(settings.max_steps, 5000);
+        assert!(repairs.is_empty());
+        assert_eq!(next_step_limit(5000), 0);
+        assert_eq!(next_step_limit(DEFAULT_MAX_STEPS), 10_000_000);
+        assert_eq!(Settings::default().max_steps, DEFAULT_MAX_STEPS);
+    }
+
+    #[test]
+    fn unsupported_quick_sort_cutoff_is_turned_off() {
+        let (settings, repairs) = Settings::from_json(r#"{ "quick_sort_cutoff": 5 }"#);
+        assert_eq!(settings.quick_sort_cutoff, 0);
+        assert_eq!(repairs.len(), 1);
+        assert_eq!(next_quick_sort_cutoff(16), 0);
+    }
+
+    #[test]
+    fn unsupported_frame_rate_is_reset() {
+        let (settings, repairs) = Settings::from_json(r#"{ "frame_rate": 1000 }"#);
+        assert_eq!(settings.frame_rate, DEFAULT_FRAME_RATE);
+        assert_eq!(repairs.len(), 1);
+        assert_eq!(next_frame_rate(60), 15);
+    }
+
+    #[test]
+    fn unknown_algorithm_names_are_dropped() {
+        let (settings, repairs) = Settings::from_json(
+            r#"{ "speed": 400, "last_visualizer": "SleepSort", "algorithm_speeds": { "SleepSort": 300, "HeapSort": 200 } }"#,
+        );
+        assert_eq!(settings.last_visualizer, None);
+        assert_eq!(settings.algorithm_speeds.len(), 1);
+        assert_eq!(settings.speed_for("HeapSort"), 200);
+        assert_eq!(repairs.len(), 2);
+    }
+
+    #[test]
+    fn wrongly_typed_fields_fall_back_individually() {
+        let (settings, repairs) = Settings::from_json(
+            r#"{ "speed": "slow", "teaching_mode": true, "favorites": 3, "last_visualizer": "MergeSort" }"#,
+        );
+        assert_eq!(settings.speed, DEFAULT_SPEED);
+        assert!(settings.teaching_mode);
+        assert!(settings.favorites.is_empty());
+        assert_eq!(settings.last_visualizer.as_deref(), Some("MergeSort"));
+        assert_eq!(repairs.len(), 2);
+    }
+
+    #[test]
+    fn valid_file_needs_no_repairs() {
+        let (settings, repairs) = Settings::from_json(r#"{ "speed": 600, "teaching_mode": false, "last_visualizer": "CombSort" }"#);
+        assert_eq!(settings.speed, 600);
+        assert!(repairs.is_empty());
+    }
+
+    #[test]
+    fn invalid_random_range_is_reset() {
+        let (settings, repairs) = Settings::from_json(r#"{ "random_min": 10, "random_max": 5000 }"#);
+        assert_eq!((settings.random_min, settings.random_max), (10, 5000));
+        assert!(repairs.is_empty());
+        for json in [r#"{ "random_min": 50, "random_max": 50 }"#, r#"{ "random_max": 10000 }"#] {
+            let (settings, repairs) = Settings::from_json(json);
+            assert_eq!((settings.random_min, settings.random_max), (DEFAULT_RANDOM_MIN, DEFAULT_RANDOM_MAX));
+            assert_eq!(repairs.len(), 1);
+        }
+    }
+
+    #[test]
+    fn tests_keep_settings_in_memory() {
+        let mut settings = Settings::load();
+        settings.last_visualizer = Some("InMemoryTest".to_string());
+        settings.save();
+        assert_eq!(Settings::load().last_visualizer.as_deref(), Some("InMemoryTest"));
+    }
+}
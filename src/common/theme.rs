@@ -0,0 +1,159 @@
+use crate::common::enums::{ColorTheme, SelectionState, TerminalTheme};
+use crossterm::style::Color;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+// Set from the settings at startup and whenever the theme is changed, read by every screen
+static LIGHT_BACKGROUND: AtomicBool = AtomicBool::new(false);
+
+// Index into ColorTheme::ALL of the color theme bars are drawn in, set like LIGHT_BACKGROUND
+static COLOR_THEME: AtomicUsize = AtomicUsize::new(0);
+
+/// Makes every screen use the neutral colors of `theme`, Auto is resolved here.
+pub fn apply(theme: TerminalTheme) {
+    LIGHT_BACKGROUND.store(is_light(theme, std::env::var("COLORFGBG").ok().as_deref()), Ordering::Relaxed);
+}
+
+/// Makes every visualizer started from now on draw its bars in `theme`.
+pub fn apply_colors(theme: ColorTheme) {
+    let index = ColorTheme::ALL.iter().position(|&color_theme| color_theme == theme).unwrap_or(0);
+    COLOR_THEME.store(index, Ordering::Relaxed);
+}
+
+/// The color theme last given to `apply_colors`.
+pub fn color_theme() -> ColorTheme {
+    ColorTheme::ALL[COLOR_THEME.load(Ordering::Relaxed) % ColorTheme::ALL.len()]
+}
+
+/// Foreground and background of a bar in `state` under `theme`.
+/// High contrast only uses bright colors on the terminal's own background, Solarized its accent colors.
+pub fn state_colors(theme: ColorTheme, state: SelectionState) -> (Color, Color) {
+    match theme {
+        ColorTheme::Classic => match state {
+            SelectionState::Normal => (Color::Cyan, Color::Reset),
+            SelectionState::Sorted => (Color::Green, Color::DarkGreen),
+            SelectionState::CurrentMin => (Color::Yellow, Color::DarkYellow),
+            SelectionState::Comparing => (Color::Magenta, Color::DarkMagenta),
+            SelectionState::Selected => (Color::White, Color::DarkBlue),
+            SelectionState::Swapping => (Color::Red, Color::DarkRed),
+            SelectionState::PartitionLeft | SelectionState::PartitionRight => (Color::Blue, Color::DarkBlue),
+            SelectionState::Removed | SelectionState::OutsideRange => (Color::DarkGrey, Color::Reset),
+            SelectionState::WouldChange => (Color::DarkYellow, Color::Reset),
+        },
+        ColorTheme::HighContrast => {
+            let foreground = match state {
+                SelectionState::Normal => Color::Cyan,
+                SelectionState::Sorted => Color::Green,
+                SelectionState::CurrentMin | SelectionState::WouldChange => Color::Yellow,
+                SelectionState::Comparing => Color::Magenta,
+                SelectionState::Selected => Color::White,
+                SelectionState::Swapping => Color::Red,
+                SelectionState::PartitionLeft | SelectionState::PartitionRight => Color::Blue,
+                SelectionState::Removed | SelectionState::OutsideRange => Color::Grey,
+            };
+            (foreground, Color::Reset)
+        }
+        ColorTheme::Solarized => {
+            let foreground = match state {
+                SelectionState::Normal => Color::Rgb { r: 0x2a, g: 0xa1, b: 0x98 },
+                SelectionState::Sorted => Color::Rgb { r: 0x85, g: 0x99, b: 0x00 },
+                SelectionState::CurrentMin => Color::Rgb { r: 0xb5, g: 0x89, b: 0x00 },
+                SelectionState::Comparing => Color::Rgb { r: 0xd3, g: 0x36, b: 0x82 },
+                SelectionState::Selected => Color::Rgb { r: 0x6c, g: 0x71, b: 0xc4 },
+                SelectionState::Swapping => Color::Rgb { r: 0xdc, g: 0x32, b: 0x2f },
+                SelectionState::PartitionLeft | SelectionState::PartitionRight => Color::Rgb { r: 0x26, g: 0x8b, b: 0xd2 },
+                SelectionState::Removed | SelectionState::OutsideRange => Color::Rgb { r: 0x58, g: 0x6e, b: 0x75 },
+                SelectionState::WouldChange => Color::Rgb { r: 0xcb, g: 0x4b, b: 0x16 },
+            };
+            (foreground, Color::Reset)
+        }
+    }
+}
+
+/// Returns true if `theme` means a light background.
+/// Auto reads `COLORFGBG` ("foreground;background" palette indices, set by rxvt, Konsole and others),
+/// a background of 7 (white) or 9-15 (bright colors except bright black) is light.
+pub fn is_light(theme: TerminalTheme, colorfgbg: Option<&str>) -> bool {
+    match theme {
+        TerminalTheme::Dark => false,
+        TerminalTheme::Light => true,
+        TerminalTheme::Auto => colorfgbg
+            .and_then(|value| value.rsplit(';').next())
+            .and_then(|background| background.trim().parse::<u8>().ok())
+            .is_some_and(|background| background == 7 || (9..=15).contains(&background)),
+    }
+}
+
+/// Regular text, e.g. menu entries and labels.
+pub fn text() -> Color {
+    if LIGHT_BACKGROUND.load(Ordering::Relaxed) { Color::Black } else { Color::White }
+}
+
+/// Secondary text such as key hints, the terminal's own foreground on a light background
+/// since grey has too little contrast there.
+pub fn dim() -> Color {
+    if LIGHT_BACKGROUND.load(Ordering::Relaxed) { Color::Reset } else { Color::DarkGrey }
+}
+
+/// Foreground and background of the selected entry in a list.
+pub fn highlight() -> (Color, Color) {
+    if LIGHT_BACKGROUND.load(Ordering::Relaxed) { (Color::White, Color::Black) } else { (Color::Black, Color::White) }
+}
+
+/// The neutral colors of the current theme in one value, for drawers that keep them for a whole run.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Palette {
+    pub text: Color,
+    pub dim: Color,
+    pub highlight: (Color, Color),
+}
+
+/// Returns the colors `text`, `dim` and `highlight` give right now.
+pub fn palette() -> Palette {
+    Palette { text: text(), dim: dim(), highlight: highlight() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_theme_reads_the_reported_background() {
+        assert!(is_light(TerminalTheme::Auto, Some("0;15")));
+        assert!(is_light(TerminalTheme::Auto, Some("0;default;7")));
+        assert!(!is_light(TerminalTheme::Auto, Some("15;0")));
+        assert!(!is_light(TerminalTheme::Auto, Some("15;8")));
+        assert!(!is_light(TerminalTheme::Auto, None));
+        assert!(is_light(TerminalTheme::Light, Some("15;0")));
+        assert!(!is_light(TerminalTheme::Dark, Some("0;15")));
+    }
+
+    #[test]
+    fn high_contrast_uses_no_dark_colors() {
+        let dark = [
+            Color::DarkGrey,
+            Color::DarkRed,
+            Color::DarkGreen,
+            Color::DarkYellow,
+            Color::DarkBlue,
+            Color::DarkMagenta,
+            Color::DarkCyan,
+            Color::Black,
+        ];
+        for state in [
+            SelectionState::Normal,
+            SelectionState::Sorted,
+            SelectionState::CurrentMin,
+            SelectionState::Comparing,
+            SelectionState::Selected,
+            SelectionState::Swapping,
+            SelectionState::PartitionLeft,
+            SelectionState::Removed,
+            SelectionState::WouldChange,
+        ] {
+            let (foreground, background) = state_colors(ColorTheme::HighContrast, state);
+            assert!(!dark.contains(&foreground), "a bar is drawn in {:?}", foreground);
+            assert_eq!(background, Color::Reset);
+        }
+        assert_eq!(state_colors(ColorTheme::Classic, SelectionState::Sorted), (Color::Green, Color::DarkGreen));
+    }
+}
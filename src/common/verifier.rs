@@ -0,0 +1,87 @@
+use crate::common::base_visualizer::Comparator;
+use crate::common::element::SortElement;
+use std::cmp::Ordering;
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+
+const VERIFY_FLAG: &str = "--verify";
+
+// Set once from the command line, checked by every run
+static VERIFY_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Turns on verification of every visualizer run for the rest of the process.
+pub fn enable() {
+    VERIFY_ENABLED.store(true, AtomicOrdering::Relaxed);
+}
+
+/// Returns true if runs are checked against the sorted result.
+pub fn is_enabled() -> bool {
+    VERIFY_ENABLED.load(AtomicOrdering::Relaxed)
+}
+
+/// Reads `--verify` from the command line arguments.
+pub fn verify_from_args<I: IntoIterator<Item = String>>(args: I) -> bool {
+    args.into_iter().any(|arg| arg == VERIFY_FLAG)
+}
+
+/// An element inside a sorted region that is not where the sorted result puts it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Divergence<T = u32> {
+    pub index: usize,
+    pub value: T,
+    pub expected: T,
+}
+
+impl<T: SortElement> Divergence<T> {
+    /// One line for the notices, e.g. "Verify: sorted index 3 holds 7, sorted result has 5"
+    pub fn describe(&self) -> String {
+        format!(
+            "Verify: sorted index {} holds {}, sorted result has {}",
+            self.index, self.value, self.expected
+        )
+    }
+}
+
+/// Finds the first element inside the given sorted regions that is not in its final position.
+///
+/// The final positions come from a stable sort of the current values, so edits made
+/// during the run are taken into account. Values the comparator finds equal may
+/// end up in either order and are not reported.
+pub fn verify_partial<T: SortElement>(array: &[T], regions: &[Range<usize>], comparator: &Comparator<T>) -> Option<Divergence<T>> {
+    let mut expected = array.to_vec();
+    expected.sort_by(|&a, &b| comparator(a, b));
+
+    regions
+        .iter()
+        .flat_map(|region| region.start..region.end.min(array.len()))
+        .find(|&index| comparator(array[index], expected[index]) != Ordering::Equal)
+        .map(|index| Divergence { index, value: array[index], expected: expected[index] })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::base_visualizer::sorted_runs;
+    use crate::common::enums::{SelectionState, SortOrder};
+
+    #[test]
+    fn sorted_element_out_of_place_is_flagged() {
+        let comparator = SortOrder::Ascending.comparator();
+        let regions = sorted_runs(&[SelectionState::Normal, SelectionState::Normal, SelectionState::Sorted]);
+
+        assert_eq!(verify_partial(&[3, 1, 9], &regions, &comparator), None);
+        assert_eq!(
+            verify_partial(&[9, 1, 3], &regions, &comparator),
+            Some(Divergence { index: 2, value: 3, expected: 9 })
+        );
+    }
+
+    #[test]
+    fn equal_keys_may_swap_places() {
+        let comparator = SortOrder::LastDigit.comparator();
+        let regions = sorted_runs(&[SelectionState::Sorted; 3]);
+
+        assert_eq!(verify_partial(&[21, 11, 5], &regions, &comparator), None);
+        assert!(verify_from_args(["app".to_string(), "--verify".to_string()]));
+    }
+}
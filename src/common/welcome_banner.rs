@@ -6,6 +6,7 @@ use crossterm::{
 };
 use std::io::{stdout, Write};
 use std::time::Duration;
+use crate::common::theme;
 
 fn print_centered_block(block: &str, color: Color, y_offset: u16) {
     let mut stdout = stdout();
@@ -79,7 +80,7 @@ pub fn print_welcome_banner() {
     } else { 0 };
 
     stdout.execute(MoveTo(x, height - 5)).unwrap();
-    stdout.execute(SetForegroundColor(Color::White)).unwrap();
+    stdout.execute(SetForegroundColor(theme::text())).unwrap();
     stdout.execute(Print(instruction)).unwrap();
     stdout.flush().unwrap();
 
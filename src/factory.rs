@@ -0,0 +1,282 @@
+use crate::common::array_manager::{ArrayData, StoredArray};
+use crate::common::base_visualizer::SortVisualizer;
+use crate::common::dialog::show_info;
+use crate::common::element::SortElement;
+use crate::common::runner::{run_suspendable_boxed, RunSummary, SuspendedRun};
+use crate::common::settings::Settings;
+use crate::joke_algorithms::*;
+use crate::puzzles::*;
+use crate::search_algorithms::*;
+use crate::sort_algorithms::counting_sort::{CountingSortVisualizer, counting_sort_visualization};
+use crate::sort_algorithms::*;
+
+/// Runs a visualization against the last completed run, returns it if suspended
+pub type VisualizeFn<T = u32> = fn(&ArrayData<T>, &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>>;
+
+/// Builds the visualizer for an array without running it
+pub type CreateFn<T = u32> = fn(&ArrayData<T>, &Settings) -> Box<dyn SortVisualizer<T>>;
+
+/// Describes a visualizer that can be launched from the main menu
+pub struct AlgorithmEntry {
+    pub menu_id: u32,               // Menu option that launches this algorithm
+    pub settings_key: &'static str, // Key used for this algorithm in the settings file
+    pub visualize: VisualizeFn,     // Runs the visualization
+    pub create: CreateFn,           // Builds the visualizer without running it, e.g. for the glossary
+    pub signed: Option<(VisualizeFn<i32>, CreateFn<i32>)>, // The same for signed integers, None if it needs non-negative ones
+    pub reals: Option<(VisualizeFn<f64>, CreateFn<f64>)>, // The same for real numbers, None if it needs integers
+}
+
+/// Every algorithm the application can visualize, keyed by its menu id
+pub const ALGORITHMS: &[AlgorithmEntry] = &[
+    AlgorithmEntry { menu_id: 2, settings_key: "LinearSearch", visualize: linear_search_visualization, create: |array, settings| Box::new(LinearSearchVisualizer::with_target(array, middle_value(array), settings)), signed: Some((linear_search_visualization, |array, settings| Box::new(LinearSearchVisualizer::with_target(array, middle_value(array), settings)))), reals: Some((linear_search_visualization, |array, settings| Box::new(LinearSearchVisualizer::with_target(array, middle_value(array), settings)))) },
+    AlgorithmEntry { menu_id: 3, settings_key: "BinarySearch", visualize: binary_search_visualization, create: |array, settings| Box::new(BinarySearchVisualizer::with_target(array, middle_value(array), settings)), signed: Some((binary_search_visualization, |array, settings| Box::new(BinarySearchVisualizer::with_target(array, middle_value(array), settings)))), reals: Some((binary_search_visualization, |array, settings| Box::new(BinarySearchVisualizer::with_target(array, middle_value(array), settings)))) },
+    AlgorithmEntry { menu_id: 27, settings_key: "JumpSearch", visualize: jump_search_visualization, create: |array, settings| Box::new(JumpSearchVisualizer::with_target(array, middle_value(array), settings)), signed: Some((jump_search_visualization, |array, settings| Box::new(JumpSearchVisualizer::with_target(array, middle_value(array), settings)))), reals: Some((jump_search_visualization, |array, settings| Box::new(JumpSearchVisualizer::with_target(array, middle_value(array), settings)))) },
+    AlgorithmEntry { menu_id: 28, settings_key: "InterpolationSearch", visualize: interpolation_search_visualization, create: |array, settings| Box::new(InterpolationSearchVisualizer::with_target(array, middle_value(array), settings)), signed: Some((interpolation_search_visualization, |array, settings| Box::new(InterpolationSearchVisualizer::with_target(array, middle_value(array), settings)))), reals: Some((interpolation_search_visualization, |array, settings| Box::new(InterpolationSearchVisualizer::with_target(array, middle_value(array), settings)))) },
+    AlgorithmEntry { menu_id: 4, settings_key: "BubbleSort", visualize: bubble_sort_visualization, create: |array, settings| Box::new(BubbleSortVisualizer::with_settings(array, settings)), signed: Some((bubble_sort_visualization, |array, settings| Box::new(BubbleSortVisualizer::with_settings(array, settings)))), reals: Some((bubble_sort_visualization, |array, settings| Box::new(BubbleSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 5, settings_key: "BucketSort", visualize: bucket_sort_visualization, create: |array, settings| Box::new(BucketSortVisualizer::with_settings(array, settings)), signed: Some((bucket_sort_visualization, |array, settings| Box::new(BucketSortVisualizer::with_settings(array, settings)))), reals: Some((bucket_sort_visualization, |array, settings| Box::new(BucketSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 6, settings_key: "CocktailSort", visualize: cocktail_sort_visualization, create: |array, settings| Box::new(CocktailSortVisualizer::with_settings(array, settings)), signed: Some((cocktail_sort_visualization, |array, settings| Box::new(CocktailSortVisualizer::with_settings(array, settings)))), reals: Some((cocktail_sort_visualization, |array, settings| Box::new(CocktailSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 7, settings_key: "CombSort", visualize: comb_sort_visualization, create: |array, settings| Box::new(CombSortVisualizer::with_settings(array, settings)), signed: Some((comb_sort_visualization, |array, settings| Box::new(CombSortVisualizer::with_settings(array, settings)))), reals: Some((comb_sort_visualization, |array, settings| Box::new(CombSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 8, settings_key: "CountingSort", visualize: counting_sort_visualization, create: |array, settings| Box::new(CountingSortVisualizer::with_settings(array, settings)), signed: Some((counting_sort_visualization, |array, settings| Box::new(CountingSortVisualizer::with_settings(array, settings)))), reals: None },
+    AlgorithmEntry { menu_id: 9, settings_key: "GnomeSort", visualize: gnome_sort_visualization, create: |array, settings| Box::new(GnomeSortVisualizer::with_settings(array, settings)), signed: Some((gnome_sort_visualization, |array, settings| Box::new(GnomeSortVisualizer::with_settings(array, settings)))), reals: Some((gnome_sort_visualization, |array, settings| Box::new(GnomeSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 10, settings_key: "HeapSort", visualize: heap_sort_visualization, create: |array, settings| Box::new(HeapSortVisualizer::with_settings(array, settings)), signed: Some((heap_sort_visualization, |array, settings| Box::new(HeapSortVisualizer::with_settings(array, settings)))), reals: Some((heap_sort_visualization, |array, settings| Box::new(HeapSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 11, settings_key: "InsertionSort", visualize: insertion_sort_visualization, create: |array, settings| Box::new(InsertionSortVisualizer::with_settings(array, settings)), signed: Some((insertion_sort_visualization, |array, settings| Box::new(InsertionSortVisualizer::with_settings(array, settings)))), reals: Some((insertion_sort_visualization, |array, settings| Box::new(InsertionSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 12, settings_key: "MergeSort", visualize: merge_sort_visualization, create: |array, settings| Box::new(MergeSortVisualizer::with_settings(array, settings)), signed: Some((merge_sort_visualization, |array, settings| Box::new(MergeSortVisualizer::with_settings(array, settings)))), reals: Some((merge_sort_visualization, |array, settings| Box::new(MergeSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 13, settings_key: "PancakeSort", visualize: pancake_sort_visualization, create: |array, settings| Box::new(PancakeSortVisualizer::with_settings(array, settings)), signed: Some((pancake_sort_visualization, |array, settings| Box::new(PancakeSortVisualizer::with_settings(array, settings)))), reals: Some((pancake_sort_visualization, |array, settings| Box::new(PancakeSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 14, settings_key: "QuickSort", visualize: quick_sort_visualization, create: |array, settings| Box::new(QuickSortVisualizer::with_settings(array, settings)), signed: Some((quick_sort_visualization, |array, settings| Box::new(QuickSortVisualizer::with_settings(array, settings)))), reals: Some((quick_sort_visualization, |array, settings| Box::new(QuickSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 15, settings_key: "RadixSort", visualize: radix_sort_visualization, create: |array, settings| Box::new(RadixSortVisualizer::with_settings(array, settings)), signed: Some((radix_sort_visualization, |array, settings| Box::new(RadixSortVisualizer::with_settings(array, settings)))), reals: None },
+    AlgorithmEntry { menu_id: 16, settings_key: "SelectionSort", visualize: selection_sort_visualization, create: |array, settings| Box::new(SelectionSortVisualizer::with_settings(array, settings)), signed: Some((selection_sort_visualization, |array, settings| Box::new(SelectionSortVisualizer::with_settings(array, settings)))), reals: Some((selection_sort_visualization, |array, settings| Box::new(SelectionSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 17, settings_key: "ShellSort", visualize: shell_sort_visualization, create: |array, settings| Box::new(ShellSortVisualizer::with_settings(array, settings)), signed: Some((shell_sort_visualization, |array, settings| Box::new(ShellSortVisualizer::with_settings(array, settings)))), reals: Some((shell_sort_visualization, |array, settings| Box::new(ShellSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 18, settings_key: "TimSort", visualize: tim_sort_visualization, create: |array, settings| Box::new(TimSortVisualizer::with_settings(array, settings)), signed: Some((tim_sort_visualization, |array, settings| Box::new(TimSortVisualizer::with_settings(array, settings)))), reals: Some((tim_sort_visualization, |array, settings| Box::new(TimSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 19, settings_key: "OddEvenSort", visualize: odd_even_sort_visualization, create: |array, settings| Box::new(OddEvenSortVisualizer::with_settings(array, settings)), signed: Some((odd_even_sort_visualization, |array, settings| Box::new(OddEvenSortVisualizer::with_settings(array, settings)))), reals: Some((odd_even_sort_visualization, |array, settings| Box::new(OddEvenSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 20, settings_key: "CycleSort", visualize: cycle_sort_visualization, create: |array, settings| Box::new(CycleSortVisualizer::with_settings(array, settings)), signed: Some((cycle_sort_visualization, |array, settings| Box::new(CycleSortVisualizer::with_settings(array, settings)))), reals: Some((cycle_sort_visualization, |array, settings| Box::new(CycleSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 21, settings_key: "PatienceSort", visualize: patience_sort_visualization, create: |array, settings| Box::new(PatienceSortVisualizer::with_settings(array, settings)), signed: Some((patience_sort_visualization, |array, settings| Box::new(PatienceSortVisualizer::with_settings(array, settings)))), reals: Some((patience_sort_visualization, |array, settings| Box::new(PatienceSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 22, settings_key: "InPlaceMergeSort", visualize: in_place_merge_sort_visualization, create: |array, settings| Box::new(InPlaceMergeSortVisualizer::with_settings(array, settings)), signed: Some((in_place_merge_sort_visualization, |array, settings| Box::new(InPlaceMergeSortVisualizer::with_settings(array, settings)))), reals: Some((in_place_merge_sort_visualization, |array, settings| Box::new(InPlaceMergeSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 23, settings_key: "BogoSort", visualize: bogo_sort_visualization, create: |array, settings| Box::new(BogoSortVisualizer::with_settings(array, settings)), signed: Some((bogo_sort_visualization, |array, settings| Box::new(BogoSortVisualizer::with_settings(array, settings)))), reals: Some((bogo_sort_visualization, |array, settings| Box::new(BogoSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 24, settings_key: "StalinSort", visualize: stalin_sort_visualization, create: |array, settings| Box::new(StalinSortVisualizer::with_settings(array, settings)), signed: Some((stalin_sort_visualization, |array, settings| Box::new(StalinSortVisualizer::with_settings(array, settings)))), reals: Some((stalin_sort_visualization, |array, settings| Box::new(StalinSortVisualizer::with_settings(array, settings)))) },
+    AlgorithmEntry { menu_id: 25, settings_key: "ComparisonChallenge", visualize: comparison_challenge_visualization, create: |array, settings| Box::new(ComparisonChallengeVisualizer::with_settings(array, settings)), signed: None, reals: None },
+    AlgorithmEntry { menu_id: 26, settings_key: "PickOrderGame", visualize: pick_order_game_visualization, create: |array, settings| Box::new(PickOrderGameVisualizer::with_settings(array, settings)), signed: None, reals: None },
+];
+
+/// Settings keys of every algorithm, the names accepted by `--algo`
+pub fn all_algorithm_names() -> impl Iterator<Item = &'static str> {
+    ALGORITHMS.iter().map(|entry| entry.settings_key)
+}
+
+/// Returns true if `key` names an algorithm, e.g. "BubbleSort"
+pub fn is_known_settings_key(key: &str) -> bool {
+    ALGORITHMS.iter().any(|entry| entry.settings_key == key)
+}
+
+/// Returns the algorithm launched by the given menu option, if any
+pub fn find_by_menu_id(menu_id: u32) -> Option<&'static AlgorithmEntry> {
+    ALGORITHMS.iter().find(|entry| entry.menu_id == menu_id)
+}
+
+/// Returns the algorithm named on the command line, ignoring case, spaces, dashes and underscores.
+/// The trailing "sort" may be left out, so "quicksort", "quick-sort" and "quick" all find QuickSort.
+pub fn find_by_name(name: &str) -> Option<&'static AlgorithmEntry> {
+    let wanted: String = name.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>().to_ascii_lowercase();
+    if wanted.is_empty() {
+        return None;
+    }
+    ALGORITHMS.iter().find(|entry| {
+        let key = entry.settings_key.to_ascii_lowercase();
+        key == wanted || key.strip_suffix("sort") == Some(wanted.as_str())
+    })
+}
+
+/// Runs the algorithm on the array, or only on its sort range if one is set.
+/// The elements outside the range stay in place and are drawn dimmed, searches and games always get the whole array.
+/// Arrays of real numbers only run on algorithms that compare values, the others explain why they need integers.
+/// Signed arrays run on every algorithm but the games.
+pub fn visualize(entry: &AlgorithmEntry, array: &StoredArray, last_run: &mut Option<RunSummary>) -> Option<SuspendedRun> {
+    match array {
+        StoredArray::Integers(array) => visualize_with(entry, entry.visualize, entry.create, array, last_run).map(SuspendedRun::Integers),
+        StoredArray::Signed(array) => match entry.signed {
+            Some((visualize, create)) => visualize_with(entry, visualize, create, array, last_run).map(SuspendedRun::Signed),
+            None => {
+                let message = format!("{} is played on values from 0 up.\n\nSelect an array without negative values to play it.", entry.settings_key);
+                show_info("No Negative Values", &message);
+                None
+            }
+        },
+        StoredArray::Reals(array) => match entry.reals {
+            Some((visualize, create)) => visualize_with(entry, visualize, create, array, last_run).map(SuspendedRun::Reals),
+            None => {
+                let message = format!("{} works on the digits or counts of integers.\n\nSelect an array without decimal points to run it.", entry.settings_key);
+                show_info("Needs Integers", &message);
+                None
+            }
+        },
+    }
+}
+
+// Runs a search through `visualize`, anything else on the visualizer `create` builds on the array or its sort range
+fn visualize_with<T: SortElement>(
+    entry: &AlgorithmEntry,
+    visualize: VisualizeFn<T>,
+    create: CreateFn<T>,
+    array: &ArrayData<T>,
+    last_run: &mut Option<RunSummary>,
+) -> Option<Box<dyn SortVisualizer<T>>> {
+    // Searches ask for their target first, which only their own visualize function does
+    if entry.settings_key.ends_with("Search") {
+        return visualize(array, last_run);
+    }
+    let slice = array.sort_slice().filter(|_| entry.settings_key.ends_with("Sort"));
+    let (array, fixed) = match slice {
+        Some((slice, fixed)) => (slice, Some(fixed)),
+        None => (array.clone(), None),
+    };
+    let settings = Settings::load();
+    let mut visualizer = create(&array, &settings);
+    visualizer.state_mut().fixed_range = fixed;
+    visualizer.state_mut().seed = array.seed;
+    run_suspendable_boxed(visualizer, settings, last_run)
+}
+
+// Target of a search built without asking, the value Esc picks at the target prompt
+fn middle_value<T: SortElement>(array_data: &ArrayData<T>) -> T {
+    array_data.data.get(array_data.data.len() / 2).copied().unwrap_or_default()
+}
+
+/// An input that drives one algorithm into its worst case, for demonstrating the pitfall
+pub struct WorstCase {
+    pub settings_key: &'static str,     // Algorithm this input targets, keyed like ALGORITHMS
+    pub name: &'static str,             // Display name, e.g. "Quick Sort"
+    pub description: &'static str,      // Why this input is the worst case
+    pub generate: fn(usize) -> Vec<u32>, // Builds an input of the given size
+}
+
+/// Returns the position in WORST_CASES of the algorithm's generator, if it has one
+pub fn worst_case_index(settings_key: &str) -> Option<usize> {
+    WORST_CASES.iter().position(|worst_case| worst_case.settings_key == settings_key)
+}
+
+/// Worst-case generators of the algorithms that have a notable worst case, keyed by settings key
+pub const WORST_CASES: &[WorstCase] = &[
+    WorstCase { settings_key: "BubbleSort", name: "Bubble Sort", description: "Reverse sorted: every pair is out of order, O(n²) swaps", generate: reverse_sorted },
+    WorstCase { settings_key: "BucketSort", name: "Bucket Sort", description: "One outlier pushes every other value into the same bucket, which is insertion sorted", generate: single_bucket },
+    WorstCase { settings_key: "CocktailSort", name: "Cocktail Sort", description: "Reverse sorted: both passes move every element, O(n²) swaps", generate: reverse_sorted },
+    WorstCase { settings_key: "GnomeSort", name: "Gnome Sort", description: "Reverse sorted: each element walks back to the start, O(n²) steps", generate: reverse_sorted },
+    WorstCase { settings_key: "InsertionSort", name: "Insertion Sort", description: "Reverse sorted: each key is compared with every sorted element, O(n²)", generate: reverse_sorted },
+    WorstCase { settings_key: "MergeSort", name: "Merge Sort", description: "Interleaved halves: no merge can finish early, the most comparisons possible", generate: interleaved },
+    WorstCase { settings_key: "QuickSort", name: "Quick Sort", description: "Already sorted: the last-element pivot splits off one element per partition, O(n²)", generate: already_sorted },
+    WorstCase { settings_key: "RadixSort", name: "Radix Sort", description: "One three-digit value forces a counting pass per digit for every element", generate: one_long_key },
+    WorstCase { settings_key: "OddEvenSort", name: "Odd-Even Sort", description: "Reverse sorted: elements move one slot per phase, n phases", generate: reverse_sorted },
+    WorstCase { settings_key: "PatienceSort", name: "Patience Sort", description: "Already sorted: every card starts a new pile, so each merge step scans n piles", generate: already_sorted },
+    WorstCase { settings_key: "InPlaceMergeSort", name: "In-Place Merge Sort", description: "Reverse sorted: every merge rotates the whole right run past the left run", generate: reverse_sorted },
+];
+
+// `size` ascending values spread over 1..=100
+fn already_sorted(size: usize) -> Vec<u32> {
+    let step = (99 / size.saturating_sub(1).max(1) as u32).max(1);
+    (0..size as u32).map(|i| (1 + i * step).min(100)).collect()
+}
+
+fn reverse_sorted(size: usize) -> Vec<u32> {
+    let mut values = already_sorted(size);
+    values.reverse();
+    values
+}
+
+// Sorted values rearranged so every merge has to alternate between its halves
+fn interleaved(size: usize) -> Vec<u32> {
+    fn split(values: &[u32]) -> Vec<u32> {
+        if values.len() <= 1 {
+            return values.to_vec();
+        }
+        let evens: Vec<u32> = values.iter().step_by(2).copied().collect();
+        let odds: Vec<u32> = values.iter().skip(1).step_by(2).copied().collect();
+        let mut result = split(&evens);
+        result.extend(split(&odds));
+        result
+    }
+    split(&already_sorted(size))
+}
+
+// A single maximum followed by small descending values that all land in the first bucket
+fn single_bucket(size: usize) -> Vec<u32> {
+    let mut values = vec![100];
+    values.extend((1..size as u32).rev().map(|i| 1 + i * 8 / size.max(1) as u32));
+    values
+}
+
+// Descending single-digit values plus one value with three digits
+fn one_long_key(size: usize) -> Vec<u32> {
+    let mut values: Vec<u32> = (0..size.saturating_sub(1) as u32).map(|i| 9 - i % 9).collect();
+    values.push(999);
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::base_visualizer::SortVisualizer;
+    use crate::common::menu::menu_categories;
+    use crate::sort_algorithms::run_headless;
+
+    #[test]
+    fn menu_options_launch_the_algorithm_they_name() {
+        let array_data = ArrayData::new(vec![4, 1, 3, 2], "Menu".to_string());
+        let settings = Settings::default();
+        let options: Vec<_> = menu_categories().into_iter().flat_map(|(_, options)| options).collect();
+        for entry in ALGORITHMS {
+            let option = options.iter().find(|option| option.id == entry.menu_id).unwrap();
+            let visualizer = (find_by_name(entry.settings_key).unwrap().create)(&array_data, &settings);
+            assert_eq!(visualizer.settings_key(), entry.settings_key);
+            assert!(
+                visualizer.display_name().eq_ignore_ascii_case(&option.name),
+                "menu option {} \"{}\" launches {}",
+                option.id,
+                option.name,
+                visualizer.display_name()
+            );
+        }
+    }
+
+    #[test]
+    fn real_arrays_run_on_every_algorithm_that_compares_values() {
+        let array_data = ArrayData::new(vec![2.5, 0.75, 1.0], "Reals".to_string());
+        let settings = Settings::default();
+        for entry in ALGORITHMS {
+            let needs_integers = ["CountingSort", "RadixSort", "ComparisonChallenge", "PickOrderGame"].contains(&entry.settings_key);
+            match entry.reals {
+                Some((_, create)) => assert_eq!(create(&array_data, &settings).settings_key(), entry.settings_key),
+                None => assert!(needs_integers, "{} does not take real numbers", entry.settings_key),
+            }
+        }
+    }
+
+    #[test]
+    fn signed_arrays_run_on_every_algorithm_but_the_games() {
+        let array_data = ArrayData::new(vec![-3, 5, 0, -12], "Signed".to_string());
+        let settings = Settings::default();
+        for entry in ALGORITHMS {
+            match entry.signed {
+                Some((_, create)) => assert_eq!(create(&array_data, &settings).settings_key(), entry.settings_key),
+                None => assert!(!entry.settings_key.ends_with("Sort"), "{} does not take negative values", entry.settings_key),
+            }
+        }
+    }
+
+    #[test]
+    fn worst_cases_have_the_requested_size_and_a_known_algorithm() {
+        for worst_case in WORST_CASES {
+            assert!(is_known_settings_key(worst_case.settings_key), "{}", worst_case.settings_key);
+            for size in [2, 7, 16, 50] {
+                assert_eq!((worst_case.generate)(size).len(), size, "{} of size {}", worst_case.name, size);
+            }
+        }
+    }
+
+    #[test]
+    fn insertion_sort_worst_case_compares_every_pair() {
+        let size = 16;
+        let generate = WORST_CASES[worst_case_index("InsertionSort").unwrap()].generate;
+        let array_data = ArrayData::new(generate(size), "Worst".to_string());
+        let mut visualizer = InsertionSortVisualizer::with_settings(&array_data, &Settings::default());
+        run_headless(&mut visualizer);
+        assert!(visualizer.state().comparisons as usize >= size * (size - 1) / 2);
+    }
+}
@@ -0,0 +1,291 @@
+use crate::common::algorithm_info::AlgorithmInfo;
+use crate::common::array_manager::ArrayData;
+use crate::common::base_visualizer::SortVisualizer;
+use crate::common::enums::TeachingQuestion;
+use crate::common::settings::Settings;
+use crate::common::theme;
+use crate::factory::ALGORITHMS;
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{poll, read, Event, KeyCode, KeyEventKind},
+    execute,
+    style::{Attribute, Color, Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor},
+    terminal::{disable_raw_mode, enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+    QueueableCommand,
+};
+use std::io::{stdout, Write};
+use std::time::Duration;
+
+// Width of the algorithm list on the left
+const LIST_WIDTH: u16 = 26;
+
+/// Teaching content of one algorithm, as shown by its visualizer
+pub struct GlossaryEntry {
+    pub name: String,
+    pub intro: String,
+    pub questions: Vec<TeachingQuestion>,
+    pub info: AlgorithmInfo,
+}
+
+impl GlossaryEntry {
+    // Reads the intro text and questions a visualizer was created with
+    fn from_visualizer(visualizer: &dyn SortVisualizer) -> Self {
+        Self {
+            name: visualizer.display_name(),
+            intro: clean_intro(visualizer.get_intro_text()),
+            questions: visualizer.state().questions.clone(),
+            info: visualizer.info(),
+        }
+    }
+
+    /// Plain text lines of the entry: intro, cheat sheet, then every question with its answer and explanation
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines: Vec<String> = self.intro.lines().map(str::to_string).collect();
+        lines.push(String::new());
+        lines.push("Cheat Sheet".to_string());
+        lines.extend(self.info.card_text().lines().map(|line| format!("  {}", line.trim_end())));
+        lines.push(String::new());
+        lines.push("Questions & Explanations".to_string());
+        for (i, question) in self.questions.iter().enumerate() {
+            lines.push(String::new());
+            lines.push(format!("Q{}. {}", i + 1, question.text));
+            for (j, option) in question.options.iter().enumerate() {
+                let marker = if j == question.correct_index { "✓" } else { " " };
+                lines.push(format!("  {} {}. {}", marker, j + 1, option));
+            }
+            lines.push(format!("  → {}", question.explanation));
+        }
+        lines
+    }
+
+    /// True if the name or any text of the entry contains `query`, ignoring case
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        query.is_empty() || self.lines().iter().chain([&self.name]).any(|line| line.to_lowercase().contains(&query))
+    }
+}
+
+// Keeps the explanation, drops the per-run lines (target, teaching toggle, key prompt)
+fn clean_intro(intro: &str) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    for line in intro.lines().map(str::trim) {
+        let per_run = line.starts_with("Teaching Mode:")
+            || line.starts_with("Press any key")
+            || line.starts_with("Target:");
+        let double_blank = line.is_empty() && lines.last().is_none_or(|last| last.is_empty());
+        if !per_run && !double_blank {
+            lines.push(line);
+        }
+    }
+    lines.join("\n").trim_end().to_string()
+}
+
+/// Teaching content of every algorithm, in menu order
+pub fn glossary_entries() -> Vec<GlossaryEntry> {
+    // The content doesn't depend on the data, any small array works
+    let sample = ArrayData::new(vec![5, 3, 8, 1, 4], "Glossary".to_string());
+    let settings = Settings::load();
+    ALGORITHMS
+        .iter()
+        .map(|entry| GlossaryEntry::from_visualizer((entry.create)(&sample, &settings).as_ref()))
+        .collect()
+}
+
+// Splits a line into pieces that fit `width` columns, breaking at spaces when possible
+fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    if line.chars().count() <= width || width == 0 {
+        return vec![line.to_string()];
+    }
+    let indent: String = line.chars().take_while(|c| *c == ' ').collect();
+    let mut wrapped = Vec::new();
+    let mut current = String::new();
+    for word in line.split_whitespace() {
+        let candidate_len = current.chars().count() + word.chars().count() + 1;
+        if !current.is_empty() && candidate_len > width {
+            wrapped.push(current);
+            current = format!("{}  ", indent);
+        } else if current.is_empty() {
+            current.push_str(&indent);
+        } else {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    if !current.is_empty() {
+        wrapped.push(current);
+    }
+    wrapped
+}
+
+/// Browsable reference of every algorithm's intro text, questions and explanations
+///
+/// # Controls
+/// - ↑/↓: choose an algorithm, PgUp/PgDn: scroll its text
+/// - /: type a search query, Enter or ESC ends typing
+/// - ESC: back to the main menu
+pub fn show_glossary() {
+    let entries = glossary_entries();
+    let mut stdout = stdout();
+    enable_raw_mode().unwrap();
+    execute!(stdout, EnterAlternateScreen, Hide).unwrap();
+
+    let mut query = String::new();
+    let mut searching = false;
+    let mut selected = 0usize;
+    let mut scroll = 0usize;
+
+    loop {
+        let visible: Vec<&GlossaryEntry> = entries.iter().filter(|entry| entry.matches(&query)).collect();
+        selected = selected.min(visible.len().saturating_sub(1));
+
+        let (width, height) = size().unwrap();
+        stdout.queue(Clear(ClearType::All)).unwrap();
+
+        // --- Title ---
+        let title = "TOGISOFT ALGORITHM GLOSSARY";
+        stdout.queue(MoveTo(width.saturating_sub(title.len() as u16) / 2, 1)).unwrap();
+        stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+        stdout.queue(SetBackgroundColor(Color::DarkBlue)).unwrap();
+        stdout.queue(Print(title)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        // --- Search ---
+        stdout.queue(MoveTo(2, 3)).unwrap();
+        stdout.queue(SetForegroundColor(if searching { Color::Yellow } else { theme::dim() })).unwrap();
+        stdout.queue(Print(format!("Search: {}{}", query, if searching { "_" } else { "" }))).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        // --- Algorithm list ---
+        let list_top = 5u16;
+        let body_height = height.saturating_sub(list_top + 3) as usize;
+        let list_offset = selected.saturating_sub(body_height.saturating_sub(1));
+        for (row, (i, entry)) in visible.iter().enumerate().skip(list_offset).take(body_height).enumerate() {
+            stdout.queue(MoveTo(2, list_top + row as u16)).unwrap();
+            if i == selected {
+                stdout.queue(SetForegroundColor(theme::highlight().0)).unwrap();
+                stdout.queue(SetBackgroundColor(theme::highlight().1)).unwrap();
+            } else {
+                stdout.queue(SetForegroundColor(theme::text())).unwrap();
+            }
+            let name: String = entry.name.chars().take(LIST_WIDTH as usize - 2).collect();
+            stdout.queue(Print(format!(" {:<width$}", name, width = LIST_WIDTH as usize - 2))).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
+
+        // --- Entry text ---
+        let text_x = LIST_WIDTH + 4;
+        let text_width = width.saturating_sub(text_x + 2) as usize;
+        match visible.get(selected) {
+            Some(entry) => {
+                let lines: Vec<String> = entry.lines().iter().flat_map(|line| wrap_line(line, text_width)).collect();
+                scroll = scroll.min(lines.len().saturating_sub(1));
+                let lowercase_query = query.to_lowercase();
+                for (row, line) in lines.iter().skip(scroll).take(body_height).enumerate() {
+                    stdout.queue(MoveTo(text_x, list_top + row as u16)).unwrap();
+                    let color = if !lowercase_query.is_empty() && line.to_lowercase().contains(&lowercase_query) {
+                        Color::Yellow
+                    } else if line.starts_with('Q') || line.starts_with("What is") {
+                        Color::Cyan
+                    } else if line.trim_start().starts_with('✓') {
+                        Color::Green
+                    } else {
+                        theme::text()
+                    };
+                    stdout.queue(SetForegroundColor(color)).unwrap();
+                    stdout.queue(Print(line)).unwrap();
+                    stdout.queue(ResetColor).unwrap();
+                }
+            }
+            None => {
+                stdout.queue(MoveTo(text_x, list_top)).unwrap();
+                stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+                stdout.queue(Print(format!("No algorithm mentions \"{}\"", query))).unwrap();
+                stdout.queue(ResetColor).unwrap();
+            }
+        }
+
+        // --- Instructions ---
+        let instructions = if searching {
+            "Type to search | Backspace: Delete | Enter/ESC: Done"
+        } else {
+            "↑/↓: Algorithm | PgUp/PgDn: Scroll | /: Search | ESC: Back"
+        };
+        stdout.queue(MoveTo(width.saturating_sub(instructions.chars().count() as u16) / 2, height.saturating_sub(2))).unwrap();
+        stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+        stdout.queue(Print(instructions)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+        stdout.flush().unwrap();
+
+        // --- Handle Input ---
+        if !poll(Duration::from_millis(100)).unwrap_or(false) {
+            continue;
+        }
+        let Ok(Event::Key(key_event)) = read() else {
+            continue;
+        };
+        if key_event.kind != KeyEventKind::Press {
+            continue;
+        }
+        if searching {
+            match key_event.code {
+                KeyCode::Char(c) => {
+                    query.push(c);
+                    selected = 0;
+                    scroll = 0;
+                }
+                KeyCode::Backspace => {
+                    query.pop();
+                }
+                KeyCode::Enter | KeyCode::Esc => searching = false,
+                _ => {}
+            }
+            continue;
+        }
+        match key_event.code {
+            KeyCode::Up => {
+                selected = selected.saturating_sub(1);
+                scroll = 0;
+            }
+            KeyCode::Down => {
+                selected = (selected + 1).min(visible.len().saturating_sub(1));
+                scroll = 0;
+            }
+            KeyCode::PageUp => scroll = scroll.saturating_sub(body_height.max(1)),
+            KeyCode::PageDown => scroll += body_height.max(1),
+            KeyCode::Char('/') => searching = true,
+            KeyCode::Esc => break,
+            _ => {}
+        }
+    }
+
+    execute!(stdout, ResetColor, Show, LeaveAlternateScreen).unwrap();
+    disable_raw_mode().unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_algorithm_has_teaching_content() {
+        let entries = glossary_entries();
+        assert_eq!(entries.len(), crate::factory::ALGORITHMS.len());
+        for entry in &entries {
+            assert!(!entry.intro.is_empty(), "{} has no intro", entry.name);
+            assert!(!entry.questions.is_empty(), "{} has no questions", entry.name);
+            assert!(!entry.intro.contains("Press any key"), "{} intro was not cleaned", entry.name);
+        }
+    }
+
+    #[test]
+    fn search_matches_explanations() {
+        let entries = glossary_entries();
+        let matches: Vec<&str> = entries
+            .iter()
+            .filter(|entry| entry.matches("EEPROM"))
+            .map(|entry| entry.name.as_str())
+            .collect();
+        assert_eq!(matches, vec!["Cycle Sort"]);
+    }
+}
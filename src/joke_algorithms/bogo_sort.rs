@@ -0,0 +1,333 @@
+use crate::common::algorithm_info::AlgorithmInfo;
+use crate::common::array_manager::ArrayData;
+use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
+use crate::common::element::SortElement;
+use crate::common::enums::{SelectionState, TeachingQuestion};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
+use crate::common::settings::Settings;
+use rand::Rng;
+use std::time::Duration;
+
+// Shuffles after which bogosort gives up, a run would otherwise take around n! shuffles
+pub const MAX_SHUFFLES: u32 = 1000;
+
+/// Represents the different phases of the bogosort algorithm
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BogoPhase {
+    Checking,  // Walking the array to see if it happens to be sorted
+    Shuffling, // Shuffling the whole array at random
+    Done,      // The array is sorted
+    GaveUp,    // The shuffle cap was reached before the array got sorted
+}
+
+/// Visualizes bogosort, an educational curiosity that shuffles until the array is sorted
+#[derive(Clone)]
+pub struct BogoSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,             // Current state of the array being sorted
+    original_array: Vec<T>,    // Original array, used for resetting
+    states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, swapping, sorted)
+
+    // Bogosort specific fields
+    check_index: usize,     // Left element of the pair being checked
+    shuffles: u32,          // Shuffles done so far
+    phase: BogoPhase,       // Current phase of the bogosort algorithm
+    state: VisualizerState<T>, // Common visualization state
+}
+
+impl<T: SortElement> BogoSortVisualizer<T> {
+    /// Creates a new BogoSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
+        let array = array_data.data.clone();
+        let len = array.len();
+
+        let questions = vec![
+            TeachingQuestion {
+                text: "How does Bogosort sort an array?".to_string(),
+                options: vec![
+                    "It shuffles the array at random until it happens to be sorted".to_string(),
+                    "It swaps neighbours that are out of order".to_string(),
+                    "It removes every element that is out of order".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "Bogosort checks whether the array is sorted and, if not, shuffles it and tries again. Nothing it does moves the array closer to sorted.".to_string(),
+            },
+            TeachingQuestion {
+                text: "How many shuffles does Bogosort expect for n distinct values?".to_string(),
+                options: vec![
+                    "About n!".to_string(),
+                    "About n²".to_string(),
+                    "About n log n".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "Only one of the n! orderings is sorted and every shuffle picks one at random, so on average n! shuffles are needed. 10 values already expect 3,628,800.".to_string(),
+            },
+            TeachingQuestion {
+                text: "Why does this visualizer stop after a fixed number of shuffles?".to_string(),
+                options: vec![
+                    "Bogosort has no upper bound, an unlucky run never ends".to_string(),
+                    "The array is always sorted by then".to_string(),
+                    "Shuffling wears out the array".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "A random shuffle can keep missing the sorted order forever, so an unbounded Bogosort has no worst-case running time. The cap makes it give up instead.".to_string(),
+            },
+        ];
+
+        randomize_questions(questions.clone());
+
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("BogoSort")));
+        state.teaching_mode = settings.teaching_mode;
+
+        let mut this = Self {
+            original_array: array.clone(),
+            array,
+            states: vec![SelectionState::Normal; len],
+            check_index: 0,
+            shuffles: 0,
+            phase: BogoPhase::Checking,
+            state,
+        };
+
+        if len <= 1 {
+            this.phase = BogoPhase::Done;
+            this.state.mark_completed();
+            this.mark_all_sorted();
+        }
+
+        this
+    }
+
+    // Fisher-Yates shuffle of the whole array, every exchange counts as a swap
+    fn shuffle(&mut self) {
+        let mut rng = rand::rng();
+        for i in (1..self.array.len()).rev() {
+            let j = rng.random_range(0..=i);
+            if i != j {
+                self.array.swap(i, j);
+                self.state.swaps += 1;
+            }
+        }
+        self.shuffles += 1;
+    }
+
+    // Expected number of shuffles for distinct values, n!
+    fn expected_shuffles(&self) -> String {
+        let expected: f64 = (1..=self.array.len()).map(|i| i as f64).product();
+        if expected < 1e9 { format!("{:.0}", expected) } else { format!("{:.1e}", expected) }
+    }
+}
+
+impl<T: SortElement> SortVisualizer<T> for BogoSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
+    fn get_states(&self) -> &[SelectionState] { &self.states }
+    fn get_comparisons(&self) -> u32 { self.state.comparisons }
+    fn get_swaps(&self) -> u32 { self.state.swaps }
+    fn get_speed(&self) -> Duration { self.state.speed }
+    fn is_running(&self) -> bool { self.state.is_running }
+    fn is_paused(&self) -> bool { self.state.is_paused }
+    fn is_completed(&self) -> bool { self.state.completed }
+    fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
+    fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
+    fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "BogoSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Bogo Sort",
+            best: "O(n)",
+            average: "O(n * n!)",
+            worst: "unbounded",
+            space: "O(1)",
+            stable: false,
+            in_place: true,
+        }
+    }
+
+    fn get_progress(&self) -> f64 {
+        // There is no real progress to report, only how much of the shuffle budget is used
+        if self.phase == BogoPhase::Done {
+            100.0
+        } else {
+            (self.shuffles as f64 / MAX_SHUFFLES as f64 * 100.0).min(100.0)
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if self.state.completed || self.state.awaiting_question.is_some() {
+            return true;
+        }
+
+        for state in &mut self.states {
+            *state = SelectionState::Normal;
+        }
+
+        let n = self.array.len();
+        match self.phase {
+            BogoPhase::Checking => {
+                let i = self.check_index;
+                if i + 1 >= n {
+                    self.phase = BogoPhase::Done;
+                    return true;
+                }
+                self.states[i] = SelectionState::Comparing;
+                self.states[i + 1] = SelectionState::Comparing;
+
+                if self.compare(i, i + 1).is_le() {
+                    self.check_index += 1;
+                    if self.check_index + 1 >= n {
+                        self.phase = BogoPhase::Done;
+                    }
+                } else if self.shuffles >= MAX_SHUFFLES {
+                    self.phase = BogoPhase::GaveUp;
+                } else {
+                    self.phase = BogoPhase::Shuffling;
+                }
+                true
+            }
+            BogoPhase::Shuffling => {
+                self.shuffle();
+                for state in &mut self.states {
+                    *state = SelectionState::Swapping;
+                }
+                self.check_index = 0;
+                self.phase = BogoPhase::Checking;
+
+                // Teaching: Ask question after a shuffle
+                if self.state.teaching_mode && !self.state.questions.is_empty() {
+                    let q_index = self.shuffles as usize % self.state.questions.len();
+                    self.state.ask_question(q_index);
+                }
+                true
+            }
+            BogoPhase::Done | BogoPhase::GaveUp => false,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.array = self.original_array.clone();
+        self.states = vec![SelectionState::Normal; self.array.len()];
+        self.check_index = 0;
+        self.shuffles = 0;
+        self.phase = BogoPhase::Checking;
+        self.state.reset_state();
+        if self.array.len() <= 1 {
+            self.phase = BogoPhase::Done;
+            self.state.mark_completed();
+            self.mark_all_sorted();
+        }
+    }
+
+    fn mark_all_sorted(&mut self) {
+        for state in &mut self.states {
+            *state = SelectionState::Sorted;
+        }
+    }
+
+    fn on_completed(&mut self) {
+        // Giving up leaves the array unsorted, so it is not colored as sorted
+        if self.phase == BogoPhase::Done {
+            self.mark_all_sorted();
+        }
+    }
+
+    fn edit_value(&mut self, index: usize, value: T) -> Result<(), String> {
+        self.array[index] = value;
+
+        // The pairs already checked may no longer be in order
+        if self.phase == BogoPhase::Checking {
+            self.check_index = 0;
+        }
+        Ok(())
+    }
+
+    fn get_title(&self) -> &str {
+        "TOGISOFT BOGOSORT VISUALIZER"
+    }
+
+    fn get_intro_text(&self) -> &str {
+        "What is Bogosort?\n\n\
+         JOKE ALGORITHM - an educational curiosity, not a real way to sort!\n\n\
+         Bogosort checks whether the array is sorted. If it is not, it shuffles the whole array at random and checks again.\n\
+         For n distinct values it expects about n! shuffles and an unlucky run may never finish,\n\
+         so this visualizer gives up after 1000 shuffles.\n\n\
+         Advantages: Tiny code, and it shows why an algorithm needs to make progress.\n\
+         Disadvantages: O(n·n!) expected time and no worst-case bound at all.\n\n\
+         Teaching Mode: ON (Toggle with T). Questions will be asked after shuffles.\n\n\
+         Press any key to continue..."
+    }
+
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
+        vec![
+            ("Normal", SelectionState::Normal),
+            ("Checking", SelectionState::Comparing),
+            ("Shuffled", SelectionState::Swapping),
+            ("Sorted", SelectionState::Sorted),
+        ]
+    }
+
+    fn get_statistics_strings(&self) -> Vec<String> {
+        let phase_str = match self.phase {
+            BogoPhase::Checking => "Checking If Sorted",
+            BogoPhase::Shuffling => "Shuffling",
+            BogoPhase::Done => "Done",
+            BogoPhase::GaveUp => "Gave Up",
+        };
+
+        vec![
+            format!("Array Size: {}", self.array.len()),
+            format!("Comparisons: {}", self.state.comparisons),
+            format!("Swaps: {}", self.state.swaps),
+            format!("Shuffles: {} / {}", self.shuffles, MAX_SHUFFLES),
+            format!("Expected Shuffles: ~{}", self.expected_shuffles()),
+            format!("Phase: {}", phase_str),
+            if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
+        ]
+    }
+
+    fn get_current_operation(&self) -> String {
+        match self.phase {
+            BogoPhase::Done if self.state.completed => {
+                format!("✓ Sorted after {} shuffles, pure luck!", self.shuffles)
+            }
+            BogoPhase::GaveUp if self.state.completed => {
+                format!("✗ Giving up after {} shuffles, the array is still not sorted", self.shuffles)
+            }
+            BogoPhase::Checking if self.check_index + 1 < self.array.len() => {
+                format!("Checking array[{}] ({}) <= array[{}] ({})",
+                        self.check_index,
+                        self.state.value_text(self.array[self.check_index]),
+                        self.check_index + 1,
+                        self.state.value_text(self.array[self.check_index + 1]))
+            }
+            BogoPhase::Shuffling => "Out of order, shuffling everything and hoping for the best".to_string(),
+            BogoPhase::GaveUp => format!("Still not sorted and the {} shuffle budget is spent", MAX_SHUFFLES),
+            _ => "Every pair is in order!".to_string(),
+        }
+    }
+
+    fn get_status(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "WAITING FOR QUESTION"
+        } else if self.state.completed && self.phase == BogoPhase::GaveUp {
+            "GAVE UP"
+        } else if self.state.completed {
+            "COMPLETED!"
+        } else if self.state.is_running && !self.state.is_paused {
+            "RUNNING..."
+        } else if self.state.is_paused {
+            "PAUSED"
+        } else {
+            "READY"
+        }
+    }
+}
+
+/// Entry point for the bogosort visualization
+pub fn bogo_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(BogoSortVisualizer::with_settings(array_data, &settings), settings, last_run)
+}
@@ -0,0 +1,42 @@
+pub mod bogo_sort;
+pub mod stalin_sort;
+
+pub use bogo_sort::*;
+pub use stalin_sort::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::array_manager::ArrayData;
+    use crate::common::base_visualizer::SortVisualizer;
+    use crate::common::settings::Settings;
+    use crate::sort_algorithms::run_headless;
+
+    #[test]
+    fn bogosort_gives_up_at_the_shuffle_cap() {
+        // 40 values have about 8e47 orderings, no run gets lucky within the cap
+        let array_data = ArrayData::new((1..=40).rev().collect(), "Bogo".to_string());
+        let mut visualizer = BogoSortVisualizer::with_settings(&array_data, &Settings::default());
+        run_headless(&mut visualizer);
+        visualizer.state_mut().mark_completed();
+        visualizer.on_completed();
+        assert_eq!(visualizer.get_status(), "GAVE UP");
+        assert!(!visualizer.get_states().contains(&crate::common::enums::SelectionState::Sorted));
+
+        let sorted = ArrayData::new(vec![1, 2, 2, 5], "Bogo Sorted".to_string());
+        let mut visualizer = BogoSortVisualizer::with_settings(&sorted, &Settings::default());
+        run_headless(&mut visualizer);
+        assert_eq!(visualizer.state().swaps, 0);
+        assert_eq!(visualizer.get_array(), [1, 2, 2, 5]);
+    }
+
+    #[test]
+    fn stalin_sort_keeps_an_ordered_subsequence() {
+        let array_data = ArrayData::new(vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3], "Stalin".to_string());
+        let mut visualizer = StalinSortVisualizer::with_settings(&array_data, &Settings::default());
+        run_headless(&mut visualizer);
+        assert_eq!(visualizer.survivors(), [3, 4, 5, 9]);
+        // The array itself is untouched, the removed elements are only marked
+        assert_eq!(visualizer.get_array(), array_data.data.as_slice());
+    }
+}
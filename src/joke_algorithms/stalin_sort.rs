@@ -0,0 +1,306 @@
+use crate::common::algorithm_info::AlgorithmInfo;
+use crate::common::array_manager::ArrayData;
+use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
+use crate::common::element::SortElement;
+use crate::common::enums::{SelectionState, TeachingQuestion};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
+use crate::common::settings::Settings;
+use std::ops::Range;
+use std::time::Duration;
+
+/// Represents the different phases of the stalin sort algorithm
+#[derive(Clone, Copy, PartialEq)]
+pub enum StalinPhase {
+    Comparing, // Comparing the next element with the last one kept
+    Keeping,   // The element is in order and stays
+    Removing,  // The element is out of order and gets removed
+    Done,      // Every element has been judged
+}
+
+/// Visualizes stalin sort, an educational curiosity that "sorts" by removing out-of-order elements
+#[derive(Clone)]
+pub struct StalinSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,             // Every element in its original position, removed ones stay visible
+    original_array: Vec<T>,    // Original array, used for resetting
+    states: Vec<SelectionState>, // Visual state of each element, kept ones are Sorted and removed ones Removed
+
+    // Stalin Sort specific fields
+    current: usize,         // Element being judged
+    last_kept: usize,       // Last element that survived, the one to compare against
+    removed: usize,         // Number of elements removed so far
+    phase: StalinPhase,     // Current phase of the stalin sort algorithm
+    state: VisualizerState<T>, // Common visualization state
+}
+
+impl<T: SortElement> StalinSortVisualizer<T> {
+    /// Creates a new StalinSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
+        let array = array_data.data.clone();
+        let len = array.len();
+
+        let questions = vec![
+            TeachingQuestion {
+                text: "What does Stalin Sort do with an element smaller than the last one kept?".to_string(),
+                options: vec![
+                    "Removes it from the array".to_string(),
+                    "Swaps it with the last one kept".to_string(),
+                    "Moves it to the front".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "Stalin Sort walks the array once and removes every element that would break the ascending order, whatever is left is sorted.".to_string(),
+            },
+            TeachingQuestion {
+                text: "Why is Stalin Sort not a real sorting algorithm?".to_string(),
+                options: vec![
+                    "Its output is not a permutation of the input".to_string(),
+                    "It is too slow".to_string(),
+                    "It needs extra memory".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "A sort must return the same elements in order. Stalin Sort runs in O(n) only because it throws data away, the result is usually shorter than the input.".to_string(),
+            },
+            TeachingQuestion {
+                text: "Which input keeps only its first element?".to_string(),
+                options: vec![
+                    "An array sorted in descending order".to_string(),
+                    "An array that is already sorted".to_string(),
+                    "An array of equal values".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "In a descending array every element is smaller than the first, so all of them are removed. Sorted arrays and equal values survive completely.".to_string(),
+            },
+        ];
+
+        randomize_questions(questions.clone());
+
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("StalinSort")));
+        state.teaching_mode = settings.teaching_mode;
+
+        let mut this = Self {
+            original_array: array.clone(),
+            array,
+            states: vec![SelectionState::Normal; len],
+            current: 1,
+            last_kept: 0,
+            removed: 0,
+            phase: StalinPhase::Comparing,
+            state,
+        };
+        this.start();
+
+        this
+    }
+
+    // The first element always survives
+    fn start(&mut self) {
+        if let Some(first) = self.states.first_mut() {
+            *first = SelectionState::Sorted;
+        }
+        if self.array.len() <= 1 {
+            self.phase = StalinPhase::Done;
+            self.state.mark_completed();
+        }
+    }
+
+    /// Elements that survived so far, in order
+    pub fn survivors(&self) -> Vec<T> {
+        self.array
+            .iter()
+            .zip(&self.states)
+            .take(self.current)
+            .filter(|(_, state)| **state != SelectionState::Removed)
+            .map(|(value, _)| *value)
+            .collect()
+    }
+
+    // Keeps the judged elements' colors, only the highlight of the last comparison is cleared
+    fn clear_highlights(&mut self) {
+        for (i, state) in self.states.iter_mut().enumerate() {
+            if *state == SelectionState::Comparing {
+                *state = if i == self.last_kept { SelectionState::Sorted } else { SelectionState::Normal };
+            }
+        }
+    }
+}
+
+impl<T: SortElement> SortVisualizer<T> for StalinSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
+    fn get_states(&self) -> &[SelectionState] { &self.states }
+    fn get_comparisons(&self) -> u32 { self.state.comparisons }
+    fn get_swaps(&self) -> u32 { self.state.swaps }
+    fn get_speed(&self) -> Duration { self.state.speed }
+    fn is_running(&self) -> bool { self.state.is_running }
+    fn is_paused(&self) -> bool { self.state.is_paused }
+    fn is_completed(&self) -> bool { self.state.completed }
+    fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
+    fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
+    fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "StalinSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Stalin Sort",
+            best: "O(n)",
+            average: "O(n)",
+            worst: "O(n)",
+            space: "O(1)",
+            stable: true,
+            in_place: true,
+        }
+    }
+    // Kept elements are sorted among themselves, not in the positions of a real sort
+    fn sorted_regions(&self) -> Vec<Range<usize>> { Vec::new() }
+
+    fn get_progress(&self) -> f64 {
+        let n = self.array.len();
+        if n <= 1 { 100.0 } else {
+            (self.current.min(n) as f64 / n as f64 * 100.0).min(100.0)
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if self.state.completed || self.state.awaiting_question.is_some() {
+            return true;
+        }
+
+        self.clear_highlights();
+
+        match self.phase {
+            StalinPhase::Comparing => {
+                self.states[self.last_kept] = SelectionState::Comparing;
+                self.states[self.current] = SelectionState::Comparing;
+
+                self.phase = if self.compare(self.last_kept, self.current).is_le() {
+                    StalinPhase::Keeping
+                } else {
+                    StalinPhase::Removing
+                };
+                true
+            }
+            StalinPhase::Keeping | StalinPhase::Removing => {
+                if self.phase == StalinPhase::Keeping {
+                    self.states[self.current] = SelectionState::Sorted;
+                    self.last_kept = self.current;
+                } else {
+                    self.states[self.current] = SelectionState::Removed;
+                    self.removed += 1;
+
+                    // Teaching: Ask question after a removal
+                    if self.state.teaching_mode && !self.state.questions.is_empty() {
+                        let q_index = self.removed % self.state.questions.len();
+                        self.state.ask_question(q_index);
+                    }
+                }
+
+                self.current += 1;
+                self.phase = if self.current < self.array.len() { StalinPhase::Comparing } else { StalinPhase::Done };
+                true
+            }
+            StalinPhase::Done => false,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.array = self.original_array.clone();
+        self.states = vec![SelectionState::Normal; self.array.len()];
+        self.current = 1;
+        self.last_kept = 0;
+        self.removed = 0;
+        self.phase = StalinPhase::Comparing;
+        self.state.reset_state();
+        self.start();
+    }
+
+    fn mark_all_sorted(&mut self) {
+        for state in &mut self.states {
+            if *state != SelectionState::Removed {
+                *state = SelectionState::Sorted;
+            }
+        }
+    }
+
+    fn edit_value(&mut self, index: usize, value: T) -> Result<(), String> {
+        if index < self.current {
+            return Err("That element has already been kept or removed".to_string());
+        }
+        self.array[index] = value;
+        Ok(())
+    }
+
+    fn get_title(&self) -> &str {
+        "TOGISOFT STALIN SORT VISUALIZER"
+    }
+
+    fn get_intro_text(&self) -> &str {
+        "What is Stalin Sort?\n\n\
+         JOKE ALGORITHM - an educational curiosity, not a real way to sort!\n\n\
+         Stalin Sort walks the array once and removes every element that is smaller than the last one kept.\n\
+         What survives is in order, but it is usually a much shorter sequence than the input.\n\
+         Removed elements stay on screen in grey so you can see what was lost.\n\n\
+         Advantages: A single O(n) pass.\n\
+         Disadvantages: It loses data, the output is not a permutation of the input.\n\n\
+         Teaching Mode: ON (Toggle with T). Questions will be asked after removals.\n\n\
+         Press any key to continue..."
+    }
+
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
+        vec![
+            ("Normal", SelectionState::Normal),
+            ("Comparing", SelectionState::Comparing),
+            ("Kept", SelectionState::Sorted),
+            ("Removed", SelectionState::Removed),
+        ]
+    }
+
+    fn get_statistics_strings(&self) -> Vec<String> {
+        let phase_str = match self.phase {
+            StalinPhase::Comparing => "Comparing With Last Kept",
+            StalinPhase::Keeping => "Keeping",
+            StalinPhase::Removing => "Removing",
+            StalinPhase::Done => "Done",
+        };
+
+        vec![
+            format!("Array Size: {}", self.array.len()),
+            format!("Comparisons: {}", self.state.comparisons),
+            format!("Kept: {}", self.survivors().len()),
+            format!("Removed: {}", self.removed),
+            format!("Phase: {}", phase_str),
+            format!("Progress: {:.1}%", self.get_progress()),
+            if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
+        ]
+    }
+
+    fn get_current_operation(&self) -> String {
+        if self.state.completed {
+            let survivors: Vec<String> = self.survivors().iter().map(|&value| self.state.value_text(value)).collect();
+            format!("✓ \"Sorted\" by removing {} of {} elements: [{}]",
+                    self.removed,
+                    self.array.len(),
+                    survivors.join(", "))
+        } else {
+            match self.phase {
+                StalinPhase::Comparing => {
+                    format!("Comparing array[{}] ({}) with the last kept array[{}] ({})",
+                            self.current,
+                            self.state.value_text(self.array[self.current]),
+                            self.last_kept,
+                            self.state.value_text(self.array[self.last_kept]))
+                }
+                StalinPhase::Keeping => format!("{} is in order, it stays", self.state.value_text(self.array[self.current])),
+                StalinPhase::Removing => format!("{} is out of order, it is removed", self.state.value_text(self.array[self.current])),
+                StalinPhase::Done => "Every element has been judged".to_string(),
+            }
+        }
+    }
+}
+
+/// Entry point for the stalin sort visualization
+pub fn stalin_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(StalinSortVisualizer::with_settings(array_data, &settings), settings, last_run)
+}
@@ -0,0 +1,198 @@
+use crate::common::array_manager::{ArrayData, StoredArray};
+use crate::common::element::SortElement;
+use crate::common::settings::{MAX_SPEED, MIN_SPEED};
+use crate::factory::{self, AlgorithmEntry};
+use rand::Rng;
+use std::io::{IsTerminal, Read};
+
+const ALGO_FLAG: &str = "--algo";
+const STDIN_FLAG: &str = "--stdin";
+const DEFAULT_SIZE: usize = 20;
+// Same limits as the generate array dialog
+const MIN_SIZE: usize = 2;
+const MAX_SIZE: usize = 50;
+
+/// Where a launch from the command line gets its array
+#[derive(Debug, PartialEq)]
+pub enum ArraySource {
+    Stdin,                                // Numbers piped on standard input
+    Generated { size: usize, seed: u64 }, // Random values, repeatable with the same seed
+}
+
+/// Which visualizer the command line launches directly, skipping the menus
+pub struct LaunchOptions {
+    pub algorithm: &'static AlgorithmEntry, // Visualizer to run
+    pub source: ArraySource,                // Array it runs on
+    pub speed: Option<u64>,                 // Step delay for this run (ms), the saved speed if not given
+}
+
+/// Reads the launch flags from the command line arguments.
+/// Returns `Ok(None)` when neither `--algo` nor `--stdin` is given, so the menus start as usual.
+///
+/// `--algo NAME [--size N] [--seed S] [--speed MS]` runs on a generated array,
+/// `--algo NAME --stdin` on the numbers piped in, e.g. `echo "5 3 8 1" | algorithm_visualizer --algo quicksort --stdin`.
+pub fn options_from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Option<LaunchOptions>, String> {
+    let args: Vec<String> = args.into_iter().collect();
+    let stdin = args.iter().any(|arg| arg == STDIN_FLAG);
+    let mut name = None;
+    let mut size = DEFAULT_SIZE;
+    let mut seed = None;
+    let mut speed = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            ALGO_FLAG => name = Some(args.next().unwrap_or_default()),
+            "--size" => size = parse_value(&arg, args.next())?,
+            "--seed" => seed = Some(parse_value(&arg, args.next())?),
+            "--speed" => speed = Some(parse_value(&arg, args.next())?),
+            _ => {
+                if let Some(value) = arg.strip_prefix("--algo=") {
+                    name = Some(value.to_string());
+                }
+            }
+        }
+    }
+
+    let Some(name) = name else {
+        return if stdin {
+            Err(format!("{} needs {} NAME to choose the visualizer, e.g. {} quicksort", STDIN_FLAG, ALGO_FLAG, ALGO_FLAG))
+        } else {
+            Ok(None)
+        };
+    };
+    let algorithm = factory::find_by_name(&name).ok_or_else(|| {
+        let names: Vec<&str> = factory::all_algorithm_names().collect();
+        format!("Unknown algorithm \"{}\", available algorithms: {}", name, names.join(", "))
+    })?;
+    if !stdin && !(MIN_SIZE..=MAX_SIZE).contains(&size) {
+        return Err(format!("--size must be between {} and {}", MIN_SIZE, MAX_SIZE));
+    }
+    if let Some(speed) = speed.filter(|speed| !(MIN_SPEED..=MAX_SPEED).contains(speed)) {
+        return Err(format!("--speed {} ms is out of range, it must be between {} and {} ms", speed, MIN_SPEED, MAX_SPEED));
+    }
+
+    let source = if stdin {
+        ArraySource::Stdin
+    } else {
+        ArraySource::Generated { size, seed: seed.unwrap_or_else(|| rand::rng().random()) }
+    };
+    Ok(Some(LaunchOptions { algorithm, source, speed }))
+}
+
+fn parse_value<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    value
+        .as_deref()
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| format!("{} needs a non-negative number", flag))
+}
+
+impl LaunchOptions {
+    /// Builds the array to visualize, reading standard input if it was asked for.
+    pub fn load_array(&self) -> Result<StoredArray, String> {
+        match self.source {
+            ArraySource::Stdin => read_array(),
+            ArraySource::Generated { size, seed } => Ok(generate_array(size, seed).into()),
+        }
+    }
+}
+
+/// Random values like the generate array dialog makes, the same seed always gives the same array.
+pub fn generate_array(size: usize, seed: u64) -> ArrayData {
+    ArrayData::generate(size, seed, format!("Seed_{}", seed))
+}
+
+/// Parses whitespace or comma separated numbers into the array to visualize.
+/// A decimal point anywhere makes every value a real number, a minus sign every value a signed integer.
+pub fn parse_array(text: &str) -> Result<StoredArray, String> {
+    if text.contains('.') {
+        Ok(ArrayData::<f64>::new(parse_entries(text, "a non-negative number")?, "Piped".to_string()).into())
+    } else if text.contains('-') {
+        Ok(ArrayData::<i32>::new(parse_entries(text, "an integer")?, "Piped".to_string()).into())
+    } else {
+        Ok(ArrayData::new(parse_entries::<u32>(text, "an integer")?, "Piped".to_string()).into())
+    }
+}
+
+// Every entry of `text` as a value, `kind` names the values in the error
+fn parse_entries<T: SortElement>(text: &str, kind: &str) -> Result<Vec<T>, String> {
+    let mut values = Vec::new();
+    for entry in text.split(|c: char| c == ',' || c.is_whitespace()).filter(|entry| !entry.is_empty()) {
+        let value = T::parse(entry).ok_or_else(|| format!("\"{}\" is not {}", entry, kind))?;
+        values.push(value);
+    }
+    if values.is_empty() {
+        return Err("No numbers were piped in".to_string());
+    }
+    Ok(values)
+}
+
+/// Reads the array from standard input, which has to be a pipe or a file rather than the terminal.
+pub fn read_array() -> Result<StoredArray, String> {
+    let mut stdin = std::io::stdin();
+    if stdin.is_terminal() {
+        return Err(format!("{} expects the numbers on a pipe, e.g. echo \"5 3 8 1\" | algorithm_visualizer {} quicksort {}", STDIN_FLAG, ALGO_FLAG, STDIN_FLAG));
+    }
+    let mut text = String::new();
+    stdin
+        .read_to_string(&mut text)
+        .map_err(|error| format!("Could not read standard input: {}", error))?;
+    parse_array(&text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    // The values of an array that has to be integers
+    fn integers(array: StoredArray) -> Vec<u32> {
+        match array {
+            StoredArray::Integers(array_data) => array_data.data,
+            StoredArray::Signed(array_data) => panic!("{:?} parsed as signed integers", array_data.data),
+            StoredArray::Reals(array_data) => panic!("{:?} parsed as real numbers", array_data.data),
+        }
+    }
+
+    #[test]
+    fn stdin_flags_name_the_algorithm() {
+        let options = options_from_args(args(&["--algo", "quicksort", "--stdin"])).unwrap().unwrap();
+        assert_eq!(options.algorithm.settings_key, "QuickSort");
+        assert_eq!(options.source, ArraySource::Stdin);
+        let options = options_from_args(args(&["--stdin", "--algo=Bubble-Sort"])).unwrap().unwrap();
+        assert_eq!(options.algorithm.settings_key, "BubbleSort");
+        assert!(options_from_args(args(&["--stdin"])).is_err());
+        assert!(options_from_args(args(&["--stdin", "--algo", "sleepsort"])).is_err());
+    }
+
+    #[test]
+    fn launch_flags_configure_a_generated_array() {
+        assert!(options_from_args(args(&["--verify"])).unwrap().is_none());
+        let options = options_from_args(args(&["--algo", "shellsort", "--size", "30", "--seed", "42", "--speed", "300"]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(options.algorithm.settings_key, "ShellSort");
+        assert_eq!(options.source, ArraySource::Generated { size: 30, seed: 42 });
+        assert_eq!(options.speed, Some(300));
+        assert_eq!(integers(options.load_array().unwrap()), generate_array(30, 42).data);
+
+        assert!(options_from_args(args(&["--algo", "shell", "--size", "500"])).is_err());
+        assert!(options_from_args(args(&["--algo", "shell", "--speed", "0"])).is_err());
+        let error = options_from_args(args(&["--algo", "slowsort"])).err().unwrap();
+        assert!(error.contains("ShellSort"), "{}", error);
+    }
+
+    #[test]
+    fn piped_text_parses_into_an_array() {
+        assert_eq!(integers(parse_array("5 3 8 1\n").unwrap()), vec![5, 3, 8, 1]);
+        assert_eq!(integers(parse_array("5,3, 8\t1").unwrap()), vec![5, 3, 8, 1]);
+        assert_eq!(parse_array("5 x 1").unwrap_err(), "\"x\" is not an integer");
+        assert!(matches!(parse_array("5 -3 0"), Ok(StoredArray::Signed(array_data)) if array_data.data == vec![5, -3, 0]));
+        assert!(parse_array(" \n").is_err());
+        assert!(matches!(parse_array("2.5 1"), Ok(StoredArray::Reals(array_data)) if array_data.data == vec![2.5, 1.0]));
+        assert_eq!(parse_array("2.5 1.2345").unwrap_err(), "\"1.2345\" is not a non-negative number");
+    }
+}
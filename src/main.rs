@@ -1,26 +1,78 @@
 mod sort_algorithms;
 mod common;
 mod search_algorithms;
+mod joke_algorithms;
+mod puzzles;
+mod benchmark;
+mod factory;
+mod glossary;
+mod size_sweep;
+mod launch;
+mod practice;
+mod stability;
 
 // Import specific functions from modules
 use crate::welcome_banner::print_welcome_banner;
 use std::error::Error;
 use crate::common::*;
-use crate::search_algorithms::{binary_search_visualization, linear_search_visualization};
-use crate::sort_algorithms::*;
-use crate::sort_algorithms::counting_sort::counting_sort_visualization;
 
 /// Main entry point for the algorithm visualizer application
 ///
 /// This function:
 /// 1. Displays a welcome banner
 /// 2. Creates an array manager to track arrays
-/// 3. Enters a main loop that displays a menu and processes user selections
-/// 4. Exits when the user selects the exit option
+/// 3. Runs the onboarding tour on the first start
+/// 4. Enters a main loop that displays a menu and processes user selections
+/// 5. Exits when the user selects the exit option
 fn main() -> Result<(), Box<dyn Error>> {
 
-    // Load settings
+    // Settings and exports go to --config-dir if given
+    if let Some(dir) = paths::config_dir_from_args(std::env::args().skip(1)) {
+        paths::set_config_dir(dir);
+    }
+
+    // --verify pauses every run where an element marked sorted is out of place
+    if verifier::verify_from_args(std::env::args().skip(1)) {
+        verifier::enable();
+    }
+
+    // --benchmark prints statistics of headless runs instead of starting the interface
+    // It runs on the default settings and leaves the settings file alone
+    if let Some(options) = benchmark::options_from_args(std::env::args().skip(1))? {
+        settings::keep_in_memory(Settings::default());
+        benchmark::run_and_report(&options)?;
+        return Ok(());
+    }
+
+    // --algo NAME visualizes a generated or piped array directly, without the banner or menu
+    let launch = launch::options_from_args(std::env::args().skip(1))
+        .and_then(|options| options.map(|options| options.load_array().map(|array| (options, array))).transpose());
+    let launch = match launch {
+        Ok(launch) => launch,
+        Err(message) => {
+            eprintln!("Error: {}", message);
+            std::process::exit(2);
+        }
+    };
+    // A speed given on the command line applies to this run only, so nothing is saved
+    if let Some((options, _)) = &launch
+        && let Some(speed) = options.speed
+    {
+        let mut settings = Settings::load();
+        settings.algorithm_speeds.insert(options.algorithm.settings_key.to_string(), speed);
+        settings::keep_in_memory(settings);
+    }
+
+    // Every screen from the banner on uses the configured terminal and color themes
     let mut settings = Settings::load();
+    theme::apply(settings.terminal_theme);
+    theme::apply_colors(settings.theme);
+
+    if let Some((options, array_data)) = launch {
+        // A suspended run has nowhere to resume, so it ends with the process
+        factory::visualize(options.algorithm, &array_data, &mut None);
+        return Ok(());
+    }
 
     // Display the welcome banner
     print_welcome_banner();
@@ -28,10 +80,28 @@ fn main() -> Result<(), Box<dyn Error>> {
     // Create an array manager to track and manage arrays
     let mut array_manager = ArrayManager::new();
 
+    // First run: guide the user through creating and selecting an array
+    let mut guided_option = None;
+    if !settings.onboarding_done {
+        if onboarding::run_onboarding(&mut array_manager) {
+            guided_option = Some(onboarding::ONBOARDING_ALGORITHM_OPTION);
+        }
+        settings.onboarding_done = true;
+        settings.save();
+    }
+
+    // A run suspended with Z, resumed from the main menu
+    let mut suspended: Option<runner::SuspendedRun> = None;
+    // The last completed run, its counters are compared on the next completion screen
+    let mut last_run: Option<runner::RunSummary> = None;
+
     // Main application loop
     loop {
         // Display the menu and get user selection
-        let selection = print_menu_banner();
+        let selection = match guided_option.take() {
+            Some(option_id) => print_menu_banner_at(option_id),
+            None => print_menu_banner(),
+        };
 
         // Process the user's selection
         match selection {
@@ -39,71 +109,52 @@ fn main() -> Result<(), Box<dyn Error>> {
                 // Array Management: Create, select, view, or delete arrays
                 array_management_screen(&mut array_manager);
             },
-            2 => {
-                // Bubble Sort: Visualize the bubble sort algorithm
-                run_sort(&mut array_manager, |array| linear_search_visualization(array));
-            },
-            3 => {
-                // Selection Sort: Visualize the selection sort algorithm
-                run_sort(&mut array_manager, |array| binary_search_visualization(array));
-            },
-            4 => {
-                run_sort(&mut array_manager, |array| bubble_sort_visualization(array));
-            },
-            5 => {
-                run_sort(&mut array_manager, |array| bucket_sort_visualization(array));
-            },
-            6 => {
-                run_sort(&mut array_manager, |array| cocktail_sort_visualization(array));
-            },
-            7 => {
-                run_sort(&mut array_manager, |array| comb_sort_visualization(array));
-            },
-            8 => {
-                run_sort(&mut array_manager, |array| counting_sort_visualization(array));
-            },
-            9 => {
-                run_sort(&mut array_manager, |array| gnome_sort_visualization(array));
-            },
-            10 => {
-                run_sort(&mut array_manager, |array| heap_sort_visualization(array));
-            },
-            11 => {
-               run_sort(&mut array_manager, |array| insertion_sort_visualization(array));
-            },
-            12 => {
-                run_sort(&mut array_manager, |array| merge_sort_visualization(array));
-            },
-            13 => {
-                run_sort(&mut array_manager, |array| pancake_sort_visualization(array));
+            31 => {
+                // Settings: Show and modify settings, every change is saved immediately.
+                // Loaded fresh so values saved by visualizers or the menu are not overwritten.
+                Settings::show_settings_menu(Settings::load());
             },
-            14 => {
-                run_sort(&mut array_manager, |array| quick_sort_visualization(array));
+            32 => {
+                // Glossary: Browse every algorithm's intro, questions and explanations
+                glossary::show_glossary();
             },
-            15 => {
-                run_sort(&mut array_manager, |array| radix_sort_visualization(array));
+            34 => {
+                // Size Sweep: Chart comparisons against n for one or two algorithms
+                size_sweep::show_size_sweep();
             },
-            16 => {
-                run_sort(&mut array_manager, |array| selection_sort_visualization(array));
+            35 => {
+                // Run History: Browse every completed run, kept across sessions
+                history::show_history();
             },
-            17 => {
-                run_sort(&mut array_manager, |array| shell_sort_visualization(array));
+            36 => {
+                // Practice Mode: Guess which sort a nameless visualization runs
+                practice::show_practice();
             },
-            18 => {
-                run_sort(&mut array_manager, |array| tim_sort_visualization(array));
+            33 => {
+                // Resume: Continue the suspended run exactly where it stopped
+                match suspended.take() {
+                    Some(run) => suspended = run.resume(&mut last_run),
+                    None => dialog::show_info("Resume Suspended Run", "There is no suspended run.\n\nPress Z inside a visualizer to suspend it."),
+                }
             },
-            31 => {
-                // Settings: Show and modify settings
-                let updated_settings = Settings::show_settings_menu(settings.clone());
-                settings = updated_settings;
-                settings.save(); // Save immediately after changes
+            37 => {
+                // Stability Report: Sort tagged duplicates with every sort and see which keep their order
+                stability::show_stability_report();
             },
             99 => {
                 // Exit the application
-                settings.save(); // Save settings on exit
                 break;
             }
-            _ => {
+            id => {
+                // Algorithms: look up the visualizer registered for this menu option, a sort range limits sorts to a slice
+                // A newly suspended run replaces the previous one
+                if let Some(algorithm) = factory::find_by_menu_id(id) {
+                    run_sort(&mut array_manager, |array| {
+                        if let Some(visualizer) = factory::visualize(algorithm, array, &mut last_run) {
+                            suspended = Some(visualizer);
+                        }
+                    });
+                }
                 // Ignore invalid selections
             }
         }
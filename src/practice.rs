@@ -0,0 +1,210 @@
+use crate::common::array_manager::ArrayData;
+use crate::common::runner::run_visualizer;
+use crate::common::settings::Settings;
+use crate::common::theme;
+use crate::factory::{AlgorithmEntry, ALGORITHMS};
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{poll, read, Event, KeyCode, KeyEventKind},
+    execute,
+    style::{Attribute, Color, Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor},
+    terminal::{disable_raw_mode, enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+    QueueableCommand,
+};
+use rand::Rng;
+use std::io::{stdout, Write};
+use std::time::Duration;
+
+// Elements of a practice array, enough for the pattern of each algorithm to show
+const PRACTICE_SIZE: usize = 24;
+// Sorts that may never finish or do not sort are left out of the quiz
+const EXCLUDED: &[&str] = &["BogoSort", "StalinSort"];
+
+/// Algorithms a practice round can pick, the same list the guess is made from
+pub fn practice_candidates() -> Vec<&'static AlgorithmEntry> {
+    ALGORITHMS
+        .iter()
+        .filter(|entry| entry.settings_key.ends_with("Sort") && !EXCLUDED.contains(&entry.settings_key))
+        .collect()
+}
+
+/// e.g. "7 of 10 correct (70%)"
+pub fn accuracy_text(correct: u32, guesses: u32) -> String {
+    if guesses == 0 {
+        return "No guesses yet".to_string();
+    }
+    format!("{} of {} correct ({:.0}%)", correct, guesses, correct as f64 / guesses as f64 * 100.0)
+}
+
+/// Runs practice rounds until ESC: a random sort runs without its name, then the user guesses which one it was.
+/// The accuracy over every round is kept in the settings.
+pub fn show_practice() {
+    let candidates = practice_candidates();
+    let sample = ArrayData::new(vec![2, 1], "Names".to_string());
+    let names: Vec<String> = candidates
+        .iter()
+        .map(|entry| (entry.create)(&sample, &Settings::default()).display_name())
+        .collect();
+
+    loop {
+        // --- Run the mystery algorithm ---
+        let answer = rand::rng().random_range(0..candidates.len());
+        let seed = rand::rng().random();
+        let array_data = ArrayData::generate(PRACTICE_SIZE, seed, "Practice".to_string());
+        let settings = Settings::load();
+        let mut visualizer = (candidates[answer].create)(&array_data, &settings);
+        visualizer.state_mut().practice = true;
+        visualizer.state_mut().seed = Some(seed);
+        // Practice runs are not compared with the run before
+        run_visualizer(visualizer.as_mut(), settings, &mut None);
+
+        // --- Guess, then reveal ---
+        let Some(guess) = pick_guess(&names) else {
+            return;
+        };
+        // Loaded after the run, which may have saved settings of its own
+        let mut settings = Settings::load();
+        settings.practice_guesses += 1;
+        if guess == answer {
+            settings.practice_correct += 1;
+        }
+        settings.save();
+        if !show_reveal(&names[answer], &names[guess], accuracy_text(settings.practice_correct, settings.practice_guesses)) {
+            return;
+        }
+    }
+}
+
+// Draws the screen title centered on the second row
+fn draw_title(stdout: &mut std::io::Stdout, width: u16, title: &str) {
+    stdout.queue(MoveTo(width.saturating_sub(title.chars().count() as u16) / 2, 1)).unwrap();
+    stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
+    stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+    stdout.queue(SetBackgroundColor(Color::DarkBlue)).unwrap();
+    stdout.queue(Print(title)).unwrap();
+    stdout.queue(ResetColor).unwrap();
+}
+
+// Draws the instructions centered above the bottom row
+fn draw_instructions(stdout: &mut std::io::Stdout, width: u16, height: u16, instructions: &str) {
+    stdout.queue(MoveTo(width.saturating_sub(instructions.chars().count() as u16) / 2, height.saturating_sub(2))).unwrap();
+    stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+    stdout.queue(Print(instructions)).unwrap();
+    stdout.queue(ResetColor).unwrap();
+}
+
+// Waits for the next key press, None until one arrives
+fn next_key() -> Option<KeyCode> {
+    if !poll(Duration::from_millis(100)).unwrap_or(false) {
+        return None;
+    }
+    match read() {
+        Ok(Event::Key(key_event)) if key_event.kind == KeyEventKind::Press => Some(key_event.code),
+        _ => None,
+    }
+}
+
+// Lists the algorithms to guess from, returns the picked one or None on ESC
+fn pick_guess(names: &[String]) -> Option<usize> {
+    let mut stdout = stdout();
+    enable_raw_mode().unwrap();
+    execute!(stdout, EnterAlternateScreen, Hide).unwrap();
+    let mut selected = 0usize;
+
+    let guess = loop {
+        let (width, height) = size().unwrap_or((80, 24));
+        stdout.queue(Clear(ClearType::All)).unwrap();
+        draw_title(&mut stdout, width, " 🎓 WHICH ALGORITHM WAS IT? ");
+
+        // Two columns keep the list on a small terminal
+        let rows = names.len().div_ceil(2);
+        for (i, name) in names.iter().enumerate() {
+            let x = if i < rows { 4 } else { 34 };
+            stdout.queue(MoveTo(x, 4 + (i % rows) as u16)).unwrap();
+            if i == selected {
+                stdout.queue(SetForegroundColor(Color::Black)).unwrap();
+                stdout.queue(SetBackgroundColor(Color::Cyan)).unwrap();
+                stdout.queue(Print(format!("▶ {:<24}", name))).unwrap();
+            } else {
+                stdout.queue(SetForegroundColor(theme::text())).unwrap();
+                stdout.queue(Print(format!("  {:<24}", name))).unwrap();
+            }
+            stdout.queue(ResetColor).unwrap();
+        }
+
+        draw_instructions(&mut stdout, width, height, "↑/↓ ←/→: Choose | ENTER: Guess | ESC: Back");
+        stdout.flush().unwrap();
+
+        match next_key() {
+            Some(KeyCode::Up) => selected = (selected + names.len() - 1) % names.len(),
+            Some(KeyCode::Down) => selected = (selected + 1) % names.len(),
+            Some(KeyCode::Left) | Some(KeyCode::Right) => selected = (selected + rows) % (rows * 2),
+            Some(KeyCode::Enter) => break Some(selected),
+            Some(KeyCode::Esc) => break None,
+            _ => {}
+        }
+        selected = selected.min(names.len() - 1);
+    };
+
+    execute!(stdout, ResetColor, Show, LeaveAlternateScreen).unwrap();
+    disable_raw_mode().unwrap();
+    guess
+}
+
+// Names the algorithm that ran next to the guess, returns true for another round
+fn show_reveal(answer: &str, guess: &str, accuracy: String) -> bool {
+    let mut stdout = stdout();
+    enable_raw_mode().unwrap();
+    execute!(stdout, EnterAlternateScreen, Hide).unwrap();
+
+    let again = loop {
+        let (width, height) = size().unwrap_or((80, 24));
+        stdout.queue(Clear(ClearType::All)).unwrap();
+        draw_title(&mut stdout, width, " 🎓 PRACTICE MODE ");
+
+        let (verdict, color) = if answer == guess {
+            (format!("✓ Correct, it was {}!", answer), Color::Green)
+        } else {
+            (format!("✗ It was {}, you guessed {}", answer, guess), Color::Red)
+        };
+        stdout.queue(MoveTo(width.saturating_sub(verdict.chars().count() as u16) / 2, 5)).unwrap();
+        stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
+        stdout.queue(SetForegroundColor(color)).unwrap();
+        stdout.queue(Print(&verdict)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        let accuracy = format!("Accuracy: {}", accuracy);
+        stdout.queue(MoveTo(width.saturating_sub(accuracy.chars().count() as u16) / 2, 7)).unwrap();
+        stdout.queue(SetForegroundColor(theme::text())).unwrap();
+        stdout.queue(Print(&accuracy)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        draw_instructions(&mut stdout, width, height, "ENTER: Next Round | ESC: Back");
+        stdout.flush().unwrap();
+
+        match next_key() {
+            Some(KeyCode::Enter) => break true,
+            Some(KeyCode::Esc) => break false,
+            _ => {}
+        }
+    };
+
+    execute!(stdout, ResetColor, Show, LeaveAlternateScreen).unwrap();
+    disable_raw_mode().unwrap();
+    again
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn practice_picks_from_sorts_that_finish() {
+        let candidates = practice_candidates();
+        assert!(candidates.iter().any(|entry| entry.settings_key == "QuickSort"));
+        assert!(candidates.iter().all(|entry| entry.settings_key.ends_with("Sort")));
+        assert!(!candidates.iter().any(|entry| EXCLUDED.contains(&entry.settings_key)));
+        assert_eq!(accuracy_text(0, 0), "No guesses yet");
+        assert_eq!(accuracy_text(7, 10), "7 of 10 correct (70%)");
+    }
+}
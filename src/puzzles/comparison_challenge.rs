@@ -0,0 +1,364 @@
+use crate::common::algorithm_info::AlgorithmInfo;
+use crate::common::array_manager::ArrayData;
+use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
+use crate::common::enums::{SelectionState, TeachingQuestion};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
+use crate::common::settings::Settings;
+use crossterm::event::KeyCode;
+use rand::seq::SliceRandom;
+use std::cmp::Ordering;
+use std::time::Duration;
+
+// Largest array the challenge uses, longer arrays are cut to their first elements
+pub const MAX_CHALLENGE_SIZE: usize = 8;
+
+/// The last comparison the player made, remembered so its pair can be swapped
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Comparison {
+    pub left: usize,        // Lower index of the compared pair
+    pub right: usize,       // Higher index of the compared pair
+    pub ordering: Ordering, // array[left] compared with array[right]
+}
+
+/// A puzzle where the player sorts hidden values by choosing every comparison and swap
+#[derive(Clone)]
+pub struct ComparisonChallengeVisualizer {
+    array: Vec<u32>,             // Current state of the array, hidden until solved
+    original_array: Vec<u32>,    // Original array, used for resetting
+    states: Vec<SelectionState>, // Visual state of each element (cursor, picked, compared, sorted)
+
+    // Challenge specific fields
+    cursor: usize,                      // Element the arrow keys point at
+    picked: Option<usize>,              // First element of the next comparison
+    last_comparison: Option<Comparison>, // Pair that may be swapped next
+    wrong_claims: u32,                  // Times D was pressed while the array was not sorted yet
+    message: String,                    // Outcome of the last key, shown as the operation
+    state: VisualizerState,             // Common visualization state
+}
+
+impl ComparisonChallengeVisualizer {
+    /// Creates a new challenge from the first values of the given array
+    pub fn with_settings(array_data: &ArrayData, settings: &Settings) -> Self {
+        let mut array: Vec<u32> = array_data.data.iter().copied().take(MAX_CHALLENGE_SIZE).collect();
+        // A sorted array would be solved before the first comparison
+        if array.is_sorted() && array.first() != array.last() {
+            while array.is_sorted() {
+                array.shuffle(&mut rand::rng());
+            }
+        }
+        let len = array.len();
+
+        let questions = vec![
+            TeachingQuestion {
+                text: "Why can no comparison sort beat about log2(n!) comparisons in the worst case?".to_string(),
+                options: vec![
+                    "Each comparison at most halves the n! possible orders that are left".to_string(),
+                    "Every element has to be compared with every other one".to_string(),
+                    "Swaps are more expensive than comparisons".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "A comparison has two outcomes, so k comparisons can tell at most 2^k orders apart. Telling all n! orders apart needs 2^k >= n!, that is k >= log2(n!).".to_string(),
+            },
+            TeachingQuestion {
+                text: "Which comparison tells you the most?".to_string(),
+                options: vec![
+                    "One whose outcome you cannot predict from earlier comparisons".to_string(),
+                    "One between two elements you compared before".to_string(),
+                    "One between neighbours that are already in order".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "A comparison whose outcome already follows from what you know rules out no orders at all, it is a wasted comparison.".to_string(),
+            },
+            TeachingQuestion {
+                text: "Why does the challenge only let you swap a pair you just compared?".to_string(),
+                options: vec![
+                    "A sort may only act on what its comparisons told it".to_string(),
+                    "Swapping is slow".to_string(),
+                    "Only neighbours may ever be swapped".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "The values are hidden like they are to a comparison sort, the only way to learn their order is to compare them.".to_string(),
+            },
+        ];
+
+        randomize_questions(questions.clone());
+
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("ComparisonChallenge")));
+        state.teaching_mode = settings.teaching_mode;
+
+        let mut this = Self {
+            original_array: array.clone(),
+            array,
+            states: vec![SelectionState::Normal; len],
+            cursor: 0,
+            picked: None,
+            last_comparison: None,
+            wrong_claims: 0,
+            message: String::new(),
+            state,
+        };
+        this.start();
+
+        this
+    }
+
+    // Hides the values and points the cursor at the first element
+    fn start(&mut self) {
+        self.state.hide_values = true;
+        self.message = format!("Pick two elements with ENTER to compare them, target: {} comparisons", self.target());
+        if self.array.len() <= 1 {
+            self.solve();
+        }
+        self.refresh_states();
+    }
+
+    /// Fewest comparisons that can sort every order of this many elements, ⌈log2(n!)⌉
+    pub fn target(&self) -> u32 {
+        let log2_factorial: f64 = (2..=self.array.len()).map(|i| (i as f64).log2()).sum();
+        // Rounding noise must not push an exact power of two to the next integer
+        (log2_factorial - 1e-9).ceil().max(0.0) as u32
+    }
+
+    // The array is sorted, reveal the values
+    fn solve(&mut self) {
+        self.state.hide_values = false;
+        self.picked = None;
+        self.last_comparison = None;
+        self.state.mark_completed();
+        self.mark_all_sorted();
+    }
+
+    // Colors the cursor, the picked element and the last compared pair
+    fn refresh_states(&mut self) {
+        if self.state.completed {
+            return;
+        }
+        self.states.fill(SelectionState::Normal);
+        if let Some(comparison) = self.last_comparison {
+            self.states[comparison.left] = SelectionState::Comparing;
+            self.states[comparison.right] = SelectionState::Comparing;
+        }
+        if let Some(picked) = self.picked {
+            self.states[picked] = SelectionState::CurrentMin;
+        }
+        if let Some(cursor) = self.states.get_mut(self.cursor) {
+            *cursor = SelectionState::Selected;
+        }
+    }
+
+    // ENTER: picks the element under the cursor, or compares it with the picked one
+    fn pick(&mut self) {
+        match self.picked {
+            None => {
+                self.picked = Some(self.cursor);
+                self.message = format!("Picked array[{}], move to another element and press ENTER to compare", self.cursor);
+            }
+            Some(picked) if picked == self.cursor => {
+                self.picked = None;
+                self.message = "Pick cancelled".to_string();
+            }
+            Some(picked) => {
+                let (left, right) = (picked.min(self.cursor), picked.max(self.cursor));
+                let ordering = self.compare(left, right);
+                self.picked = None;
+                self.last_comparison = Some(Comparison { left, right, ordering });
+                let relation = match ordering {
+                    Ordering::Less => "<",
+                    Ordering::Equal => "=",
+                    Ordering::Greater => ">",
+                };
+                self.message = format!("array[{}] {} array[{}]{}", left, relation, right,
+                                       if ordering.is_gt() { ", M swaps them" } else { "" });
+            }
+        }
+    }
+
+    // M: swaps the last compared pair, only if the comparison showed it out of order
+    fn swap_compared(&mut self) {
+        match self.last_comparison {
+            None => {
+                self.message = "Compare a pair first, only a compared pair can be swapped".to_string();
+            }
+            Some(comparison) if !comparison.ordering.is_gt() => {
+                self.message = format!("array[{}] and array[{}] are already in order, swapping them would not help",
+                                       comparison.left, comparison.right);
+            }
+            Some(comparison) => {
+                self.array.swap(comparison.left, comparison.right);
+                self.state.swaps += 1;
+                self.last_comparison = None;
+                self.message = format!("Swapped array[{}] and array[{}]", comparison.left, comparison.right);
+
+                // Teaching: Ask question after a swap
+                if self.state.teaching_mode && !self.state.questions.is_empty() {
+                    let q_index = self.state.swaps as usize % self.state.questions.len();
+                    self.state.ask_question(q_index);
+                }
+            }
+        }
+    }
+
+    // D: the player claims the array is sorted
+    fn claim_sorted(&mut self) {
+        if self.array.is_sorted() {
+            self.solve();
+        } else {
+            self.wrong_claims += 1;
+            self.message = "Not sorted yet, some pair is still out of order".to_string();
+        }
+    }
+}
+
+impl SortVisualizer for ComparisonChallengeVisualizer {
+    fn get_array(&self) -> &[u32] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [u32] { &mut self.array }
+    fn get_original_array(&self) -> &[u32] { &self.original_array }
+    fn get_states(&self) -> &[SelectionState] { &self.states }
+    fn get_comparisons(&self) -> u32 { self.state.comparisons }
+    fn get_swaps(&self) -> u32 { self.state.swaps }
+    fn get_speed(&self) -> Duration { self.state.speed }
+    fn is_running(&self) -> bool { self.state.is_running }
+    fn is_paused(&self) -> bool { self.state.is_paused }
+    fn is_completed(&self) -> bool { self.state.completed }
+    fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
+    fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
+    fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState { &mut self.state }
+    fn settings_key(&self) -> &str { "ComparisonChallenge" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Comparison Challenge",
+            best: "n - 1 comparisons",
+            average: "O(n log n)",
+            worst: "ceil(log2(n!)) at best",
+            space: "O(1)",
+            stable: false,
+            in_place: true,
+        }
+    }
+    // The order of the hidden values is what the player has to find out
+    fn supports_custom_order(&self) -> bool { false }
+
+    fn handle_extra_input(&mut self, key_code: KeyCode) -> bool {
+        if self.state.completed {
+            return false;
+        }
+        match key_code {
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(self.array.len().saturating_sub(1)),
+            KeyCode::Enter => self.pick(),
+            KeyCode::Char('m') | KeyCode::Char('M') => self.swap_compared(),
+            KeyCode::Char('d') | KeyCode::Char('D') => self.claim_sorted(),
+            _ => return false,
+        }
+        self.refresh_states();
+        true
+    }
+
+    fn get_progress(&self) -> f64 {
+        // Anything more would give the hidden order away
+        if self.state.completed { 100.0 } else { 0.0 }
+    }
+
+    fn step(&mut self) -> bool {
+        // Nothing happens on its own, every move comes from the keyboard
+        !self.state.completed
+    }
+
+    fn reset(&mut self) {
+        self.array = self.original_array.clone();
+        self.states = vec![SelectionState::Normal; self.array.len()];
+        self.cursor = 0;
+        self.picked = None;
+        self.last_comparison = None;
+        self.wrong_claims = 0;
+        self.state.reset_state();
+        self.start();
+    }
+
+    fn mark_all_sorted(&mut self) {
+        for state in &mut self.states {
+            *state = SelectionState::Sorted;
+        }
+    }
+
+    fn edit_value(&mut self, _index: usize, _value: u32) -> Result<(), String> {
+        Err("Values can't be edited during the challenge".to_string())
+    }
+
+    fn get_title(&self) -> &str {
+        "TOGISOFT COMPARISON CHALLENGE VISUALIZER"
+    }
+
+    fn get_intro_text(&self) -> &str {
+        "What is the Comparison Challenge?\n\n\
+         Sort up to 8 hidden values yourself, using as few comparisons as you can.\n\
+         Pick two elements to compare them, then swap the pair if it is out of order.\n\
+         Press D once you are sure the array is sorted, the values are revealed when it is.\n\n\
+         Target: ⌈log2(n!)⌉ comparisons, the fewest any comparison sort needs in the worst case.\n\n\
+         Teaching Mode: ON (Toggle with T). Questions will be asked after swaps.\n\n\
+         Press any key to continue..."
+    }
+
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
+        vec![
+            ("Hidden", SelectionState::Normal),
+            ("Cursor", SelectionState::Selected),
+            ("Picked", SelectionState::CurrentMin),
+            ("Compared", SelectionState::Comparing),
+            ("Sorted", SelectionState::Sorted),
+        ]
+    }
+
+    fn get_statistics_strings(&self) -> Vec<String> {
+        vec![
+            format!("Array Size: {}", self.array.len()),
+            format!("Comparisons: {}", self.state.comparisons),
+            format!("Target: {} comparisons", self.target()),
+            format!("Swaps: {}", self.state.swaps),
+            format!("Wrong Claims: {}", self.wrong_claims),
+            if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
+        ]
+    }
+
+    fn get_current_operation(&self) -> String {
+        if !self.state.completed {
+            return self.message.clone();
+        }
+        let comparisons = self.state.comparisons;
+        let target = self.target();
+        match comparisons.cmp(&target) {
+            Ordering::Greater => format!("✓ Sorted with {} comparisons, {} more than the target of {}",
+                                         comparisons, comparisons - target, target),
+            _ => format!("✓ Sorted with {} comparisons, within the target of {}!", comparisons, target),
+        }
+    }
+
+    fn get_status(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "WAITING FOR QUESTION"
+        } else if self.state.completed {
+            "SOLVED!"
+        } else {
+            "YOUR MOVE"
+        }
+    }
+
+    fn get_controls_text(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "1,2,3: Answer | ESC: Exit"
+        } else if self.state.completed {
+            "SPACE: Play Again | R: Reset | T: Teaching Toggle | ESC: Exit"
+        } else {
+            "←/→: Move | ENTER: Pick/Compare | M: Swap Compared | D: Done | R: Reset | T: Teaching | ESC: Exit"
+        }
+    }
+}
+
+/// Entry point for the comparison challenge
+pub fn comparison_challenge_visualization(array_data: &ArrayData, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer>> {
+    let settings = Settings::load();
+    run_suspendable(ComparisonChallengeVisualizer::with_settings(array_data, &settings), settings, last_run)
+}
@@ -0,0 +1,90 @@
+pub mod comparison_challenge;
+pub mod pick_order_game;
+
+pub use comparison_challenge::*;
+pub use pick_order_game::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::array_manager::ArrayData;
+    use crate::common::base_visualizer::SortVisualizer;
+    use crate::common::settings::Settings;
+    use crossterm::event::KeyCode;
+
+    fn press(challenge: &mut ComparisonChallengeVisualizer, keys: &[KeyCode]) {
+        for &key in keys {
+            assert!(challenge.handle_extra_input(key));
+        }
+    }
+
+    fn press_game(game: &mut PickOrderGameVisualizer, keys: &[KeyCode]) {
+        for &key in keys {
+            assert!(game.handle_extra_input(key));
+        }
+    }
+
+    #[test]
+    fn target_is_the_information_theoretic_bound() {
+        let target = |size: usize| {
+            let array_data = ArrayData::new((1..=size as u32).rev().collect(), "Target".to_string());
+            ComparisonChallengeVisualizer::with_settings(&array_data, &Settings::default()).target()
+        };
+        assert_eq!(target(2), 1);
+        assert_eq!(target(4), 5); // log2(24) ≈ 4.58
+        assert_eq!(target(5), 7); // log2(120) ≈ 6.91
+        assert_eq!(target(20), 16); // cut to 8 elements, log2(40320) ≈ 15.3
+    }
+
+    #[test]
+    fn only_a_compared_out_of_order_pair_can_be_swapped() {
+        let array_data = ArrayData::new(vec![2, 1, 3], "Challenge".to_string());
+        let mut challenge = ComparisonChallengeVisualizer::with_settings(&array_data, &Settings::default());
+        challenge.state_mut().teaching_mode = false;
+        assert!(challenge.state().hide_values);
+
+        // Swapping before comparing is refused
+        press(&mut challenge, &[KeyCode::Char('m')]);
+        assert_eq!(challenge.get_array(), &[2, 1, 3]);
+
+        // 2 > 1, so the pair may be swapped
+        press(&mut challenge, &[KeyCode::Enter, KeyCode::Right, KeyCode::Enter, KeyCode::Char('m')]);
+        assert_eq!(challenge.get_array(), &[1, 2, 3]);
+        assert_eq!(challenge.state().comparisons, 1);
+
+        // 2 < 3 is already in order
+        press(&mut challenge, &[KeyCode::Enter, KeyCode::Right, KeyCode::Enter, KeyCode::Char('m')]);
+        assert_eq!(challenge.get_array(), &[1, 2, 3]);
+
+        press(&mut challenge, &[KeyCode::Char('d')]);
+        assert!(challenge.state().completed);
+        assert!(!challenge.state().hide_values);
+    }
+
+    #[test]
+    fn pick_order_guess_is_scored_against_selection_sort() {
+        let array_data = ArrayData::new(vec![30, 10, 20], "Picks".to_string());
+        let mut game = PickOrderGameVisualizer::with_settings(&array_data, &Settings::default());
+        game.state_mut().teaching_mode = false;
+
+        // Guess #1 (10) first, then #2 (20), the last pick #0 is filled in
+        assert!(game.handle_extra_input(KeyCode::Right));
+        assert!(game.handle_extra_input(KeyCode::Enter));
+        assert!(game.has_extra_state());
+        assert!(game.handle_extra_input(KeyCode::Right));
+        assert!(game.handle_extra_input(KeyCode::Enter));
+        assert!(!game.has_extra_state());
+        assert!(game.state().is_running);
+
+        while game.step() {}
+        assert_eq!(game.get_array(), &[10, 20, 30]);
+        assert_eq!(game.score(), 3);
+
+        // A second round guessing #0 first misses every pick
+        game.reset();
+        press_game(&mut game, &[KeyCode::Enter, KeyCode::Right, KeyCode::Enter]);
+        while game.step() {}
+        assert_eq!(game.score(), 0);
+        assert!(game.get_statistics_strings().contains(&"Best Score: 3 / 3".to_string()));
+    }
+}
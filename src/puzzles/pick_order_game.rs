@@ -0,0 +1,469 @@
+use crate::common::algorithm_info::AlgorithmInfo;
+use crate::common::array_manager::ArrayData;
+use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
+use crate::common::common_visualizer::VisualizerDrawer;
+use crate::common::enums::{SelectionState, TeachingQuestion};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
+use crate::common::settings::Settings;
+use crossterm::event::KeyCode;
+use rand::seq::SliceRandom;
+use std::ops::Range;
+use std::time::Duration;
+
+// Largest array the game uses, longer arrays are cut to their first elements
+pub const MAX_GAME_SIZE: usize = 8;
+
+/// Represents the different phases of the pick order game
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PickPhase {
+    Guessing, // The player enters the order selection sort will pick the elements in
+    Scanning, // Selection sort scans the unsorted part for its minimum
+    Placing,  // The minimum is swapped to the front of the unsorted part and scored
+    Done,     // Every pick has been scored
+}
+
+/// A game where the player guesses the order selection sort picks the elements in, then watches it
+#[derive(Clone)]
+pub struct PickOrderGameVisualizer {
+    array: Vec<u32>,             // Current state of the array being sorted
+    original_array: Vec<u32>,    // Original array, used for resetting
+    states: Vec<SelectionState>, // Visual state of each element (cursor, guessed, comparing, sorted)
+
+    // Game specific fields
+    origin: Vec<usize>,        // Original index of the element in each slot, moved along with the swaps
+    guess: Vec<usize>,         // Original indices in the order the player expects them to be picked
+    picks: Vec<usize>,         // Original indices in the order selection sort really picked them
+    cursor: usize,             // Element the arrow keys point at while guessing
+    current_i: usize,          // First position of the unsorted part
+    current_j: usize,          // Element being compared with the minimum
+    min_index: usize,          // Minimum of the unsorted part found so far
+    best_score: Option<usize>, // Most picks guessed right in any round on this array
+    phase: PickPhase,          // Current phase of the game
+    state: VisualizerState,    // Common visualization state
+}
+
+impl PickOrderGameVisualizer {
+    /// Creates a new game from the first values of the given array
+    pub fn with_settings(array_data: &ArrayData, settings: &Settings) -> Self {
+        let mut array: Vec<u32> = array_data.data.iter().copied().take(MAX_GAME_SIZE).collect();
+        // On a sorted array every pick is simply the next element
+        if array.is_sorted() && array.first() != array.last() {
+            while array.is_sorted() {
+                array.shuffle(&mut rand::rng());
+            }
+        }
+        let len = array.len();
+
+        let questions = vec![
+            TeachingQuestion {
+                text: "Which element does selection sort pick next?".to_string(),
+                options: vec![
+                    "The smallest element of the unsorted part".to_string(),
+                    "The element right after the sorted part".to_string(),
+                    "The largest element of the whole array".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "Each pass scans the whole unsorted part for its minimum and swaps it to the front, so the picks come out in sorted order.".to_string(),
+            },
+            TeachingQuestion {
+                text: "Why does a correct guess list the elements in sorted order?".to_string(),
+                options: vec![
+                    "Every pick is the smallest element that is left".to_string(),
+                    "Selection sort never swaps".to_string(),
+                    "The guess is sorted before it is scored".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "Taking the smallest remaining element again and again lists the elements from smallest to largest.".to_string(),
+            },
+            TeachingQuestion {
+                text: "Two elements are equal. Which one does this selection sort pick first?".to_string(),
+                options: vec![
+                    "The one that is further left in the unsorted part".to_string(),
+                    "Always the one that started further left".to_string(),
+                    "The one that started further right".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "The scan only replaces its minimum with a strictly smaller element. Earlier swaps may have moved an equal element forward, which is why selection sort is not stable.".to_string(),
+            },
+        ];
+
+        randomize_questions(questions.clone());
+
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("PickOrderGame")));
+        state.teaching_mode = settings.teaching_mode;
+
+        let mut this = Self {
+            original_array: array.clone(),
+            array,
+            states: vec![SelectionState::Normal; len],
+            origin: (0..len).collect(),
+            guess: Vec::new(),
+            picks: Vec::new(),
+            cursor: 0,
+            current_i: 0,
+            current_j: 1,
+            min_index: 0,
+            best_score: None,
+            phase: PickPhase::Guessing,
+            state,
+        };
+        this.refresh_guess_states();
+
+        this
+    }
+
+    /// Number of picks guessed right so far
+    pub fn score(&self) -> usize {
+        self.guess.iter().zip(&self.picks).filter(|(guess, pick)| guess == pick).count()
+    }
+
+    // Colors the cursor and the elements already in the guess
+    fn refresh_guess_states(&mut self) {
+        self.states.fill(SelectionState::Normal);
+        for &index in &self.guess {
+            self.states[index] = SelectionState::CurrentMin;
+        }
+        if let Some(cursor) = self.states.get_mut(self.cursor) {
+            *cursor = SelectionState::Selected;
+        }
+    }
+
+    // ENTER: adds the element under the cursor as the next pick
+    fn add_guess(&mut self) {
+        if self.guess.contains(&self.cursor) {
+            return;
+        }
+        self.guess.push(self.cursor);
+
+        // The last element left is the only possible last pick
+        let len = self.array.len();
+        if self.guess.len() + 1 == len
+            && let Some(last) = (0..len).find(|index| !self.guess.contains(index))
+        {
+            self.guess.push(last);
+        }
+        if self.guess.len() == len {
+            self.start_sort();
+        }
+    }
+
+    // The guess is complete, let selection sort play it out
+    fn start_sort(&mut self) {
+        self.states.fill(SelectionState::Normal);
+        self.phase = PickPhase::Scanning;
+        if self.array.len() <= 1 {
+            self.picks = self.origin.clone();
+            self.phase = PickPhase::Done;
+            self.state.mark_completed();
+            self.record_score();
+            return;
+        }
+        self.state.is_running = true;
+        self.state.is_paused = false;
+    }
+
+    // Keeps the best score of this array across rounds
+    fn record_score(&mut self) {
+        let score = self.score();
+        self.best_score = Some(self.best_score.map_or(score, |best| best.max(score)));
+    }
+
+    // Shows an element as "#original index=value"
+    fn label(&self, index: usize) -> String {
+        format!("#{}={}", index, self.state.value_text(self.original_array[index]))
+    }
+}
+
+impl SortVisualizer for PickOrderGameVisualizer {
+    fn get_array(&self) -> &[u32] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [u32] { &mut self.array }
+    fn get_original_array(&self) -> &[u32] { &self.original_array }
+    fn get_states(&self) -> &[SelectionState] { &self.states }
+    fn get_comparisons(&self) -> u32 { self.state.comparisons }
+    fn get_swaps(&self) -> u32 { self.state.swaps }
+    fn get_speed(&self) -> Duration { self.state.speed }
+    fn is_running(&self) -> bool { self.state.is_running }
+    fn is_paused(&self) -> bool { self.state.is_paused }
+    fn is_completed(&self) -> bool { self.state.completed }
+    fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
+    fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
+    fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState { &mut self.state }
+    fn settings_key(&self) -> &str { "PickOrderGame" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Selection Sort",
+            best: "O(n^2)",
+            average: "O(n^2)",
+            worst: "O(n^2)",
+            space: "O(1)",
+            stable: false,
+            in_place: true,
+        }
+    }
+
+    // Nothing is sorted before the guess is in
+    fn has_extra_state(&self) -> bool { self.phase == PickPhase::Guessing }
+
+    fn handle_extra_input(&mut self, key_code: KeyCode) -> bool {
+        if self.phase != PickPhase::Guessing {
+            return false;
+        }
+        match key_code {
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(self.array.len().saturating_sub(1)),
+            KeyCode::Enter => self.add_guess(),
+            KeyCode::Backspace => {
+                self.guess.pop();
+            }
+            _ => return false,
+        }
+        if self.phase == PickPhase::Guessing {
+            self.refresh_guess_states();
+        }
+        true
+    }
+
+    // The front of the array holds the picks, which are final
+    fn sorted_regions(&self) -> Vec<Range<usize>> {
+        let finalized = if self.state.completed { 0..self.array.len() } else { 0..self.current_i };
+        vec![finalized]
+    }
+
+    fn draw_extra(&self, drawer: &mut VisualizerDrawer, width: u16, height: u16) {
+        let guesses: Vec<(String, Option<bool>)> = self.guess
+            .iter()
+            .enumerate()
+            .map(|(k, &index)| (self.label(index), self.picks.get(k).map(|&pick| pick == index)))
+            .collect();
+        drawer.draw_guess_row("Your Order", &guesses, width, height, 5);
+    }
+
+    fn get_progress(&self) -> f64 {
+        let n = self.array.len();
+        if self.state.completed || n == 0 { 100.0 } else {
+            (self.picks.len() as f64 / n as f64 * 100.0).min(100.0)
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if self.phase == PickPhase::Guessing {
+            // Nothing happens until the guess is complete
+            return true;
+        }
+        if self.state.completed || self.state.awaiting_question.is_some() {
+            return true;
+        }
+
+        for state in &mut self.states[self.current_i..] {
+            *state = SelectionState::Normal;
+        }
+
+        let n = self.array.len();
+        match self.phase {
+            PickPhase::Scanning => {
+                if self.current_j < n {
+                    self.states[self.min_index] = SelectionState::CurrentMin;
+                    self.states[self.current_j] = SelectionState::Comparing;
+                    if self.compare(self.current_j, self.min_index).is_lt() {
+                        self.min_index = self.current_j;
+                    }
+                    self.current_j += 1;
+                } else {
+                    self.states[self.min_index] = SelectionState::CurrentMin;
+                    self.phase = PickPhase::Placing;
+                }
+                true
+            }
+            PickPhase::Placing => {
+                let i = self.current_i;
+                if self.min_index != i {
+                    self.array.swap(i, self.min_index);
+                    self.origin.swap(i, self.min_index);
+                    self.state.swaps += 1;
+                }
+                self.picks.push(self.origin[i]);
+                self.states[i] = SelectionState::Sorted;
+                self.current_i += 1;
+
+                // The last element is picked without a scan
+                if self.current_i + 1 >= n {
+                    self.picks.push(self.origin[n - 1]);
+                    self.current_i = n;
+                    self.phase = PickPhase::Done;
+                    self.record_score();
+                    return false;
+                }
+                self.min_index = self.current_i;
+                self.current_j = self.current_i + 1;
+                self.phase = PickPhase::Scanning;
+
+                // Teaching: Ask question after a pick
+                if self.state.teaching_mode && !self.state.questions.is_empty() {
+                    let q_index = self.picks.len() % self.state.questions.len();
+                    self.state.ask_question(q_index);
+                }
+                true
+            }
+            PickPhase::Guessing | PickPhase::Done => false,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.array = self.original_array.clone();
+        self.states = vec![SelectionState::Normal; self.array.len()];
+        self.origin = (0..self.array.len()).collect();
+        self.guess.clear();
+        self.picks.clear();
+        self.cursor = 0;
+        self.current_i = 0;
+        self.current_j = 1;
+        self.min_index = 0;
+        self.phase = PickPhase::Guessing;
+        self.state.reset_state();
+        self.refresh_guess_states();
+    }
+
+    fn mark_all_sorted(&mut self) {
+        for state in &mut self.states {
+            *state = SelectionState::Sorted;
+        }
+    }
+
+    fn edit_value(&mut self, _index: usize, _value: u32) -> Result<(), String> {
+        Err("Values can't be edited during the game".to_string())
+    }
+
+    fn get_title(&self) -> &str {
+        "TOGISOFT PICK ORDER GAME VISUALIZER"
+    }
+
+    fn get_intro_text(&self) -> &str {
+        "What is the Pick Order Game?\n\n\
+         Selection sort repeatedly picks the smallest element that is left and swaps it to the front.\n\
+         Before it runs, guess the order it will pick up to 8 elements in: move with ←/→ and press ENTER on each.\n\
+         Once the guess is complete selection sort plays it out, every pick is scored against your guess.\n\n\
+         Teaching Mode: ON (Toggle with T). Questions will be asked after picks.\n\n\
+         Press any key to continue..."
+    }
+
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
+        if self.phase == PickPhase::Guessing {
+            vec![
+                ("Normal", SelectionState::Normal),
+                ("Cursor", SelectionState::Selected),
+                ("Guessed", SelectionState::CurrentMin),
+            ]
+        } else {
+            vec![
+                ("Normal", SelectionState::Normal),
+                ("Current Min", SelectionState::CurrentMin),
+                ("Comparing", SelectionState::Comparing),
+                ("Picked", SelectionState::Sorted),
+            ]
+        }
+    }
+
+    fn get_statistics_strings(&self) -> Vec<String> {
+        let phase_str = match self.phase {
+            PickPhase::Guessing => "Guessing",
+            PickPhase::Scanning => "Scanning For Minimum",
+            PickPhase::Placing => "Placing Minimum",
+            PickPhase::Done => "Done",
+        };
+
+        vec![
+            format!("Array Size: {}", self.array.len()),
+            format!("Comparisons: {}", self.state.comparisons),
+            format!("Swaps: {}", self.state.swaps),
+            format!("Guessed: {} / {}", self.guess.len(), self.array.len()),
+            format!("Score: {} / {}", self.score(), self.picks.len()),
+            match self.best_score {
+                Some(best) => format!("Best Score: {} / {}", best, self.array.len()),
+                None => "Best Score: -".to_string(),
+            },
+            format!("Phase: {}", phase_str),
+            if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
+        ]
+    }
+
+    fn get_current_operation(&self) -> String {
+        if self.state.completed {
+            let score = self.score();
+            let len = self.array.len();
+            return if score == len {
+                format!("✓ Perfect! All {} picks guessed right", len)
+            } else {
+                format!("✓ Sorted, {} of {} picks guessed right", score, len)
+            };
+        }
+        match self.phase {
+            PickPhase::Guessing => match self.cursor {
+                cursor if self.guess.contains(&cursor) => {
+                    format!("{} is already pick {} of your guess",
+                            self.label(cursor),
+                            self.guess.iter().position(|&index| index == cursor).unwrap_or(0) + 1)
+                }
+                cursor if cursor < self.array.len() => {
+                    format!("Which element is picked {}? ENTER makes {} pick {}",
+                            if self.guess.is_empty() { "first" } else { "next" },
+                            self.label(cursor),
+                            self.guess.len() + 1)
+                }
+                _ => "Nothing to guess".to_string(),
+            },
+            PickPhase::Scanning if self.current_j < self.array.len() => {
+                format!("Comparing array[{}] ({}) with the minimum so far array[{}] ({})",
+                        self.current_j,
+                        self.state.value_text(self.array[self.current_j]),
+                        self.min_index,
+                        self.state.value_text(self.array[self.min_index]))
+            }
+            _ => match self.guess.get(self.current_i) {
+                Some(&guessed) if guessed == self.origin[self.min_index] => {
+                    format!("Picking {}, as you guessed!", self.label(guessed))
+                }
+                Some(&guessed) => {
+                    format!("Picking {}, you guessed {}", self.label(self.origin[self.min_index]), self.label(guessed))
+                }
+                None => String::new(),
+            },
+        }
+    }
+
+    fn get_status(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "WAITING FOR QUESTION"
+        } else if self.phase == PickPhase::Guessing {
+            "YOUR GUESS"
+        } else if self.state.completed {
+            "COMPLETED!"
+        } else if self.state.is_running && !self.state.is_paused {
+            "RUNNING..."
+        } else if self.state.is_paused {
+            "PAUSED"
+        } else {
+            "READY"
+        }
+    }
+
+    fn get_controls_text(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "1,2,3: Answer | ESC: Exit"
+        } else if self.phase == PickPhase::Guessing {
+            "←/→: Move | ENTER: Add Pick | BACKSPACE: Undo Pick | T: Teaching | ESC: Exit"
+        } else if self.state.completed {
+            "SPACE: Guess Again | R: Reset | T: Teaching Toggle | ESC: Exit"
+        } else {
+            "SPACE: Start/Pause | S: Step | R: Guess Again | T: Teaching | +/-: Speed | ESC: Exit"
+        }
+    }
+}
+
+/// Entry point for the pick order game
+pub fn pick_order_game_visualization(array_data: &ArrayData, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer>> {
+    let settings = Settings::load();
+    run_suspendable(PickOrderGameVisualizer::with_settings(array_data, &settings), settings, last_run)
+}
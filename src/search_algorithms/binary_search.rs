@@ -1,17 +1,12 @@
-use crate::common::array_manager::ArrayData;
+use crate::common::algorithm_info::AlgorithmInfo;
+use crate::common::array_manager::{search_target_dialog, ArrayData};
 use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
-use crate::common::common_visualizer::{show_intro_screen, show_question_feedback, VisualizerDrawer};
+use crate::common::element::SortElement;
 use crate::common::enums::{SelectionState, TeachingQuestion};
 use crate::common::helper::{cleanup_terminal, randomize_questions};
+use crate::common::runner::{run_suspendable, RunSummary};
 use crate::common::settings::Settings;
-use crossterm::{
-    event::{poll, read, Event, KeyCode, KeyEvent, KeyEventKind},
-    style::{Color, Print},
-    terminal::{enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen},
-    cursor::{MoveTo, Show, Hide},
-    ExecutableCommand,
-};
-use std::io::{stdout, Stdout, Write};
+use std::ops::Range;
 use std::time::Duration;
 
 /// Represents the different phases of the binary search algorithm
@@ -24,98 +19,38 @@ pub enum BinarySearchPhase {
 }
 
 /// Visualizes the binary search algorithm step-by-step with interactive controls
-pub struct BinarySearchVisualizer {
-    array: Vec<u32>,           // Current state of the array (sorted)
-    original_array: Vec<u32>,  // Original array, used for resetting
+#[derive(Clone)]
+pub struct BinarySearchVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array (sorted)
+    original_array: Vec<T>,  // Original array, used for resetting
     states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, found)
 
     // Binary Search specific fields
-    target: u32,               // Target value to search for
+    target: T,               // Target value to search for
     intro_text: String,        // Intro text with target
     low: usize,                // Current low index
     high: usize,               // Current high index
     mid: usize,                // Current mid index
     found_index: Option<usize>, // Index where target was found (if any)
     phase: BinarySearchPhase,  // Current phase of the binary search algorithm
-    state: VisualizerState,    // Common visualization state
+    state: VisualizerState<T>,    // Common visualization state
 }
 
-impl BinarySearchVisualizer {
-    /// Prompts the user to input the target value for the search
-    fn prompt_for_target(stdout: &mut Stdout, array: &[u32]) -> u32 {
-        let mut input = String::new();
-        let prompt = format!(
-            "Enter the target value to search for (e.g., a number in the sorted array: {}): ",
-            array.iter().map(|x| x.to_string()).collect::<Vec<String>>().join(", ")
-        );
-
-        // Clear the screen once and hide the cursor
-        stdout.execute(Clear(ClearType::All)).unwrap();
-        stdout.execute(Hide).unwrap();
-
-        // Write the initial prompt
-        stdout.execute(MoveTo(0, 0)).unwrap();
-        stdout.execute(Print(&prompt)).unwrap();
-        stdout.flush().unwrap();
-
-        loop {
-            // Read user input
-            if let Event::Key(KeyEvent { code, kind: KeyEventKind::Press, .. }) = read().unwrap() {
-                match code {
-                    KeyCode::Char(c) if c.is_digit(10) => {
-                        input.push(c);
-                        // Update the display with the current input
-                        stdout.execute(MoveTo(0, 1)).unwrap();
-                        stdout.execute(Clear(ClearType::CurrentLine)).unwrap();
-                        stdout.execute(Print(&input)).unwrap();
-                        stdout.flush().unwrap();
-                    }
-                    KeyCode::Backspace => {
-                        input.pop(); // Remove the last character
-                        // Update the display with the current input
-                        stdout.execute(MoveTo(0, 1)).unwrap();
-                        stdout.execute(Clear(ClearType::CurrentLine)).unwrap();
-                        stdout.execute(Print(&input)).unwrap();
-                        stdout.flush().unwrap();
-                    }
-                    KeyCode::Enter if !input.is_empty() => {
-                        if let Ok(target) = input.parse::<u32>() {
-                            // Show cursor and return to normal
-                            stdout.execute(Show).unwrap();
-                            return target;
-                        } else {
-                            // Show error message on the next line
-                            stdout.execute(MoveTo(0, 1)).unwrap();
-                            stdout.execute(Clear(ClearType::CurrentLine)).unwrap();
-                            stdout.execute(Print("Invalid input. Please enter a valid number.")).unwrap();
-                            stdout.flush().unwrap();
-                            input.clear();
-                        }
-                    }
-                    KeyCode::Esc => {
-                        // Show cursor and default to middle element
-                        stdout.execute(Show).unwrap();
-                        return if !array.is_empty() { array[array.len() / 2] } else { 0 };
-                    }
-                    _ => {}
-                }
-            }
-        }
+impl<T: SortElement> BinarySearchVisualizer<T> {
+    /// Creates a new BinarySearchVisualizer with the given array, asking for the target.
+    /// None if the user cancelled the target dialog.
+    pub fn new(array_data: &ArrayData<T>, settings: &Settings) -> Option<Self> {
+        let target = search_target_dialog(array_data, "Binary Search", array_data.random_value())?;
+        Some(Self::with_target(array_data, target, settings))
     }
 
-    /// Creates a new BinarySearchVisualizer with the given array
-    pub fn new(array_data: &ArrayData) -> Self {
-        let settings = Settings::load();
+    /// Creates a new BinarySearchVisualizer searching for a known target
+    pub fn with_target(array_data: &ArrayData<T>, target: T, settings: &Settings) -> Self {
         let mut array = array_data.data.clone();
         let len = array.len();
 
         // Sort the array for binary search
-        array.sort_unstable();
-
-        // Enable raw mode and prompt for target
-        enable_raw_mode().unwrap();
-        let mut stdout = stdout();
-        let target = Self::prompt_for_target(&mut stdout, &array);
+        array.sort_unstable_by(|a, b| a.order(*b));
 
         let mut questions = vec![
             TeachingQuestion {
@@ -152,24 +87,15 @@ impl BinarySearchVisualizer {
 
         randomize_questions(questions.clone());
 
-        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed));
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("BinarySearch")));
         state.teaching_mode = settings.teaching_mode;
 
-        let intro_text = format!("What is Binary Search?\n\n\
-         Binary Search is an efficient algorithm that finds the target in a sorted array by repeatedly dividing\n\
-         the search interval in half. It starts by comparing the middle element with the target.\n\n\
-         Target: {}\n\n\
-         Advantages: O(log n) time complexity for sorted arrays.\n\
-         Disadvantages: Requires the array to be sorted first.\n\n\
-         Teaching Mode: ON (Toggle with T). Questions will be asked after each comparison.\n\n\
-         Press any key to continue...", target);
-
         let mut this = Self {
             original_array: array.clone(),
             array,
             states: vec![SelectionState::Normal; len],
             target,
-            intro_text,
+            intro_text: Self::intro_for(target),
             low: 0,
             high: len.saturating_sub(1),
             mid: 0,
@@ -178,11 +104,6 @@ impl BinarySearchVisualizer {
             state,
         };
 
-        // Set last visualizer
-        let mut settings = Settings::load();
-        settings.last_visualizer = Some("BinarySearch".to_string());
-        settings.save();
-
         if len == 0 {
             this.state.mark_completed();
         }
@@ -190,140 +111,22 @@ impl BinarySearchVisualizer {
         this
     }
 
-    /// Main loop: handles rendering, input, and stepping through the search
-    pub fn run_visualization(&mut self) {
-        let mut stdout = stdout();
-        stdout.execute(EnterAlternateScreen).unwrap();
-
-        show_intro_screen(&self.intro_text);
-
-        loop {
-            self.draw(&mut stdout);
-
-            if poll(Duration::from_millis(50)).unwrap_or(false) {
-                match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
-                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                        // Handle question
-                        if let Some(q_index) = self.state.awaiting_question {
-                            match key_event.code {
-                                KeyCode::Char('1') => self.handle_question_answer(q_index, 0),
-                                KeyCode::Char('2') => self.handle_question_answer(q_index, 1),
-                                KeyCode::Char('3') => self.handle_question_answer(q_index, 2),
-                                _ => continue,
-                            }
-                            continue;
-                        }
-
-                        match key_event.code {
-                            KeyCode::Char(' ') => {
-                                if self.state.completed {
-                                    self.reset();
-                                } else {
-                                    self.state.toggle_play_pause();
-                                }
-                            },
-                            KeyCode::Char('r') | KeyCode::Char('R') => {
-                                self.reset();
-                            },
-                            KeyCode::Char('s') | KeyCode::Char('S') => {
-                                if !self.state.completed && !self.state.is_running {
-                                    if !self.step() {
-                                        self.state.mark_completed();
-                                    }
-                                }
-                            },
-                            KeyCode::Char('t') | KeyCode::Char('T') => {
-                                self.state.toggle_teaching_mode();
-                                let mut settings = Settings::load();
-                                settings.teaching_mode = self.state.teaching_mode;
-                                settings.save();
-                            },
-                            KeyCode::Char('+') => {
-                                self.state.increase_speed(50);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Char('-') => {
-                                self.state.decrease_speed(2000);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Esc => {
-                                let mut settings = Settings::load();
-                                settings.last_visualizer = Some("BinarySearch".to_string());
-                                settings.save();
-                                cleanup_terminal();
-                                return;
-                            },
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
-            }
-
-            // Auto step
-            if self.state.is_running && !self.state.is_paused && !self.state.completed
-                && self.state.awaiting_question.is_none() {
-                std::thread::sleep(self.state.speed);
-                if !self.step() {
-                    self.state.mark_completed();
-                }
-            }
-        }
-    }
-
-    fn handle_question_answer(&mut self, q_index: usize, answer: usize) {
-        if let Some(question) = self.state.questions.get(q_index) {
-            let correct = answer == question.correct_index;
-            show_question_feedback(correct, question, answer);
-            self.state.clear_question();
-        }
-    }
-
-    fn draw(&mut self, stdout: &mut std::io::Stdout) {
-        let (width, height) = size().unwrap();
-        stdout.execute(Clear(ClearType::All)).unwrap();
-
-        // Title
-        VisualizerDrawer::draw_title(stdout, self.get_title());
-
-        // Array
-        VisualizerDrawer::draw_array_bars(stdout, &self.array, &self.states, width, height, 5);
-
-        // Legend
-        VisualizerDrawer::draw_legend(stdout, &self.get_legend_items(), width, height, 5);
-
-        // Statistics
-        let stats = self.get_statistics_strings();
-        VisualizerDrawer::draw_statistics(stdout, &stats, width, height);
-
-        // Controls
-        VisualizerDrawer::draw_controls(stdout, self.get_status(), self.get_controls_text(), width, height);
-
-        // Current operation
-        if self.state.awaiting_question.is_none() {
-            let operation = self.get_current_operation();
-            let color = if self.state.completed { Color::Green } else { Color::White };
-            VisualizerDrawer::draw_operation_info(stdout, &operation, width, height, color);
-        }
-
-        // Question
-        if let Some(q_index) = self.state.awaiting_question {
-            if let Some(question) = self.state.questions.get(q_index) {
-                VisualizerDrawer::draw_question(stdout, question, width, height);
-            }
-        }
-
-        stdout.flush().unwrap();
+    fn intro_for(target: T) -> String {
+        format!("What is Binary Search?\n\n\
+         Binary Search is an efficient algorithm that finds the target in a sorted array by repeatedly dividing\n\
+         the search interval in half. It starts by comparing the middle element with the target.\n\n\
+         Target: {}\n\n\
+         Advantages: O(log n) time complexity for sorted arrays.\n\
+         Disadvantages: Requires the array to be sorted first.\n\n\
+         Teaching Mode: ON (Toggle with T). Questions will be asked after each comparison.\n\n\
+         Press any key to continue...", target)
     }
 }
 
-impl SortVisualizer for BinarySearchVisualizer {
-    fn get_array(&self) -> &[u32] { &self.array }
-    fn get_original_array(&self) -> &[u32] { &self.original_array }
+impl<T: SortElement> SortVisualizer<T> for BinarySearchVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
     fn get_states(&self) -> &[SelectionState] { &self.states }
     fn get_comparisons(&self) -> u32 { self.state.comparisons }
     fn get_swaps(&self) -> u32 { self.state.swaps } // Not used for search
@@ -334,6 +137,28 @@ impl SortVisualizer for BinarySearchVisualizer {
     fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
     fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
     fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "BinarySearch" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Binary Search",
+            best: "O(1)",
+            average: "O(log n)",
+            worst: "O(log n)",
+            space: "O(1)",
+            stable: true,
+            in_place: true,
+        }
+    }
+    // Sorted marks the found element here, not a sorted position
+    fn sorted_regions(&self) -> Vec<Range<usize>> { Vec::new() }
+    // Looks for the exact target value
+    fn supports_custom_order(&self) -> bool { false }
+
+    fn on_completed(&mut self) {
+        // Keep the found/eliminated states visible instead of marking everything sorted
+    }
 
     fn get_progress(&self) -> f64 {
         let n = self.array.len() as f64;
@@ -419,7 +244,7 @@ impl SortVisualizer for BinarySearchVisualizer {
 
     fn reset(&mut self) {
         let mut array = self.original_array.clone();
-        array.sort_unstable();
+        array.sort_unstable_by(|a, b| a.order(*b));
         let len = array.len();
         self.array = array;
         self.states = vec![SelectionState::Normal; len];
@@ -429,18 +254,12 @@ impl SortVisualizer for BinarySearchVisualizer {
         self.found_index = None;
         self.phase = BinarySearchPhase::Searching;
 
-        // Prompt for new target
-        let mut stdout = stdout();
-        let target = Self::prompt_for_target(&mut stdout, &self.array);
-        self.target = target;
-        self.intro_text = format!("What is Binary Search?\n\n\
-         Binary Search is an efficient algorithm that finds the target in a sorted array by repeatedly dividing\n\
-         the search interval in half. It starts by comparing the middle element with the target.\n\n\
-         Target: {}\n\n\
-         Advantages: O(log n) time complexity for sorted arrays.\n\
-         Disadvantages: Requires the array to be sorted first.\n\n\
-         Teaching Mode: ON (Toggle with T). Questions will be asked after each comparison.\n\n\
-         Press any key to continue...", target);
+        // Ask for a new target, cancelling keeps the current one
+        let array_data = ArrayData::new(self.array.clone(), "Current array".to_string());
+        if let Some(target) = search_target_dialog(&array_data, "Binary Search", self.target) {
+            self.target = target;
+            self.intro_text = Self::intro_for(target);
+        }
         self.state.reset_state();
         if len == 0 {
             self.state.mark_completed();
@@ -462,11 +281,11 @@ impl SortVisualizer for BinarySearchVisualizer {
         &self.intro_text
     }
 
-    fn get_legend_items(&self) -> Vec<(&str, Color)> {
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
         vec![
-            ("Normal", Color::Cyan),
-            ("Comparing", Color::Yellow),
-            ("Found", Color::Green),
+            ("Normal", SelectionState::Normal),
+            ("Comparing", SelectionState::Comparing),
+            ("Found", SelectionState::Sorted),
         ]
     }
 
@@ -480,7 +299,7 @@ impl SortVisualizer for BinarySearchVisualizer {
 
         vec![
             format!("Array Size: {}", self.array.len()),
-            format!("Target: {}", self.target),
+            format!("Searching for: {}", self.state.value_text(self.target)),
             format!("Comparisons: {}", self.state.comparisons),
             format!("Search Range: [{}..{}]", self.low, self.high),
             format!("Phase: {}", phase_str),
@@ -492,24 +311,24 @@ impl SortVisualizer for BinarySearchVisualizer {
     fn get_current_operation(&self) -> String {
         if self.state.completed {
             if self.found_index.is_some() {
-                format!("✓ Target {} found at index {}!", self.target, self.found_index.unwrap())
+                format!("✓ Target {} found at index {}!", self.state.value_text(self.target), self.found_index.unwrap())
             } else {
-                format!("✗ Target {} not found in the array.", self.target)
+                format!("✗ Target {} not found after {} comparisons.", self.state.value_text(self.target), self.state.comparisons)
             }
         } else {
             match self.phase {
                 BinarySearchPhase::Searching => {
                     if self.low <= self.high && self.mid < self.array.len() {
-                        format!("Binary search: low={} mid={}({}) high={}, target={}", self.low, self.mid, self.array[self.mid], self.high, self.target)
+                        format!("Binary search: low={} mid={}({}) high={}, target={}", self.low, self.mid, self.state.value_text(self.array[self.mid]), self.high, self.state.value_text(self.target))
                     } else {
                         "Search space exhausted".to_string()
                     }
                 },
                 BinarySearchPhase::Found => {
-                    format!("Target {} found at index {}!", self.target, self.found_index.unwrap())
+                    format!("Target {} found at index {}!", self.state.value_text(self.target), self.found_index.unwrap())
                 },
                 BinarySearchPhase::NotFound => {
-                    format!("Target {} not found after {} comparisons.", self.target, self.state.comparisons)
+                    format!("Target {} not found after {} comparisons.", self.state.value_text(self.target), self.state.comparisons)
                 },
                 BinarySearchPhase::Done => {
                     "Binary search completed!".to_string()
@@ -544,7 +363,11 @@ impl SortVisualizer for BinarySearchVisualizer {
 }
 
 /// Entry point for the binary search visualization
-pub fn binary_search_visualization(array_data: &ArrayData) {
-    let mut visualizer = BinarySearchVisualizer::new(array_data);
-    visualizer.run_visualization();
+pub fn binary_search_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    let Some(visualizer) = BinarySearchVisualizer::new(array_data, &settings) else {
+        cleanup_terminal();
+        return None;
+    };
+    run_suspendable(visualizer, settings, last_run)
 }
\ No newline at end of file
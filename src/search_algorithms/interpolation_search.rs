@@ -0,0 +1,406 @@
+use crate::common::algorithm_info::AlgorithmInfo;
+use crate::common::array_manager::{search_target_dialog, ArrayData};
+use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
+use crate::common::element::SortElement;
+use crate::common::enums::{SelectionState, TeachingQuestion};
+use crate::common::helper::{cleanup_terminal, randomize_questions};
+use crate::common::runner::{run_suspendable, RunSummary};
+use crate::common::settings::Settings;
+use std::ops::Range;
+use std::time::Duration;
+
+/// Represents the different phases of the interpolation search algorithm
+#[derive(Clone, Copy, PartialEq)]
+pub enum InterpolationSearchPhase {
+    Probing,   // Estimating where the target should be and probing there
+    Found,     // Target found
+    NotFound,  // Target not found
+}
+
+/// Visualizes the interpolation search algorithm step-by-step with interactive controls
+#[derive(Clone)]
+pub struct InterpolationSearchVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array (sorted)
+    original_array: Vec<T>,  // Original array, used for resetting
+    states: Vec<SelectionState>, // Visual state of each element (e.g., probing, found)
+
+    // Interpolation Search specific fields
+    target: T,               // Target value to search for
+    intro_text: String,        // Intro text with target
+    low: usize,                // Current low index
+    high: usize,               // Current high index
+    probe: Option<usize>,      // Index probed by the last step
+    probe_formula: String,     // The probe position formula with the last step's numbers filled in
+    found_index: Option<usize>, // Index where target was found (if any)
+    phase: InterpolationSearchPhase, // Current phase of the interpolation search algorithm
+    state: VisualizerState<T>,    // Common visualization state
+}
+
+impl<T: SortElement> InterpolationSearchVisualizer<T> {
+    /// Creates a new InterpolationSearchVisualizer with the given array, asking for the target.
+    /// None if the user cancelled the target dialog.
+    pub fn new(array_data: &ArrayData<T>, settings: &Settings) -> Option<Self> {
+        let target = search_target_dialog(array_data, "Interpolation Search", array_data.random_value())?;
+        Some(Self::with_target(array_data, target, settings))
+    }
+
+    /// Creates a new InterpolationSearchVisualizer searching for a known target
+    pub fn with_target(array_data: &ArrayData<T>, target: T, settings: &Settings) -> Self {
+        let mut array = array_data.data.clone();
+        let len = array.len();
+
+        // Sort the array for interpolation search
+        array.sort_unstable_by(|a, b| a.order(*b));
+
+        let questions = vec![
+            TeachingQuestion {
+                text: "How does Interpolation Search choose the next position to probe?".to_string(),
+                options: vec![
+                    "It estimates it from where the target falls between arr[low] and arr[high]".to_string(),
+                    "It always probes the middle of the range".to_string(),
+                    "It probes the next element in order".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "The probe is low + (target - arr[low]) * (high - low) / (arr[high] - arr[low]), the position the target would have if the values rose evenly.".to_string(),
+            },
+            TeachingQuestion {
+                text: "On which data is Interpolation Search fastest?".to_string(),
+                options: vec![
+                    "Sorted and uniformly distributed values".to_string(),
+                    "Unsorted values".to_string(),
+                    "Sorted values that grow exponentially".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "With evenly spread values the estimate lands close to the target, taking O(log log n) probes on average.".to_string(),
+            },
+            TeachingQuestion {
+                text: "What happens when arr[high] equals arr[low]?".to_string(),
+                options: vec![
+                    "The formula would divide by zero, so the midpoint is probed instead".to_string(),
+                    "The search stops with the target found".to_string(),
+                    "The range is doubled".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "Every value in the range is equal, so the estimate is undefined and the midpoint is as good a probe as any.".to_string(),
+            },
+        ];
+
+        randomize_questions(questions.clone());
+
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("InterpolationSearch")));
+        state.teaching_mode = settings.teaching_mode;
+
+        let mut this = Self {
+            original_array: array.clone(),
+            array,
+            states: vec![SelectionState::Normal; len],
+            target,
+            intro_text: Self::intro_for(target),
+            low: 0,
+            high: len.saturating_sub(1),
+            probe: None,
+            probe_formula: String::new(),
+            found_index: None,
+            phase: InterpolationSearchPhase::Probing,
+            state,
+        };
+
+        if len == 0 {
+            this.state.mark_completed();
+        }
+
+        this
+    }
+
+    fn intro_for(target: T) -> String {
+        format!("What is Interpolation Search?\n\n\
+         Interpolation Search finds the target in a sorted array by estimating where it should be,\n\
+         like opening a dictionary near the front for a word starting with 'b'. Each probe is at\n\
+         low + ((target - arr[low]) * (high - low)) / (arr[high] - arr[low]).\n\n\
+         Target: {}\n\n\
+         Advantages: O(log log n) probes on sorted, uniformly distributed data.\n\
+         Disadvantages: Requires a sorted array, degrades to O(n) on skewed values.\n\n\
+         Teaching Mode: ON (Toggle with T). Questions will be asked after each comparison.\n\n\
+         Press any key to continue...", target)
+    }
+
+    // The position to probe between low and high, with the formula that produced it.
+    // Equal values at both ends would divide by zero, the midpoint is probed instead.
+    fn probe_position(&self) -> (usize, String) {
+        let (low, high) = (self.low, self.high);
+        let (arr_low, arr_high) = (self.array[low], self.array[high]);
+        if arr_high == arr_low {
+            let probe = low + (high - low) / 2;
+            return (probe, format!("arr[{}] = arr[{}], midpoint {} + ({}-{})/2 = {}", high, low, low, high, low, probe));
+        }
+        // Worked out in f64 so signed and real values probe the same way, min keeps rounding inside the range
+        let offset = (self.target.to_f64() - arr_low.to_f64()) * (high - low) as f64 / (arr_high.to_f64() - arr_low.to_f64());
+        let probe = (low + offset as usize).min(high);
+        let (target, arr_low, arr_high) =
+            (self.state.value_text(self.target), self.state.value_text(arr_low), self.state.value_text(arr_high));
+        let formula = format!(
+            "pos = {} + (({}-{})*({}-{}))/({}-{}) = {}",
+            low, target, arr_low, high, low, arr_high, arr_low, probe
+        );
+        (probe, formula)
+    }
+}
+
+impl<T: SortElement> SortVisualizer<T> for InterpolationSearchVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
+    fn get_states(&self) -> &[SelectionState] { &self.states }
+    fn get_comparisons(&self) -> u32 { self.state.comparisons }
+    fn get_swaps(&self) -> u32 { self.state.swaps } // Not used for search
+    fn get_speed(&self) -> Duration { self.state.speed }
+    fn is_running(&self) -> bool { self.state.is_running }
+    fn is_paused(&self) -> bool { self.state.is_paused }
+    fn is_completed(&self) -> bool { self.state.completed }
+    fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
+    fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
+    fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "InterpolationSearch" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Interpolation Search",
+            best: "O(1)",
+            average: "O(log log n)",
+            worst: "O(n)",
+            space: "O(1)",
+            stable: true,
+            in_place: true,
+        }
+    }
+    // Sorted marks the found element here, not a sorted position
+    fn sorted_regions(&self) -> Vec<Range<usize>> { Vec::new() }
+    // Looks for the exact target value
+    fn supports_custom_order(&self) -> bool { false }
+
+    fn on_completed(&mut self) {
+        // Keep the found/probe states visible instead of marking everything sorted
+    }
+
+    fn get_progress(&self) -> f64 {
+        let n = self.array.len() as f64;
+        if n == 0.0 { 100.0 } else {
+            // Uniform data needs about log2(log2(n)) probes, the bar fills up after that many
+            let expected = n.log2().max(1.0).log2().ceil() + 1.0;
+            (self.state.comparisons as f64 / expected * 100.0).min(100.0)
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if self.state.completed || self.state.awaiting_question.is_some() {
+            return true;
+        }
+
+        // Reset states except found
+        for (i, state) in self.states.iter_mut().enumerate() {
+            *state = if self.found_index == Some(i) {
+                SelectionState::Sorted // Reuse Sorted for Found
+            } else {
+                SelectionState::Normal
+            };
+        }
+
+        match self.phase {
+            InterpolationSearchPhase::Probing => {
+                // Outside arr[low]..=arr[high] the target cannot be in the range
+                if self.low > self.high
+                    || self.high >= self.array.len()
+                    || self.target < self.array[self.low]
+                    || self.target > self.array[self.high]
+                {
+                    self.phase = InterpolationSearchPhase::NotFound;
+                    return false;
+                }
+
+                let (probe, formula) = self.probe_position();
+                self.probe = Some(probe);
+                self.probe_formula = formula;
+                self.states[self.low] = SelectionState::PartitionLeft;
+                self.states[self.high] = SelectionState::PartitionRight;
+                self.states[probe] = SelectionState::CurrentMin;
+                self.state.comparisons += 1;
+
+                if self.array[probe] == self.target {
+                    self.found_index = Some(probe);
+                    self.phase = InterpolationSearchPhase::Found;
+                } else if self.array[probe] < self.target {
+                    self.low = probe + 1;
+                } else {
+                    // The target is at least arr[low], so a larger probe is never at low itself
+                    self.high = probe - 1;
+                }
+
+                // Teaching after each comparison
+                if self.state.teaching_mode && !self.state.questions.is_empty() {
+                    let q_index = self.state.comparisons as usize % self.state.questions.len();
+                    self.state.ask_question(q_index);
+                }
+                true
+            },
+            InterpolationSearchPhase::Found | InterpolationSearchPhase::NotFound => {
+                false
+            },
+        }
+    }
+
+    fn reset(&mut self) {
+        let len = self.original_array.len();
+        self.array = self.original_array.clone();
+        self.states = vec![SelectionState::Normal; len];
+        self.low = 0;
+        self.high = len.saturating_sub(1);
+        self.probe = None;
+        self.probe_formula.clear();
+        self.found_index = None;
+        self.phase = InterpolationSearchPhase::Probing;
+
+        // Ask for a new target, cancelling keeps the current one
+        let array_data = ArrayData::new(self.array.clone(), "Current array".to_string());
+        if let Some(target) = search_target_dialog(&array_data, "Interpolation Search", self.target) {
+            self.target = target;
+            self.intro_text = Self::intro_for(target);
+        }
+        self.state.reset_state();
+        if len == 0 {
+            self.state.mark_completed();
+        }
+    }
+
+    fn mark_all_sorted(&mut self) {
+        // For search, mark found as sorted, others normal
+        if let Some(found) = self.found_index {
+            self.states[found] = SelectionState::Sorted;
+        }
+    }
+
+    fn get_title(&self) -> &str {
+        "TOGISOFT INTERPOLATION SEARCH VISUALIZER"
+    }
+
+    fn get_intro_text(&self) -> &str {
+        &self.intro_text
+    }
+
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
+        vec![
+            ("Normal", SelectionState::Normal),
+            ("Low/High", SelectionState::PartitionLeft),
+            ("Probe", SelectionState::CurrentMin),
+            ("Found", SelectionState::Sorted),
+        ]
+    }
+
+    fn get_statistics_strings(&self) -> Vec<String> {
+        let phase_str = match self.phase {
+            InterpolationSearchPhase::Probing => match self.probe {
+                Some(probe) => format!("Probed index {}", probe),
+                None => "Probing".to_string(),
+            },
+            InterpolationSearchPhase::Found => format!("Found at index {}", self.found_index.unwrap()),
+            InterpolationSearchPhase::NotFound => "Not Found".to_string(),
+        };
+
+        vec![
+            format!("Array Size: {}", self.array.len()),
+            format!("Searching for: {}", self.state.value_text(self.target)),
+            format!("Comparisons: {}", self.state.comparisons),
+            format!("Search Range: [{}..{}]", self.low, self.high),
+            format!("Phase: {}", phase_str),
+            format!("Progress: {:.1}%", self.get_progress()),
+            if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
+        ]
+    }
+
+    fn get_current_operation(&self) -> String {
+        if self.state.completed {
+            if let Some(found) = self.found_index {
+                format!("✓ Target {} found at index {}! {}", self.state.value_text(self.target), found, self.probe_formula)
+            } else {
+                format!("✗ Target {} not found after {} comparisons.", self.state.value_text(self.target), self.state.comparisons)
+            }
+        } else {
+            match self.phase {
+                InterpolationSearchPhase::Probing => {
+                    if self.probe_formula.is_empty() {
+                        format!("Interpolation search: low={} high={}, target={}", self.low, self.high, self.state.value_text(self.target))
+                    } else {
+                        self.probe_formula.clone()
+                    }
+                },
+                InterpolationSearchPhase::Found => {
+                    format!("Target {} found at index {}! {}", self.state.value_text(self.target), self.found_index.unwrap(), self.probe_formula)
+                },
+                InterpolationSearchPhase::NotFound => {
+                    format!("Target {} not found after {} comparisons.", self.state.value_text(self.target), self.state.comparisons)
+                },
+            }
+        }
+    }
+
+    fn get_status(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "WAITING FOR QUESTION"
+        } else if self.state.completed {
+            "COMPLETED!"
+        } else if self.state.is_running && !self.state.is_paused {
+            "SEARCHING..."
+        } else if self.state.is_paused {
+            "PAUSED"
+        } else {
+            "READY"
+        }
+    }
+
+    fn get_controls_text(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "1,2,3: Answer | ESC: Exit"
+        } else if self.state.completed {
+            "SPACE: Restart | R: Reset | T: Teaching Toggle | ESC: Exit"
+        } else {
+            "SPACE: Start/Pause | S: Step | R: Reset | T: Teaching | +/-: Speed | ESC: Exit"
+        }
+    }
+}
+
+/// Entry point for the interpolation search visualization
+pub fn interpolation_search_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    let Some(visualizer) = InterpolationSearchVisualizer::new(array_data, &settings) else {
+        cleanup_terminal();
+        return None;
+    };
+    run_suspendable(visualizer, settings, last_run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Runs the search to completion, returns the found index and the comparisons made
+    fn search(values: &[u32], target: u32) -> (Option<usize>, u32) {
+        let array_data = ArrayData::new(values.to_vec(), "Interpolation".to_string());
+        let mut visualizer = InterpolationSearchVisualizer::with_target(&array_data, target, &Settings::default());
+        visualizer.state.teaching_mode = false;
+        while visualizer.step() {}
+        (visualizer.found_index, visualizer.state.comparisons)
+    }
+
+    #[test]
+    fn probes_where_the_target_should_be() {
+        let uniform: Vec<u32> = (1..=100).map(|value| value * 3).collect();
+        // Evenly spread values are found with the first probe
+        assert_eq!(search(&uniform, 150), (Some(49), 1));
+        assert_eq!(search(&uniform, 3), (Some(0), 1));
+        assert_eq!(search(&uniform, 151).0, None);
+        assert_eq!(search(&uniform, 1000), (None, 0));
+        // Equal values at both ends fall back to the midpoint instead of dividing by zero
+        assert_eq!(search(&[7, 7, 7, 7, 7], 7), (Some(2), 1));
+        assert_eq!(search(&[1, 2, 4, 8, 16, 32, 64, 128], 64).0, Some(6));
+    }
+}
@@ -0,0 +1,420 @@
+use crate::common::algorithm_info::AlgorithmInfo;
+use crate::common::array_manager::{search_target_dialog, ArrayData};
+use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
+use crate::common::element::SortElement;
+use crate::common::dialog::show_warning;
+use crate::common::enums::{SelectionState, TeachingQuestion};
+use crate::common::helper::{cleanup_terminal, randomize_questions};
+use crate::common::runner::{run_suspendable, RunSummary};
+use crate::common::settings::Settings;
+use std::ops::Range;
+use std::time::Duration;
+
+/// Represents the different phases of the jump search algorithm
+#[derive(Clone, Copy, PartialEq)]
+pub enum JumpSearchPhase {
+    Jumping,       // Jumping block by block until a block's last element reaches the target
+    BackScanning,  // Scanning the block the target must be in from its start
+    Found,         // Target found
+    NotFound,      // Target not found
+}
+
+/// Visualizes the jump search algorithm step-by-step with interactive controls
+#[derive(Clone)]
+pub struct JumpSearchVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array (sorted)
+    original_array: Vec<T>,  // Original array, used for resetting
+    states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, found)
+
+    // Jump Search specific fields
+    target: T,               // Target value to search for
+    intro_text: String,        // Intro text with target
+    jump: usize,               // Block size, the square root of the array size
+    block_start: usize,        // First index of the current block
+    block_end: usize,          // One past the last index of the current block
+    scan_index: usize,         // Element the back-scan compares next
+    found_index: Option<usize>, // Index where target was found (if any)
+    phase: JumpSearchPhase,    // Current phase of the jump search algorithm
+    state: VisualizerState<T>,    // Common visualization state
+}
+
+impl<T: SortElement> JumpSearchVisualizer<T> {
+    /// Creates a new JumpSearchVisualizer with the given sorted array, asking for the target.
+    /// None if the user cancelled the target dialog.
+    pub fn new(array_data: &ArrayData<T>, settings: &Settings) -> Option<Self> {
+        let target = search_target_dialog(array_data, "Jump Search", array_data.random_value())?;
+        Some(Self::with_target(array_data, target, settings))
+    }
+
+    /// Creates a new JumpSearchVisualizer searching a sorted array for a known target
+    pub fn with_target(array_data: &ArrayData<T>, target: T, settings: &Settings) -> Self {
+        let array = array_data.data.clone();
+        let len = array.len();
+        let jump = Self::jump_for(len);
+
+        let questions = vec![
+            TeachingQuestion {
+                text: "How large is each jump in Jump Search?".to_string(),
+                options: vec![
+                    "The square root of the array size".to_string(),
+                    "Half of the array size".to_string(),
+                    "One element".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "A jump of √n balances the n/√n jumps against the √n elements scanned back, giving O(√n) comparisons.".to_string(),
+            },
+            TeachingQuestion {
+                text: "When does Jump Search stop jumping?".to_string(),
+                options: vec![
+                    "When a block's last element is not smaller than the target".to_string(),
+                    "When it finds an element equal to the target".to_string(),
+                    "After exactly √n jumps".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "Once a block ends with a value at least as large as the target, the target can only be inside that block, so it is scanned linearly.".to_string(),
+            },
+            TeachingQuestion {
+                text: "What is the time complexity of Jump Search?".to_string(),
+                options: vec![
+                    "O(√n)".to_string(),
+                    "O(log n)".to_string(),
+                    "O(n log n)".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "At most √n jumps and √n back-scan comparisons are made, so Jump Search sits between linear and binary search.".to_string(),
+            },
+        ];
+
+        randomize_questions(questions.clone());
+
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("JumpSearch")));
+        state.teaching_mode = settings.teaching_mode;
+
+        let mut this = Self {
+            original_array: array.clone(),
+            array,
+            states: vec![SelectionState::Normal; len],
+            target,
+            intro_text: Self::intro_for(target, jump),
+            jump,
+            block_start: 0,
+            block_end: jump.min(len),
+            scan_index: 0,
+            found_index: None,
+            phase: JumpSearchPhase::Jumping,
+            state,
+        };
+
+        if len == 0 {
+            this.state.mark_completed();
+        }
+
+        this
+    }
+
+    // Block size for an array of n elements, at least one
+    fn jump_for(n: usize) -> usize {
+        ((n as f64).sqrt() as usize).max(1)
+    }
+
+    fn intro_for(target: T, jump: usize) -> String {
+        format!("What is Jump Search?\n\n\
+         Jump Search finds the target in a sorted array by jumping ahead in blocks of √n elements\n\
+         until a block ends with a value at least as large as the target, then scanning that block.\n\n\
+         Target: {}    Jump: {}\n\n\
+         Advantages: O(√n) comparisons, jumps only move forward.\n\
+         Disadvantages: Requires the array to be sorted first, slower than binary search.\n\n\
+         Teaching Mode: ON (Toggle with T). Questions will be asked after each comparison.\n\n\
+         Press any key to continue...", target, jump)
+    }
+
+    // Asks the next teaching question after a comparison
+    fn ask_after_comparison(&mut self) {
+        if self.state.teaching_mode && !self.state.questions.is_empty() {
+            let q_index = self.state.comparisons as usize % self.state.questions.len();
+            self.state.ask_question(q_index);
+        }
+    }
+}
+
+impl<T: SortElement> SortVisualizer<T> for JumpSearchVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
+    fn get_states(&self) -> &[SelectionState] { &self.states }
+    fn get_comparisons(&self) -> u32 { self.state.comparisons }
+    fn get_swaps(&self) -> u32 { self.state.swaps } // Not used for search
+    fn get_speed(&self) -> Duration { self.state.speed }
+    fn is_running(&self) -> bool { self.state.is_running }
+    fn is_paused(&self) -> bool { self.state.is_paused }
+    fn is_completed(&self) -> bool { self.state.completed }
+    fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
+    fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
+    fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "JumpSearch" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Jump Search",
+            best: "O(1)",
+            average: "O(√n)",
+            worst: "O(√n)",
+            space: "O(1)",
+            stable: true,
+            in_place: true,
+        }
+    }
+    // Sorted marks the found element here, not a sorted position
+    fn sorted_regions(&self) -> Vec<Range<usize>> { Vec::new() }
+    // Looks for the exact target value
+    fn supports_custom_order(&self) -> bool { false }
+
+    fn on_completed(&mut self) {
+        // Keep the found/boundary states visible instead of marking everything sorted
+    }
+
+    fn get_progress(&self) -> f64 {
+        let n = self.array.len();
+        if n == 0 { 100.0 } else {
+            // Worst case: every jump, then a full block scanned back
+            let max_comparisons = n.div_ceil(self.jump) + self.jump;
+            (self.state.comparisons as f64 / max_comparisons as f64 * 100.0).min(100.0)
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if self.state.completed || self.state.awaiting_question.is_some() {
+            return true;
+        }
+
+        // Reset states except found
+        for (i, state) in self.states.iter_mut().enumerate() {
+            *state = if self.found_index == Some(i) {
+                SelectionState::Sorted // Reuse Sorted for Found
+            } else {
+                SelectionState::Normal
+            };
+        }
+
+        let n = self.array.len();
+        match self.phase {
+            JumpSearchPhase::Jumping => {
+                if self.block_start >= n {
+                    self.phase = JumpSearchPhase::NotFound;
+                    return false;
+                }
+
+                // Compare the last element of the block with the target
+                let boundary = self.block_end - 1;
+                self.states[boundary] = SelectionState::PartitionRight;
+                self.state.comparisons += 1;
+
+                if self.array[boundary] < self.target {
+                    // The target lies past this block, jump to the next one
+                    self.block_start = self.block_end;
+                    self.block_end = (self.block_end + self.jump).min(n);
+                } else {
+                    self.phase = JumpSearchPhase::BackScanning;
+                    self.scan_index = self.block_start;
+                }
+                self.ask_after_comparison();
+                true
+            },
+            JumpSearchPhase::BackScanning => {
+                if self.scan_index >= self.block_end {
+                    self.phase = JumpSearchPhase::NotFound;
+                    return false;
+                }
+
+                self.states[self.block_end - 1] = SelectionState::PartitionRight;
+                self.states[self.scan_index] = SelectionState::Comparing;
+                self.state.comparisons += 1;
+
+                if self.array[self.scan_index] == self.target {
+                    self.found_index = Some(self.scan_index);
+                    self.phase = JumpSearchPhase::Found;
+                } else if self.array[self.scan_index] > self.target {
+                    // Every later element is larger still
+                    self.phase = JumpSearchPhase::NotFound;
+                } else {
+                    self.scan_index += 1;
+                }
+                self.ask_after_comparison();
+                true
+            },
+            JumpSearchPhase::Found | JumpSearchPhase::NotFound => {
+                false
+            },
+        }
+    }
+
+    fn reset(&mut self) {
+        let len = self.original_array.len();
+        self.array = self.original_array.clone();
+        self.states = vec![SelectionState::Normal; len];
+        self.block_start = 0;
+        self.block_end = self.jump.min(len);
+        self.scan_index = 0;
+        self.found_index = None;
+        self.phase = JumpSearchPhase::Jumping;
+
+        // Ask for a new target, cancelling keeps the current one
+        let array_data = ArrayData::new(self.array.clone(), "Current array".to_string());
+        if let Some(target) = search_target_dialog(&array_data, "Jump Search", self.target) {
+            self.target = target;
+            self.intro_text = Self::intro_for(target, self.jump);
+        }
+        self.state.reset_state();
+        if len == 0 {
+            self.state.mark_completed();
+        }
+    }
+
+    fn mark_all_sorted(&mut self) {
+        // For search, mark found as sorted, others normal
+        if let Some(found) = self.found_index {
+            self.states[found] = SelectionState::Sorted;
+        }
+    }
+
+    fn get_title(&self) -> &str {
+        "TOGISOFT JUMP SEARCH VISUALIZER"
+    }
+
+    fn get_intro_text(&self) -> &str {
+        &self.intro_text
+    }
+
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
+        vec![
+            ("Normal", SelectionState::Normal),
+            ("Block End", SelectionState::PartitionRight),
+            ("Scanning", SelectionState::Comparing),
+            ("Found", SelectionState::Sorted),
+        ]
+    }
+
+    fn get_statistics_strings(&self) -> Vec<String> {
+        let phase_str = match self.phase {
+            JumpSearchPhase::Jumping => format!("Jumping, block [{}..{})", self.block_start, self.block_end),
+            JumpSearchPhase::BackScanning => format!("Scanning index {}", self.scan_index),
+            JumpSearchPhase::Found => format!("Found at index {}", self.found_index.unwrap()),
+            JumpSearchPhase::NotFound => "Not Found".to_string(),
+        };
+
+        vec![
+            format!("Array Size: {}", self.array.len()),
+            format!("Searching for: {}", self.state.value_text(self.target)),
+            format!("Jump: {}", self.jump),
+            format!("Comparisons: {}", self.state.comparisons),
+            format!("Phase: {}", phase_str),
+            format!("Progress: {:.1}%", self.get_progress()),
+            if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
+        ]
+    }
+
+    fn get_current_operation(&self) -> String {
+        if self.state.completed {
+            if let Some(found) = self.found_index {
+                format!("✓ Target {} found at index {}!", self.state.value_text(self.target), found)
+            } else {
+                format!("✗ Target {} not found after {} comparisons.", self.state.value_text(self.target), self.state.comparisons)
+            }
+        } else {
+            match self.phase {
+                JumpSearchPhase::Jumping => {
+                    if self.block_start < self.array.len() {
+                        let boundary = self.block_end - 1;
+                        format!("Jump search: block end [{}]={} vs target={}", boundary, self.state.value_text(self.array[boundary]), self.state.value_text(self.target))
+                    } else {
+                        "Jumped past the end of the array".to_string()
+                    }
+                },
+                JumpSearchPhase::BackScanning => {
+                    if self.scan_index < self.block_end {
+                        format!("Scanning block [{}..{}): [{}]={} vs target={}", self.block_start, self.block_end, self.scan_index, self.state.value_text(self.array[self.scan_index]), self.state.value_text(self.target))
+                    } else {
+                        "Block scanned without a match".to_string()
+                    }
+                },
+                JumpSearchPhase::Found => {
+                    format!("Target {} found at index {}!", self.state.value_text(self.target), self.found_index.unwrap())
+                },
+                JumpSearchPhase::NotFound => {
+                    format!("Target {} not found after {} comparisons.", self.state.value_text(self.target), self.state.comparisons)
+                },
+            }
+        }
+    }
+
+    fn get_status(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "WAITING FOR QUESTION"
+        } else if self.state.completed {
+            "COMPLETED!"
+        } else if self.state.is_running && !self.state.is_paused {
+            "SEARCHING..."
+        } else if self.state.is_paused {
+            "PAUSED"
+        } else {
+            "READY"
+        }
+    }
+
+    fn get_controls_text(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "1,2,3: Answer | ESC: Exit"
+        } else if self.state.completed {
+            "SPACE: Restart | R: Reset | T: Teaching Toggle | ESC: Exit"
+        } else {
+            "SPACE: Start/Pause | S: Step | R: Reset | T: Teaching | +/-: Speed | ESC: Exit"
+        }
+    }
+}
+
+/// Entry point for the jump search visualization, which only runs on a sorted array
+pub fn jump_search_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    if !array_data.data.is_sorted() {
+        show_warning(
+            "Array Not Sorted",
+            &format!(
+                "Jump Search skips whole blocks, which only works\non a sorted array, and \"{}\" is not sorted.\n\nCreate or select a sorted array first.",
+                array_data.name
+            ),
+        );
+        return None;
+    }
+    let settings = Settings::load();
+    let Some(visualizer) = JumpSearchVisualizer::new(array_data, &settings) else {
+        cleanup_terminal();
+        return None;
+    };
+    run_suspendable(visualizer, settings, last_run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Runs the search to completion, returns the found index and the comparisons made
+    fn search(values: &[u32], target: u32) -> (Option<usize>, u32) {
+        let array_data = ArrayData::new(values.to_vec(), "Jump".to_string());
+        let mut visualizer = JumpSearchVisualizer::with_target(&array_data, target, &Settings::default());
+        visualizer.state.teaching_mode = false;
+        while visualizer.step() {}
+        (visualizer.found_index, visualizer.state.comparisons)
+    }
+
+    #[test]
+    fn jumps_by_square_root_then_scans_the_block() {
+        let values: Vec<u32> = (1..=16).map(|value| value * 10).collect();
+        // Jump of 4: block ends 40, 80, 120 then scan 90, 100, 110
+        assert_eq!(search(&values, 110), (Some(10), 6));
+        assert_eq!(search(&values, 10), (Some(0), 2));
+        assert_eq!(search(&values, 160), (Some(15), 8));
+        // Stops at the first larger element in the block, or after jumping past the end
+        assert_eq!(search(&values, 95).0, None);
+        assert_eq!(search(&values, 500), (None, 4));
+    }
+}
@@ -1,5 +1,9 @@
 pub mod linear_search;
 mod binary_search;
+mod jump_search;
+mod interpolation_search;
 
 pub use linear_search::*;
-pub use binary_search::*;
\ No newline at end of file
+pub use binary_search::*;
+pub use jump_search::*;
+pub use interpolation_search::*;
\ No newline at end of file
@@ -0,0 +1,289 @@
+use crate::common::array_manager::ArrayData;
+use crate::common::clipboard::{copy_markdown, markdown_table};
+use crate::common::settings::Settings;
+use crate::common::theme;
+use crate::sort_algorithms::{headless_run, headless_sort_names};
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{poll, read, Event, KeyCode, KeyEventKind},
+    execute,
+    style::{Attribute, Color, Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor},
+    terminal::{disable_raw_mode, enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+    QueueableCommand,
+};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::io::{stdout, Write};
+use std::time::Duration;
+
+// Smallest size of every sweep, each further size doubles the one before
+const FIRST_SIZE: usize = 10;
+// Largest sizes the sweep can stop at, cycled with +/-
+const LARGEST_SIZES: [usize; 4] = [40, 80, 160, 320];
+// Rows of the chart between its top value and zero
+const CHART_ROWS: usize = 12;
+// Same value range as the random array generator
+const MIN_VALUE: u32 = 1;
+const MAX_VALUE: u32 = 100;
+
+/// The measured comparisons as a Markdown table, one row per size and one column per algorithm
+pub fn sweep_markdown(sizes: &[usize], series: &[(&str, Vec<u32>)]) -> String {
+    let mut header = vec!["n"];
+    header.extend(series.iter().map(|(name, _)| *name));
+    let rows: Vec<Vec<String>> = sizes
+        .iter()
+        .enumerate()
+        .map(|(i, size)| {
+            std::iter::once(size.to_string())
+                .chain(series.iter().map(|(_, values)| values.get(i).map_or(String::new(), u32::to_string)))
+                .collect()
+        })
+        .collect();
+    markdown_table(&header, &rows)
+}
+
+/// Sizes of a sweep: 10, 20, 40, ... up to and including `largest`
+pub fn sweep_sizes(largest: usize) -> Vec<usize> {
+    std::iter::successors(Some(FIRST_SIZE), |size| Some(size * 2))
+        .take_while(|&size| size <= largest)
+        .collect()
+}
+
+/// Comparisons the named algorithm makes at every size.
+/// Each size gets one random array seeded by the size, so a sweep always shows the same curve
+/// and two algorithms compared in one chart sort the same arrays.
+pub fn sweep(name: &str, sizes: &[usize], settings: &Settings) -> Vec<u32> {
+    sizes
+        .iter()
+        .map(|&size| {
+            let mut rng = StdRng::seed_from_u64(size as u64);
+            let data = (0..size).map(|_| rng.random_range(MIN_VALUE..=MAX_VALUE)).collect();
+            let array_data = ArrayData::new(data, format!("Sweep_{}", size));
+            headless_run(name, &array_data, settings).map_or(0, |run| run.comparisons)
+        })
+        .collect()
+}
+
+/// Row of the chart, counted from the top, every column of the curve through `values` falls on.
+/// The points sit `spacing` columns apart and are joined by straight lines,
+/// `top` is the value of the top row and zero is the bottom row.
+pub fn curve_rows(values: &[u32], top: u32, rows: usize, spacing: usize) -> Vec<usize> {
+    let row_of = |value: f64| {
+        let height = (value / top.max(1) as f64 * (rows - 1) as f64).round() as usize;
+        rows - 1 - height.min(rows - 1)
+    };
+    let Some(&last) = values.last() else {
+        return Vec::new();
+    };
+    let mut curve: Vec<usize> = values
+        .windows(2)
+        .flat_map(|pair| {
+            (0..spacing).map(move |column| {
+                let t = column as f64 / spacing as f64;
+                pair[0] as f64 + (pair[1] as f64 - pair[0] as f64) * t
+            })
+        })
+        .map(row_of)
+        .collect();
+    curve.push(row_of(last as f64));
+    curve
+}
+
+// Draws a curve from `curve_rows` with its first point at (x, y), the measured points stand out
+fn draw_curve(stdout: &mut std::io::Stdout, curve: &[usize], spacing: usize, x: u16, y: u16, color: Color) {
+    stdout.queue(SetForegroundColor(color)).unwrap();
+    for (column, &row) in curve.iter().enumerate() {
+        let marker = if column % spacing == 0 { "●" } else { "·" };
+        stdout.queue(MoveTo(x + column as u16, y + row as u16)).unwrap();
+        stdout.queue(Print(marker)).unwrap();
+    }
+    stdout.queue(ResetColor).unwrap();
+}
+
+// e.g. "×3.9 per doubling", how the count grew over the last doubling of n
+fn growth_text(values: &[u32]) -> String {
+    match values {
+        [.., before, last] if *before > 0 => format!("×{:.1} per doubling", *last as f64 / *before as f64),
+        _ => String::new(),
+    }
+}
+
+/// Runs one algorithm, and optionally a second one, across growing random arrays
+/// and charts their comparisons against n, e.g. O(n²) against O(n log n)
+///
+/// # Controls
+/// - ←/→: algorithm, ↑/↓: algorithm to overlay
+/// - +/-: largest size of the sweep
+/// - ESC: back to the main menu
+pub fn show_size_sweep() {
+    let names: Vec<&'static str> = headless_sort_names().collect();
+    let settings = Settings::load();
+    let mut stdout = stdout();
+    enable_raw_mode().unwrap();
+    execute!(stdout, EnterAlternateScreen, Hide).unwrap();
+
+    let mut algorithm = names.iter().position(|&name| name == "Bubble").unwrap_or(0);
+    let mut overlay = names.iter().position(|&name| name == "Merge"); // None shows one curve
+    let mut largest = 2;
+    let mut results: Option<(Vec<u32>, Option<Vec<u32>>)> = None;
+    let mut notice: Option<String> = None;
+
+    loop {
+        let sizes = sweep_sizes(LARGEST_SIZES[largest]);
+        let (width, height) = size().unwrap();
+        stdout.queue(Clear(ClearType::All)).unwrap();
+
+        // --- Title ---
+        let title = "TOGISOFT SIZE SWEEP";
+        stdout.queue(MoveTo(width.saturating_sub(title.len() as u16) / 2, 1)).unwrap();
+        stdout.queue(SetAttribute(Attribute::Bold)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Yellow)).unwrap();
+        stdout.queue(SetBackgroundColor(Color::DarkBlue)).unwrap();
+        stdout.queue(Print(title)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        // --- Selection ---
+        stdout.queue(MoveTo(4, 3)).unwrap();
+        stdout.queue(SetForegroundColor(Color::Cyan)).unwrap();
+        stdout.queue(Print(format!("Algorithm: < {} >", names[algorithm]))).unwrap();
+        stdout.queue(SetForegroundColor(Color::Magenta)).unwrap();
+        stdout.queue(Print(format!("   Overlay: < {} >", overlay.map_or("None", |overlay| names[overlay])))).unwrap();
+        stdout.queue(SetForegroundColor(theme::text())).unwrap();
+        stdout.queue(Print(format!("   Sizes: {} to {}, doubling", FIRST_SIZE, LARGEST_SIZES[largest]))).unwrap();
+        stdout.queue(ResetColor).unwrap();
+
+        // Running the sorts takes a moment, say so before the screen freezes
+        if results.is_none() {
+            stdout.queue(MoveTo(4, 5)).unwrap();
+            stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+            stdout.queue(Print("Running the sweep...")).unwrap();
+            stdout.queue(ResetColor).unwrap();
+            stdout.flush().unwrap();
+            results = Some((
+                sweep(names[algorithm], &sizes, &settings),
+                overlay.map(|overlay| sweep(names[overlay], &sizes, &settings)),
+            ));
+            continue;
+        }
+        let Some((values, overlay_values)) = results.as_ref() else {
+            continue;
+        };
+
+        // --- Chart ---
+        let top = values.iter().chain(overlay_values.iter().flatten()).copied().max().unwrap_or(0).max(1);
+        let label_width = top.to_string().len() as u16 + 1;
+        let chart_x = 4 + label_width + 1;
+        let chart_y = 5u16;
+        let spacing = (width.saturating_sub(chart_x + 6) as usize / sizes.len().saturating_sub(1).max(1)).clamp(1, 16);
+        stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+        for row in 0..CHART_ROWS as u16 {
+            stdout.queue(MoveTo(chart_x - 1, chart_y + row)).unwrap();
+            stdout.queue(Print("│")).unwrap();
+        }
+        stdout.queue(MoveTo(4, chart_y)).unwrap();
+        stdout.queue(Print(format!("{:>width$}", top, width = label_width as usize))).unwrap();
+        stdout.queue(MoveTo(4, chart_y + CHART_ROWS as u16 - 1)).unwrap();
+        stdout.queue(Print(format!("{:>width$}", 0, width = label_width as usize))).unwrap();
+        stdout.queue(MoveTo(chart_x - 1, chart_y + CHART_ROWS as u16)).unwrap();
+        stdout.queue(Print(format!("└{}", "─".repeat(spacing * (sizes.len() - 1) + 2)))).unwrap();
+        for (i, size) in sizes.iter().enumerate() {
+            stdout.queue(MoveTo(chart_x + (i * spacing) as u16, chart_y + CHART_ROWS as u16 + 1)).unwrap();
+            stdout.queue(Print(size)).unwrap();
+        }
+        stdout.queue(ResetColor).unwrap();
+
+        if let Some(overlay_values) = overlay_values {
+            draw_curve(&mut stdout, &curve_rows(overlay_values, top, CHART_ROWS, spacing), spacing, chart_x, chart_y, Color::Magenta);
+        }
+        draw_curve(&mut stdout, &curve_rows(values, top, CHART_ROWS, spacing), spacing, chart_x, chart_y, Color::Cyan);
+
+        // --- Measured values ---
+        let table_y = chart_y + CHART_ROWS as u16 + 3;
+        let series = std::iter::once((algorithm, values, Color::Cyan))
+            .chain(overlay.zip(overlay_values.as_ref()).map(|(overlay, values)| (overlay, values, Color::Magenta)));
+        for (row, (index, values, color)) in series.enumerate() {
+            let counts: Vec<String> = values.iter().map(u32::to_string).collect();
+            stdout.queue(MoveTo(4, table_y + row as u16)).unwrap();
+            stdout.queue(SetForegroundColor(color)).unwrap();
+            stdout.queue(Print(format!("{:<16}{}  {}", names[index], counts.join(" "), growth_text(values)))).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
+        stdout.queue(MoveTo(4, table_y + 3)).unwrap();
+        stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+        stdout.queue(Print("Comparisons vs n. ×4 per doubling grows like n², a little over ×2 like n log n.")).unwrap();
+        stdout.queue(ResetColor).unwrap();
+        if let Some(notice) = &notice {
+            stdout.queue(MoveTo(4, table_y + 5)).unwrap();
+            stdout.queue(SetForegroundColor(Color::Green)).unwrap();
+            stdout.queue(Print(notice)).unwrap();
+            stdout.queue(ResetColor).unwrap();
+        }
+
+        // --- Instructions ---
+        let instructions = "←/→: Algorithm | ↑/↓: Overlay | +/-: Largest Size | M: Copy as Markdown | ESC: Back";
+        stdout.queue(MoveTo(width.saturating_sub(instructions.chars().count() as u16) / 2, height.saturating_sub(2))).unwrap();
+        stdout.queue(SetForegroundColor(theme::dim())).unwrap();
+        stdout.queue(Print(instructions)).unwrap();
+        stdout.queue(ResetColor).unwrap();
+        stdout.flush().unwrap();
+
+        // --- Handle Input ---
+        if !poll(Duration::from_millis(100)).unwrap_or(false) {
+            continue;
+        }
+        let Ok(Event::Key(key_event)) = read() else {
+            continue;
+        };
+        if key_event.kind != KeyEventKind::Press {
+            continue;
+        }
+        notice = None;
+        match key_event.code {
+            KeyCode::Char('m') | KeyCode::Char('M') => {
+                let mut series = vec![(names[algorithm], values.clone())];
+                series.extend(overlay.zip(overlay_values.clone()).map(|(overlay, values)| (names[overlay], values)));
+                notice = Some(copy_markdown(&sweep_markdown(&sizes, &series), "the sweep"));
+                continue;
+            }
+            KeyCode::Right => algorithm = (algorithm + 1) % names.len(),
+            KeyCode::Left => algorithm = (algorithm + names.len() - 1) % names.len(),
+            // The overlay cycles through every algorithm and then None
+            KeyCode::Down => overlay = overlay.map_or(Some(0), |overlay| (overlay + 1 < names.len()).then_some(overlay + 1)),
+            KeyCode::Up => overlay = overlay.map_or(Some(names.len() - 1), |overlay| overlay.checked_sub(1)),
+            KeyCode::Char('+') | KeyCode::Char('=') => largest = (largest + 1).min(LARGEST_SIZES.len() - 1),
+            KeyCode::Char('-') => largest = largest.saturating_sub(1),
+            KeyCode::Esc => break,
+            _ => continue,
+        }
+        results = None;
+    }
+
+    execute!(stdout, ResetColor, Show, LeaveAlternateScreen).unwrap();
+    disable_raw_mode().unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sweep_doubles_up_to_the_largest_size() {
+        assert_eq!(sweep_sizes(80), vec![10, 20, 40, 80]);
+        assert_eq!(sweep_sizes(100), vec![10, 20, 40, 80]);
+
+        // O(n²) against O(n log n) on the same arrays
+        let sizes = sweep_sizes(40);
+        let bubble = sweep("Bubble", &sizes, &Settings::default());
+        let merge = sweep("Merge", &sizes, &Settings::default());
+        assert!(bubble.iter().zip(&merge).all(|(bubble, merge)| bubble >= merge));
+        assert_eq!(sweep("Unknown", &sizes, &Settings::default()), vec![0; 3]);
+    }
+
+    #[test]
+    fn curve_joins_the_points_with_straight_lines() {
+        // 0 sits on the bottom row, the top value on the first row, halfway between them in between
+        assert_eq!(curve_rows(&[0, 100], 100, 5, 2), vec![4, 2, 0]);
+        assert_eq!(curve_rows(&[50], 100, 5, 4), vec![2]);
+        assert!(curve_rows(&[], 100, 5, 4).is_empty());
+    }
+}
@@ -1,32 +1,30 @@
+use crate::common::algorithm_info::AlgorithmInfo;
 use crate::common::array_manager::ArrayData;
 use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
-use crate::common::common_visualizer::{show_intro_screen, show_question_feedback, VisualizerDrawer};
+use crate::common::element::SortElement;
 use crate::common::enums::{SelectionState, TeachingQuestion};
-use crate::common::helper::{cleanup_terminal, randomize_questions};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
 use crate::common::settings::Settings;
-use crossterm::{
-    event::{poll, read, Event, KeyCode, KeyEventKind},
-    style::Color,
-    terminal::{enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen},
-    ExecutableCommand,
-};
-use std::io::{stdout, Write};
+use crossterm::event::KeyCode;
+use std::cmp::Ordering;
+use std::ops::Range;
 use std::time::Duration;
 
-pub struct BubbleSortVisualizer {
-    array: Vec<u32>,
-    original_array: Vec<u32>,
+#[derive(Clone)]
+pub struct BubbleSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,
+    original_array: Vec<T>,
     states: Vec<SelectionState>,
     current_i: usize,
     current_j: usize,
     sorted_count: usize,
-    state: VisualizerState,
+    state: VisualizerState<T>,
     awaiting_swap_confirmation: bool,
 }
 
-impl BubbleSortVisualizer {
-    pub fn new(array_data: &ArrayData) -> Self {
-        let settings = Settings::load();
+impl<T: SortElement> BubbleSortVisualizer<T> {
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
         let array = array_data.data.clone();
         let len = array.len();
 
@@ -65,10 +63,10 @@ impl BubbleSortVisualizer {
 
         randomize_questions(questions.clone());
 
-        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed));
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("BubbleSort")));
         state.teaching_mode = settings.teaching_mode;
 
-        let mut visualizer = Self {
+        Self {
             original_array: array.clone(),
             array,
             states: vec![SelectionState::Normal; len],
@@ -77,173 +75,14 @@ impl BubbleSortVisualizer {
             sorted_count: 0,
             state,
             awaiting_swap_confirmation: false,
-        };
-
-        // Set last visualizer
-        let mut settings = Settings::load();
-        settings.last_visualizer = Some("BubbleSort".to_string());
-        settings.save();
-
-        visualizer
-    }
-
-    pub fn run_visualization(&mut self) {
-        let mut stdout = stdout();
-        enable_raw_mode().unwrap();
-        stdout.execute(EnterAlternateScreen).unwrap();
-
-        show_intro_screen(self.get_intro_text());
-
-        loop {
-            self.draw(&mut stdout);
-
-            if poll(Duration::from_millis(50)).unwrap_or(false) {
-                match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
-                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                        // Handle question
-                        if let Some(q_index) = self.state.awaiting_question {
-                            match key_event.code {
-                                KeyCode::Char('1') => self.handle_question_answer(q_index, 0),
-                                KeyCode::Char('2') => self.handle_question_answer(q_index, 1),
-                                KeyCode::Char('3') => self.handle_question_answer(q_index, 2),
-                                _ => continue,
-                            }
-                            continue;
-                        }
-
-                        // Handle swap confirmation
-                        if self.awaiting_swap_confirmation {
-                            match key_event.code {
-                                KeyCode::Char('y') | KeyCode::Char('Y') => {
-                                    self.states[self.current_j] = SelectionState::Swapping;
-                                    self.states[self.current_j + 1] = SelectionState::Swapping;
-                                    self.array.swap(self.current_j, self.current_j + 1);
-                                    self.state.swaps += 1;
-                                    self.awaiting_swap_confirmation = false;
-                                    self.current_j += 1;
-                                    continue;
-                                },
-                                KeyCode::Char('n') | KeyCode::Char('N') => {
-                                    self.awaiting_swap_confirmation = false;
-                                    self.current_j += 1;
-                                    continue;
-                                },
-                                _ => {}
-                            }
-                        }
-
-                        match key_event.code {
-                            KeyCode::Char(' ') => {
-                                if self.state.completed {
-                                    self.reset();
-                                } else {
-                                    self.state.toggle_play_pause();
-                                }
-                            },
-                            KeyCode::Char('r') | KeyCode::Char('R') => {
-                                self.reset();
-                            },
-                            KeyCode::Char('s') | KeyCode::Char('S') => {
-                                if !self.state.completed && !self.state.is_running {
-                                    if !self.step() {
-                                        self.state.mark_completed();
-                                        self.mark_all_sorted();
-                                    }
-                                }
-                            },
-                            KeyCode::Char('t') | KeyCode::Char('T') => {
-                                self.state.toggle_teaching_mode();
-                                let mut settings = Settings::load();
-                                settings.teaching_mode = self.state.teaching_mode;
-                                settings.save();
-                            },
-                            KeyCode::Char('+') => {
-                                self.state.increase_speed(50);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Char('-') => {
-                                self.state.decrease_speed(2000);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Esc => {
-                                let mut settings = Settings::load();
-                                settings.last_visualizer = Some("BubbleSort".to_string());
-                                settings.save();
-                                cleanup_terminal();
-                                return;
-                            },
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
-            }
-
-            // Auto step
-            if self.state.is_running && !self.state.is_paused && !self.state.completed
-                && !self.awaiting_swap_confirmation && self.state.awaiting_question.is_none() {
-                std::thread::sleep(self.state.speed);
-                if !self.step() {
-                    self.state.mark_completed();
-                    self.mark_all_sorted();
-                }
-            }
-        }
-    }
-
-    fn handle_question_answer(&mut self, q_index: usize, answer: usize) {
-        if let Some(question) = self.state.questions.get(q_index) {
-            let correct = answer == question.correct_index;
-            show_question_feedback(correct, question, answer);
-            self.state.clear_question();
         }
     }
-
-    fn draw(&mut self, stdout: &mut std::io::Stdout) {
-        let (width, height) = size().unwrap();
-        stdout.execute(Clear(ClearType::All)).unwrap();
-
-        // Title
-        VisualizerDrawer::draw_title(stdout, self.get_title());
-
-        // Array
-        VisualizerDrawer::draw_array_bars(stdout, &self.array, &self.states, width, height, 5);
-
-        // Legend
-        VisualizerDrawer::draw_legend(stdout, &self.get_legend_items(), width, height, 5);
-
-        // Statistics
-        let stats = self.get_statistics_strings();
-        VisualizerDrawer::draw_statistics(stdout, &stats, width, height);
-
-        // Controls
-        VisualizerDrawer::draw_controls(stdout, self.get_status(), self.get_controls_text(), width, height);
-
-        // Current operation
-        if self.state.awaiting_question.is_none() && !self.awaiting_swap_confirmation {
-            let operation = self.get_current_operation();
-            let color = if self.state.completed { Color::Green } else { Color::White };
-            VisualizerDrawer::draw_operation_info(stdout, &operation, width, height, color);
-        }
-
-        // Question
-        if let Some(q_index) = self.state.awaiting_question {
-            if let Some(question) = self.state.questions.get(q_index) {
-                VisualizerDrawer::draw_question(stdout, question, width, height);
-            }
-        }
-
-        stdout.flush().unwrap();
-    }
 }
 
-impl SortVisualizer for BubbleSortVisualizer {
-    fn get_array(&self) -> &[u32] { &self.array }
-    fn get_original_array(&self) -> &[u32] { &self.original_array }
+impl<T: SortElement> SortVisualizer<T> for BubbleSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
     fn get_states(&self) -> &[SelectionState] { &self.states }
     fn get_comparisons(&self) -> u32 { self.state.comparisons }
     fn get_swaps(&self) -> u32 { self.state.swaps }
@@ -254,6 +93,53 @@ impl SortVisualizer for BubbleSortVisualizer {
     fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
     fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
     fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "BubbleSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Bubble Sort",
+            best: "O(n)",
+            average: "O(n^2)",
+            worst: "O(n^2)",
+            space: "O(1)",
+            stable: true,
+            in_place: true,
+        }
+    }
+    // Each pass bubbles the largest remaining value to the end of the unsorted part
+    fn sorted_regions(&self) -> Vec<Range<usize>> {
+        let finalized = if self.state.completed { 0..self.array.len() } else { self.array.len() - self.sorted_count..self.array.len() };
+        vec![finalized]
+    }
+
+    fn handle_extra_input(&mut self, key_code: KeyCode) -> bool {
+        // Handle swap confirmation
+        if !self.awaiting_swap_confirmation {
+            return false;
+        }
+        match key_code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                self.states[self.current_j] = SelectionState::Swapping;
+                self.states[self.current_j + 1] = SelectionState::Swapping;
+                self.array.swap(self.current_j, self.current_j + 1);
+                self.state.swaps += 1;
+                self.awaiting_swap_confirmation = false;
+                self.current_j += 1;
+                true
+            },
+            KeyCode::Char('n') | KeyCode::Char('N') => {
+                self.awaiting_swap_confirmation = false;
+                self.current_j += 1;
+                true
+            },
+            _ => false,
+        }
+    }
+
+    fn has_extra_state(&self) -> bool {
+        self.awaiting_swap_confirmation
+    }
 
     fn get_progress(&self) -> f64 {
         let total = (self.array.len() * (self.array.len() - 1)) / 2;
@@ -282,9 +168,8 @@ impl SortVisualizer for BubbleSortVisualizer {
         if self.current_j < n - 1 - self.current_i {
             self.states[self.current_j] = SelectionState::Comparing;
             self.states[self.current_j + 1] = SelectionState::Comparing;
-            self.state.comparisons += 1;
 
-            if self.array[self.current_j] > self.array[self.current_j + 1] {
+            if self.compare(self.current_j, self.current_j + 1).is_gt() {
                 if self.state.is_running {
                     self.states[self.current_j] = SelectionState::Swapping;
                     self.states[self.current_j + 1] = SelectionState::Swapping;
@@ -339,15 +224,37 @@ impl SortVisualizer for BubbleSortVisualizer {
          Press any key to continue..."
     }
 
-    fn get_legend_items(&self) -> Vec<(&str, Color)> {
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
         vec![
-            ("Normal", Color::Cyan),
-            ("Comparing", Color::Yellow),
-            ("Swapping", Color::Red),
-            ("Sorted", Color::Green),
+            ("Normal", SelectionState::Normal),
+            ("Comparing", SelectionState::Comparing),
+            ("Swapping", SelectionState::Swapping),
+            ("Sorted", SelectionState::Sorted),
         ]
     }
 
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        let hint = if self.current_j < self.array.len().saturating_sub(1 + self.current_i) {
+            "Neighbours are compared because a larger value on the left is out of order; swapping it right carries the largest value of the pass to the end"
+        } else {
+            "A pass ends at the sorted tail: each pass leaves the largest remaining value in its final place, so the next pass can stop one earlier"
+        };
+        Some(hint)
+    }
+
+    fn decision_changers(&self) -> Vec<usize> {
+        let n = self.array.len();
+        if self.state.completed || self.awaiting_swap_confirmation || self.current_i + 1 >= n || self.current_j + 1 >= n - self.current_i {
+            return Vec::new();
+        }
+        // array[j] swaps with its right neighbour if it orders after it
+        let j = self.current_j;
+        self.state.decision_changers(&self.array, j, self.array[j + 1], &[j, j + 1], Ordering::is_gt)
+    }
+
     fn get_current_operation(&self) -> String {
         if self.state.completed {
             "✓ Array is now sorted! Congratulations!".to_string()
@@ -357,9 +264,9 @@ impl SortVisualizer for BubbleSortVisualizer {
                     "Pass {}: comparing array[{}] ({}) with array[{}] ({})",
                     self.current_i + 1,
                     self.current_j,
-                    self.array[self.current_j],
+                    self.state.value_text(self.array[self.current_j]),
                     self.current_j + 1,
-                    self.array[self.current_j + 1]
+                    self.state.value_text(self.array[self.current_j + 1])
                 )
             } else {
                 format!("Pass {} completed. Largest element bubbled to the end.", self.current_i + 1)
@@ -398,7 +305,7 @@ impl SortVisualizer for BubbleSortVisualizer {
     }
 }
 
-pub fn bubble_sort_visualization(array_data: &ArrayData) {
-    let mut visualizer = BubbleSortVisualizer::new(array_data);
-    visualizer.run_visualization();
+pub fn bubble_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(BubbleSortVisualizer::with_settings(array_data, &settings), settings, last_run)
 }
\ No newline at end of file
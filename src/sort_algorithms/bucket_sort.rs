@@ -1,35 +1,32 @@
+use crate::common::algorithm_info::AlgorithmInfo;
 use crate::common::array_manager::ArrayData;
 use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
-use crate::common::common_visualizer::{show_intro_screen, show_question_feedback, VisualizerDrawer};
-use crate::common::enums::{SelectionState, TeachingQuestion};
-use crate::common::helper::{cleanup_terminal, randomize_questions};
+use crate::common::common_visualizer::VisualizerDrawer;
+use crate::common::element::SortElement;
+use crate::common::enums::{BucketInnerSort, SelectionState, TeachingQuestion};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
 use crate::common::settings::Settings;
-use crossterm::{
-    event::{poll, read, Event, KeyCode, KeyEventKind},
-    style::Color,
-    terminal::{enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen},
-    ExecutableCommand,
-};
-use std::io::{stdout, Write};
 use std::time::Duration;
 
 /// Represents the different phases of the bucket sort algorithm
 #[derive(Clone, Copy, PartialEq)]
 pub enum BucketPhase {
     Distributing,  // Distributing elements to buckets
-    Sorting,       // Sorting all buckets (one step)
+    Sorting,       // Running the inner sort on each bucket
     Collecting,    // Collecting sorted buckets into array
     Done,          // Sorting is complete
 }
 
 /// Visualizes the bucket sort algorithm step-by-step with interactive controls
-pub struct BucketSortVisualizer {
-    array: Vec<u32>,           // Current state of the array being sorted
-    original_array: Vec<u32>,  // Original array, used for resetting
+#[derive(Clone)]
+pub struct BucketSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array being sorted
+    original_array: Vec<T>,  // Original array, used for resetting
     states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, swapping, sorted)
 
     // Bucket Sort specific fields
-    buckets: Vec<Vec<u32>>,    // Buckets for distribution
+    buckets: Vec<Vec<T>>,    // Buckets for distribution
     num_buckets: usize,        // Number of buckets
     max_val: f64,              // Maximum value in array for bucket calculation
     current_i: usize,          // Current index for distribution
@@ -38,19 +35,23 @@ pub struct BucketSortVisualizer {
     current_in_bucket: usize,  // Current index within current bucket
     last_idx: usize,           // Last distributed index
     last_bucket: usize,        // Last bucket used
-    last_placed: u32,          // Last placed value
+    last_placed: T,          // Last placed value
+    inner_sort: BucketInnerSort, // Algorithm run on each bucket
+    sorting_bucket: usize,     // Bucket the inner sort is running on
+    inner_steps: Vec<InnerStep<T>>, // Recorded operations of the inner sort on the current bucket
+    inner_pos: usize,          // Next inner step to show
+    inner_active: Option<usize>, // Position in the bucket the last inner step touched
     phase: BucketPhase,        // Current phase of the bucket sort algorithm
-    state: VisualizerState,    // Common visualization state
+    state: VisualizerState<T>,    // Common visualization state
 }
 
-impl BucketSortVisualizer {
-    /// Creates a new BucketSortVisualizer with the given array
-    pub fn new(array_data: &ArrayData) -> Self {
-        let settings = Settings::load();
+impl<T: SortElement> BucketSortVisualizer<T> {
+    /// Creates a new BucketSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
         let array = array_data.data.clone();
         let len = array.len();
 
-        let mut questions = vec![
+        let questions = vec![
             TeachingQuestion {
                 text: "What is the key idea behind Bucket Sort?".to_string(),
                 options: vec![
@@ -81,17 +82,23 @@ impl BucketSortVisualizer {
                 correct_index: 0,
                 explanation: "With k buckets and uniform distribution, Bucket Sort achieves O(n + k) average time complexity.".to_string(),
             },
+            TeachingQuestion {
+                text: "What decides how long sorting the buckets takes?".to_string(),
+                options: vec![
+                    "The inner sort's cost on each bucket, so it depends on how evenly the values were spread".to_string(),
+                    "Only the number of buckets, whatever they contain".to_string(),
+                    "Nothing, distributing the values already sorted them".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "Each bucket costs the inner sort's time on its size. Evenly spread values keep buckets tiny and the total near O(n), but if most values share one bucket, insertion sort makes it O(n^2) while a recursive bucket sort splits that bucket's range again.".to_string(),
+            },
         ];
 
         randomize_questions(questions.clone());
 
-        let max_val = if let Some(&m) = array.iter().max() {
-            m as f64
-        } else {
-            1.0
-        };
+        let max_val = largest_value(&array);
 
-        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed));
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("BucketSort")));
         state.teaching_mode = settings.teaching_mode;
 
         let mut this = Self {
@@ -107,16 +114,16 @@ impl BucketSortVisualizer {
             current_in_bucket: 0,
             last_idx: 0,
             last_bucket: 0,
-            last_placed: 0,
+            last_placed: T::default(),
+            inner_sort: settings.bucket_inner_sort,
+            sorting_bucket: 0,
+            inner_steps: Vec::new(),
+            inner_pos: 0,
+            inner_active: None,
             phase: BucketPhase::Distributing,
             state,
         };
 
-        // Set last visualizer
-        let mut settings = Settings::load();
-        settings.last_visualizer = Some("BucketSort".to_string());
-        settings.save();
-
         if len <= 1 {
             this.state.mark_completed();
             this.mark_all_sorted();
@@ -125,170 +132,141 @@ impl BucketSortVisualizer {
         this
     }
 
-    /// Sorts all buckets using insertion sort and counts operations
-    fn sort_all_buckets(&mut self) {
-        for bucket in self.buckets.clone().iter_mut() {
-            self.insertion_sort_bucket(bucket);
-        }
+    // Starts the inner sort on the first bucket from `bucket` on that needs sorting,
+    // moves on to collecting once every bucket is sorted
+    fn start_inner_sort(&mut self, bucket: usize) {
+        let next = (bucket..self.num_buckets).find(|&i| self.buckets[i].len() > 1);
+        let Some(next) = next else {
+            self.inner_steps.clear();
+            self.inner_active = None;
+            self.phase = BucketPhase::Collecting;
+            self.current_pos = 0;
+            self.current_bucket = 0;
+            self.current_in_bucket = 0;
+            // Teaching: Ask what the overall cost depends on once every bucket is sorted
+            if self.state.teaching_mode {
+                self.state.ask_question(COMPOSITION_QUESTION);
+            }
+            return;
+        };
+        self.sorting_bucket = next;
+        self.inner_steps = trace_inner_sort(&self.buckets[next], self.inner_sort, &self.state);
+        self.inner_pos = 0;
+        self.inner_active = None;
     }
+}
 
-    /// Insertion sort for a single bucket, counting comparisons and swaps
-    fn insertion_sort_bucket(&mut self, bucket: &mut Vec<u32>) {
-        let m = bucket.len();
-        for i in 1..m {
-            let key = bucket[i];
-            let mut j = i as isize - 1;
-            while j >= 0 && bucket[j as usize] > key {
-                bucket[(j + 1) as usize] = bucket[j as usize];
-                self.state.swaps += 1;
-                self.state.comparisons += 1;
-                j -= 1;
-            }
-            if (j + 1) as usize != i {
-                bucket[(j + 1) as usize] = key;
-                self.state.swaps += 1;
-            }
-            self.state.comparisons += 1; // For the final comparison
+/// One operation of the sort running inside a bucket
+#[derive(Clone)]
+struct InnerStep<T> {
+    values: Vec<T>,      // The bucket after the operation
+    active: Option<usize>, // Position in the bucket the operation touched
+    comparisons: u32,      // Comparisons the operation made
+    moves: u32,            // Moves the operation made
+    note: String,          // What happened, for the current operation line
+}
+
+// Sub-buckets the recursive inner sort splits a bucket into
+const INNER_BUCKETS: usize = 4;
+// Asked once every bucket is sorted, before collecting
+const COMPOSITION_QUESTION: usize = 3;
+
+// Sorts a copy of `values` with `inner_sort`, recording every operation so it can be replayed step by step.
+// `state` writes the values in the notes as the user sees them.
+fn trace_inner_sort<T: SortElement>(values: &[T], inner_sort: BucketInnerSort, state: &VisualizerState<T>) -> Vec<InnerStep<T>> {
+    let mut values = values.to_vec();
+    let mut steps = Vec::new();
+    match inner_sort {
+        BucketInnerSort::Insertion => trace_insertion(&mut values, &mut steps, state),
+        BucketInnerSort::Bucket => {
+            let len = values.len();
+            trace_bucket_pass(&mut values, 0..len, &mut steps, state);
         }
     }
+    steps
+}
 
-    /// Main loop: handles rendering, input, and stepping through the sort
-    pub fn run_visualization(&mut self) {
-        let mut stdout = stdout();
-        enable_raw_mode().unwrap();
-        stdout.execute(EnterAlternateScreen).unwrap();
-
-        show_intro_screen(self.get_intro_text());
-
-        loop {
-            self.draw(&mut stdout);
-
-            if poll(Duration::from_millis(50)).unwrap_or(false) {
-                match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
-                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                        // Handle question
-                        if let Some(q_index) = self.state.awaiting_question {
-                            match key_event.code {
-                                KeyCode::Char('1') => self.handle_question_answer(q_index, 0),
-                                KeyCode::Char('2') => self.handle_question_answer(q_index, 1),
-                                KeyCode::Char('3') => self.handle_question_answer(q_index, 2),
-                                _ => continue,
-                            }
-                            continue;
-                        }
-
-                        match key_event.code {
-                            KeyCode::Char(' ') => {
-                                if self.state.completed {
-                                    self.reset();
-                                } else {
-                                    self.state.toggle_play_pause();
-                                }
-                            },
-                            KeyCode::Char('r') | KeyCode::Char('R') => {
-                                self.reset();
-                            },
-                            KeyCode::Char('s') | KeyCode::Char('S') => {
-                                if !self.state.completed && !self.state.is_running {
-                                    if !self.step() {
-                                        self.state.mark_completed();
-                                        self.mark_all_sorted();
-                                    }
-                                }
-                            },
-                            KeyCode::Char('t') | KeyCode::Char('T') => {
-                                self.state.toggle_teaching_mode();
-                                let mut settings = Settings::load();
-                                settings.teaching_mode = self.state.teaching_mode;
-                                settings.save();
-                            },
-                            KeyCode::Char('+') => {
-                                self.state.increase_speed(50);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Char('-') => {
-                                self.state.decrease_speed(2000);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Esc => {
-                                let mut settings = Settings::load();
-                                settings.last_visualizer = Some("BucketSort".to_string());
-                                settings.save();
-                                cleanup_terminal();
-                                return;
-                            },
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
+// Insertion sort: the held key moves left past every larger value, one shift per step
+fn trace_insertion<T: SortElement>(values: &mut [T], steps: &mut Vec<InnerStep<T>>, state: &VisualizerState<T>) {
+    for i in 1..values.len() {
+        let key = values[i];
+        let mut j = i;
+        while j > 0 {
+            let shifts = values[j - 1] > key;
+            let (previous, held) = (state.value_text(values[j - 1]), state.value_text(key));
+            let note = if shifts {
+                format!("{} > {}, shifting {} right", previous, held, previous)
+            } else {
+                format!("{} <= {}, {} stays at position {}", previous, held, held, j)
+            };
+            if shifts {
+                values.swap(j - 1, j);
+                j -= 1;
             }
-
-            // Auto step
-            if self.state.is_running && !self.state.is_paused && !self.state.completed
-                && self.state.awaiting_question.is_none() {
-                std::thread::sleep(self.state.speed);
-                if !self.step() {
-                    self.state.mark_completed();
-                    self.mark_all_sorted();
-                }
+            steps.push(InnerStep { values: values.to_vec(), active: Some(j), comparisons: 1, moves: shifts as u32, note });
+            if !shifts {
+                break;
             }
         }
-    }
-
-    fn handle_question_answer(&mut self, q_index: usize, answer: usize) {
-        if let Some(question) = self.state.questions.get(q_index) {
-            let correct = answer == question.correct_index;
-            show_question_feedback(correct, question, answer);
-            self.state.clear_question();
+        // The held key is written back only if it moved
+        if j != i && let Some(last) = steps.last_mut() {
+            last.moves += 1;
         }
     }
+}
 
-    fn draw(&mut self, stdout: &mut std::io::Stdout) {
-        let (width, height) = size().unwrap();
-        stdout.execute(Clear(ClearType::All)).unwrap();
-
-        // Title
-        VisualizerDrawer::draw_title(stdout, self.get_title());
-
-        // Array
-        VisualizerDrawer::draw_array_bars(stdout, &self.array, &self.states, width, height, 5);
-
-        // Legend
-        VisualizerDrawer::draw_legend(stdout, &self.get_legend_items(), width, height, 5);
-
-        // Statistics
-        let stats = self.get_statistics_strings();
-        VisualizerDrawer::draw_statistics(stdout, &stats, width, height);
-
-        // Controls
-        VisualizerDrawer::draw_controls(stdout, self.get_status(), self.get_controls_text(), width, height);
+// Bucket sort on values[range]: splits the range of values into sub-buckets, placing one element per step,
+// then sorts every sub-bucket the same way. Values that are all equal are already sorted.
+fn trace_bucket_pass<T: SortElement>(values: &mut [T], range: std::ops::Range<usize>, steps: &mut Vec<InnerStep<T>>, state: &VisualizerState<T>) {
+    let bucket = values[range.clone()].iter().copied();
+    let (Some(min), Some(max)) = (bucket.clone().min_by(|a, b| a.order(*b)), bucket.max_by(|a, b| a.order(*b))) else {
+        return;
+    };
+    if min == max {
+        return;
+    }
+    let count = INNER_BUCKETS.min(range.len());
+    let pending = values[range.clone()].to_vec();
+    let mut sub_buckets: Vec<Vec<T>> = vec![Vec::new(); count];
+    for (placed, &value) in pending.iter().enumerate() {
+        let sub = sub_bucket(value, min, max, count);
+        sub_buckets[sub].push(value);
+        // The bucket shows the sub-buckets filled so far, then the values still to place
+        let grouped: Vec<T> = sub_buckets.iter().flatten().copied().chain(pending[placed + 1..].iter().copied()).collect();
+        values[range.clone()].copy_from_slice(&grouped);
+        let position = range.start + sub_buckets[..=sub].iter().map(Vec::len).sum::<usize>() - 1;
+        let note = format!("{} goes to sub-bucket {} of {} (values {} to {})",
+                           state.value_text(value), sub + 1, count, state.value_text(min), state.value_text(max));
+        steps.push(InnerStep { values: values.to_vec(), active: Some(position), comparisons: 1, moves: 1, note });
+    }
 
-        // Current operation
-        if self.state.awaiting_question.is_none() {
-            let operation = self.get_current_operation();
-            let color = if self.state.completed { Color::Green } else { Color::White };
-            VisualizerDrawer::draw_operation_info(stdout, &operation, width, height, color);
+    // Every sub-bucket covers a narrower range of values, so the recursion ends
+    let mut start = range.start;
+    for sub_bucket in &sub_buckets {
+        let end = start + sub_bucket.len();
+        if sub_bucket.len() > 1 {
+            trace_bucket_pass(values, start..end, steps, state);
         }
+        start = end;
+    }
+}
 
-        // Question
-        if let Some(q_index) = self.state.awaiting_question {
-            if let Some(question) = self.state.questions.get(q_index) {
-                VisualizerDrawer::draw_question(stdout, question, width, height);
-            }
-        }
+// Which of `count` equal parts of min..=max holds `value`, the largest value lands in the last one
+fn sub_bucket<T: SortElement>(value: T, min: T, max: T, count: usize) -> usize {
+    let offset = value.to_f64() - min.to_f64();
+    let span = max.to_f64() - min.to_f64();
+    ((offset / span * count as f64) as usize).min(count - 1)
+}
 
-        stdout.flush().unwrap();
-    }
+// Largest value as a number, bucket indices are scaled by it
+fn largest_value<T: SortElement>(array: &[T]) -> f64 {
+    array.iter().map(|value| value.to_f64()).reduce(f64::max).unwrap_or(1.0)
 }
 
-impl SortVisualizer for BucketSortVisualizer {
-    fn get_array(&self) -> &[u32] { &self.array }
-    fn get_original_array(&self) -> &[u32] { &self.original_array }
+impl<T: SortElement> SortVisualizer<T> for BucketSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
     fn get_states(&self) -> &[SelectionState] { &self.states }
     fn get_comparisons(&self) -> u32 { self.state.comparisons }
     fn get_swaps(&self) -> u32 { self.state.swaps }
@@ -299,6 +277,71 @@ impl SortVisualizer for BucketSortVisualizer {
     fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
     fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
     fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "BucketSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Bucket Sort",
+            best: "O(n + k)",
+            average: "O(n + k)",
+            worst: "O(n^2)",
+            space: "O(n + k)",
+            stable: true,
+            in_place: false,
+        }
+    }
+    // The buckets are written back over the input, the values not yet overwritten are stale
+    fn verifiable(&self) -> bool { false }
+
+    // Buckets below the legend, the one being filled, sorted or collected is highlighted
+    fn draw_extra(&self, drawer: &mut VisualizerDrawer, width: u16, height: u16) {
+        let format = self.state.value_format;
+        let highlighted = match self.phase {
+            BucketPhase::Distributing if self.current_i > 0 => Some(self.last_bucket),
+            BucketPhase::Sorting => Some(self.sorting_bucket),
+            BucketPhase::Collecting => Some(self.current_bucket),
+            _ => None,
+        };
+        let buckets: Vec<Vec<String>> = self.buckets
+            .iter()
+            .enumerate()
+            .map(|(i, bucket)| {
+                bucket
+                    .iter()
+                    .enumerate()
+                    .map(|(j, &value)| {
+                        // The value the inner sort just moved or compared
+                        let touched = self.phase == BucketPhase::Sorting && i == self.sorting_bucket && self.inner_active == Some(j);
+                        if touched { format!("({})", value.format(format)) } else { value.format(format) }
+                    })
+                    .collect()
+            })
+            .collect();
+        drawer.draw_buckets("Bucket", &buckets, highlighted, width, height, 5);
+    }
+
+    // The statistics show the phase with a counter, the speed ramp needs it without
+    fn phase_label(&self) -> Option<String> {
+        let label = match self.phase {
+            BucketPhase::Distributing => "Distributing",
+            BucketPhase::Sorting => "Sorting Buckets",
+            BucketPhase::Collecting => "Collecting",
+            BucketPhase::Done => "Done",
+        };
+        Some(label.to_string())
+    }
+
+    // Distributes by value, so the result is always ascending
+    fn supports_custom_order(&self) -> bool { false }
+
+    fn edit_value(&mut self, index: usize, value: T) -> Result<(), String> {
+        self.array[index] = value;
+
+        // Bucket indices are scaled by the maximum value
+        self.max_val = largest_value(&self.array);
+        Ok(())
+    }
 
     fn get_progress(&self) -> f64 {
         let total = (self.array.len() * (self.array.len() - 1)) / 2;
@@ -329,13 +372,14 @@ impl SortVisualizer for BucketSortVisualizer {
 
                     // FIX: Clamp bucket index to prevent out of bounds access
                     let bucket_idx = if self.max_val > 0.0 {
-                        let idx = ((val as f64 / self.max_val) * self.num_buckets as f64).floor() as usize;
+                        let idx = ((val.to_f64() / self.max_val) * self.num_buckets as f64).floor() as usize;
                         idx.min(self.num_buckets - 1)  // Ensure index is within valid range
                     } else {
                         0
                     };
 
                     self.buckets[bucket_idx].push(val);
+                    self.state.moves += 1;
                     self.state.comparisons += 1;
                     self.last_idx = idx;
                     self.last_bucket = bucket_idx;
@@ -343,26 +387,24 @@ impl SortVisualizer for BucketSortVisualizer {
                     return true;
                 } else {
                     // End of distribution
-                    self.sort_all_buckets();
                     if self.state.teaching_mode && !self.state.questions.is_empty() {
-                        let q_index = 0;
-                        self.state.ask_question(q_index);
-                        self.phase = BucketPhase::Sorting;
-                        return true;
-                    } else {
-                        self.phase = BucketPhase::Collecting;
-                        self.current_pos = 0;
-                        self.current_bucket = 0;
-                        self.current_in_bucket = 0;
-                        return true;
+                        self.state.ask_question(0);
                     }
+                    self.phase = BucketPhase::Sorting;
+                    self.start_inner_sort(0);
+                    return true;
                 }
             },
             BucketPhase::Sorting => {
-                self.phase = BucketPhase::Collecting;
-                self.current_pos = 0;
-                self.current_bucket = 0;
-                self.current_in_bucket = 0;
+                if let Some(inner_step) = self.inner_steps.get(self.inner_pos) {
+                    self.buckets[self.sorting_bucket] = inner_step.values.clone();
+                    self.inner_active = inner_step.active;
+                    self.state.comparisons += inner_step.comparisons;
+                    self.state.moves += inner_step.moves;
+                    self.inner_pos += 1;
+                } else {
+                    self.start_inner_sort(self.sorting_bucket + 1);
+                }
                 return true;
             },
             BucketPhase::Collecting => {
@@ -374,7 +416,7 @@ impl SortVisualizer for BucketSortVisualizer {
                         self.last_placed = val;
                         self.current_pos += 1;
                         self.current_in_bucket += 1;
-                        self.state.swaps += 1;
+                        self.state.moves += 1;
                         return true;
                     } else {
                         self.current_in_bucket = 0;
@@ -393,6 +435,7 @@ impl SortVisualizer for BucketSortVisualizer {
     fn reset(&mut self) {
         self.array = self.original_array.clone();
         self.states = vec![SelectionState::Normal; self.array.len()];
+        self.max_val = largest_value(&self.array);
         self.buckets = vec![vec![]; self.num_buckets];
         self.current_i = 0;
         self.current_pos = 0;
@@ -400,7 +443,11 @@ impl SortVisualizer for BucketSortVisualizer {
         self.current_in_bucket = 0;
         self.last_idx = 0;
         self.last_bucket = 0;
-        self.last_placed = 0;
+        self.last_placed = T::default();
+        self.sorting_bucket = 0;
+        self.inner_steps.clear();
+        self.inner_pos = 0;
+        self.inner_active = None;
         self.phase = BucketPhase::Distributing;
         self.state.reset_state();
         if self.array.len() <= 1 {
@@ -421,25 +468,26 @@ impl SortVisualizer for BucketSortVisualizer {
 
     fn get_intro_text(&self) -> &str {
         "What is Bucket Sort?\n\n\
-         Bucket Sort is a distribution sorting algorithm that divides the input into a number of buckets, sorts each bucket individually (often using insertion sort), and then concatenates the buckets.\n\n\
+         Bucket Sort is a distribution sorting algorithm that divides the input into a number of buckets, sorts each bucket individually (often using insertion sort), and then concatenates the buckets.\n\
+         The inner sort run on each bucket can be changed in Settings.\n\n\
          Advantages: Linear time O(n+k) for uniform distributions.\n\
          Disadvantages: Requires knowing the range of values; performance degrades with uneven distribution.\n\n\
          Teaching Mode: ON (Toggle with T). Questions will be asked after distribution phase.\n\n\
          Press any key to continue..."
     }
 
-    fn get_legend_items(&self) -> Vec<(&str, Color)> {
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
         vec![
-            ("Normal", Color::Cyan),
-            ("Comparing", Color::Yellow),
-            ("Sorted", Color::Green),
+            ("Normal", SelectionState::Normal),
+            ("Comparing", SelectionState::Comparing),
+            ("Sorted", SelectionState::Sorted),
         ]
     }
 
     fn get_statistics_strings(&self) -> Vec<String> {
         let phase_str = match self.phase {
             BucketPhase::Distributing => format!("Distributing {}/{}", self.current_i, self.array.len()),
-            BucketPhase::Sorting => "Sorting Buckets".to_string(),
+            BucketPhase::Sorting => format!("Sorting Bucket {}/{}", self.sorting_bucket + 1, self.num_buckets),
             BucketPhase::Collecting => format!("Collecting {}/{}", self.current_pos, self.array.len()),
             BucketPhase::Done => "Done".to_string(),
         };
@@ -447,15 +495,29 @@ impl SortVisualizer for BucketSortVisualizer {
         vec![
             format!("Array Size: {}", self.array.len()),
             format!("Num Buckets: {}", self.num_buckets),
-            format!("Max Value: {}", self.max_val as u32),
+            format!("Max Value: {}", self.max_val),
             format!("Comparisons: {}", self.state.comparisons),
-            format!("Swaps: {}", self.state.swaps),
+            format!("Moves: {}", self.state.moves),
+            format!("Inner Sort: {}", self.inner_sort.label()),
             phase_str,
             format!("Progress: {:.1}%", self.get_progress()),
             if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
         ]
     }
 
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        let hint = match self.phase {
+            BucketPhase::Distributing => "Each value goes to the bucket covering its part of the value range, so every bucket only holds values close to each other",
+            BucketPhase::Sorting => "Buckets are small, so a simple inner sort finishes each one quickly; sorted buckets in order are the sorted array",
+            BucketPhase::Collecting => "Buckets are read back in order of their value ranges, so concatenating them keeps the whole array sorted",
+            _ => return None,
+        };
+        Some(hint)
+    }
+
     fn get_current_operation(&self) -> String {
         if self.state.completed {
             "✓ Array is now sorted using Bucket Sort! Congratulations!".to_string()
@@ -463,19 +525,20 @@ impl SortVisualizer for BucketSortVisualizer {
             match self.phase {
                 BucketPhase::Distributing => {
                     if self.current_i > 0 && self.last_idx < self.array.len() && self.current_i <= self.array.len() {
-                        format!("Distributing array[{}] ({}) to bucket {}", self.last_idx, self.array[self.last_idx], self.last_bucket)
+                        format!("Distributing array[{}] ({}) to bucket {}", self.last_idx, self.state.value_text(self.array[self.last_idx]), self.last_bucket)
                     } else {
                         "Starting distribution to buckets or preparing to sort".to_string()
                     }
                 },
-                BucketPhase::Sorting => {
-                    "Sorting each bucket using insertion sort".to_string()
+                BucketPhase::Sorting => match self.inner_pos.checked_sub(1).and_then(|last| self.inner_steps.get(last)) {
+                    Some(inner_step) => format!("Bucket {}: {}", self.sorting_bucket, inner_step.note),
+                    None => format!("Sorting bucket {} with {}", self.sorting_bucket, self.inner_sort.label()),
                 },
                 BucketPhase::Collecting => {
                     if self.current_in_bucket == 0 {
                         format!("Starting collection from bucket {}", self.current_bucket)
                     } else {
-                        format!("Placed {} at position {}", self.array[self.current_pos - 1], self.current_pos - 1)
+                        format!("Placed {} at position {}", self.state.value_text(self.array[self.current_pos - 1]), self.current_pos - 1)
                     }
                 },
                 BucketPhase::Done => {
@@ -511,7 +574,7 @@ impl SortVisualizer for BucketSortVisualizer {
 }
 
 /// Entry point for the bucket sort visualization
-pub fn bucket_sort_visualization(array_data: &ArrayData) {
-    let mut visualizer = BucketSortVisualizer::new(array_data);
-    visualizer.run_visualization();
+pub fn bucket_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(BucketSortVisualizer::with_settings(array_data, &settings), settings, last_run)
 }
\ No newline at end of file
@@ -1,18 +1,19 @@
+use crate::common::algorithm_info::AlgorithmInfo;
 use crate::common::array_manager::ArrayData;
 use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
-use crate::common::common_visualizer::{show_intro_screen, show_question_feedback, VisualizerDrawer};
+use crate::common::element::IntegerElement;
+use crate::common::common_visualizer::VisualizerDrawer;
 use crate::common::enums::{SelectionState, TeachingQuestion};
-use crate::common::helper::{cleanup_terminal, randomize_questions};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
 use crate::common::settings::Settings;
-use crossterm::{
-    event::{poll, read, Event, KeyCode, KeyEventKind},
-    style::Color,
-    terminal::{enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen},
-    ExecutableCommand,
-};
-use std::io::{stdout, Write};
+use crossterm::style::Color;
+use std::collections::HashSet;
 use std::time::Duration;
 
+// A count array this many times longer than the input spends more on the counts than on the values
+const WASTEFUL_RANGE_RATIO: usize = 4;
+
 /// Represents the different phases of the counting sort algorithm
 #[derive(Clone, Copy, PartialEq)]
 pub enum CountingPhase {
@@ -23,36 +24,37 @@ pub enum CountingPhase {
 }
 
 /// Visualizes the counting sort algorithm step-by-step with interactive controls
-pub struct CountingSortVisualizer {
-    array: Vec<u32>,           // Current state of the array being sorted
-    original_array: Vec<u32>,  // Original array, used for resetting
+#[derive(Clone)]
+pub struct CountingSortVisualizer<T: IntegerElement = u32> {
+    array: Vec<T>,             // Current state of the array being sorted
+    original_array: Vec<T>,    // Original array, used for resetting
     states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, sorted)
 
     // Counting Sort specific fields
     count: Vec<usize>,         // Count array
-    min_val: u32,              // Minimum value in array
-    max_val: u32,              // Maximum value in array
+    min_val: T,                // Minimum value in array
+    max_val: T,                // Maximum value in array
     range: usize,              // Range of values (max - min + 1)
     current_i: usize,          // Current index
-    last_val: u32,             // Last processed value
+    last_val: T,               // Last processed value
     last_pos: usize,           // Last placement position
     last_count_idx: usize,     // Last count index used
+    counted: Vec<T>,           // Values that were counted, placed back during the placing phase
     phase: CountingPhase,      // Current phase of the counting sort algorithm
-    state: VisualizerState,    // Common visualization state
+    state: VisualizerState<T>, // Common visualization state
 }
 
-impl CountingSortVisualizer {
-    /// Creates a new CountingSortVisualizer with the given array
-    pub fn new(array_data: &ArrayData) -> Self {
-        let settings = Settings::load();
+impl<T: IntegerElement> CountingSortVisualizer<T> {
+    /// Creates a new CountingSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
         let array = array_data.data.clone();
         let len = array.len();
         let (min_val, max_val) = if len == 0 {
-            (0u32, 0u32)
+            (T::default(), T::default())
         } else {
             (array.iter().min().unwrap().clone(), array.iter().max().unwrap().clone())
         };
-        let range = (max_val.saturating_sub(min_val) + 1) as usize;
+        let range = max_val.offset_from(min_val) as usize + 1;
 
         let mut questions = vec![
             TeachingQuestion {
@@ -89,7 +91,7 @@ impl CountingSortVisualizer {
 
         randomize_questions(questions.clone());
 
-        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed));
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("CountingSort")));
         state.teaching_mode = settings.teaching_mode;
 
         let mut this = Self {
@@ -101,18 +103,14 @@ impl CountingSortVisualizer {
             max_val,
             range,
             current_i: 0,
-            last_val: 0,
+            last_val: T::default(),
             last_pos: 0,
             last_count_idx: 0,
+            counted: Vec::new(),
             phase: CountingPhase::Counting,
             state,
         };
 
-        // Set last visualizer
-        let mut settings = Settings::load();
-        settings.last_visualizer = Some("CountingSort".to_string());
-        settings.save();
-
         if len <= 1 {
             this.state.mark_completed();
             this.mark_all_sorted();
@@ -121,143 +119,28 @@ impl CountingSortVisualizer {
         this
     }
 
-    /// Main loop: handles rendering, input, and stepping through the sort
-    pub fn run_visualization(&mut self) {
-        let mut stdout = stdout();
-        enable_raw_mode().unwrap();
-        stdout.execute(EnterAlternateScreen).unwrap();
-
-        show_intro_screen(self.get_intro_text());
-
-        loop {
-            self.draw(&mut stdout);
-
-            if poll(Duration::from_millis(50)).unwrap_or(false) {
-                match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
-                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                        // Handle question
-                        if let Some(q_index) = self.state.awaiting_question {
-                            match key_event.code {
-                                KeyCode::Char('1') => self.handle_question_answer(q_index, 0),
-                                KeyCode::Char('2') => self.handle_question_answer(q_index, 1),
-                                KeyCode::Char('3') => self.handle_question_answer(q_index, 2),
-                                _ => continue,
-                            }
-                            continue;
-                        }
-
-                        match key_event.code {
-                            KeyCode::Char(' ') => {
-                                if self.state.completed {
-                                    self.reset();
-                                } else {
-                                    self.state.toggle_play_pause();
-                                }
-                            },
-                            KeyCode::Char('r') | KeyCode::Char('R') => {
-                                self.reset();
-                            },
-                            KeyCode::Char('s') | KeyCode::Char('S') => {
-                                if !self.state.completed && !self.state.is_running {
-                                    if !self.step() {
-                                        self.state.mark_completed();
-                                        self.mark_all_sorted();
-                                    }
-                                }
-                            },
-                            KeyCode::Char('t') | KeyCode::Char('T') => {
-                                self.state.toggle_teaching_mode();
-                                let mut settings = Settings::load();
-                                settings.teaching_mode = self.state.teaching_mode;
-                                settings.save();
-                            },
-                            KeyCode::Char('+') => {
-                                self.state.increase_speed(50);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Char('-') => {
-                                self.state.decrease_speed(2000);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Esc => {
-                                let mut settings = Settings::load();
-                                settings.last_visualizer = Some("CountingSort".to_string());
-                                settings.save();
-                                cleanup_terminal();
-                                return;
-                            },
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
-            }
-
-            // Auto step
-            if self.state.is_running && !self.state.is_paused && !self.state.completed
-                && self.state.awaiting_question.is_none() {
-                std::thread::sleep(self.state.speed);
-                if !self.step() {
-                    self.state.mark_completed();
-                    self.mark_all_sorted();
-                }
-            }
-        }
+    /// Recomputes the minimum, maximum and range from the current array
+    fn update_value_range(&mut self) {
+        self.min_val = self.array.iter().copied().min().unwrap_or_default();
+        self.max_val = self.array.iter().copied().max().unwrap_or_default();
+        self.range = self.max_val.offset_from(self.min_val) as usize + 1;
     }
 
-    fn handle_question_answer(&mut self, q_index: usize, answer: usize) {
-        if let Some(question) = self.state.questions.get(q_index) {
-            let correct = answer == question.correct_index;
-            show_question_feedback(correct, question, answer);
-            self.state.clear_question();
-        }
+    /// Memory the count array takes, in bytes
+    pub fn count_array_bytes(&self) -> usize {
+        self.range * size_of::<usize>()
     }
 
-    fn draw(&mut self, stdout: &mut std::io::Stdout) {
-        let (width, height) = size().unwrap();
-        stdout.execute(Clear(ClearType::All)).unwrap();
-
-        // Title
-        VisualizerDrawer::draw_title(stdout, self.get_title());
-
-        // Array
-        VisualizerDrawer::draw_array_bars(stdout, &self.array, &self.states, width, height, 5);
-
-        // Legend
-        VisualizerDrawer::draw_legend(stdout, &self.get_legend_items(), width, height, 5);
-
-        // Statistics
-        let stats = self.get_statistics_strings();
-        VisualizerDrawer::draw_statistics(stdout, &stats, width, height);
-
-        // Controls
-        VisualizerDrawer::draw_controls(stdout, self.get_status(), self.get_controls_text(), width, height);
-
-        // Current operation
-        if self.state.awaiting_question.is_none() {
-            let operation = self.get_current_operation();
-            let color = if self.state.completed { Color::Green } else { Color::White };
-            VisualizerDrawer::draw_operation_info(stdout, &operation, width, height, color);
-        }
-
-        // Question
-        if let Some(q_index) = self.state.awaiting_question {
-            if let Some(question) = self.state.questions.get(q_index) {
-                VisualizerDrawer::draw_question(stdout, question, width, height);
-            }
-        }
-
-        stdout.flush().unwrap();
+    /// True if the value range is much larger than the array, so most count slots stay empty
+    pub fn is_range_wasteful(&self) -> bool {
+        self.range > WASTEFUL_RANGE_RATIO * self.array.len()
     }
 }
 
-impl SortVisualizer for CountingSortVisualizer {
-    fn get_array(&self) -> &[u32] { &self.array }
-    fn get_original_array(&self) -> &[u32] { &self.original_array }
+impl<T: IntegerElement> SortVisualizer<T> for CountingSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
     fn get_states(&self) -> &[SelectionState] { &self.states }
     fn get_comparisons(&self) -> u32 { self.state.comparisons }
     fn get_swaps(&self) -> u32 { self.state.swaps }
@@ -268,6 +151,39 @@ impl SortVisualizer for CountingSortVisualizer {
     fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
     fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
     fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "CountingSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Counting Sort",
+            best: "O(n + k)",
+            average: "O(n + k)",
+            worst: "O(n + k)",
+            space: "O(n + k)",
+            stable: true,
+            in_place: false,
+        }
+    }
+    // The output is written over the input from the counts, the values not yet overwritten are stale
+    fn verifiable(&self) -> bool { false }
+    // Distributes by value, so the result is always ascending
+    fn supports_custom_order(&self) -> bool { false }
+
+    fn edit_value(&mut self, index: usize, value: T) -> Result<(), String> {
+        if self.phase != CountingPhase::Counting {
+            return Err("Values can only be edited while they are still being counted".to_string());
+        }
+        self.array[index] = value;
+
+        // Rebuild the count array for the new value range
+        self.update_value_range();
+        self.count = vec![0; self.range];
+        for &val in &self.array[..self.current_i] {
+            self.count[val.offset_from(self.min_val) as usize] += 1;
+        }
+        Ok(())
+    }
 
     fn get_progress(&self) -> f64 {
         let total = (self.array.len() * (self.array.len() - 1)) / 2;
@@ -295,7 +211,7 @@ impl SortVisualizer for CountingSortVisualizer {
                     let idx = self.current_i;
                     self.states[idx] = SelectionState::Comparing;
                     let val = self.array[idx];
-                    let c_idx = (val - self.min_val) as usize;
+                    let c_idx = val.offset_from(self.min_val) as usize;
                     self.count[c_idx] += 1;
                     self.last_val = val;
                     self.last_count_idx = c_idx;
@@ -325,6 +241,7 @@ impl SortVisualizer for CountingSortVisualizer {
                 } else {
                     // End of prefix sum
                     self.phase = CountingPhase::Placing;
+                    self.counted = self.array.clone();
                     self.current_i = n;
                     return true;
                 }
@@ -332,8 +249,8 @@ impl SortVisualizer for CountingSortVisualizer {
             CountingPhase::Placing => {
                 if self.current_i > 0 {
                     self.current_i -= 1;
-                    let val = self.original_array[self.current_i];
-                    let idx = (val - self.min_val) as usize;
+                    let val = self.counted[self.current_i];
+                    let idx = val.offset_from(self.min_val) as usize;
                     let pos = self.count[idx].saturating_sub(1);
                     self.array[pos] = val;
                     self.states[pos] = SelectionState::Sorted;
@@ -341,7 +258,7 @@ impl SortVisualizer for CountingSortVisualizer {
                     self.last_val = val;
                     self.last_pos = pos;
                     self.last_count_idx = idx;
-                    self.state.swaps += 1;
+                    self.state.moves += 1;
                     return true;
                 } else {
                     self.phase = CountingPhase::Done;
@@ -356,9 +273,11 @@ impl SortVisualizer for CountingSortVisualizer {
         let len = self.original_array.len();
         self.array = self.original_array.clone();
         self.states = vec![SelectionState::Normal; len];
+        self.update_value_range();
         self.count = vec![0; self.range];
+        self.counted.clear();
         self.current_i = 0;
-        self.last_val = 0;
+        self.last_val = T::default();
         self.last_pos = 0;
         self.last_count_idx = 0;
         self.phase = CountingPhase::Counting;
@@ -389,11 +308,11 @@ impl SortVisualizer for CountingSortVisualizer {
          Press any key to continue..."
     }
 
-    fn get_legend_items(&self) -> Vec<(&str, Color)> {
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
         vec![
-            ("Normal", Color::Cyan),
-            ("Comparing", Color::Yellow),
-            ("Sorted", Color::Green),
+            ("Normal", SelectionState::Normal),
+            ("Comparing", SelectionState::Comparing),
+            ("Sorted", SelectionState::Sorted),
         ]
     }
 
@@ -410,27 +329,68 @@ impl SortVisualizer for CountingSortVisualizer {
             format!("Min Value: {}", self.min_val),
             format!("Max Value: {}", self.max_val),
             format!("Range: {}", self.range),
+            format!("Range / n: {:.1}x", self.range as f64 / self.array.len().max(1) as f64),
+            format!("Count Array: {} slots, {} bytes", self.range, self.count_array_bytes()),
             format!("Comparisons: {}", self.state.comparisons),
-            format!("Placements: {}", self.state.swaps),
+            format!("Moves: {}", self.state.moves),
             format!("Phase: {}", phase_str),
             format!("Progress: {:.1}%", self.get_progress()),
             if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
         ]
     }
 
+    // Warns under the legend when the count array dwarfs the input
+    fn draw_extra(&self, drawer: &mut VisualizerDrawer, width: u16, height: u16) {
+        if !self.is_range_wasteful() {
+            return;
+        }
+        let distinct = self.array.iter().collect::<HashSet<_>>().len();
+        let rows = [
+            (
+                format!(
+                    "⚠ The value range is {:.0}x the array size: counting sort is inefficient here",
+                    self.range as f64 / self.array.len().max(1) as f64
+                ),
+                Color::Red,
+            ),
+            (
+                format!(
+                    "  {} of the {} count slots stay empty, a comparison sort would need no extra memory",
+                    self.range - distinct.min(self.range),
+                    self.range
+                ),
+                Color::Red,
+            ),
+        ];
+        drawer.draw_text_rows(&rows, width, height, 5);
+    }
+
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        let hint = match self.phase {
+            CountingPhase::Counting => "Counting how often each value occurs replaces comparisons: the counts alone decide where every value goes",
+            CountingPhase::PrefixSum => "Adding up the counts turns them into end positions: the sum up to a value is how many elements are not larger than it",
+            CountingPhase::Placing => "Each value is written at the position its prefix sum points to; walking backwards keeps equal values in their order",
+            _ => return None,
+        };
+        Some(hint)
+    }
+
     fn get_current_operation(&self) -> String {
         if self.state.completed {
             "✓ Array is now sorted using Counting Sort! Congratulations!".to_string()
         } else {
             match self.phase {
                 CountingPhase::Counting => {
-                    format!("Counting: value {} -> count[{}]", self.last_val, self.last_count_idx)
+                    format!("Counting: value {} -> count[{}]", self.state.value_text(self.last_val), self.last_count_idx)
                 },
                 CountingPhase::PrefixSum => {
                     format!("Cumulative: count[{}] += count[{}]", self.last_count_idx + 1, self.last_count_idx)
                 },
                 CountingPhase::Placing => {
-                    format!("Placing value {} at position {}", self.last_val, self.last_pos)
+                    format!("Placing value {} at position {}", self.state.value_text(self.last_val), self.last_pos)
                 },
                 CountingPhase::Done => {
                     "Counting sort completed!".to_string()
@@ -465,7 +425,23 @@ impl SortVisualizer for CountingSortVisualizer {
 }
 
 /// Entry point for the counting sort visualization
-pub fn counting_sort_visualization(array_data: &ArrayData) {
-    let mut visualizer = CountingSortVisualizer::new(array_data);
-    visualizer.run_visualization();
-}
\ No newline at end of file
+pub fn counting_sort_visualization<T: IntegerElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(CountingSortVisualizer::with_settings(array_data, &settings), settings, last_run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wide_value_range_is_flagged() {
+        let settings = Settings::default();
+        let dense = CountingSortVisualizer::with_settings(&ArrayData::new(vec![3, 1, 2, 2, 4], "Dense".to_string()), &settings);
+        assert!(!dense.is_range_wasteful());
+        assert_eq!(dense.count_array_bytes(), 4 * size_of::<usize>());
+
+        let sparse = CountingSortVisualizer::with_settings(&ArrayData::new(vec![1, 100, 50], "Sparse".to_string()), &settings);
+        assert!(sparse.is_range_wasteful());
+    }
+}
@@ -0,0 +1,364 @@
+use crate::common::algorithm_info::AlgorithmInfo;
+use crate::common::array_manager::ArrayData;
+use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
+use crate::common::element::SortElement;
+use crate::common::enums::{SelectionState, TeachingQuestion};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
+use crate::common::settings::Settings;
+use std::time::Duration;
+
+/// Represents the different phases of the cycle sort algorithm
+#[derive(Clone, Copy, PartialEq)]
+pub enum CyclePhase {
+    FindingCyclePosition, // Counting smaller elements to find where the held item belongs
+    PlacingElement,       // Writing the held item into its final position
+    AdvancingCycle,       // Current cycle is closed, moving to the next cycle start
+    Done,                 // Sorting is complete
+}
+
+/// Visualizes the cycle sort algorithm step-by-step with interactive controls
+#[derive(Clone)]
+pub struct CycleSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array being sorted
+    original_array: Vec<T>,  // Original array, used for resetting
+    states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, swapping, sorted)
+
+    // Cycle Sort specific fields
+    cycle_start: usize,        // Index where the current cycle starts
+    item: T,                 // Value currently held "in hand" and being placed
+    pos: usize,                // Computed final position of the held item
+    scan_index: usize,         // Index being examined during the position-counting scan
+    placed_in_cycle: bool,     // Whether the current cycle has written anything yet
+    cycles: usize,             // Number of non-trivial cycles rotated so far
+    phase: CyclePhase,         // Current phase of the cycle sort algorithm
+    state: VisualizerState<T>,    // Common visualization state
+}
+
+impl<T: SortElement> CycleSortVisualizer<T> {
+    /// Creates a new CycleSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
+        let array = array_data.data.clone();
+        let len = array.len();
+
+        let questions = vec![
+            TeachingQuestion {
+                text: "Why is Cycle Sort optimal in the number of memory writes?".to_string(),
+                options: vec![
+                    "Each element is written directly into its final position at most once".to_string(),
+                    "It only writes when two neighbours are out of order".to_string(),
+                    "It copies the array into a buffer first".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "Cycle Sort computes each element's final position by counting smaller elements, then writes it there. An element already in place is never written, so the write count equals the minimum possible.".to_string(),
+            },
+            TeachingQuestion {
+                text: "How does Cycle Sort find where the held item belongs?".to_string(),
+                options: vec![
+                    "It counts how many elements after the cycle start are smaller".to_string(),
+                    "It uses binary search on the sorted prefix".to_string(),
+                    "It compares only with its right neighbour".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "The number of smaller elements is exactly the number of slots in front of the item, which gives its final index.".to_string(),
+            },
+            TeachingQuestion {
+                text: "Where is minimizing writes most useful?".to_string(),
+                options: vec![
+                    "On flash memory or EEPROM, where every write wears the cells".to_string(),
+                    "On CPU registers".to_string(),
+                    "When the array is already sorted".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "Flash and EEPROM have limited write endurance, so an algorithm that writes each element at most once extends the life of the storage.".to_string(),
+            },
+        ];
+
+        randomize_questions(questions.clone());
+
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("CycleSort")));
+        state.teaching_mode = settings.teaching_mode;
+
+        let mut this = Self {
+            item: array.first().copied().unwrap_or_default(),
+            original_array: array.clone(),
+            array,
+            states: vec![SelectionState::Normal; len],
+            cycle_start: 0,
+            pos: 0,
+            scan_index: 1,
+            placed_in_cycle: false,
+            cycles: 0,
+            phase: CyclePhase::FindingCyclePosition,
+            state,
+        };
+
+        if len <= 1 {
+            this.state.mark_completed();
+            this.mark_all_sorted();
+        }
+
+        this
+    }
+
+    /// Starts a new position-counting scan for the held item
+    fn begin_scan(&mut self) {
+        self.pos = self.cycle_start;
+        self.scan_index = self.cycle_start + 1;
+        self.phase = CyclePhase::FindingCyclePosition;
+    }
+}
+
+impl<T: SortElement> SortVisualizer<T> for CycleSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
+    fn get_states(&self) -> &[SelectionState] { &self.states }
+    fn get_comparisons(&self) -> u32 { self.state.comparisons }
+    fn get_swaps(&self) -> u32 { self.state.swaps }
+    fn get_speed(&self) -> Duration { self.state.speed }
+    fn is_running(&self) -> bool { self.state.is_running }
+    fn is_paused(&self) -> bool { self.state.is_paused }
+    fn is_completed(&self) -> bool { self.state.completed }
+    fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
+    fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
+    fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "CycleSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Cycle Sort",
+            best: "O(n^2)",
+            average: "O(n^2)",
+            worst: "O(n^2)",
+            space: "O(1)",
+            stable: false,
+            in_place: true,
+        }
+    }
+
+    fn get_progress(&self) -> f64 {
+        let total = self.array.len().saturating_sub(1);
+        if total == 0 { 100.0 } else {
+            (self.cycle_start as f64 / total as f64 * 100.0).min(100.0)
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if self.state.completed || self.state.awaiting_question.is_some() {
+            return true;
+        }
+
+        // Reset states except sorted
+        for state in &mut self.states {
+            if *state != SelectionState::Sorted {
+                *state = SelectionState::Normal;
+            }
+        }
+
+        let n = self.array.len();
+        if self.cycle_start >= n.saturating_sub(1) {
+            return false;
+        }
+
+        match self.phase {
+            CyclePhase::FindingCyclePosition => {
+                self.states[self.cycle_start] = SelectionState::CurrentMin;
+                if self.scan_index < n {
+                    // Every smaller element pushes the final position one slot right
+                    self.states[self.scan_index] = SelectionState::Comparing;
+                    if self.state.compare_values(self.array[self.scan_index], self.item).is_lt() {
+                        self.pos += 1;
+                    }
+                    self.scan_index += 1;
+                    if self.states[self.pos] != SelectionState::Sorted {
+                        self.states[self.pos] = SelectionState::Selected;
+                    }
+                } else if !self.placed_in_cycle && self.pos == self.cycle_start {
+                    // Item is already where it belongs, no write needed
+                    self.phase = CyclePhase::AdvancingCycle;
+                } else {
+                    self.phase = CyclePhase::PlacingElement;
+                }
+            },
+            CyclePhase::PlacingElement => {
+                // Skip over equal keys so they keep distinct slots
+                while self.pos < n && self.pos != self.cycle_start
+                    && self.state.compare_values(self.array[self.pos], self.item).is_eq() {
+                    self.pos += 1;
+                }
+
+                // Write the held item and pick up the displaced one
+                std::mem::swap(&mut self.array[self.pos], &mut self.item);
+                self.state.moves += 1;
+                self.states[self.pos] = SelectionState::Sorted;
+
+                if self.pos == self.cycle_start {
+                    // The cycle closed back on its start
+                    self.cycles += 1;
+                    self.phase = CyclePhase::AdvancingCycle;
+                } else {
+                    self.placed_in_cycle = true;
+                    self.begin_scan();
+                }
+            },
+            CyclePhase::AdvancingCycle => {
+                self.states[self.cycle_start] = SelectionState::Sorted;
+                self.cycle_start += 1;
+                if self.cycle_start >= n - 1 {
+                    self.phase = CyclePhase::Done;
+                    return false;
+                }
+                self.item = self.array[self.cycle_start];
+                self.placed_in_cycle = false;
+                self.begin_scan();
+
+                // Teaching: Ask question after each cycle start
+                self.state.ask_question(self.cycle_start);
+            },
+            CyclePhase::Done => return false,
+        }
+
+        true
+    }
+
+    fn reset(&mut self) {
+        self.array = self.original_array.clone();
+        self.states = vec![SelectionState::Normal; self.array.len()];
+        self.cycle_start = 0;
+        self.item = self.array.first().copied().unwrap_or_default();
+        self.placed_in_cycle = false;
+        self.cycles = 0;
+        self.begin_scan();
+        self.state.reset_state();
+        if self.array.len() <= 1 {
+            self.state.mark_completed();
+            self.mark_all_sorted();
+        }
+    }
+
+    fn mark_all_sorted(&mut self) {
+        for state in &mut self.states {
+            *state = SelectionState::Sorted;
+        }
+    }
+
+    fn get_title(&self) -> &str {
+        "TOGISOFT CYCLE SORT VISUALIZER"
+    }
+
+    fn get_intro_text(&self) -> &str {
+        "What is Cycle Sort?\n\n\
+         Cycle Sort picks up an element, counts how many elements are smaller to find its final position,\n\
+         writes it there and picks up the element it displaced, following the cycle until it returns to the start.\n\n\
+         Advantages: Minimal number of memory writes (each element is written at most once).\n\
+         Disadvantages: O(n^2) comparisons, not stable.\n\n\
+         Watch the Writes counter: it is the headline metric for this algorithm.\n\n\
+         Teaching Mode: ON (Toggle with T). Questions will be asked after each cycle.\n\n\
+         Press any key to continue..."
+    }
+
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
+        vec![
+            ("Normal", SelectionState::Normal),
+            ("Cycle Start", SelectionState::CurrentMin),
+            ("Scanning", SelectionState::Comparing),
+            ("Target Pos", SelectionState::Selected),
+            ("Placed", SelectionState::Sorted),
+        ]
+    }
+
+    fn get_statistics_strings(&self) -> Vec<String> {
+        let phase_str = match self.phase {
+            CyclePhase::FindingCyclePosition => "Finding Cycle Position",
+            CyclePhase::PlacingElement => "Placing Element",
+            CyclePhase::AdvancingCycle => "Advancing Cycle",
+            CyclePhase::Done => "Done",
+        };
+
+        vec![
+            format!("Array Size: {}", self.array.len()),
+            format!("Moves: {}", self.state.moves),
+            format!("Comparisons: {}", self.state.comparisons),
+            format!("Cycle Start: {}", self.cycle_start),
+            format!("Holding: {}", self.item),
+            format!("Cycles: {}", self.cycles),
+            format!("Phase: {}", phase_str),
+            format!("Progress: {:.1}%", self.get_progress()),
+            if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
+        ]
+    }
+
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        let hint = match self.phase {
+            CyclePhase::FindingCyclePosition => "Counting the smaller values tells exactly where the held value belongs, so it is written only once",
+            CyclePhase::PlacingElement => "The held value goes straight to its final position, picking up the value that was there to place next",
+            CyclePhase::AdvancingCycle => "The cycle closed back at its start, so every value in it is in place and the next start is checked",
+            _ => return None,
+        };
+        Some(hint)
+    }
+
+    fn get_current_operation(&self) -> String {
+        if self.state.completed {
+            format!("✓ Array is now sorted using Cycle Sort with only {} writes! Congratulations!", self.state.moves)
+        } else {
+            match self.phase {
+                CyclePhase::FindingCyclePosition => {
+                    if self.scan_index < self.array.len() {
+                        format!("Holding {}: is array[{}] ({}) smaller? Position so far: {}",
+                                self.state.value_text(self.item),
+                                self.scan_index,
+                                self.state.value_text(self.array[self.scan_index]),
+                                self.pos)
+                    } else {
+                        format!("Scan finished: {} belongs at index {}", self.state.value_text(self.item), self.pos)
+                    }
+                },
+                CyclePhase::PlacingElement => {
+                    format!("Writing {} into index {} and picking up {}", self.state.value_text(self.item), self.pos, self.state.value_text(self.array[self.pos]))
+                },
+                CyclePhase::AdvancingCycle => {
+                    format!("Cycle starting at index {} is closed, moving on", self.cycle_start)
+                },
+                CyclePhase::Done => {
+                    "Cycle sort completed!".to_string()
+                },
+            }
+        }
+    }
+
+    fn get_status(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "WAITING FOR QUESTION"
+        } else if self.state.completed {
+            "COMPLETED!"
+        } else if self.state.is_running && !self.state.is_paused {
+            "RUNNING..."
+        } else if self.state.is_paused {
+            "PAUSED"
+        } else {
+            "READY"
+        }
+    }
+
+    fn get_controls_text(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "1,2,3: Answer | ESC: Exit"
+        } else if self.state.completed {
+            "SPACE: Restart | R: Reset | T: Teaching Toggle | ESC: Exit"
+        } else {
+            "SPACE: Start/Pause | S: Step | R: Reset | T: Teaching | +/-: Speed | ESC: Exit"
+        }
+    }
+}
+
+/// Entry point for the cycle sort visualization
+pub fn cycle_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(CycleSortVisualizer::with_settings(array_data, &settings), settings, last_run)
+}
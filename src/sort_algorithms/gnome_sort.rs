@@ -1,16 +1,12 @@
+use crate::common::algorithm_info::AlgorithmInfo;
 use crate::common::array_manager::ArrayData;
 use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
-use crate::common::common_visualizer::{show_intro_screen, show_question_feedback, VisualizerDrawer};
+use crate::common::element::SortElement;
 use crate::common::enums::{SelectionState, TeachingQuestion};
-use crate::common::helper::{cleanup_terminal, randomize_questions};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
 use crate::common::settings::Settings;
-use crossterm::{
-    event::{poll, read, Event, KeyCode, KeyEventKind},
-    style::Color,
-    terminal::{enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen},
-    ExecutableCommand,
-};
-use std::io::{stdout, Write};
+use std::cmp::Ordering;
 use std::time::Duration;
 
 /// Represents the different phases of the gnome sort algorithm
@@ -22,21 +18,21 @@ pub enum GnomePhase {
 }
 
 /// Visualizes the gnome sort algorithm step-by-step with interactive controls
-pub struct GnomeSortVisualizer {
-    array: Vec<u32>,           // Current state of the array being sorted
-    original_array: Vec<u32>,  // Original array, used for resetting
+#[derive(Clone)]
+pub struct GnomeSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array being sorted
+    original_array: Vec<T>,  // Original array, used for resetting
     states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, swapping, sorted)
 
     // Gnome Sort specific fields
     current_i: usize,          // Current index
     phase: GnomePhase,         // Current phase of the gnome sort algorithm
-    state: VisualizerState,    // Common visualization state
+    state: VisualizerState<T>,    // Common visualization state
 }
 
-impl GnomeSortVisualizer {
-    /// Creates a new GnomeSortVisualizer with the given array
-    pub fn new(array_data: &ArrayData) -> Self {
-        let settings = Settings::load();
+impl<T: SortElement> GnomeSortVisualizer<T> {
+    /// Creates a new GnomeSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
         let array = array_data.data.clone();
         let len = array.len();
 
@@ -75,7 +71,7 @@ impl GnomeSortVisualizer {
 
         randomize_questions(questions.clone());
 
-        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed));
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("GnomeSort")));
         state.teaching_mode = settings.teaching_mode;
 
         let mut this = Self {
@@ -87,11 +83,6 @@ impl GnomeSortVisualizer {
             state,
         };
 
-        // Set last visualizer
-        let mut settings = Settings::load();
-        settings.last_visualizer = Some("GnomeSort".to_string());
-        settings.save();
-
         if len <= 1 {
             this.state.mark_completed();
             this.mark_all_sorted();
@@ -99,144 +90,12 @@ impl GnomeSortVisualizer {
 
         this
     }
-
-    /// Main loop: handles rendering, input, and stepping through the sort
-    pub fn run_visualization(&mut self) {
-        let mut stdout = stdout();
-        enable_raw_mode().unwrap();
-        stdout.execute(EnterAlternateScreen).unwrap();
-
-        show_intro_screen(self.get_intro_text());
-
-        loop {
-            self.draw(&mut stdout);
-
-            if poll(Duration::from_millis(50)).unwrap_or(false) {
-                match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
-                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                        // Handle question
-                        if let Some(q_index) = self.state.awaiting_question {
-                            match key_event.code {
-                                KeyCode::Char('1') => self.handle_question_answer(q_index, 0),
-                                KeyCode::Char('2') => self.handle_question_answer(q_index, 1),
-                                KeyCode::Char('3') => self.handle_question_answer(q_index, 2),
-                                _ => continue,
-                            }
-                            continue;
-                        }
-
-                        match key_event.code {
-                            KeyCode::Char(' ') => {
-                                if self.state.completed {
-                                    self.reset();
-                                } else {
-                                    self.state.toggle_play_pause();
-                                }
-                            },
-                            KeyCode::Char('r') | KeyCode::Char('R') => {
-                                self.reset();
-                            },
-                            KeyCode::Char('s') | KeyCode::Char('S') => {
-                                if !self.state.completed && !self.state.is_running {
-                                    if !self.step() {
-                                        self.state.mark_completed();
-                                        self.mark_all_sorted();
-                                    }
-                                }
-                            },
-                            KeyCode::Char('t') | KeyCode::Char('T') => {
-                                self.state.toggle_teaching_mode();
-                                let mut settings = Settings::load();
-                                settings.teaching_mode = self.state.teaching_mode;
-                                settings.save();
-                            },
-                            KeyCode::Char('+') => {
-                                self.state.increase_speed(50);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Char('-') => {
-                                self.state.decrease_speed(2000);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Esc => {
-                                let mut settings = Settings::load();
-                                settings.last_visualizer = Some("GnomeSort".to_string());
-                                settings.save();
-                                cleanup_terminal();
-                                return;
-                            },
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
-            }
-
-            // Auto step
-            if self.state.is_running && !self.state.is_paused && !self.state.completed
-                && self.state.awaiting_question.is_none() {
-                std::thread::sleep(self.state.speed);
-                if !self.step() {
-                    self.state.mark_completed();
-                    self.mark_all_sorted();
-                }
-            }
-        }
-    }
-
-    fn handle_question_answer(&mut self, q_index: usize, answer: usize) {
-        if let Some(question) = self.state.questions.get(q_index) {
-            let correct = answer == question.correct_index;
-            show_question_feedback(correct, question, answer);
-            self.state.clear_question();
-        }
-    }
-
-    fn draw(&mut self, stdout: &mut std::io::Stdout) {
-        let (width, height) = size().unwrap();
-        stdout.execute(Clear(ClearType::All)).unwrap();
-
-        // Title
-        VisualizerDrawer::draw_title(stdout, self.get_title());
-
-        // Array
-        VisualizerDrawer::draw_array_bars(stdout, &self.array, &self.states, width, height, 5);
-
-        // Legend
-        VisualizerDrawer::draw_legend(stdout, &self.get_legend_items(), width, height, 5);
-
-        // Statistics
-        let stats = self.get_statistics_strings();
-        VisualizerDrawer::draw_statistics(stdout, &stats, width, height);
-
-        // Controls
-        VisualizerDrawer::draw_controls(stdout, self.get_status(), self.get_controls_text(), width, height);
-
-        // Current operation
-        if self.state.awaiting_question.is_none() {
-            let operation = self.get_current_operation();
-            let color = if self.state.completed { Color::Green } else { Color::White };
-            VisualizerDrawer::draw_operation_info(stdout, &operation, width, height, color);
-        }
-
-        // Question
-        if let Some(q_index) = self.state.awaiting_question {
-            if let Some(question) = self.state.questions.get(q_index) {
-                VisualizerDrawer::draw_question(stdout, question, width, height);
-            }
-        }
-
-        stdout.flush().unwrap();
-    }
 }
 
-impl SortVisualizer for GnomeSortVisualizer {
-    fn get_array(&self) -> &[u32] { &self.array }
-    fn get_original_array(&self) -> &[u32] { &self.original_array }
+impl<T: SortElement> SortVisualizer<T> for GnomeSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
     fn get_states(&self) -> &[SelectionState] { &self.states }
     fn get_comparisons(&self) -> u32 { self.state.comparisons }
     fn get_swaps(&self) -> u32 { self.state.swaps }
@@ -247,6 +106,20 @@ impl SortVisualizer for GnomeSortVisualizer {
     fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
     fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
     fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "GnomeSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Gnome Sort",
+            best: "O(n)",
+            average: "O(n^2)",
+            worst: "O(n^2)",
+            space: "O(1)",
+            stable: true,
+            in_place: true,
+        }
+    }
 
     fn get_progress(&self) -> f64 {
         let total = (self.array.len() * (self.array.len() - 1)) / 2;
@@ -280,9 +153,8 @@ impl SortVisualizer for GnomeSortVisualizer {
                 if self.current_i < n {
                     self.states[self.current_i - 1] = SelectionState::Comparing;
                     self.states[self.current_i] = SelectionState::Comparing;
-                    self.state.comparisons += 1;
 
-                    if self.array[self.current_i - 1] <= self.array[self.current_i] {
+                    if self.compare(self.current_i - 1, self.current_i).is_le() {
                         self.phase = GnomePhase::Comparing;
                         self.current_i += 1;
                     } else {
@@ -352,12 +224,12 @@ impl SortVisualizer for GnomeSortVisualizer {
          Press any key to continue..."
     }
 
-    fn get_legend_items(&self) -> Vec<(&str, Color)> {
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
         vec![
-            ("Normal", Color::Cyan),
-            ("Comparing", Color::Yellow),
-            ("Swapping", Color::Red),
-            ("Sorted", Color::Green),
+            ("Normal", SelectionState::Normal),
+            ("Comparing", SelectionState::Comparing),
+            ("Swapping", SelectionState::Swapping),
+            ("Sorted", SelectionState::Sorted),
         ]
     }
 
@@ -379,6 +251,27 @@ impl SortVisualizer for GnomeSortVisualizer {
         ]
     }
 
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        let hint = match self.phase {
+            GnomePhase::Comparing => "The gnome compares the value with its left neighbour; in order means everything up to here is sorted, so it steps forward",
+            GnomePhase::Swapping => "Out of order values are swapped and the gnome steps back, carrying the smaller value left until it fits",
+            _ => return None,
+        };
+        Some(hint)
+    }
+
+    fn decision_changers(&self) -> Vec<usize> {
+        let i = self.current_i.max(1);
+        if self.state.completed || self.phase != GnomePhase::Comparing || i >= self.array.len() {
+            return Vec::new();
+        }
+        // The gnome steps forward if array[i] does not order before its left neighbour
+        self.state.decision_changers(&self.array, i, self.array[i - 1], &[i - 1, i], Ordering::is_ge)
+    }
+
     fn get_current_operation(&self) -> String {
         if self.state.completed {
             "✓ Array is now sorted using Gnome Sort! Congratulations!".to_string()
@@ -388,9 +281,9 @@ impl SortVisualizer for GnomeSortVisualizer {
                     if self.current_i < self.array.len() {
                         format!("Comparing array[{}] ({}) with array[{}] ({})",
                                 self.current_i - 1,
-                                self.array[self.current_i - 1],
+                                self.state.value_text(self.array[self.current_i - 1]),
                                 self.current_i,
-                                self.array[self.current_i])
+                                self.state.value_text(self.array[self.current_i]))
                     } else {
                         "Reached end of array".to_string()
                     }
@@ -433,7 +326,7 @@ impl SortVisualizer for GnomeSortVisualizer {
 }
 
 /// Entry point for the gnome sort visualization
-pub fn gnome_sort_visualization(array_data: &ArrayData) {
-    let mut visualizer = GnomeSortVisualizer::new(array_data);
-    visualizer.run_visualization();
+pub fn gnome_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(GnomeSortVisualizer::with_settings(array_data, &settings), settings, last_run)
 }
\ No newline at end of file
@@ -1,32 +1,29 @@
+use crate::common::algorithm_info::AlgorithmInfo;
 use crate::common::array_manager::ArrayData;
 use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
-use crate::common::common_visualizer::{show_intro_screen, show_question_feedback, VisualizerDrawer};
-use crate::common::enums::{SelectionState, TeachingQuestion};
-use crate::common::helper::{cleanup_terminal, randomize_questions};
+use crate::common::element::SortElement;
+use crate::common::enums::{SelectionState, SortOrder, TeachingQuestion};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
 use crate::common::settings::Settings;
-use crossterm::{
-    event::{poll, read, Event, KeyCode, KeyEventKind},
-    style::Color,
-    terminal::{enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen},
-    ExecutableCommand,
-};
-use std::io::{stdout, Write};
+use std::ops::Range;
 use std::time::Duration;
 
 // Represents the current phase of the heap sort algorithm
 #[derive(Clone, Copy, PartialEq)]
 pub enum HeapPhase {
-    BuildingMaxHeap,    // Building the max heap from the array
+    BuildingMaxHeap,    // Building the heap (max, or min for descending order) from the array
     HeapifyDown,        // Heapifying down after extraction
-    ExtractingMax,      // Extracting the maximum element from the heap
+    ExtractingMax,      // Extracting the root element from the heap
     SwappingRootWithLast, // Swapping the root with the last element in the heap
     Done,               // Sorting is complete
 }
 
 // Visualizes the heap sort algorithm step-by-step with interactive controls
-pub struct HeapSortVisualizer {
-    array: Vec<u32>,           // Current state of the array being sorted
-    original_array: Vec<u32>,  // Original array, used for resetting
+#[derive(Clone)]
+pub struct HeapSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array being sorted
+    original_array: Vec<T>,  // Original array, used for resetting
     states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, swapping, sorted)
     intro_text: String,        // Dynamic intro text
 
@@ -35,17 +32,17 @@ pub struct HeapSortVisualizer {
     current_index: usize,      // Current index being processed
     left_child: usize,         // Index of the left child of current_index
     right_child: usize,        // Index of the right child of current_index
-    largest: usize,            // Index of the largest element found during heapify
+    largest: usize,            // Index of the element that belongs on top found during heapify (smallest in a min heap)
     phase: HeapPhase,          // Current phase of the heap sort algorithm
-    build_heap_index: i32,     // Index used during the max heap building phase (i32 to handle negative values)
+    build_heap_index: i32,     // Index used during the heap building phase (i32 to handle negative values)
+    build_sifting: bool,       // True while the subtree at build_heap_index is still being heapified down
     extraction_count: usize,   // Number of extractions performed (for teaching questions)
-    state: VisualizerState,    // Common visualization state
+    state: VisualizerState<T>,    // Common visualization state
 }
 
-impl HeapSortVisualizer {
-    // Initializes a new HeapSortVisualizer with the given array
-    pub fn new(array_data: &ArrayData) -> Self {
-        let settings = Settings::load();
+impl<T: SortElement> HeapSortVisualizer<T> {
+    // Initializes a new HeapSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
         let array = array_data.data.clone();
         let len = array.len();
 
@@ -100,16 +97,27 @@ impl HeapSortVisualizer {
                 correct_index: 0,
                 explanation: "Heap Sort is an in-place algorithm, meaning it does not require additional memory.".to_string(),
             },
+            TeachingQuestion {
+                text: "Which heap does Heap Sort build to sort in descending order?".to_string(),
+                options: vec![
+                    "A min heap, so the smallest element is extracted to the end first".to_string(),
+                    "A max heap, the same as for ascending order".to_string(),
+                    "No heap, it reverses the ascending result".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "The root is swapped to the end of the array, so a min heap fills the array from the back with the smallest values and leaves it in descending order.".to_string(),
+            },
         ];
 
         randomize_questions(questions.clone());
 
-        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed));
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("HeapSort")));
         state.teaching_mode = settings.teaching_mode;
 
         let intro_text = format!(
             "What is Heap Sort?\n\n\
-             Heap Sort utilizes a binary heap data structure. First, it builds a max heap where the largest element is at the root. Then, it repeatedly extracts the max (root), swaps it with the last unsorted element, and heapifies down to restore the heap property.\n\n\
+             Heap Sort utilizes a binary heap data structure. First, it builds a max heap where the largest element is at the root. Then, it repeatedly extracts the max (root), swaps it with the last unsorted element, and heapifies down to restore the heap property.\n\
+             With the Descending sort order it builds a min heap instead and the smallest elements move to the end.\n\n\
              Advantages: O(n log n) time, in-place.\n\
              Disadvantages: Not stable.\n\n\
              Teaching Mode: {} (Toggle with T). Questions will be asked after key phases (build complete, each extraction).\n\n\
@@ -129,15 +137,11 @@ impl HeapSortVisualizer {
             largest: 0,
             phase: if len <= 1 { HeapPhase::Done } else { HeapPhase::BuildingMaxHeap },
             build_heap_index: if len <= 1 { -1 } else { (len / 2) as i32 - 1 },
+            build_sifting: false,
             extraction_count: 0,
             state,
         };
 
-        // Set last visualizer
-        let mut settings = Settings::load();
-        settings.last_visualizer = Some("HeapSort".to_string());
-        settings.save();
-
         if len <= 1 {
             this.state.mark_completed();
             this.mark_all_sorted();
@@ -145,144 +149,12 @@ impl HeapSortVisualizer {
 
         this
     }
-
-    // Main loop: handles rendering, input, and stepping through the sort
-    pub fn run_visualization(&mut self) {
-        let mut stdout = stdout();
-        enable_raw_mode().unwrap();
-        stdout.execute(EnterAlternateScreen).unwrap();
-
-        show_intro_screen(self.get_intro_text());
-
-        loop {
-            self.draw(&mut stdout);
-
-            if poll(Duration::from_millis(50)).unwrap_or(false) {
-                match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
-                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                        // Handle question
-                        if let Some(q_index) = self.state.awaiting_question {
-                            match key_event.code {
-                                KeyCode::Char('1') => self.handle_question_answer(q_index, 0),
-                                KeyCode::Char('2') => self.handle_question_answer(q_index, 1),
-                                KeyCode::Char('3') => self.handle_question_answer(q_index, 2),
-                                _ => continue,
-                            }
-                            continue;
-                        }
-
-                        match key_event.code {
-                            KeyCode::Char(' ') => {
-                                if self.state.completed {
-                                    self.reset();
-                                } else {
-                                    self.state.toggle_play_pause();
-                                }
-                            },
-                            KeyCode::Char('r') | KeyCode::Char('R') => {
-                                self.reset();
-                            },
-                            KeyCode::Char('s') | KeyCode::Char('S') => {
-                                if !self.state.completed && !self.state.is_running {
-                                    if !self.step() {
-                                        self.state.mark_completed();
-                                        self.mark_all_sorted();
-                                    }
-                                }
-                            },
-                            KeyCode::Char('t') | KeyCode::Char('T') => {
-                                self.state.toggle_teaching_mode();
-                                let mut settings = Settings::load();
-                                settings.teaching_mode = self.state.teaching_mode;
-                                settings.save();
-                            },
-                            KeyCode::Char('+') => {
-                                self.state.increase_speed(50);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Char('-') => {
-                                self.state.decrease_speed(2000);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Esc => {
-                                let mut settings = Settings::load();
-                                settings.last_visualizer = Some("HeapSort".to_string());
-                                settings.save();
-                                cleanup_terminal();
-                                return;
-                            },
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
-            }
-
-            // Auto step
-            if self.state.is_running && !self.state.is_paused && !self.state.completed
-                && self.state.awaiting_question.is_none() {
-                std::thread::sleep(self.state.speed);
-                if !self.step() {
-                    self.state.mark_completed();
-                    self.mark_all_sorted();
-                }
-            }
-        }
-    }
-
-    fn handle_question_answer(&mut self, q_index: usize, answer: usize) {
-        if let Some(question) = self.state.questions.get(q_index) {
-            let correct = answer == question.correct_index;
-            show_question_feedback(correct, question, answer);
-            self.state.clear_question();
-        }
-    }
-
-    fn draw(&mut self, stdout: &mut std::io::Stdout) {
-        let (width, height) = size().unwrap();
-        stdout.execute(Clear(ClearType::All)).unwrap();
-
-        // Title
-        VisualizerDrawer::draw_title(stdout, self.get_title());
-
-        // Array
-        VisualizerDrawer::draw_array_bars(stdout, &self.array, &self.states, width, height, 5);
-
-        // Legend
-        VisualizerDrawer::draw_legend(stdout, &self.get_legend_items(), width, height, 5);
-
-        // Statistics
-        let stats = self.get_statistics_strings();
-        VisualizerDrawer::draw_statistics(stdout, &stats, width, height);
-
-        // Controls
-        VisualizerDrawer::draw_controls(stdout, self.get_status(), self.get_controls_text(), width, height);
-
-        // Current operation
-        if self.state.awaiting_question.is_none() {
-            let operation = self.get_current_operation();
-            let color = if self.state.completed { Color::Green } else { Color::White };
-            VisualizerDrawer::draw_operation_info(stdout, &operation, width, height, color);
-        }
-
-        // Question
-        if let Some(q_index) = self.state.awaiting_question {
-            if let Some(question) = self.state.questions.get(q_index) {
-                VisualizerDrawer::draw_question(stdout, question, width, height);
-            }
-        }
-
-        stdout.flush().unwrap();
-    }
 }
 
-impl SortVisualizer for HeapSortVisualizer {
-    fn get_array(&self) -> &[u32] { &self.array }
-    fn get_original_array(&self) -> &[u32] { &self.original_array }
+impl<T: SortElement> SortVisualizer<T> for HeapSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
     fn get_states(&self) -> &[SelectionState] { &self.states }
     fn get_comparisons(&self) -> u32 { self.state.comparisons }
     fn get_swaps(&self) -> u32 { self.state.swaps }
@@ -293,6 +165,25 @@ impl SortVisualizer for HeapSortVisualizer {
     fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
     fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
     fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "HeapSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Heap Sort",
+            best: "O(n log n)",
+            average: "O(n log n)",
+            worst: "O(n log n)",
+            space: "O(1)",
+            stable: false,
+            in_place: true,
+        }
+    }
+    // Everything behind the heap was extracted as the largest remaining value
+    fn sorted_regions(&self) -> Vec<Range<usize>> {
+        let finalized = if self.state.completed { 0..self.array.len() } else { self.heap_size..self.array.len() };
+        vec![finalized]
+    }
 
     fn get_progress(&self) -> f64 {
         if self.array.len() <= 1 {
@@ -328,15 +219,19 @@ impl SortVisualizer for HeapSortVisualizer {
         let result = match self.phase {
             HeapPhase::BuildingMaxHeap => {
                 if self.build_heap_index >= 0 {
-                    self.current_index = self.build_heap_index as usize;
+                    // Start on the next subtree, or keep sifting the current one down
+                    if !self.build_sifting {
+                        self.current_index = self.build_heap_index as usize;
+                    }
                     // Perform one step of heapify down
-                    if !self.heapify_down_step() {
+                    self.build_sifting = self.heapify_down_step();
+                    if !self.build_sifting {
                         // This subtree is done, move to next
                         self.build_heap_index -= 1;
                     }
                     true
                 } else {
-                    // Max heap built, start extraction phase
+                    // Heap built, start extraction phase
                     self.phase = HeapPhase::ExtractingMax;
                     // Teaching: Ask question after build
                     if self.state.teaching_mode && !self.state.questions.is_empty() {
@@ -417,10 +312,12 @@ impl SortVisualizer for HeapSortVisualizer {
         self.extraction_count = 0;
         self.phase = if len <= 1 { HeapPhase::Done } else { HeapPhase::BuildingMaxHeap };
         self.build_heap_index = if len <= 1 { -1 } else { (len / 2) as i32 - 1 };
+        self.build_sifting = false;
         self.state.reset_state();
         self.intro_text = format!(
             "What is Heap Sort?\n\n\
-             Heap Sort utilizes a binary heap data structure. First, it builds a max heap where the largest element is at the root. Then, it repeatedly extracts the max (root), swaps it with the last unsorted element, and heapifies down to restore the heap property.\n\n\
+             Heap Sort utilizes a binary heap data structure. First, it builds a max heap where the largest element is at the root. Then, it repeatedly extracts the max (root), swaps it with the last unsorted element, and heapifies down to restore the heap property.\n\
+             With the Descending sort order it builds a min heap instead and the smallest elements move to the end.\n\n\
              Advantages: O(n log n) time, in-place.\n\
              Disadvantages: Not stable.\n\n\
              Teaching Mode: {} (Toggle with T). Questions will be asked after key phases (build complete, each extraction).\n\n\
@@ -447,15 +344,15 @@ impl SortVisualizer for HeapSortVisualizer {
         &self.intro_text
     }
 
-    fn get_legend_items(&self) -> Vec<(&str, Color)> {
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
         vec![
-            ("Heap Elements", Color::Cyan),
-            ("Parent", Color::Yellow),
-            ("Left Child", Color::Blue),
-            ("Right Child", Color::AnsiValue(208)),
-            ("Largest", Color::White),
-            ("Swapping", Color::Red),
-            ("Sorted", Color::Green),
+            ("Heap Elements", SelectionState::Normal),
+            ("Parent", SelectionState::CurrentMin),
+            ("Left Child", SelectionState::PartitionLeft),
+            ("Right Child", SelectionState::PartitionRight),
+            (if self.is_min_heap() { "Smallest" } else { "Largest" }, SelectionState::Selected),
+            ("Swapping", SelectionState::Swapping),
+            ("Sorted", SelectionState::Sorted),
         ]
     }
 
@@ -465,9 +362,12 @@ impl SortVisualizer for HeapSortVisualizer {
             format!("Heap Size: {}", self.heap_size),
             format!("Comparisons: {}", self.state.comparisons),
             format!("Swaps: {}", self.state.swaps),
+            format!("Heap Type: {}", if self.is_min_heap() { "Min Heap" } else { "Max Heap" }),
             format!("Phase: {}", match self.phase {
+                HeapPhase::BuildingMaxHeap if self.is_min_heap() => "Building Min Heap",
                 HeapPhase::BuildingMaxHeap => "Building Max Heap",
                 HeapPhase::HeapifyDown => "Heapifying Down",
+                HeapPhase::ExtractingMax if self.is_min_heap() => "Extracting Minimum",
                 HeapPhase::ExtractingMax => "Extracting Maximum",
                 HeapPhase::SwappingRootWithLast => "Swapping Root",
                 HeapPhase::Done => "Done",
@@ -477,33 +377,56 @@ impl SortVisualizer for HeapSortVisualizer {
         ]
     }
 
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        // A descending sort builds a min heap, the same reasons hold with the order flipped
+        let hint = match (self.phase, self.is_min_heap()) {
+            (HeapPhase::BuildingMaxHeap, false) => "Sifting down from the last parent makes every parent at least as large as its children, so the largest value ends up at the root",
+            (HeapPhase::BuildingMaxHeap, true) => "Sifting down from the last parent makes every parent at most as large as its children, so the smallest value ends up at the root",
+            (HeapPhase::HeapifyDown, false) => "The new root may be smaller than a child, sifting it down restores the heap so the root is the largest again",
+            (HeapPhase::HeapifyDown, true) => "The new root may be larger than a child, sifting it down restores the heap so the root is the smallest again",
+            (HeapPhase::ExtractingMax, false) => "The root is the largest value of the heap, so it belongs at the end of the unsorted part",
+            (HeapPhase::ExtractingMax, true) => "The root is the smallest value of the heap, so it belongs at the end of the unsorted part",
+            (HeapPhase::SwappingRootWithLast, _) => "Swapping the root with the last heap element puts the root in place and shrinks the heap by one",
+            (HeapPhase::Done, _) => return None,
+        };
+        Some(hint)
+    }
+
     fn get_current_operation(&self) -> String {
+        let (heap, extreme, root) = if self.is_min_heap() {
+            ("min heap", "smallest", "min")
+        } else {
+            ("max heap", "largest", "max")
+        };
         if self.state.completed {
             "✓ Array is now sorted using Heap Sort! Congratulations!".to_string()
         } else {
             match self.phase {
                 HeapPhase::BuildingMaxHeap => {
                     if self.build_heap_index >= 0 {
-                        format!("Building max heap: heapifying subtree rooted at index {}", self.build_heap_index)
+                        format!("Building {}: heapifying subtree rooted at index {}", heap, self.build_heap_index)
                     } else {
-                        "Building max heap completed".to_string()
+                        format!("Building {} completed", heap)
                     }
                 },
                 HeapPhase::HeapifyDown => {
                     if self.current_index < self.array.len() && self.largest < self.array.len() {
-                        format!("Heapify down from index {} (value: {}), largest so far: {} (value: {})",
-                                self.current_index, self.array[self.current_index],
-                                self.largest, self.array[self.largest])
+                        format!("Heapify down from index {} (value: {}), {} so far: {} (value: {})",
+                                self.current_index, self.state.value_text(self.array[self.current_index]),
+                                extreme, self.largest, self.state.value_text(self.array[self.largest]))
                     } else {
                         "Heapifying down...".to_string()
                     }
                 },
                 HeapPhase::ExtractingMax => {
-                    format!("Extracting maximum element from heap (size: {})", self.heap_size)
+                    format!("Extracting {} element from heap (size: {})", extreme, self.heap_size)
                 },
                 HeapPhase::SwappingRootWithLast => {
                     if self.heap_size > 0 && self.heap_size <= self.array.len() {
-                        format!("Swapping root (max) with last heap element at index {}", self.heap_size - 1)
+                        format!("Swapping root ({}) with last heap element at index {}", root, self.heap_size - 1)
                     } else {
                         "Swapping root with last element".to_string()
                     }
@@ -540,9 +463,15 @@ impl SortVisualizer for HeapSortVisualizer {
     }
 }
 
-// Performs a single step of the heapify down operation
-// Returns `true` if heapifying should continue, `false` if complete
-impl HeapSortVisualizer {
+impl<T: SortElement> HeapSortVisualizer<T> {
+    // The descending order turns the heap upside down: compare() then treats
+    // smaller values as greater, so the same sift-down builds a min heap
+    fn is_min_heap(&self) -> bool {
+        self.state.sort_order == SortOrder::Descending
+    }
+
+    // Performs a single step of the heapify down operation
+    // Returns `true` if heapifying should continue, `false` if complete
     fn heapify_down_step(&mut self) -> bool {
         let left = 2 * self.current_index + 1;
         let right = 2 * self.current_index + 2;
@@ -559,8 +488,7 @@ impl HeapSortVisualizer {
         // Compare with left child
         if left < self.heap_size && left < self.array.len() {
             self.states[left] = SelectionState::PartitionLeft;
-            self.state.comparisons += 1;
-            if self.array[left] > self.array[self.largest] {
+            if self.compare(left, self.largest).is_gt() {
                 self.largest = left;
             }
         }
@@ -568,8 +496,7 @@ impl HeapSortVisualizer {
         // Compare with right child
         if right < self.heap_size && right < self.array.len() {
             self.states[right] = SelectionState::PartitionRight;
-            self.state.comparisons += 1;
-            if self.array[right] > self.array[self.largest] {
+            if self.compare(right, self.largest).is_gt() {
                 self.largest = right;
             }
         }
@@ -599,7 +526,65 @@ impl HeapSortVisualizer {
 }
 
 // Entry point for the heap sort visualization
-pub fn heap_sort_visualization(array_data: &ArrayData) {
-    let mut visualizer = HeapSortVisualizer::new(array_data);
-    visualizer.run_visualization();
-}
\ No newline at end of file
+pub fn heap_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(HeapSortVisualizer::with_settings(array_data, &settings), settings, last_run)
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort_algorithms::run_headless;
+
+    fn sample() -> ArrayData {
+        ArrayData::new(vec![9, 41, 7, 100, 58, 2, 63, 30, 5, 41, 10, 12, 71], "Heap".to_string())
+    }
+
+    // Steps until the heap is built and checks every parent against its children
+    fn assert_heap_built(order: SortOrder, holds: fn(u32, u32) -> bool) {
+        let mut visualizer = HeapSortVisualizer::with_settings(&sample(), &Settings::default());
+        visualizer.state_mut().set_sort_order(order);
+        visualizer.state_mut().teaching_mode = false;
+        while visualizer.phase == HeapPhase::BuildingMaxHeap {
+            visualizer.step();
+        }
+        let heap = &visualizer.array[..visualizer.heap_size];
+        for child in 1..heap.len() {
+            let parent = (child - 1) / 2;
+            assert!(holds(heap[parent], heap[child]), "{:?}: {} above {} in {:?}", order, heap[parent], heap[child], heap);
+        }
+    }
+
+    #[test]
+    fn heap_type_follows_the_sort_order() {
+        assert_heap_built(SortOrder::Ascending, |parent, child| parent >= child);
+        assert_heap_built(SortOrder::Descending, |parent, child| parent <= child);
+    }
+
+    #[test]
+    fn max_heap_sorts_ascending_and_min_heap_descending() {
+        let mut expected = sample().data;
+        expected.sort();
+
+        let mut visualizer = HeapSortVisualizer::with_settings(&sample(), &Settings::default());
+        run_headless(&mut visualizer);
+        assert_eq!(visualizer.array, expected);
+
+        let mut visualizer = HeapSortVisualizer::with_settings(&sample(), &Settings::default());
+        visualizer.state_mut().set_sort_order(SortOrder::Descending);
+        run_headless(&mut visualizer);
+        expected.reverse();
+        assert_eq!(visualizer.array, expected);
+    }
+
+    #[test]
+    fn sorted_region_is_the_suffix_behind_the_heap() {
+        let len = sample().data.len();
+        let mut visualizer = HeapSortVisualizer::with_settings(&sample(), &Settings::default());
+        visualizer.state_mut().teaching_mode = false;
+        while visualizer.heap_size > len - 3 {
+            visualizer.step();
+        }
+        visualizer.step();
+        assert_eq!(visualizer.sorted_regions(), vec![visualizer.heap_size..len]);
+    }
+}
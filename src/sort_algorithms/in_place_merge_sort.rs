@@ -0,0 +1,383 @@
+use crate::common::algorithm_info::AlgorithmInfo;
+use crate::common::array_manager::ArrayData;
+use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
+use crate::common::element::SortElement;
+use crate::common::enums::{SelectionState, TeachingQuestion};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
+use crate::common::settings::Settings;
+use std::time::Duration;
+
+/// Represents the different phases of the in-place merge sort algorithm
+#[derive(Clone, Copy, PartialEq)]
+pub enum InPlaceMergePhase {
+    Comparing, // Comparing the heads of the left and right runs
+    Rotating,  // Rotating the right head into place in front of the left run
+    Done,      // Sorting is complete
+}
+
+/// Visualizes a bottom-up merge sort that merges runs with rotations instead of a temp array
+#[derive(Clone)]
+pub struct InPlaceMergeSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array being sorted
+    original_array: Vec<T>,  // Original array, used for resetting
+    states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, rotating, sorted)
+
+    // In-place merge specific fields
+    width: usize,              // Length of the runs merged in the current pass
+    left: usize,               // Start of the left run
+    mid: usize,                // Start of the right run
+    right: usize,              // End (exclusive) of the right run
+    i: usize,                  // Head of the left run
+    j: usize,                  // Head of the right run
+    passes: usize,             // Number of completed merge passes
+    rotations: u32,            // Number of block rotations performed
+    reads: u32,                // Number of array reads
+    phase: InPlaceMergePhase,  // Current phase of the algorithm
+    state: VisualizerState<T>,    // Common visualization state
+}
+
+impl<T: SortElement> InPlaceMergeSortVisualizer<T> {
+    /// Creates a new InPlaceMergeSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
+        let array = array_data.data.clone();
+        let len = array.len();
+
+        let questions = vec![
+            TeachingQuestion {
+                text: "How much extra memory does this merge sort use?".to_string(),
+                options: vec![
+                    "O(1), runs are merged by rotating elements inside the array".to_string(),
+                    "O(n), a temporary array holds the merged run".to_string(),
+                    "O(log n), for the recursion stack".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "Instead of copying into a temp array, each out-of-order element of the right run is rotated in front of the left run, so only one element is held at a time.".to_string(),
+            },
+            TeachingQuestion {
+                text: "What is the price of merging without a temp array?".to_string(),
+                options: vec![
+                    "More writes, every rotation shifts the whole remaining left run".to_string(),
+                    "More comparisons than bubble sort".to_string(),
+                    "The result is no longer sorted".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "A rotation moves every element between the two heads, so in the worst case a merge costs O(n^2) writes instead of O(n). That is the time/space tradeoff.".to_string(),
+            },
+            TeachingQuestion {
+                text: "Why is this variant still stable?".to_string(),
+                options: vec![
+                    "Equal elements are taken from the left run first and rotations keep their order".to_string(),
+                    "Because it uses rotations".to_string(),
+                    "It is not stable".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "The right head is only moved when it is strictly smaller, and a rotation preserves the relative order of the elements it shifts.".to_string(),
+            },
+        ];
+
+        randomize_questions(questions.clone());
+
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("InPlaceMergeSort")));
+        state.teaching_mode = settings.teaching_mode;
+
+        let mut this = Self {
+            original_array: array.clone(),
+            array,
+            states: vec![SelectionState::Normal; len],
+            width: 1,
+            left: 0,
+            mid: 0,
+            right: 0,
+            i: 0,
+            j: 0,
+            passes: 0,
+            rotations: 0,
+            reads: 0,
+            phase: InPlaceMergePhase::Comparing,
+            state,
+        };
+        this.begin_merge();
+
+        if len <= 1 {
+            this.phase = InPlaceMergePhase::Done;
+            this.state.mark_completed();
+            this.mark_all_sorted();
+        }
+
+        this
+    }
+
+    /// Sets up the bounds of the merge starting at `left`
+    fn begin_merge(&mut self) {
+        let n = self.array.len();
+        self.mid = (self.left + self.width).min(n);
+        self.right = (self.left + 2 * self.width).min(n);
+        self.i = self.left;
+        self.j = self.mid;
+        self.phase = InPlaceMergePhase::Comparing;
+    }
+
+    /// Colors the two runs of the current merge
+    fn mark_runs(&mut self) {
+        for k in self.left..self.mid.min(self.j) {
+            self.states[k] = SelectionState::PartitionLeft;
+        }
+        for k in self.j..self.right {
+            self.states[k] = SelectionState::PartitionRight;
+        }
+    }
+}
+
+impl<T: SortElement> SortVisualizer<T> for InPlaceMergeSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
+    fn get_states(&self) -> &[SelectionState] { &self.states }
+    fn get_comparisons(&self) -> u32 { self.state.comparisons }
+    fn get_swaps(&self) -> u32 { self.state.swaps }
+    fn get_speed(&self) -> Duration { self.state.speed }
+    fn is_running(&self) -> bool { self.state.is_running }
+    fn is_paused(&self) -> bool { self.state.is_paused }
+    fn is_completed(&self) -> bool { self.state.completed }
+    fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
+    fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
+    fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "InPlaceMergeSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "In-Place Merge Sort",
+            best: "O(n log n)",
+            average: "O(n^2)",
+            worst: "O(n^2)",
+            space: "O(log n)",
+            stable: true,
+            in_place: true,
+        }
+    }
+
+    fn get_progress(&self) -> f64 {
+        let n = self.array.len();
+        if n <= 1 {
+            return 100.0;
+        }
+        let total_passes = (n as f64).log2().ceil();
+        let pass_progress = self.left as f64 / n as f64;
+        ((self.passes as f64 + pass_progress) / total_passes * 100.0).min(100.0)
+    }
+
+    fn step(&mut self) -> bool {
+        if self.state.completed || self.state.awaiting_question.is_some() {
+            return true;
+        }
+
+        // Reset states except sorted
+        for state in &mut self.states {
+            if *state != SelectionState::Sorted {
+                *state = SelectionState::Normal;
+            }
+        }
+
+        let n = self.array.len();
+        if self.width >= n {
+            return false;
+        }
+
+        match self.phase {
+            InPlaceMergePhase::Comparing => {
+                if self.i < self.j && self.j < self.right {
+                    self.mark_runs();
+                    self.states[self.i] = SelectionState::Comparing;
+                    self.states[self.j] = SelectionState::Comparing;
+                    self.reads += 2;
+                    if self.compare(self.i, self.j).is_le() {
+                        // Left head is already in place
+                        self.i += 1;
+                    } else {
+                        self.phase = InPlaceMergePhase::Rotating;
+                    }
+                } else {
+                    // Current merge is finished, move to the next pair of runs
+                    self.left += 2 * self.width;
+                    if self.left + self.width >= n {
+                        self.passes += 1;
+                        self.width *= 2;
+                        self.left = 0;
+                        if self.width >= n {
+                            self.phase = InPlaceMergePhase::Done;
+                            return false;
+                        }
+
+                        // Teaching: Ask question after each pass
+                        self.state.ask_question(self.passes - 1);
+                    }
+                    self.begin_merge();
+                }
+            },
+            InPlaceMergePhase::Rotating => {
+                // Rotate array[i..=j] right by one so the right head lands at i
+                let length = (self.j - self.i + 1) as u32;
+                self.array[self.i..=self.j].rotate_right(1);
+                self.rotations += 1;
+                self.reads += length;
+                self.state.moves += length;
+                for k in self.i..=self.j {
+                    self.states[k] = SelectionState::Swapping;
+                }
+                self.i += 1;
+                self.j += 1;
+                self.mid += 1;
+                self.phase = InPlaceMergePhase::Comparing;
+            },
+            InPlaceMergePhase::Done => return false,
+        }
+
+        true
+    }
+
+    fn reset(&mut self) {
+        self.array = self.original_array.clone();
+        self.states = vec![SelectionState::Normal; self.array.len()];
+        self.width = 1;
+        self.left = 0;
+        self.passes = 0;
+        self.rotations = 0;
+        self.reads = 0;
+        self.begin_merge();
+        self.state.reset_state();
+        if self.array.len() <= 1 {
+            self.phase = InPlaceMergePhase::Done;
+            self.state.mark_completed();
+            self.mark_all_sorted();
+        }
+    }
+
+    fn mark_all_sorted(&mut self) {
+        for state in &mut self.states {
+            *state = SelectionState::Sorted;
+        }
+    }
+
+    fn get_title(&self) -> &str {
+        "TOGISOFT IN-PLACE MERGE SORT VISUALIZER"
+    }
+
+    fn get_intro_text(&self) -> &str {
+        "What is In-Place Merge Sort?\n\n\
+         A bottom-up merge sort that merges neighbouring runs without a temporary array.\n\
+         When the head of the right run is smaller, the block between the two heads is rotated\n\
+         by one so that element moves in front of the left run.\n\n\
+         Advantages: O(1) extra memory, stable.\n\
+         Disadvantages: Rotations make a merge cost O(n^2) writes in the worst case.\n\n\
+         Compare the Writes and Rotations counters with the regular Merge Sort, which uses O(n) memory instead.\n\n\
+         Teaching Mode: ON (Toggle with T). Questions will be asked after each pass.\n\n\
+         Press any key to continue..."
+    }
+
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
+        vec![
+            ("Normal", SelectionState::Normal),
+            ("Merging Runs", SelectionState::PartitionLeft),
+            ("Comparing", SelectionState::Comparing),
+            ("Rotating", SelectionState::Swapping),
+            ("Sorted", SelectionState::Sorted),
+        ]
+    }
+
+    fn get_statistics_strings(&self) -> Vec<String> {
+        let phase_str = match self.phase {
+            InPlaceMergePhase::Comparing => "Comparing",
+            InPlaceMergePhase::Rotating => "Rotating",
+            InPlaceMergePhase::Done => "Done",
+        };
+
+        vec![
+            format!("Array Size: {}", self.array.len()),
+            format!("Comparisons: {}", self.state.comparisons),
+            format!("Reads: {}", self.reads),
+            format!("Moves: {}", self.state.moves),
+            format!("Rotations: {}", self.rotations),
+            "Extra Memory: O(1)".to_string(),
+            format!("Run Width: {}", self.width),
+            format!("Phase: {}", phase_str),
+            format!("Progress: {:.1}%", self.get_progress()),
+            if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
+        ]
+    }
+
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        let hint = match self.phase {
+            InPlaceMergePhase::Comparing => "The heads of both runs are compared; if the left one is not larger it is already in place and nothing moves",
+            InPlaceMergePhase::Rotating => "The right head is smaller, so it is rotated in front of the left run instead of copied to a buffer, no extra space is used",
+            _ => return None,
+        };
+        Some(hint)
+    }
+
+    fn get_current_operation(&self) -> String {
+        if self.state.completed {
+            format!(
+                "✓ Array is now sorted in place with {} rotations and {} moves! Congratulations!",
+                self.rotations, self.state.moves
+            )
+        } else {
+            match self.phase {
+                InPlaceMergePhase::Comparing => {
+                    if self.i < self.j && self.j < self.right {
+                        format!(
+                            "Merging [{}..{}) with [{}..{}): comparing array[{}] ({}) with array[{}] ({})",
+                            self.left, self.mid, self.mid, self.right,
+                            self.i, self.state.value_text(self.array[self.i]), self.j, self.state.value_text(self.array[self.j])
+                        )
+                    } else {
+                        format!("Runs [{}..{}) merged, moving to the next pair", self.left, self.right)
+                    }
+                },
+                InPlaceMergePhase::Rotating => {
+                    format!(
+                        "{} is smaller: rotating array[{}..={}] to move it to index {}",
+                        self.state.value_text(self.array[self.j]), self.i, self.j, self.i
+                    )
+                },
+                InPlaceMergePhase::Done => {
+                    "In-place merge sort completed!".to_string()
+                },
+            }
+        }
+    }
+
+    fn get_status(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "WAITING FOR QUESTION"
+        } else if self.state.completed {
+            "COMPLETED!"
+        } else if self.state.is_running && !self.state.is_paused {
+            "RUNNING..."
+        } else if self.state.is_paused {
+            "PAUSED"
+        } else {
+            "READY"
+        }
+    }
+
+    fn get_controls_text(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "1,2,3: Answer | ESC: Exit"
+        } else if self.state.completed {
+            "SPACE: Restart | R: Reset | T: Teaching Toggle | ESC: Exit"
+        } else {
+            "SPACE: Start/Pause | S: Step | R: Reset | T: Teaching | +/-: Speed | ESC: Exit"
+        }
+    }
+}
+
+/// Entry point for the in-place merge sort visualization
+pub fn in_place_merge_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(InPlaceMergeSortVisualizer::with_settings(array_data, &settings), settings, last_run)
+}
@@ -1,16 +1,13 @@
+use crate::common::algorithm_info::AlgorithmInfo;
 use crate::common::array_manager::ArrayData;
-use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
-use crate::common::common_visualizer::{show_intro_screen, show_question_feedback, VisualizerDrawer};
+use crate::common::base_visualizer::{sorted_runs, SortVisualizer, VisualizerState};
+use crate::common::element::SortElement;
 use crate::common::enums::{SelectionState, TeachingQuestion};
-use crate::common::helper::{cleanup_terminal, randomize_questions};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
 use crate::common::settings::Settings;
-use crossterm::{
-    event::{poll, read, Event, KeyCode, KeyEventKind},
-    style::Color,
-    terminal::{enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen},
-    ExecutableCommand,
-};
-use std::io::{stdout, Write};
+use std::cmp::Ordering;
+use std::ops::Range;
 use std::time::Duration;
 
 /// Represents the different phases of the insertion sort algorithm
@@ -23,22 +20,22 @@ enum InsertionPhase {
 }
 
 /// Visualizes the insertion sort algorithm step-by-step with interactive controls
-pub struct InsertionSortVisualizer {
-    array: Vec<u32>,           // Current state of the array being sorted
-    original_array: Vec<u32>,  // Original array, used for resetting
+#[derive(Clone)]
+pub struct InsertionSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array being sorted
+    original_array: Vec<T>,  // Original array, used for resetting
     states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, shifting, sorted)
     intro_text: String,        // Dynamic intro text
     current_i: usize,          // Current outer loop index (element to insert)
     current_j: usize,          // Current inner loop index (position being compared)
-    key: u32,                  // Current key element being inserted
+    key: T,                  // Current key element being inserted
     phase: InsertionPhase,     // Current phase of the insertion sort algorithm
-    state: VisualizerState,    // Common visualization state
+    state: VisualizerState<T>,    // Common visualization state
 }
 
-impl InsertionSortVisualizer {
-    /// Creates a new InsertionSortVisualizer with the given array
-    pub fn new(array_data: &ArrayData) -> Self {
-        let settings = Settings::load();
+impl<T: SortElement> InsertionSortVisualizer<T> {
+    /// Creates a new InsertionSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
         let array = array_data.data.clone();
         let len = array.len();
         let mut questions = vec![
@@ -76,7 +73,7 @@ impl InsertionSortVisualizer {
 
         randomize_questions(questions.clone());
 
-        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed));
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("InsertionSort")));
         state.teaching_mode = settings.teaching_mode;
 
         let intro_text = format!(
@@ -96,7 +93,7 @@ impl InsertionSortVisualizer {
             intro_text,
             current_i: if len <= 1 { len } else { 1 },
             current_j: 0,
-            key: 0,
+            key: T::default(),
             phase: if len <= 1 { InsertionPhase::MoveToNext } else { InsertionPhase::SelectingElement },
             state,
         };
@@ -105,11 +102,6 @@ impl InsertionSortVisualizer {
             this.states[0] = SelectionState::Sorted; // First element is always sorted
         }
 
-        // Set last visualizer
-        let mut settings = Settings::load();
-        settings.last_visualizer = Some("InsertionSort".to_string());
-        settings.save();
-
         if len <= 1 {
             this.state.mark_completed();
             this.mark_all_sorted();
@@ -117,153 +109,12 @@ impl InsertionSortVisualizer {
 
         this
     }
-
-    /// Main loop: handles rendering, input, and stepping through the sort
-    pub fn run_visualization(&mut self) {
-        let mut stdout = stdout();
-        enable_raw_mode().unwrap();
-        stdout.execute(EnterAlternateScreen).unwrap();
-
-        show_intro_screen(self.get_intro_text());
-
-        loop {
-            self.draw(&mut stdout);
-
-            if poll(Duration::from_millis(50)).unwrap_or(false) {
-                match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
-                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                        // Handle question
-                        if let Some(q_index) = self.state.awaiting_question {
-                            match key_event.code {
-                                KeyCode::Char('1') => self.handle_question_answer(q_index, 0),
-                                KeyCode::Char('2') => self.handle_question_answer(q_index, 1),
-                                KeyCode::Char('3') => self.handle_question_answer(q_index, 2),
-                                _ => continue,
-                            }
-                            continue;
-                        }
-
-                        match key_event.code {
-                            KeyCode::Char(' ') => {
-                                if self.state.completed {
-                                    self.reset();
-                                } else {
-                                    self.state.toggle_play_pause();
-                                }
-                            },
-                            KeyCode::Char('r') | KeyCode::Char('R') => {
-                                self.reset();
-                            },
-                            KeyCode::Char('s') | KeyCode::Char('S') => {
-                                if !self.state.completed && !self.state.is_running {
-                                    if !self.step() {
-                                        self.state.mark_completed();
-                                        self.mark_all_sorted();
-                                    }
-                                }
-                            },
-                            KeyCode::Char('t') | KeyCode::Char('T') => {
-                                self.state.toggle_teaching_mode();
-                                self.intro_text = format!(
-                                    "What is Insertion Sort?\n\n\
-                                     Insertion Sort builds the final sorted array one item at a time. It is much like sorting a hand of playing cards: assume the cards are to the left of your hand are in sorted order. For each new card, you slide it into the correct position among the cards to its left.\n\n\
-                                     Advantages: Simple, efficient for small or nearly sorted data.\n\
-                                     Disadvantages: O(n^2) worst case.\n\n\
-                                     Teaching Mode: {} (Toggle with T). Questions will be asked after each insertion.\n\n\
-                                     Press any key to continue...",
-                                    if self.state.teaching_mode { "ON" } else { "OFF" }
-                                );
-                                let mut settings = Settings::load();
-                                settings.teaching_mode = self.state.teaching_mode;
-                                settings.save();
-                            },
-                            KeyCode::Char('+') => {
-                                self.state.increase_speed(50);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Char('-') => {
-                                self.state.decrease_speed(2000);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Esc => {
-                                let mut settings = Settings::load();
-                                settings.last_visualizer = Some("InsertionSort".to_string());
-                                settings.save();
-                                cleanup_terminal();
-                                return;
-                            },
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
-            }
-
-            // Auto step
-            if self.state.is_running && !self.state.is_paused && !self.state.completed
-                && self.state.awaiting_question.is_none() {
-                std::thread::sleep(self.state.speed);
-                if !self.step() {
-                    self.state.mark_completed();
-                    self.mark_all_sorted();
-                }
-            }
-        }
-    }
-
-    fn handle_question_answer(&mut self, q_index: usize, answer: usize) {
-        if let Some(question) = self.state.questions.get(q_index) {
-            let correct = answer == question.correct_index;
-            show_question_feedback(correct, question, answer);
-            self.state.clear_question();
-        }
-    }
-
-    fn draw(&mut self, stdout: &mut std::io::Stdout) {
-        let (width, height) = size().unwrap();
-        stdout.execute(Clear(ClearType::All)).unwrap();
-
-        // Title
-        VisualizerDrawer::draw_title(stdout, self.get_title());
-
-        // Array
-        VisualizerDrawer::draw_array_bars(stdout, &self.array, &self.states, width, height, 5);
-
-        // Legend
-        VisualizerDrawer::draw_legend(stdout, &self.get_legend_items(), width, height, 5);
-
-        // Statistics
-        let stats = self.get_statistics_strings();
-        VisualizerDrawer::draw_statistics(stdout, &stats, width, height);
-
-        // Controls
-        VisualizerDrawer::draw_controls(stdout, self.get_status(), self.get_controls_text(), width, height);
-
-        // Current operation
-        if self.state.awaiting_question.is_none() {
-            let operation = self.get_current_operation();
-            let color = if self.state.completed { Color::Green } else { Color::White };
-            VisualizerDrawer::draw_operation_info(stdout, &operation, width, height, color);
-        }
-
-        // Question
-        if let Some(q_index) = self.state.awaiting_question {
-            if let Some(question) = self.state.questions.get(q_index) {
-                VisualizerDrawer::draw_question(stdout, question, width, height);
-            }
-        }
-
-        stdout.flush().unwrap();
-    }
 }
 
-impl SortVisualizer for InsertionSortVisualizer {
-    fn get_array(&self) -> &[u32] { &self.array }
-    fn get_original_array(&self) -> &[u32] { &self.original_array }
+impl<T: SortElement> SortVisualizer<T> for InsertionSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
     fn get_states(&self) -> &[SelectionState] { &self.states }
     fn get_comparisons(&self) -> u32 { self.state.comparisons }
     fn get_swaps(&self) -> u32 { self.state.swaps }
@@ -274,6 +125,38 @@ impl SortVisualizer for InsertionSortVisualizer {
     fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
     fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
     fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "InsertionSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Insertion Sort",
+            best: "O(n)",
+            average: "O(n^2)",
+            worst: "O(n^2)",
+            space: "O(1)",
+            stable: true,
+            in_place: true,
+        }
+    }
+    // The green prefix is sorted among itself, later keys can still be inserted into it,
+    // so nothing is in its final place before the last insertion
+    fn sorted_regions(&self) -> Vec<Range<usize>> {
+        if self.state.completed { sorted_runs(&self.states) } else { Vec::new() }
+    }
+
+    fn on_teaching_mode_changed(&mut self) {
+        // Keep the intro text in sync with the teaching mode
+        self.intro_text = format!(
+            "What is Insertion Sort?\n\n\
+             Insertion Sort builds the final sorted array one item at a time. It is much like sorting a hand of playing cards: assume the cards are to the left of your hand are in sorted order. For each new card, you slide it into the correct position among the cards to its left.\n\n\
+             Advantages: Simple, efficient for small or nearly sorted data.\n\
+             Disadvantages: O(n^2) worst case.\n\n\
+             Teaching Mode: {} (Toggle with T). Questions will be asked after each insertion.\n\n\
+             Press any key to continue...",
+            if self.state.teaching_mode { "ON" } else { "OFF" }
+        );
+    }
 
     fn get_progress(&self) -> f64 {
         if self.array.len() <= 1 {
@@ -327,14 +210,13 @@ impl SortVisualizer for InsertionSortVisualizer {
                 // Compare key with current element
                 if self.current_j < self.array.len() {
                     self.states[self.current_j] = SelectionState::Comparing;
-                    self.state.comparisons += 1;
 
-                    if self.array[self.current_j] > self.key {
+                    if self.state.compare_values(self.array[self.current_j], self.key).is_gt() {
                         // Need to shift this element right
                         self.states[self.current_j] = SelectionState::Swapping;
                         if self.current_j + 1 < self.array.len() {
                             self.array[self.current_j + 1] = self.array[self.current_j];
-                            self.state.swaps += 1;
+                            self.state.moves += 1;
                         }
 
                         if self.current_j > 0 {
@@ -359,6 +241,10 @@ impl SortVisualizer for InsertionSortVisualizer {
             InsertionPhase::InsertingElement => {
                 // Insert the key at current_j position
                 if self.current_j < self.array.len() {
+                    // Writing the key back counts as a move once something was shifted past it
+                    if self.current_j != self.current_i {
+                        self.state.moves += 1;
+                    }
                     self.array[self.current_j] = self.key;
                     self.states[self.current_j] = SelectionState::Selected;
                 }
@@ -398,7 +284,7 @@ impl SortVisualizer for InsertionSortVisualizer {
 
         self.current_i = if len <= 1 { len } else { 1 };
         self.current_j = 0;
-        self.key = 0;
+        self.key = T::default();
         self.phase = if len <= 1 { InsertionPhase::MoveToNext } else { InsertionPhase::SelectingElement };
         self.state.reset_state();
         self.intro_text = format!(
@@ -430,14 +316,14 @@ impl SortVisualizer for InsertionSortVisualizer {
         &self.intro_text
     }
 
-    fn get_legend_items(&self) -> Vec<(&str, Color)> {
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
         vec![
-            ("Normal", Color::Cyan),
-            ("Key Element", Color::Yellow),
-            ("Comparing", Color::Magenta),
-            ("Position", Color::White),
-            ("Shifting", Color::Red),
-            ("Sorted", Color::Green),
+            ("Normal", SelectionState::Normal),
+            ("Key Element", SelectionState::CurrentMin),
+            ("Comparing", SelectionState::Comparing),
+            ("Position", SelectionState::Selected),
+            ("Shifting", SelectionState::Swapping),
+            ("Sorted", SelectionState::Sorted),
         ]
     }
 
@@ -445,13 +331,35 @@ impl SortVisualizer for InsertionSortVisualizer {
         vec![
             format!("Array Size: {}", self.array.len()),
             format!("Comparisons: {}", self.state.comparisons),
-            format!("Shifts: {}", self.state.swaps),
+            format!("Moves: {}", self.state.moves),
             format!("Current Index: {}", if self.current_i < self.array.len() { self.current_i.to_string() } else { "Done".to_string() }),
             format!("Progress: {:.1}%", self.get_progress()),
             if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
         ]
     }
 
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        let hint = match self.phase {
+            InsertionPhase::SelectingElement => "Everything left of this element is sorted, so it only has to be inserted into that sorted part",
+            InsertionPhase::SearchingPosition => "Larger values are shifted right because insertion sort scans left until it finds a value not larger than the key",
+            InsertionPhase::InsertingElement => "The scan stopped at a smaller value, so the gap right of it is where the key belongs",
+            InsertionPhase::MoveToNext => "The sorted part grew by one element, the next unsorted element is inserted the same way",
+        };
+        Some(hint)
+    }
+
+    fn decision_changers(&self) -> Vec<usize> {
+        let j = self.current_j;
+        if self.state.completed || self.phase != InsertionPhase::SearchingPosition || j >= self.array.len() {
+            return Vec::new();
+        }
+        // array[j] shifts right if it orders after the key
+        self.state.decision_changers(&self.array, j, self.key, &[j], Ordering::is_gt)
+    }
+
     fn get_current_operation(&self) -> String {
         if self.state.completed {
             "✓ Array is now sorted using Insertion Sort! Congratulations!".to_string()
@@ -460,7 +368,7 @@ impl SortVisualizer for InsertionSortVisualizer {
                 InsertionPhase::SelectingElement => {
                     if self.current_i < self.array.len() {
                         format!("Step {}/{}: Selecting key element {} (value: {})",
-                                self.current_i, self.array.len() - 1, self.current_i, self.array[self.current_i])
+                                self.current_i, self.array.len() - 1, self.current_i, self.state.value_text(self.array[self.current_i]))
                     } else {
                         "Selecting element...".to_string()
                     }
@@ -468,17 +376,17 @@ impl SortVisualizer for InsertionSortVisualizer {
                 InsertionPhase::SearchingPosition => {
                     if self.current_j < self.array.len() && self.current_j + 1 < self.array.len() {
                         format!("Comparing key {} with element {} (value: {})",
-                                self.key, self.current_j, self.array[self.current_j])
+                                self.state.value_text(self.key), self.current_j, self.state.value_text(self.array[self.current_j]))
                     } else {
-                        format!("Finding correct position for key {}", self.key)
+                        format!("Finding correct position for key {}", self.state.value_text(self.key))
                     }
                 },
                 InsertionPhase::InsertingElement => {
-                    format!("Inserting key {} at position {}", self.key, self.current_j + 1)
+                    format!("Inserting key {} at position {}", self.state.value_text(self.key), self.current_j + 1)
                 },
                 InsertionPhase::MoveToNext => {
                     if self.current_i < self.array.len() {
-                        format!("Element {} positioned correctly, moving to next", self.key)
+                        format!("Element {} positioned correctly, moving to next", self.state.value_text(self.key))
                     } else {
                         "Insertion sort completed!".to_string()
                     }
@@ -513,7 +421,7 @@ impl SortVisualizer for InsertionSortVisualizer {
 }
 
 /// Entry point for the insertion sort visualization
-pub fn insertion_sort_visualization(array_data: &ArrayData) {
-    let mut visualizer = InsertionSortVisualizer::new(array_data);
-    visualizer.run_visualization();
+pub fn insertion_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(InsertionSortVisualizer::with_settings(array_data, &settings), settings, last_run)
 }
\ No newline at end of file
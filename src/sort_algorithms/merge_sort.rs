@@ -1,16 +1,15 @@
+use crate::common::algorithm_info::AlgorithmInfo;
 use crate::common::array_manager::ArrayData;
-use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
-use crate::common::common_visualizer::{show_intro_screen, show_question_feedback, VisualizerDrawer};
+use crate::common::base_visualizer::{sorted_runs, SortVisualizer, VisualizerState};
+use crate::common::common_visualizer::VisualizerDrawer;
+use crate::common::element::SortElement;
 use crate::common::enums::{SelectionState, TeachingQuestion};
-use crate::common::helper::{cleanup_terminal, randomize_questions};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
 use crate::common::settings::Settings;
-use crossterm::{
-    event::{poll, read, Event, KeyCode, KeyEventKind},
-    style::Color,
-    terminal::{enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen},
-    ExecutableCommand,
-};
-use std::io::{stdout, Write};
+use crate::common::theme;
+use crossterm::style::Color;
+use std::ops::Range;
 use std::time::Duration;
 
 /// Represents the different phases of the merge sort algorithm
@@ -23,12 +22,13 @@ enum MergePhase {
 }
 
 /// Visualizes the merge sort algorithm step-by-step with interactive controls
-pub struct MergeSortVisualizer {
-    array: Vec<u32>,           // Current state of the array being sorted
-    original_array: Vec<u32>,  // Original array, used for resetting
+#[derive(Clone)]
+pub struct MergeSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array being sorted
+    original_array: Vec<T>,  // Original array, used for resetting
     states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, merging, sorted)
     intro_text: String,        // Dynamic intro text
-    temp: Vec<u32>,            // Temporary array used during merging
+    temp: Vec<T>,            // Temporary array used during merging
 
     // Bottom-up merge sort fields
     current_size: usize,       // Current size of subarrays being merged
@@ -41,13 +41,12 @@ pub struct MergeSortVisualizer {
     k: usize,                  // Index for the merged array
     phase: MergePhase,         // Current phase of the merge sort algorithm
     merge_count: usize,        // Number of merges performed (for teaching questions)
-    state: VisualizerState,    // Common visualization state
+    state: VisualizerState<T>,    // Common visualization state
 }
 
-impl MergeSortVisualizer {
-    /// Creates a new MergeSortVisualizer with the given array
-    pub fn new(array_data: &ArrayData) -> Self {
-        let settings = Settings::load();
+impl<T: SortElement> MergeSortVisualizer<T> {
+    /// Creates a new MergeSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
         let array = array_data.data.clone();
         let len = array.len();
 
@@ -86,7 +85,7 @@ impl MergeSortVisualizer {
 
         randomize_questions(questions.clone());
 
-        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed));
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("MergeSort")));
         state.teaching_mode = settings.teaching_mode;
 
         let intro_text = format!(
@@ -102,7 +101,7 @@ impl MergeSortVisualizer {
         let mut this = Self {
             original_array: array.clone(),
             array,
-            temp: vec![0; len],
+            temp: vec![T::default(); len],
             states: vec![SelectionState::Normal; len],
             intro_text,
             current_size: 1,
@@ -118,11 +117,6 @@ impl MergeSortVisualizer {
             state,
         };
 
-        // Set last visualizer
-        let mut settings = Settings::load();
-        settings.last_visualizer = Some("MergeSort".to_string());
-        settings.save();
-
         if len <= 1 {
             this.state.mark_completed();
             this.mark_all_sorted();
@@ -130,153 +124,40 @@ impl MergeSortVisualizer {
 
         this
     }
+}
 
-    /// Main loop: handles rendering, input, and stepping through the sort
-    pub fn run_visualization(&mut self) {
-        let mut stdout = stdout();
-        enable_raw_mode().unwrap();
-        stdout.execute(EnterAlternateScreen).unwrap();
-
-        show_intro_screen(self.get_intro_text());
-
-        loop {
-            self.draw(&mut stdout);
-
-            if poll(Duration::from_millis(50)).unwrap_or(false) {
-                match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
-                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                        // Handle question
-                        if let Some(q_index) = self.state.awaiting_question {
-                            match key_event.code {
-                                KeyCode::Char('1') => self.handle_question_answer(q_index, 0),
-                                KeyCode::Char('2') => self.handle_question_answer(q_index, 1),
-                                KeyCode::Char('3') => self.handle_question_answer(q_index, 2),
-                                _ => continue,
-                            }
-                            continue;
-                        }
-
-                        match key_event.code {
-                            KeyCode::Char(' ') => {
-                                if self.state.completed {
-                                    self.reset();
-                                } else {
-                                    self.state.toggle_play_pause();
-                                }
-                            },
-                            KeyCode::Char('r') | KeyCode::Char('R') => {
-                                self.reset();
-                            },
-                            KeyCode::Char('s') | KeyCode::Char('S') => {
-                                if !self.state.completed && !self.state.is_running {
-                                    if !self.step() {
-                                        self.state.mark_completed();
-                                        self.mark_all_sorted();
-                                    }
-                                }
-                            },
-                            KeyCode::Char('t') | KeyCode::Char('T') => {
-                                self.state.toggle_teaching_mode();
-                                self.intro_text = format!(
-                                    "What is Merge Sort?\n\n\
-                                     Merge Sort is a divide-and-conquer algorithm that recursively divides the array into halves, sorts them, and then merges the sorted halves back together.\n\n\
-                                     Advantages: Stable, O(n log n) time.\n\
-                                     Disadvantages: Requires extra space O(n).\n\n\
-                                     Teaching Mode: {} (Toggle with T). Questions will be asked after each merge.\n\n\
-                                     Press any key to continue...",
-                                    if self.state.teaching_mode { "ON" } else { "OFF" }
-                                );
-                                let mut settings = Settings::load();
-                                settings.teaching_mode = self.state.teaching_mode;
-                                settings.save();
-                            },
-                            KeyCode::Char('+') => {
-                                self.state.increase_speed(50);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Char('-') => {
-                                self.state.decrease_speed(2000);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Esc => {
-                                let mut settings = Settings::load();
-                                settings.last_visualizer = Some("MergeSort".to_string());
-                                settings.save();
-                                cleanup_terminal();
-                                return;
-                            },
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
-            }
-
-            // Auto step
-            if self.state.is_running && !self.state.is_paused && !self.state.completed
-                && self.state.awaiting_question.is_none() {
-                std::thread::sleep(self.state.speed);
-                if !self.step() {
-                    self.state.mark_completed();
-                    self.mark_all_sorted();
-                }
-            }
-        }
-    }
-
-    fn handle_question_answer(&mut self, q_index: usize, answer: usize) {
-        if let Some(question) = self.state.questions.get(q_index) {
-            let correct = answer == question.correct_index;
-            show_question_feedback(correct, question, answer);
-            self.state.clear_question();
-        }
-    }
-
-    fn draw(&mut self, stdout: &mut std::io::Stdout) {
-        let (width, height) = size().unwrap();
-        stdout.execute(Clear(ClearType::All)).unwrap();
-
-        // Title
-        VisualizerDrawer::draw_title(stdout, self.get_title());
-
-        // Array
-        VisualizerDrawer::draw_array_bars(stdout, &self.array, &self.states, width, height, 5);
-
-        // Legend
-        VisualizerDrawer::draw_legend(stdout, &self.get_legend_items(), width, height, 5);
-
-        // Statistics
-        let stats = self.get_statistics_strings();
-        VisualizerDrawer::draw_statistics(stdout, &stats, width, height);
-
-        // Controls
-        VisualizerDrawer::draw_controls(stdout, self.get_status(), self.get_controls_text(), width, height);
-
-        // Current operation
-        if self.state.awaiting_question.is_none() {
-            let operation = self.get_current_operation();
-            let color = if self.state.completed { Color::Green } else { Color::White };
-            VisualizerDrawer::draw_operation_info(stdout, &operation, width, height, color);
+impl<T: SortElement> MergeSortVisualizer<T> {
+    /// The merge buffer by array index while a merge is under way, None outside the runs being merged.
+    /// Copies of the left and right run keep their run colors until they are merged back,
+    /// the two at the front of the runs are the ones compared next.
+    pub fn buffer_slots(&self) -> Option<Vec<Option<(T, Color)>>> {
+        if self.state.completed || self.phase == MergePhase::MergePairs {
+            return None;
         }
-
-        // Question
-        if let Some(q_index) = self.state.awaiting_question {
-            if let Some(question) = self.state.questions.get(q_index) {
-                VisualizerDrawer::draw_question(stdout, question, width, height);
-            }
+        let mut slots = vec![None; self.array.len()];
+        for (index, slot) in slots.iter_mut().enumerate().take(self.high + 1).skip(self.low) {
+            let (next, run_color) = if index <= self.mid {
+                (self.i, Color::Blue)
+            } else {
+                (self.j, Color::AnsiValue(208))
+            };
+            let color = if index < next {
+                theme::dim()
+            } else if index == next {
+                Color::Magenta
+            } else {
+                run_color
+            };
+            *slot = Some((self.temp[index], color));
         }
-
-        stdout.flush().unwrap();
+        Some(slots)
     }
 }
 
-impl SortVisualizer for MergeSortVisualizer {
-    fn get_array(&self) -> &[u32] { &self.array }
-    fn get_original_array(&self) -> &[u32] { &self.original_array }
+impl<T: SortElement> SortVisualizer<T> for MergeSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
     fn get_states(&self) -> &[SelectionState] { &self.states }
     fn get_comparisons(&self) -> u32 { self.state.comparisons }
     fn get_swaps(&self) -> u32 { self.state.swaps }
@@ -287,6 +168,51 @@ impl SortVisualizer for MergeSortVisualizer {
     fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
     fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
     fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "MergeSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Merge Sort",
+            best: "O(n log n)",
+            average: "O(n log n)",
+            worst: "O(n log n)",
+            space: "O(n)",
+            stable: true,
+            in_place: false,
+        }
+    }
+    // Merged runs are sorted among themselves, only the last merge puts elements in their final place
+    fn sorted_regions(&self) -> Vec<Range<usize>> {
+        if self.state.completed { sorted_runs(&self.states) } else { Vec::new() }
+    }
+
+    // The temporary buffer under the array, the O(n) extra space merging needs
+    fn draw_extra(&self, drawer: &mut VisualizerDrawer, width: u16, height: u16) {
+        let Some(slots) = self.buffer_slots() else {
+            return;
+        };
+        let used = self.high + 1 - self.low;
+        let label = format!(
+            "Merge Buffer: left run [{}..{}] + right run [{}..{}], {} of {} slots (dim: merged back)",
+            self.low, self.mid, self.mid + 1, self.high, used, self.temp.len()
+        );
+        let max_value = self.array.iter().copied().max_by(|a, b| a.order(*b)).unwrap_or_default();
+        drawer.draw_buffer_row(&label, &slots, max_value, width, height, 5);
+    }
+
+    fn on_teaching_mode_changed(&mut self) {
+        // Keep the intro text in sync with the teaching mode
+        self.intro_text = format!(
+            "What is Merge Sort?\n\n\
+             Merge Sort is a divide-and-conquer algorithm that recursively divides the array into halves, sorts them, and then merges the sorted halves back together.\n\n\
+             Advantages: Stable, O(n log n) time.\n\
+             Disadvantages: Requires extra space O(n).\n\n\
+             Teaching Mode: {} (Toggle with T). Questions will be asked after each merge.\n\n\
+             Press any key to continue...",
+            if self.state.teaching_mode { "ON" } else { "OFF" }
+        );
+    }
 
     fn get_progress(&self) -> f64 {
         if self.array.len() <= 1 {
@@ -311,14 +237,15 @@ impl SortVisualizer for MergeSortVisualizer {
 
         match self.phase {
             MergePhase::MergePairs => {
-                // Check if we've processed all pairs at current size
-                if self.current_pair_start + 2 * self.current_size > self.array.len() {
+                // Check if we've processed all pairs at current size, a trailing run
+                // without a right half to merge with waits for the next pass
+                if self.current_pair_start + self.current_size >= self.array.len() {
                     // Double the size for next pass
                     self.current_size *= 2;
                     self.current_pair_start = 0;
 
                     // Check if we're done
-                    if self.current_size > self.array.len() {
+                    if self.current_size >= self.array.len() {
                         return false;
                     }
                 }
@@ -336,9 +263,11 @@ impl SortVisualizer for MergeSortVisualizer {
                 // Copy subarrays to temp for merging
                 if self.low <= self.mid {
                     self.temp[self.low..=self.mid].copy_from_slice(&self.array[self.low..=self.mid]);
+                    self.state.moves += (self.mid + 1 - self.low) as u32;
                 }
                 if right_start <= self.high {
                     self.temp[right_start..=self.high].copy_from_slice(&self.array[right_start..=self.high]);
+                    self.state.moves += (self.high + 1 - right_start) as u32;
                 }
 
                 self.phase = MergePhase::MergingInit;
@@ -364,21 +293,20 @@ impl SortVisualizer for MergeSortVisualizer {
                 } else if self.i > self.mid {
                     // Take from right subarray
                     self.array[self.k] = self.temp[self.j];
-                    self.state.swaps += 1;
+                    self.state.moves += 1;
                     self.k += 1;
                     self.j += 1;
                     true
                 } else if self.j > self.high {
                     // Take from left subarray
                     self.array[self.k] = self.temp[self.i];
-                    self.state.swaps += 1;
+                    self.state.moves += 1;
                     self.k += 1;
                     self.i += 1;
                     true
                 } else {
                     // Compare elements from both subarrays
-                    self.state.comparisons += 1;
-                    if self.temp[self.i] <= self.temp[self.j] {
+                    if self.state.compare_values(self.temp[self.i], self.temp[self.j]).is_le() {
                         self.array[self.k] = self.temp[self.i];
                         self.k += 1;
                         self.i += 1;
@@ -387,7 +315,7 @@ impl SortVisualizer for MergeSortVisualizer {
                         self.k += 1;
                         self.j += 1;
                     }
-                    self.state.swaps += 1;
+                    self.state.moves += 1;
                     true
                 }
             },
@@ -416,7 +344,7 @@ impl SortVisualizer for MergeSortVisualizer {
     fn reset(&mut self) {
         let len = self.original_array.len();
         self.array = self.original_array.clone();
-        self.temp = vec![0; len];
+        self.temp = vec![T::default(); len];
         self.states = vec![SelectionState::Normal; len];
         self.current_size = 1;
         self.current_pair_start = 0;
@@ -458,13 +386,13 @@ impl SortVisualizer for MergeSortVisualizer {
         &self.intro_text
     }
 
-    fn get_legend_items(&self) -> Vec<(&str, Color)> {
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
         vec![
-            ("Normal", Color::Cyan),
-            ("Merging L", Color::Blue),
-            ("Merging R", Color::AnsiValue(208)),
-            ("Comparing", Color::Magenta),
-            ("Sorted", Color::Green),
+            ("Normal", SelectionState::Normal),
+            ("Merging L", SelectionState::PartitionLeft),
+            ("Merging R", SelectionState::PartitionRight),
+            ("Comparing", SelectionState::Comparing),
+            ("Sorted", SelectionState::Sorted),
         ]
     }
 
@@ -472,13 +400,26 @@ impl SortVisualizer for MergeSortVisualizer {
         vec![
             format!("Array Size: {}", self.array.len()),
             format!("Comparisons: {}", self.state.comparisons),
-            format!("Moves: {}", self.state.swaps),
+            format!("Moves: {}", self.state.moves),
             format!("Subarray Size: {}", self.current_size),
             format!("Progress: {:.1}%", self.get_progress()),
             if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
         ]
     }
 
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        let hint = match self.phase {
+            MergePhase::MergePairs => "Bottom-up merge sort merges neighbouring sorted runs, doubling the run size every pass",
+            MergePhase::MergingInit => "Both runs are copied to the buffer first, so merging can write over the array without losing values",
+            MergePhase::MergingStep => "Only the fronts of the two runs are compared: each run is sorted, so the smaller front is the smallest value left",
+            MergePhase::DoneMerge => "The two runs became one sorted run, ready to be merged with its neighbour in the next pass",
+        };
+        Some(hint)
+    }
+
     fn get_current_operation(&self) -> String {
         if self.state.completed {
             "✓ Array is now sorted using Merge Sort! Congratulations!".to_string()
@@ -492,9 +433,9 @@ impl SortVisualizer for MergeSortVisualizer {
                             self.low, self.mid, self.mid + 1, self.high)
                 },
                 MergePhase::MergingStep => {
-                    let left_val = if self.i <= self.mid { self.temp[self.i] } else { 0 };
-                    let right_val = if self.j <= self.high { self.temp[self.j] } else { 0 };
-                    format!("Merging: left[{}]={:?} vs right[{}]={:?} -> pos {}",
+                    let left_val = if self.i <= self.mid { self.state.value_text(self.temp[self.i]) } else { "none".to_string() };
+                    let right_val = if self.j <= self.high { self.state.value_text(self.temp[self.j]) } else { "none".to_string() };
+                    format!("Merging: left[{}]={} vs right[{}]={} -> pos {}",
                             self.i.saturating_sub(self.low), left_val, self.j.saturating_sub(self.mid + 1), right_val, self.k)
                 },
                 MergePhase::DoneMerge => {
@@ -530,7 +471,7 @@ impl SortVisualizer for MergeSortVisualizer {
 }
 
 /// Entry point for the merge sort visualization
-pub fn merge_sort_visualization(array_data: &ArrayData) {
-    let mut visualizer = MergeSortVisualizer::new(array_data);
-    visualizer.run_visualization();
+pub fn merge_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(MergeSortVisualizer::with_settings(array_data, &settings), settings, last_run)
 }
\ No newline at end of file
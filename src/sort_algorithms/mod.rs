@@ -13,6 +13,10 @@ pub mod counting_sort;
 pub mod tim_sort;
 pub mod gnome_sort;
 pub mod pancake_sort;
+pub mod odd_even_sort;
+pub mod cycle_sort;
+pub mod patience_sort;
+pub mod in_place_merge_sort;
 
 pub use bubble_sort::*;
 pub use heap_sort::*;
@@ -28,3 +32,520 @@ pub use bucket_sort::*;
 pub use tim_sort::*;
 pub use gnome_sort::*;
 pub use pancake_sort::*;
+pub use odd_even_sort::*;
+pub use cycle_sort::*;
+pub use patience_sort::*;
+pub use in_place_merge_sort::*;
+
+use crate::common::array_manager::ArrayData;
+use crate::common::base_visualizer::SortVisualizer;
+use crate::common::element::SortElement;
+use crate::common::settings::Settings;
+use crate::sort_algorithms::counting_sort::CountingSortVisualizer;
+use std::time::{Duration, Instant};
+
+// Upper bound on headless steps so a preview can never hang the caller
+const HEADLESS_STEP_LIMIT: usize = 1_000_000;
+
+/// Runs a visualizer to completion without drawing or waiting for input,
+/// the way auto-play does with teaching mode off.
+pub fn run_headless<T: SortElement, V: SortVisualizer<T>>(visualizer: &mut V) {
+    visualizer.state_mut().teaching_mode = false;
+    visualizer.state_mut().is_running = true;
+
+    let mut steps = 0;
+    while visualizer.step() && steps < HEADLESS_STEP_LIMIT {
+        steps += 1;
+    }
+}
+
+/// Counters of one headless run
+#[derive(Clone, Copy, Debug)]
+pub struct HeadlessRun {
+    pub comparisons: u32,
+    pub swaps: u32,
+    pub moves: u32,
+    pub duration: Duration,
+}
+
+// Runs a visualizer headlessly on its copy of the array and keeps its counters
+fn measure<T: SortElement, V: SortVisualizer<T>>(mut visualizer: V) -> HeadlessRun {
+    let started = Instant::now();
+    run_headless(&mut visualizer);
+    HeadlessRun {
+        comparisons: visualizer.state().comparisons,
+        swaps: visualizer.state().swaps,
+        moves: visualizer.state().moves,
+        duration: started.elapsed(),
+    }
+}
+
+// Builds an algorithm's visualizer on the array and runs it headlessly
+type Measure = fn(&ArrayData, &Settings) -> HeadlessRun;
+
+// Every sorting algorithm that can run headlessly, by short name
+const HEADLESS_SORTS: &[(&str, Measure)] = &[
+    ("Bubble", |array_data, settings| measure(BubbleSortVisualizer::with_settings(array_data, settings))),
+    ("Bucket", |array_data, settings| measure(BucketSortVisualizer::with_settings(array_data, settings))),
+    ("Cocktail", |array_data, settings| measure(CocktailSortVisualizer::with_settings(array_data, settings))),
+    ("Comb", |array_data, settings| measure(CombSortVisualizer::with_settings(array_data, settings))),
+    ("Counting", |array_data, settings| measure(CountingSortVisualizer::with_settings(array_data, settings))),
+    ("Cycle", |array_data, settings| measure(CycleSortVisualizer::with_settings(array_data, settings))),
+    ("Gnome", |array_data, settings| measure(GnomeSortVisualizer::with_settings(array_data, settings))),
+    ("Heap", |array_data, settings| measure(HeapSortVisualizer::with_settings(array_data, settings))),
+    ("In-Place Merge", |array_data, settings| measure(InPlaceMergeSortVisualizer::with_settings(array_data, settings))),
+    ("Insertion", |array_data, settings| measure(InsertionSortVisualizer::with_settings(array_data, settings))),
+    ("Merge", |array_data, settings| measure(MergeSortVisualizer::with_settings(array_data, settings))),
+    ("Odd-Even", |array_data, settings| measure(OddEvenSortVisualizer::with_settings(array_data, settings))),
+    ("Pancake", |array_data, settings| measure(PancakeSortVisualizer::with_settings(array_data, settings))),
+    ("Patience", |array_data, settings| measure(PatienceSortVisualizer::with_settings(array_data, settings))),
+    ("Quick", |array_data, settings| measure(QuickSortVisualizer::with_settings(array_data, settings))),
+    ("Radix", |array_data, settings| measure(RadixSortVisualizer::with_settings(array_data, settings))),
+    ("Selection", |array_data, settings| measure(SelectionSortVisualizer::with_settings(array_data, settings))),
+    ("Shell", |array_data, settings| measure(ShellSortVisualizer::with_settings(array_data, settings))),
+    ("Tim", |array_data, settings| measure(TimSortVisualizer::with_settings(array_data, settings))),
+];
+
+/// Short names of the algorithms `headless_run` accepts, e.g. "Bubble"
+pub fn headless_sort_names() -> impl Iterator<Item = &'static str> {
+    HEADLESS_SORTS.iter().map(|(name, _)| *name)
+}
+
+/// Runs one sorting algorithm headlessly on its own copy of the array, None for an unknown name.
+pub fn headless_run(name: &str, array_data: &ArrayData, settings: &Settings) -> Option<HeadlessRun> {
+    HEADLESS_SORTS
+        .iter()
+        .find(|(sort_name, _)| *sort_name == name)
+        .map(|(_, measure)| measure(array_data, settings))
+}
+
+/// Runs every sorting algorithm headlessly on its own copy of the array.
+/// The settings are loaded once and shared by every run.
+pub fn headless_runs(array_data: &ArrayData) -> Vec<(&'static str, HeadlessRun)> {
+    let settings = Settings::load();
+    HEADLESS_SORTS
+        .iter()
+        .map(|(name, measure)| (*name, measure(array_data, &settings)))
+        .collect()
+}
+
+/// Predicted comparison count of every sorting algorithm on the given array,
+/// obtained by running each one headlessly on its own copy.
+pub fn comparison_estimates(array_data: &ArrayData) -> Vec<(&'static str, u32)> {
+    headless_runs(array_data)
+        .into_iter()
+        .map(|(name, run)| (name, run.comparisons))
+        .collect()
+}
+
+// The simple quadratic sorts explained side by side: settings key, short name, how it relates to the others
+const QUADRATIC_FAMILY: &[(&str, &str, &str)] = &[
+    ("BubbleSort", "Bubble", "swaps neighbours, each pass sinks the max"),
+    ("CocktailSort", "Cocktail", "bubble sort passing both ways"),
+    ("GnomeSort", "Gnome", "insertion sort by swaps, then steps back"),
+    ("InsertionSort", "Insertion", "shifts larger values, then drops the key"),
+];
+
+// Runs one member of the family headlessly, they all compare values so real numbers work too
+fn family_run<T: SortElement>(key: &str, array_data: &ArrayData<T>, settings: &Settings) -> HeadlessRun {
+    match key {
+        "BubbleSort" => measure(BubbleSortVisualizer::with_settings(array_data, settings)),
+        "CocktailSort" => measure(CocktailSortVisualizer::with_settings(array_data, settings)),
+        "GnomeSort" => measure(GnomeSortVisualizer::with_settings(array_data, settings)),
+        _ => measure(InsertionSortVisualizer::with_settings(array_data, settings)),
+    }
+}
+
+/// Whether the algorithm belongs to the gnome, insertion, bubble and cocktail family of simple quadratic sorts
+pub fn in_quadratic_family(settings_key: &str) -> bool {
+    QUADRATIC_FAMILY.iter().any(|(key, _, _)| *key == settings_key)
+}
+
+/// Table of the four simple quadratic sorts run headlessly on the same array, with a note on how each relates to the others.
+/// The row of `current`, a settings key, is marked with ">".
+pub fn quadratic_family_text<T: SortElement>(array_data: &ArrayData<T>, settings: &Settings, current: &str) -> String {
+    let mut lines = vec![format!("  {:<10} {:>11} {:>7} {:>7}", "Algorithm", "Comparisons", "Swaps", "Moves")];
+    for (key, name, _) in QUADRATIC_FAMILY {
+        let run = family_run(key, array_data, settings);
+        let marker = if *key == current { ">" } else { " " };
+        lines.push(format!("{} {:<10} {:>11} {:>7} {:>7}", marker, name, run.comparisons, run.swaps, run.moves));
+    }
+    lines.push(String::new());
+    for (_, name, note) in QUADRATIC_FAMILY {
+        lines.push(format!("{}: {}", name, note));
+    }
+    lines.push(String::new());
+    lines.push("Bubble, cocktail and gnome swap once per inversion,".to_string());
+    lines.push("insertion shifts those values instead of swapping.".to_string());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::enums::{BucketInnerSort, SortOrder};
+    use crate::common::verifier::verify_partial;
+
+    // Runs a visualizer to completion the way auto-play does and checks no question is ever asked
+    fn assert_no_questions<V: SortVisualizer>(mut visualizer: V) {
+        visualizer.state_mut().teaching_mode = false;
+        visualizer.state_mut().is_running = true;
+
+        let mut steps = 0;
+        while visualizer.step() {
+            assert!(visualizer.state().awaiting_question.is_none(), "{} asked a question", visualizer.settings_key());
+            steps += 1;
+            assert!(steps < 100_000, "{} did not finish", visualizer.settings_key());
+        }
+        assert!(visualizer.state().awaiting_question.is_none());
+    }
+
+    #[test]
+    fn quadratic_family_table_runs_all_four_on_the_same_array() {
+        let array_data = ArrayData::new(vec![5, 1, 4, 2, 3], "Family".to_string());
+        let text = quadratic_family_text(&array_data, &Settings::default(), "GnomeSort");
+        let lines: Vec<&str> = text.lines().collect();
+        // 5 1 4 2 3 has 6 inversions, bubble and gnome swap each one once
+        assert_eq!(lines[1], "  Bubble              10       6       0");
+        assert!(lines[3].starts_with("> Gnome"), "{}", lines[3]);
+        assert!(lines.iter().all(|line| line.len() <= 52), "{}", text);
+        assert!(in_quadratic_family("CocktailSort") && !in_quadratic_family("QuickSort"));
+    }
+
+    #[test]
+    fn quick_sort_cutoff_insertion_sorts_small_ranges() {
+        let data = vec![9, 4, 7, 1, 8, 2, 6, 3, 5, 4, 10, 1, 12, 0, 11];
+        let array_data = ArrayData::new(data.clone(), "Cutoff".to_string());
+        let mut expected = data;
+        expected.sort();
+
+        let mut plain = QuickSortVisualizer::with_settings(&array_data, &Settings::default());
+        run_headless(&mut plain);
+        let settings = Settings { quick_sort_cutoff: 8, ..Settings::default() };
+        let mut hybrid = QuickSortVisualizer::with_settings(&array_data, &settings);
+        assert_eq!(hybrid.insertion_cutoff(), Some(8));
+        run_headless(&mut hybrid);
+        assert_eq!(hybrid.get_array(), &expected[..]);
+        assert_eq!(plain.get_array(), &expected[..]);
+        assert!(hybrid.get_statistics_strings().iter().any(|line| line.starts_with("Insertion Cutoff: 8")));
+
+        // Tuned live to cover the whole array, no partition is needed at all
+        let mut tuned = QuickSortVisualizer::with_settings(&array_data, &Settings::default());
+        tuned.set_insertion_cutoff(15);
+        run_headless(&mut tuned);
+        assert_eq!(tuned.get_array(), &expected[..]);
+        assert!(tuned.get_statistics_strings().contains(&"Partitions: 0".to_string()));
+    }
+
+    #[test]
+    fn teaching_mode_off_never_asks_questions() {
+        let array_data = ArrayData::new(vec![9, 4, 7, 1, 8, 2, 6, 3, 5, 4, 10, 1], "Teaching Off".to_string());
+        let settings = Settings::default();
+
+        assert_no_questions(BubbleSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(BucketSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(CocktailSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(CombSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(CountingSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(CycleSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(GnomeSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(HeapSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(InPlaceMergeSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(InsertionSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(MergeSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(OddEvenSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(PancakeSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(PatienceSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(QuickSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(RadixSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(SelectionSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(ShellSortVisualizer::with_settings(&array_data, &settings));
+        assert_no_questions(TimSortVisualizer::with_settings(&array_data, &settings));
+    }
+
+    // Checks a step hint is given while sorting and none once the sort is complete
+    fn assert_step_hints<V: SortVisualizer>(mut visualizer: V) {
+        assert!(visualizer.get_step_hint().is_some(), "{} has no hint at the start", visualizer.settings_key());
+        run_headless(&mut visualizer);
+        // The runner marks the sort completed once step returns false
+        visualizer.state_mut().mark_completed();
+        assert!(visualizer.get_step_hint().is_none(), "{} still explains a step", visualizer.settings_key());
+    }
+
+    #[test]
+    fn every_sort_explains_its_steps() {
+        let array_data = ArrayData::new(vec![9, 4, 7, 1, 8, 2, 6, 3], "Hints".to_string());
+        let settings = Settings::default();
+
+        assert_step_hints(BubbleSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(BucketSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(CocktailSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(CombSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(CountingSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(CycleSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(GnomeSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(HeapSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(InPlaceMergeSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(InsertionSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(MergeSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(OddEvenSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(PancakeSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(PatienceSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(QuickSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(RadixSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(SelectionSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(ShellSortVisualizer::with_settings(&array_data, &settings));
+        assert_step_hints(TimSortVisualizer::with_settings(&array_data, &settings));
+    }
+
+    // Sorts with the given ordering and checks no neighbouring pair is out of order
+    fn assert_sorted_by<V: SortVisualizer>(mut visualizer: V, order: SortOrder) {
+        visualizer.state_mut().set_sort_order(order);
+        run_headless(&mut visualizer);
+        let comparator = order.comparator();
+        assert!(
+            visualizer.get_array().windows(2).all(|pair| comparator(pair[0], pair[1]).is_le()),
+            "{} is out of order with {:?}: {:?}",
+            visualizer.settings_key(),
+            order,
+            visualizer.get_array()
+        );
+    }
+
+    #[test]
+    fn comparison_sorts_follow_the_sort_order() {
+        let array_data = ArrayData::new(vec![9, 41, 7, 100, 58, 2, 63, 30, 5, 41, 10, 12, 71], "Sort Order".to_string());
+        let settings = Settings::default();
+
+        for order in SortOrder::ALL {
+            assert_sorted_by(BubbleSortVisualizer::with_settings(&array_data, &settings), order);
+            assert_sorted_by(CocktailSortVisualizer::with_settings(&array_data, &settings), order);
+            assert_sorted_by(CombSortVisualizer::with_settings(&array_data, &settings), order);
+            assert_sorted_by(CycleSortVisualizer::with_settings(&array_data, &settings), order);
+            assert_sorted_by(GnomeSortVisualizer::with_settings(&array_data, &settings), order);
+            assert_sorted_by(HeapSortVisualizer::with_settings(&array_data, &settings), order);
+            assert_sorted_by(InPlaceMergeSortVisualizer::with_settings(&array_data, &settings), order);
+            assert_sorted_by(InsertionSortVisualizer::with_settings(&array_data, &settings), order);
+            assert_sorted_by(MergeSortVisualizer::with_settings(&array_data, &settings), order);
+            assert_sorted_by(OddEvenSortVisualizer::with_settings(&array_data, &settings), order);
+            assert_sorted_by(PancakeSortVisualizer::with_settings(&array_data, &settings), order);
+            assert_sorted_by(PatienceSortVisualizer::with_settings(&array_data, &settings), order);
+            assert_sorted_by(QuickSortVisualizer::with_settings(&array_data, &settings), order);
+            assert_sorted_by(SelectionSortVisualizer::with_settings(&array_data, &settings), order);
+            assert_sorted_by(ShellSortVisualizer::with_settings(&array_data, &settings), order);
+            assert_sorted_by(TimSortVisualizer::with_settings(&array_data, &settings), order);
+        }
+    }
+
+    // Steps a visualizer to completion and checks its sorted regions after every step
+    fn assert_regions_final<V: SortVisualizer>(mut visualizer: V) {
+        visualizer.state_mut().teaching_mode = false;
+        visualizer.state_mut().is_running = true;
+        let comparator = SortOrder::Ascending.comparator();
+
+        let mut steps = 0;
+        loop {
+            if visualizer.verifiable() {
+                let divergence = verify_partial(visualizer.get_array(), &visualizer.sorted_regions(), &comparator);
+                assert_eq!(divergence, None, "{} after {} steps", visualizer.settings_key(), steps);
+            }
+            if !visualizer.step() || steps >= HEADLESS_STEP_LIMIT {
+                break;
+            }
+            steps += 1;
+        }
+    }
+
+    #[test]
+    fn sorted_regions_hold_their_final_values() {
+        let array_data = ArrayData::new(vec![9, 41, 7, 100, 58, 2, 63, 30, 5, 41, 10, 12, 71], "Regions".to_string());
+        let settings = Settings::default();
+
+        assert_regions_final(BubbleSortVisualizer::with_settings(&array_data, &settings));
+        assert_regions_final(CocktailSortVisualizer::with_settings(&array_data, &settings));
+        assert_regions_final(CombSortVisualizer::with_settings(&array_data, &settings));
+        assert_regions_final(CycleSortVisualizer::with_settings(&array_data, &settings));
+        assert_regions_final(GnomeSortVisualizer::with_settings(&array_data, &settings));
+        assert_regions_final(HeapSortVisualizer::with_settings(&array_data, &settings));
+        assert_regions_final(InPlaceMergeSortVisualizer::with_settings(&array_data, &settings));
+        assert_regions_final(InsertionSortVisualizer::with_settings(&array_data, &settings));
+        assert_regions_final(MergeSortVisualizer::with_settings(&array_data, &settings));
+        assert_regions_final(OddEvenSortVisualizer::with_settings(&array_data, &settings));
+        assert_regions_final(PancakeSortVisualizer::with_settings(&array_data, &settings));
+        assert_regions_final(QuickSortVisualizer::with_settings(&array_data, &settings));
+        assert_regions_final(RadixSortVisualizer::with_settings(&array_data, &settings));
+        assert_regions_final(SelectionSortVisualizer::with_settings(&array_data, &settings));
+        assert_regions_final(ShellSortVisualizer::with_settings(&array_data, &settings));
+        assert_regions_final(TimSortVisualizer::with_settings(&array_data, &settings));
+    }
+
+    // Runs a visualizer to completion through the trait alone, the way the runner finishes a run,
+    // and checks the result is sorted and passes --verify
+    fn assert_sorts_and_verifies<T: SortElement, V: SortVisualizer<T>>(mut visualizer: V, array_data: &ArrayData<T>) {
+        run_headless(&mut visualizer);
+        visualizer.state_mut().mark_completed();
+        visualizer.on_completed();
+
+        let name = visualizer.settings_key().to_string();
+        let mut expected = array_data.data.clone();
+        expected.sort_unstable_by(|a, b| a.order(*b));
+        assert_eq!(visualizer.get_array(), expected, "{} on {}", name, array_data.name);
+
+        let comparator = SortOrder::Ascending.comparator();
+        let divergence = verify_partial(visualizer.get_array(), &visualizer.sorted_regions(), &comparator);
+        assert_eq!(divergence, None, "{} on {}", name, array_data.name);
+    }
+
+    #[test]
+    fn every_sort_completes_without_a_terminal() {
+        let settings = Settings::default();
+        let arrays = [
+            ArrayData::new(vec![9, 41, 7, 100, 58, 2, 63, 30, 5, 41, 10, 12, 71], "Random".to_string()),
+            ArrayData::new(vec![8, 7, 6, 5, 4, 3, 2, 1], "Reversed".to_string()),
+            ArrayData::new(vec![3, 1, 3, 1, 3, 1], "Duplicates".to_string()),
+            ArrayData::new(vec![1, 2, 3, 4, 5], "Sorted".to_string()),
+            ArrayData::new(vec![42], "Single".to_string()),
+        ];
+
+        for array_data in &arrays {
+            assert_sorts_and_verifies(BubbleSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(BucketSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(CocktailSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(CombSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(CountingSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(CycleSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(GnomeSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(HeapSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(InPlaceMergeSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(InsertionSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(MergeSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(OddEvenSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(PancakeSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(PatienceSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(QuickSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(RadixSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(SelectionSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(ShellSortVisualizer::with_settings(array_data, &settings), array_data);
+            assert_sorts_and_verifies(TimSortVisualizer::with_settings(array_data, &settings), array_data);
+        }
+    }
+
+    #[test]
+    fn comparison_sorts_sort_real_numbers() {
+        let settings = Settings::default();
+        let array_data = ArrayData::new(vec![2.5, 0.75, 9.125, 2.5, 0.5, 7.0, 3.25, 0.25], "Reals".to_string());
+
+        assert_sorts_and_verifies(BubbleSortVisualizer::with_settings(&array_data, &settings), &array_data);
+        assert_sorts_and_verifies(BucketSortVisualizer::with_settings(&array_data, &settings), &array_data);
+        assert_sorts_and_verifies(CombSortVisualizer::with_settings(&array_data, &settings), &array_data);
+        assert_sorts_and_verifies(CycleSortVisualizer::with_settings(&array_data, &settings), &array_data);
+        assert_sorts_and_verifies(HeapSortVisualizer::with_settings(&array_data, &settings), &array_data);
+        assert_sorts_and_verifies(InPlaceMergeSortVisualizer::with_settings(&array_data, &settings), &array_data);
+        assert_sorts_and_verifies(MergeSortVisualizer::with_settings(&array_data, &settings), &array_data);
+        assert_sorts_and_verifies(PatienceSortVisualizer::with_settings(&array_data, &settings), &array_data);
+        assert_sorts_and_verifies(QuickSortVisualizer::with_settings(&array_data, &settings), &array_data);
+        assert_sorts_and_verifies(ShellSortVisualizer::with_settings(&array_data, &settings), &array_data);
+        assert_sorts_and_verifies(TimSortVisualizer::with_settings(&array_data, &settings), &array_data);
+    }
+
+    #[test]
+    fn sorts_shift_negative_values_or_compare_them() {
+        let settings = Settings::default();
+        let array_data = ArrayData::<i32>::new(vec![-7, 41, 0, -120, 58, 2, -7, 30, 5], "Signed".to_string());
+
+        assert_sorts_and_verifies(BucketSortVisualizer::with_settings(&array_data, &settings), &array_data);
+        assert_sorts_and_verifies(CountingSortVisualizer::with_settings(&array_data, &settings), &array_data);
+        assert_sorts_and_verifies(RadixSortVisualizer::with_settings(&array_data, &settings), &array_data);
+        assert_sorts_and_verifies(QuickSortVisualizer::with_settings(&array_data, &settings), &array_data);
+        assert_sorts_and_verifies(TimSortVisualizer::with_settings(&array_data, &settings), &array_data);
+    }
+
+    #[test]
+    fn counters_follow_the_operation_model() {
+        let array_data = ArrayData::new(vec![3, 2, 1], "Reversed".to_string());
+        let settings = Settings::default();
+
+        // Insertion sort shifts 2 then 3, and writes each held key back: moves only
+        let mut visualizer = InsertionSortVisualizer::with_settings(&array_data, &settings);
+        run_headless(&mut visualizer);
+        assert_eq!((visualizer.state().swaps, visualizer.state().moves), (0, 5));
+
+        // Heap sort only exchanges positions
+        let mut visualizer = HeapSortVisualizer::with_settings(&array_data, &settings);
+        run_headless(&mut visualizer);
+        assert_eq!(visualizer.state().moves, 0);
+        assert!(visualizer.state().swaps > 0);
+
+        // Merge sort copies into its buffer and back
+        let mut visualizer = MergeSortVisualizer::with_settings(&array_data, &settings);
+        run_headless(&mut visualizer);
+        assert_eq!(visualizer.state().swaps, 0);
+        assert!(visualizer.state().moves >= 2 * 3);
+    }
+
+    #[test]
+    fn tim_sort_merges_runs_of_large_arrays() {
+        // 64 or more elements split into runs of 32 that have to be merged
+        let data: Vec<u32> = (0..100).map(|i| (i * 37 + 11) % 101).collect();
+        let array_data = ArrayData::new(data, "Runs".to_string());
+        let settings = Settings::default();
+
+        assert_sorts_and_verifies(TimSortVisualizer::with_settings(&array_data, &settings), &array_data);
+        assert_regions_final(TimSortVisualizer::with_settings(&array_data, &settings));
+    }
+
+    #[test]
+    fn bucket_sort_runs_each_inner_sort() {
+        // Most values share the lowest bucket, so the inner sort does the real work
+        let array_data = ArrayData::new(vec![7, 3, 9, 1, 8, 2, 6, 100, 5, 4, 3, 60], "Skewed".to_string());
+        let mut settings = Settings::default();
+
+        for inner_sort in BucketInnerSort::ALL {
+            settings.bucket_inner_sort = inner_sort;
+            assert_sorts_and_verifies(BucketSortVisualizer::with_settings(&array_data, &settings), &array_data);
+        }
+    }
+
+    #[test]
+    fn merge_buffer_drains_as_runs_are_merged() {
+        use crossterm::style::Color;
+
+        let array_data = ArrayData::new(vec![4, 3, 2, 1], "Buffer".to_string());
+        let mut visualizer = MergeSortVisualizer::with_settings(&array_data, &Settings::default());
+        visualizer.state_mut().teaching_mode = false;
+        assert!(visualizer.buffer_slots().is_none());
+
+        // The first pair is copied into the buffer, both fronts are compared next
+        visualizer.step();
+        let slots = visualizer.buffer_slots().unwrap();
+        assert_eq!(slots[0], Some((4, Color::Magenta)));
+        assert_eq!(slots[1], Some((3, Color::Magenta)));
+        assert_eq!(slots[2], None);
+
+        // 3 is merged back first, its slot is used up
+        visualizer.step();
+        visualizer.step();
+        let slots = visualizer.buffer_slots().unwrap();
+        assert_eq!(slots[0], Some((4, Color::Magenta)));
+        assert_ne!(slots[1].unwrap().1, Color::AnsiValue(208));
+        assert_eq!(visualizer.get_array()[0], 3);
+    }
+
+    #[test]
+    fn watched_pair_counts_comparisons_in_either_order() {
+        let array_data = ArrayData::new(vec![5, 1, 4, 2, 3], "Watch".to_string());
+        let settings = Settings::default();
+
+        let mut visualizer = BubbleSortVisualizer::with_settings(&array_data, &settings);
+        visualizer.state_mut().watch_pair(1, 5);
+        run_headless(&mut visualizer);
+        // 5 is compared with 1 once on its way to the end
+        assert_eq!(visualizer.state().watched_comparisons, 1);
+
+        let mut visualizer = SelectionSortVisualizer::with_settings(&array_data, &settings);
+        visualizer.state_mut().watch_pair(2, 4);
+        run_headless(&mut visualizer);
+        assert!(visualizer.state().watched_comparisons > 0);
+        assert!(visualizer.state().watched_comparisons < visualizer.state().comparisons);
+    }
+}
@@ -0,0 +1,341 @@
+use crate::common::algorithm_info::AlgorithmInfo;
+use crate::common::array_manager::ArrayData;
+use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
+use crate::common::element::SortElement;
+use crate::common::enums::{SelectionState, TeachingQuestion};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
+use crate::common::settings::Settings;
+use std::cmp::Ordering;
+use std::time::Duration;
+
+/// Represents the different phases of the odd-even (brick) sort algorithm
+#[derive(Clone, Copy, PartialEq)]
+pub enum OddEvenPhase {
+    OddPass,    // Comparing pairs starting at odd indices (1-2, 3-4, ...)
+    EvenPass,   // Comparing pairs starting at even indices (0-1, 2-3, ...)
+    Done,       // Sorting is complete
+}
+
+/// Visualizes the odd-even transposition sort step-by-step with interactive controls
+#[derive(Clone)]
+pub struct OddEvenSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array being sorted
+    original_array: Vec<T>,  // Original array, used for resetting
+    states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, swapping, sorted)
+
+    // Odd-Even Sort specific fields
+    current_index: usize,      // Left index of the pair being compared
+    round: usize,              // Number of completed rounds (odd pass + even pass)
+    swapped_in_round: bool,    // Whether any swap happened during the current round
+    phase: OddEvenPhase,       // Current phase of the odd-even sort algorithm
+    state: VisualizerState<T>,    // Common visualization state
+}
+
+impl<T: SortElement> OddEvenSortVisualizer<T> {
+    /// Creates a new OddEvenSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
+        let array = array_data.data.clone();
+        let len = array.len();
+
+        let questions = vec![
+            TeachingQuestion {
+                text: "Why is Odd-Even Sort well suited to parallel hardware?".to_string(),
+                options: vec![
+                    "All pairs in a pass are disjoint, so they can be compared at the same time".to_string(),
+                    "It never needs to swap elements".to_string(),
+                    "It only uses one comparison per pass".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "Within one pass every pair touches different elements, so n/2 processors can compare-swap simultaneously and the sort finishes in n parallel rounds.".to_string(),
+            },
+            TeachingQuestion {
+                text: "When does Odd-Even Sort know the array is sorted?".to_string(),
+                options: vec![
+                    "When a full round (odd pass + even pass) makes no swaps".to_string(),
+                    "After exactly one odd pass".to_string(),
+                    "When the first and last elements are in order".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "If neither the odd nor the even pass swapped anything, every adjacent pair is in order, so the whole array is sorted.".to_string(),
+            },
+            TeachingQuestion {
+                text: "What is the sequential time complexity of Odd-Even Sort?".to_string(),
+                options: vec![
+                    "O(n^2)".to_string(),
+                    "O(n log n)".to_string(),
+                    "O(n)".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "On a single processor it performs up to n rounds of n/2 comparisons, which is O(n^2) like Bubble Sort.".to_string(),
+            },
+        ];
+
+        randomize_questions(questions.clone());
+
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("OddEvenSort")));
+        state.teaching_mode = settings.teaching_mode;
+
+        let mut this = Self {
+            original_array: array.clone(),
+            array,
+            states: vec![SelectionState::Normal; len],
+            current_index: 1,
+            round: 0,
+            swapped_in_round: false,
+            phase: OddEvenPhase::OddPass,
+            state,
+        };
+
+        if len <= 1 {
+            this.state.mark_completed();
+            this.mark_all_sorted();
+        }
+
+        this
+    }
+}
+
+impl<T: SortElement> SortVisualizer<T> for OddEvenSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
+    fn get_states(&self) -> &[SelectionState] { &self.states }
+    fn get_comparisons(&self) -> u32 { self.state.comparisons }
+    fn get_swaps(&self) -> u32 { self.state.swaps }
+    fn get_speed(&self) -> Duration { self.state.speed }
+    fn is_running(&self) -> bool { self.state.is_running }
+    fn is_paused(&self) -> bool { self.state.is_paused }
+    fn is_completed(&self) -> bool { self.state.completed }
+    fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
+    fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
+    fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "OddEvenSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Odd-Even Sort",
+            best: "O(n)",
+            average: "O(n^2)",
+            worst: "O(n^2)",
+            space: "O(1)",
+            stable: true,
+            in_place: true,
+        }
+    }
+
+    fn get_progress(&self) -> f64 {
+        // At most n rounds are ever needed
+        let total = self.array.len();
+        if total == 0 { 100.0 } else {
+            (self.round as f64 / total as f64 * 100.0).min(100.0)
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if self.state.completed || self.state.awaiting_question.is_some() {
+            return true;
+        }
+
+        // Reset states except sorted
+        for state in &mut self.states {
+            if *state != SelectionState::Sorted {
+                *state = SelectionState::Normal;
+            }
+        }
+
+        let n = self.array.len();
+        if n < 2 {
+            return false;
+        }
+
+        match self.phase {
+            OddEvenPhase::OddPass | OddEvenPhase::EvenPass => {
+                if self.current_index + 1 < n {
+                    // Show every pair of this pass, they are independent of each other
+                    let pass_start = if self.phase == OddEvenPhase::OddPass { 1 } else { 0 };
+                    for k in (pass_start..n - 1).step_by(2) {
+                        self.states[k] = SelectionState::Selected;
+                        self.states[k + 1] = SelectionState::Selected;
+                    }
+
+                    let i = self.current_index;
+                    self.states[i] = SelectionState::Comparing;
+                    self.states[i + 1] = SelectionState::Comparing;
+
+                    if self.compare(i, i + 1).is_gt() {
+                        self.states[i] = SelectionState::Swapping;
+                        self.states[i + 1] = SelectionState::Swapping;
+                        self.array.swap(i, i + 1);
+                        self.state.swaps += 1;
+                        self.swapped_in_round = true;
+                    }
+                    self.current_index += 2;
+                } else if self.phase == OddEvenPhase::OddPass {
+                    // Odd pass finished, continue with the even pass of this round
+                    self.phase = OddEvenPhase::EvenPass;
+                    self.current_index = 0;
+                } else {
+                    // Even pass finished, the round is over
+                    if !self.swapped_in_round {
+                        self.phase = OddEvenPhase::Done;
+                        return false;
+                    }
+                    self.round += 1;
+                    self.swapped_in_round = false;
+                    self.phase = OddEvenPhase::OddPass;
+                    self.current_index = 1;
+
+                    // Teaching: Ask question after each full round
+                    self.state.ask_question(self.round);
+                }
+            },
+            OddEvenPhase::Done => return false,
+        }
+
+        true
+    }
+
+    fn reset(&mut self) {
+        self.array = self.original_array.clone();
+        self.states = vec![SelectionState::Normal; self.array.len()];
+        self.current_index = 1;
+        self.round = 0;
+        self.swapped_in_round = false;
+        self.phase = OddEvenPhase::OddPass;
+        self.state.reset_state();
+        if self.array.len() <= 1 {
+            self.state.mark_completed();
+            self.mark_all_sorted();
+        }
+    }
+
+    fn mark_all_sorted(&mut self) {
+        for state in &mut self.states {
+            *state = SelectionState::Sorted;
+        }
+    }
+
+    fn get_title(&self) -> &str {
+        "TOGISOFT ODD-EVEN SORT VISUALIZER"
+    }
+
+    fn get_intro_text(&self) -> &str {
+        "What is Odd-Even Sort?\n\n\
+         Odd-Even Sort, also known as Brick Sort, alternates between two kinds of passes.\n\
+         The odd pass compare-swaps the pairs (1,2), (3,4), ... and the even pass the pairs (0,1), (2,3), ...\n\
+         It stops once a full round of both passes makes no swaps.\n\n\
+         Advantages: Every pair in a pass is independent, so it parallelizes perfectly.\n\
+         Disadvantages: O(n^2) comparisons on a single processor.\n\n\
+         Teaching Mode: ON (Toggle with T). Questions will be asked after each round.\n\n\
+         Press any key to continue..."
+    }
+
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
+        vec![
+            ("Normal", SelectionState::Normal),
+            ("Pass Pair", SelectionState::Selected),
+            ("Comparing", SelectionState::Comparing),
+            ("Swapping", SelectionState::Swapping),
+            ("Sorted", SelectionState::Sorted),
+        ]
+    }
+
+    fn get_statistics_strings(&self) -> Vec<String> {
+        let phase_str = match self.phase {
+            OddEvenPhase::OddPass => "Odd Pass".to_string(),
+            OddEvenPhase::EvenPass => "Even Pass".to_string(),
+            OddEvenPhase::Done => "Done".to_string(),
+        };
+
+        vec![
+            format!("Array Size: {}", self.array.len()),
+            format!("Comparisons: {}", self.state.comparisons),
+            format!("Swaps: {}", self.state.swaps),
+            format!("Round: {}", self.round + 1),
+            format!("Phase: {}", phase_str),
+            format!("Swapped This Round: {}", if self.swapped_in_round { "Yes" } else { "No" }),
+            format!("Progress: {:.1}%", self.get_progress()),
+            if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
+        ]
+    }
+
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        let hint = match self.phase {
+            OddEvenPhase::OddPass => "Pairs starting at odd indices are compared; they share no element, so all of them could be swapped at the same time",
+            OddEvenPhase::EvenPass => "Pairs starting at even indices fix the boundaries the odd pass left; alternating passes sort like a parallel bubble sort",
+            _ => return None,
+        };
+        Some(hint)
+    }
+
+    fn decision_changers(&self) -> Vec<usize> {
+        let i = self.current_index;
+        if self.state.completed || self.phase == OddEvenPhase::Done || i + 1 >= self.array.len() {
+            return Vec::new();
+        }
+        // array[i] swaps with its right neighbour if it orders after it
+        self.state.decision_changers(&self.array, i, self.array[i + 1], &[i, i + 1], Ordering::is_gt)
+    }
+
+    fn get_current_operation(&self) -> String {
+        if self.state.completed {
+            "✓ Array is now sorted using Odd-Even Sort! Congratulations!".to_string()
+        } else {
+            let n = self.array.len();
+            let pass_name = match self.phase {
+                OddEvenPhase::OddPass => "odd",
+                OddEvenPhase::EvenPass => "even",
+                OddEvenPhase::Done => return "Odd-even sort completed!".to_string(),
+            };
+
+            if self.current_index + 1 < n {
+                format!("Round {}, {} pass: comparing array[{}] ({}) with array[{}] ({})",
+                        self.round + 1,
+                        pass_name,
+                        self.current_index,
+                        self.state.value_text(self.array[self.current_index]),
+                        self.current_index + 1,
+                        self.state.value_text(self.array[self.current_index + 1]))
+            } else if self.phase == OddEvenPhase::EvenPass && !self.swapped_in_round {
+                format!("Round {} finished without swaps", self.round + 1)
+            } else {
+                format!("Round {}: {} pass finished", self.round + 1, pass_name)
+            }
+        }
+    }
+
+    fn get_status(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "WAITING FOR QUESTION"
+        } else if self.state.completed {
+            "COMPLETED!"
+        } else if self.state.is_running && !self.state.is_paused {
+            "RUNNING..."
+        } else if self.state.is_paused {
+            "PAUSED"
+        } else {
+            "READY"
+        }
+    }
+
+    fn get_controls_text(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "1,2,3: Answer | ESC: Exit"
+        } else if self.state.completed {
+            "SPACE: Restart | R: Reset | T: Teaching Toggle | ESC: Exit"
+        } else {
+            "SPACE: Start/Pause | S: Step | R: Reset | T: Teaching | +/-: Speed | ESC: Exit"
+        }
+    }
+}
+
+/// Entry point for the odd-even sort visualization
+pub fn odd_even_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(OddEvenSortVisualizer::with_settings(array_data, &settings), settings, last_run)
+}
@@ -1,16 +1,12 @@
+use crate::common::algorithm_info::AlgorithmInfo;
 use crate::common::array_manager::ArrayData;
 use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
-use crate::common::common_visualizer::{show_intro_screen, show_question_feedback, VisualizerDrawer};
+use crate::common::element::SortElement;
 use crate::common::enums::{SelectionState, TeachingQuestion};
-use crate::common::helper::{cleanup_terminal, randomize_questions};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
 use crate::common::settings::Settings;
-use crossterm::{
-    event::{poll, read, Event, KeyCode, KeyEventKind},
-    style::Color,
-    terminal::{enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen},
-    ExecutableCommand,
-};
-use std::io::{stdout, Write};
+use std::ops::Range;
 use std::time::Duration;
 
 /// Represents the different phases of the pancake sort algorithm
@@ -23,23 +19,25 @@ pub enum PancakePhase {
 }
 
 /// Visualizes the pancake sort algorithm step-by-step with interactive controls
-pub struct PancakeSortVisualizer {
-    array: Vec<u32>,           // Current state of the array being sorted
-    original_array: Vec<u32>,  // Original array, used for resetting
+#[derive(Clone)]
+pub struct PancakeSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array being sorted
+    original_array: Vec<T>,  // Original array, used for resetting
     states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, flipping, sorted)
 
     // Pancake Sort specific fields
     unsorted_size: usize,      // Current size of unsorted portion
     max_pos: usize,            // Position of the current maximum
+    scan_pos: usize,           // Position being compared against the current maximum
     flip_pos: usize,           // Position for flipping
+    flips: u32,                // Prefix flips so far, each one is several swaps
     phase: PancakePhase,       // Current phase of the pancake sort algorithm
-    state: VisualizerState,    // Common visualization state
+    state: VisualizerState<T>,    // Common visualization state
 }
 
-impl PancakeSortVisualizer {
-    /// Creates a new PancakeSortVisualizer with the given array
-    pub fn new(array_data: &ArrayData) -> Self {
-        let settings = Settings::load();
+impl<T: SortElement> PancakeSortVisualizer<T> {
+    /// Creates a new PancakeSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
         let array = array_data.data.clone();
         let len = array.len();
 
@@ -78,7 +76,7 @@ impl PancakeSortVisualizer {
 
         randomize_questions(questions.clone());
 
-        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed));
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("PancakeSort")));
         state.teaching_mode = settings.teaching_mode;
 
         let mut this = Self {
@@ -87,16 +85,13 @@ impl PancakeSortVisualizer {
             states: vec![SelectionState::Normal; len],
             unsorted_size: len,
             max_pos: 0,
+            scan_pos: 1,
             flip_pos: 0,
+            flips: 0,
             phase: PancakePhase::FindingMax,
             state,
         };
 
-        // Set last visualizer
-        let mut settings = Settings::load();
-        settings.last_visualizer = Some("PancakeSort".to_string());
-        settings.save();
-
         if len <= 1 {
             this.state.mark_completed();
             this.mark_all_sorted();
@@ -105,156 +100,24 @@ impl PancakeSortVisualizer {
         this
     }
 
-    /// Main loop: handles rendering, input, and stepping through the sort
-    pub fn run_visualization(&mut self) {
-        let mut stdout = stdout();
-        enable_raw_mode().unwrap();
-        stdout.execute(EnterAlternateScreen).unwrap();
-
-        show_intro_screen(self.get_intro_text());
-
-        loop {
-            self.draw(&mut stdout);
-
-            if poll(Duration::from_millis(50)).unwrap_or(false) {
-                match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
-                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                        // Handle question
-                        if let Some(q_index) = self.state.awaiting_question {
-                            match key_event.code {
-                                KeyCode::Char('1') => self.handle_question_answer(q_index, 0),
-                                KeyCode::Char('2') => self.handle_question_answer(q_index, 1),
-                                KeyCode::Char('3') => self.handle_question_answer(q_index, 2),
-                                _ => continue,
-                            }
-                            continue;
-                        }
-
-                        match key_event.code {
-                            KeyCode::Char(' ') => {
-                                if self.state.completed {
-                                    self.reset();
-                                } else {
-                                    self.state.toggle_play_pause();
-                                }
-                            },
-                            KeyCode::Char('r') | KeyCode::Char('R') => {
-                                self.reset();
-                            },
-                            KeyCode::Char('s') | KeyCode::Char('S') => {
-                                if !self.state.completed && !self.state.is_running {
-                                    if !self.step() {
-                                        self.state.mark_completed();
-                                        self.mark_all_sorted();
-                                    }
-                                }
-                            },
-                            KeyCode::Char('t') | KeyCode::Char('T') => {
-                                self.state.toggle_teaching_mode();
-                                let mut settings = Settings::load();
-                                settings.teaching_mode = self.state.teaching_mode;
-                                settings.save();
-                            },
-                            KeyCode::Char('+') => {
-                                self.state.increase_speed(50);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Char('-') => {
-                                self.state.decrease_speed(2000);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Esc => {
-                                let mut settings = Settings::load();
-                                settings.last_visualizer = Some("PancakeSort".to_string());
-                                settings.save();
-                                cleanup_terminal();
-                                return;
-                            },
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
-            }
-
-            // Auto step
-            if self.state.is_running && !self.state.is_paused && !self.state.completed
-                && self.state.awaiting_question.is_none() {
-                std::thread::sleep(self.state.speed);
-                if !self.step() {
-                    self.state.mark_completed();
-                    self.mark_all_sorted();
-                }
-            }
-        }
-    }
-
-    fn handle_question_answer(&mut self, q_index: usize, answer: usize) {
-        if let Some(question) = self.state.questions.get(q_index) {
-            let correct = answer == question.correct_index;
-            show_question_feedback(correct, question, answer);
-            self.state.clear_question();
-        }
-    }
-
-    fn draw(&mut self, stdout: &mut std::io::Stdout) {
-        let (width, height) = size().unwrap();
-        stdout.execute(Clear(ClearType::All)).unwrap();
-
-        // Title
-        VisualizerDrawer::draw_title(stdout, self.get_title());
-
-        // Array
-        VisualizerDrawer::draw_array_bars(stdout, &self.array, &self.states, width, height, 5);
-
-        // Legend
-        VisualizerDrawer::draw_legend(stdout, &self.get_legend_items(), width, height, 5);
-
-        // Statistics
-        let stats = self.get_statistics_strings();
-        VisualizerDrawer::draw_statistics(stdout, &stats, width, height);
-
-        // Controls
-        VisualizerDrawer::draw_controls(stdout, self.get_status(), self.get_controls_text(), width, height);
-
-        // Current operation
-        if self.state.awaiting_question.is_none() {
-            let operation = self.get_current_operation();
-            let color = if self.state.completed { Color::Green } else { Color::White };
-            VisualizerDrawer::draw_operation_info(stdout, &operation, width, height, color);
-        }
-
-        // Question
-        if let Some(q_index) = self.state.awaiting_question {
-            if let Some(question) = self.state.questions.get(q_index) {
-                VisualizerDrawer::draw_question(stdout, question, width, height);
-            }
-        }
-
-        stdout.flush().unwrap();
-    }
-
-    /// Perform a pancake flip from 0 to flip_pos
+    /// Perform a pancake flip from 0 to flip_pos, reversing the prefix exchanges its outer pairs
     fn flip_prefix(&mut self, flip_pos: usize) {
-        let mut temp = self.array[0..=flip_pos].to_vec();
-        temp.reverse();
-        self.array.splice(0..=flip_pos, temp);
+        self.array[0..=flip_pos].reverse();
 
         // Update states for flipped elements
         for i in 0..=flip_pos {
             self.states[i] = SelectionState::Swapping;
         }
-        self.state.swaps += 1;
+        let length = flip_pos as u32 + 1;
+        self.flips += 1;
+        self.state.swaps += length / 2;
     }
 }
 
-impl SortVisualizer for PancakeSortVisualizer {
-    fn get_array(&self) -> &[u32] { &self.array }
-    fn get_original_array(&self) -> &[u32] { &self.original_array }
+impl<T: SortElement> SortVisualizer<T> for PancakeSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
     fn get_states(&self) -> &[SelectionState] { &self.states }
     fn get_comparisons(&self) -> u32 { self.state.comparisons }
     fn get_swaps(&self) -> u32 { self.state.swaps }
@@ -265,6 +128,25 @@ impl SortVisualizer for PancakeSortVisualizer {
     fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
     fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
     fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "PancakeSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Pancake Sort",
+            best: "O(n^2)",
+            average: "O(n^2)",
+            worst: "O(n^2)",
+            space: "O(1)",
+            stable: false,
+            in_place: true,
+        }
+    }
+    // Each round flips the largest remaining value behind the unsorted part
+    fn sorted_regions(&self) -> Vec<Range<usize>> {
+        let finalized = if self.state.completed { 0..self.array.len() } else { self.unsorted_size..self.array.len() };
+        vec![finalized]
+    }
 
     fn get_progress(&self) -> f64 {
         let total = (self.array.len() * (self.array.len() - 1)) / 2;
@@ -294,21 +176,16 @@ impl SortVisualizer for PancakeSortVisualizer {
         match self.phase {
             PancakePhase::FindingMax => {
                 // Scan for max in unsorted portion
-                if self.max_pos < self.unsorted_size {
+                if self.scan_pos < self.unsorted_size {
                     self.states[self.max_pos] = SelectionState::Comparing;
-                    if self.max_pos + 1 < self.unsorted_size {
-                        self.states[self.max_pos + 1] = SelectionState::Comparing;
-                        self.state.comparisons += 1;
-                        if self.array[self.max_pos] < self.array[self.max_pos + 1] {
-                            self.max_pos += 1;
-                        }
-                    } else {
-                        self.max_pos += 1;
+                    self.states[self.scan_pos] = SelectionState::Comparing;
+                    if self.compare(self.scan_pos, self.max_pos).is_gt() {
+                        self.max_pos = self.scan_pos;
                     }
+                    self.scan_pos += 1;
                     return true;
                 } else {
                     // Max found, prepare to flip to front
-                    self.max_pos -= 1; // Adjust for 0-based
                     if self.max_pos != self.unsorted_size - 1 {
                         self.phase = PancakePhase::FlippingToFront;
                         self.flip_pos = self.max_pos;
@@ -317,6 +194,7 @@ impl SortVisualizer for PancakeSortVisualizer {
                         self.unsorted_size -= 1;
                         self.states[self.unsorted_size] = SelectionState::Sorted;
                         self.max_pos = 0;
+                        self.scan_pos = 1;
                         self.phase = PancakePhase::FindingMax;
 
                         // Teaching: Ask question after placing a max
@@ -342,6 +220,7 @@ impl SortVisualizer for PancakeSortVisualizer {
                 self.unsorted_size -= 1;
                 self.states[self.unsorted_size] = SelectionState::Sorted;
                 self.max_pos = 0;
+                self.scan_pos = 1;
                 self.phase = PancakePhase::FindingMax;
 
                 // Teaching: Ask question after placing a max
@@ -362,7 +241,9 @@ impl SortVisualizer for PancakeSortVisualizer {
         self.states = vec![SelectionState::Normal; len];
         self.unsorted_size = len;
         self.max_pos = 0;
+        self.scan_pos = 1;
         self.flip_pos = 0;
+        self.flips = 0;
         self.phase = PancakePhase::FindingMax;
         self.state.reset_state();
         if len <= 1 {
@@ -391,12 +272,12 @@ impl SortVisualizer for PancakeSortVisualizer {
          Press any key to continue..."
     }
 
-    fn get_legend_items(&self) -> Vec<(&str, Color)> {
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
         vec![
-            ("Normal", Color::Cyan),
-            ("Comparing", Color::Yellow),
-            ("Swapping", Color::Red),
-            ("Sorted", Color::Green),
+            ("Normal", SelectionState::Normal),
+            ("Comparing", SelectionState::Comparing),
+            ("Swapping", SelectionState::Swapping),
+            ("Sorted", SelectionState::Sorted),
         ]
     }
 
@@ -412,7 +293,8 @@ impl SortVisualizer for PancakeSortVisualizer {
             format!("Array Size: {}", self.array.len()),
             format!("Unsorted Size: {}", self.unsorted_size),
             format!("Comparisons: {}", self.state.comparisons),
-            format!("Flips: {}", self.state.swaps),
+            format!("Flips: {}", self.flips),
+            format!("Swaps: {}", self.state.swaps),
             format!("Max Pos: {}", self.max_pos),
             format!("Phase: {}", phase_str),
             format!("Progress: {:.1}%", self.get_progress()),
@@ -420,6 +302,19 @@ impl SortVisualizer for PancakeSortVisualizer {
         ]
     }
 
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        let hint = match self.phase {
+            PancakePhase::FindingMax => "The largest unsorted value is searched because two flips can move it to the end of the unsorted part",
+            PancakePhase::FlippingToFront => "Flipping the prefix up to the maximum brings it to the front, the only place a flip can move it from",
+            PancakePhase::FlippingToEnd => "Flipping the whole unsorted part moves the maximum from the front to its final place at the end",
+            _ => return None,
+        };
+        Some(hint)
+    }
+
     fn get_current_operation(&self) -> String {
         if self.state.completed {
             "✓ Array is now sorted using Pancake Sort! Congratulations!".to_string()
@@ -467,7 +362,7 @@ impl SortVisualizer for PancakeSortVisualizer {
 }
 
 /// Entry point for the pancake sort visualization
-pub fn pancake_sort_visualization(array_data: &ArrayData) {
-    let mut visualizer = PancakeSortVisualizer::new(array_data);
-    visualizer.run_visualization();
+pub fn pancake_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(PancakeSortVisualizer::with_settings(array_data, &settings), settings, last_run)
 }
\ No newline at end of file
@@ -0,0 +1,360 @@
+use crate::common::algorithm_info::AlgorithmInfo;
+use crate::common::array_manager::ArrayData;
+use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
+use crate::common::common_visualizer::VisualizerDrawer;
+use crate::common::element::SortElement;
+use crate::common::enums::{SelectionState, TeachingQuestion};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
+use crate::common::settings::Settings;
+use std::time::Duration;
+
+/// Represents the different phases of the patience sort algorithm
+#[derive(Clone, Copy, PartialEq)]
+pub enum PatiencePhase {
+    Dealing,    // Dealing each element onto the leftmost pile whose top is not smaller
+    Merging,    // Repeatedly taking the smallest pile top back into the array
+    Done,       // Sorting is complete
+}
+
+/// Visualizes the patience sort algorithm step-by-step with interactive controls
+#[derive(Clone)]
+pub struct PatienceSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array being sorted
+    original_array: Vec<T>,  // Original array, used for resetting
+    states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, on a pile, sorted)
+
+    // Patience Sort specific fields
+    piles: Vec<Vec<T>>,      // Piles like in solitaire, the last element is the visible top
+    deal_index: usize,         // Next array index to deal onto a pile
+    merge_index: usize,        // Next array index to fill while merging
+    active_pile: Option<usize>, // Pile touched by the last step
+    max_piles: usize,          // Number of piles after dealing (= longest increasing subsequence)
+    phase: PatiencePhase,      // Current phase of the patience sort algorithm
+    state: VisualizerState<T>,    // Common visualization state
+}
+
+impl<T: SortElement> PatienceSortVisualizer<T> {
+    /// Creates a new PatienceSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
+        let array = array_data.data.clone();
+        let len = array.len();
+
+        let questions = vec![
+            TeachingQuestion {
+                text: "What does the number of piles tell you after dealing?".to_string(),
+                options: vec![
+                    "The length of the longest increasing subsequence".to_string(),
+                    "The number of duplicate values".to_string(),
+                    "The number of inversions".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "Each pile is decreasing, so an increasing subsequence uses at most one card per pile, and the dealing rule guarantees one exists that touches every pile.".to_string(),
+            },
+            TeachingQuestion {
+                text: "Where is a new card placed while dealing?".to_string(),
+                options: vec![
+                    "On the leftmost pile whose top is greater than or equal to it".to_string(),
+                    "Always on a new pile".to_string(),
+                    "On the pile with the fewest cards".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "Using the leftmost valid pile keeps every pile sorted from bottom to top and keeps the pile tops increasing from left to right.".to_string(),
+            },
+            TeachingQuestion {
+                text: "How are the piles turned back into a sorted array?".to_string(),
+                options: vec![
+                    "By repeatedly taking the smallest visible top card (a k-way merge)".to_string(),
+                    "By concatenating the piles left to right".to_string(),
+                    "By sorting each pile again".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "Every pile top is that pile's minimum, so the smallest top is the smallest remaining element overall.".to_string(),
+            },
+        ];
+
+        randomize_questions(questions.clone());
+
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("PatienceSort")));
+        state.teaching_mode = settings.teaching_mode;
+
+        let mut this = Self {
+            original_array: array.clone(),
+            array,
+            states: vec![SelectionState::Normal; len],
+            piles: Vec::new(),
+            deal_index: 0,
+            merge_index: 0,
+            active_pile: None,
+            max_piles: 0,
+            phase: PatiencePhase::Dealing,
+            state,
+        };
+
+        if len <= 1 {
+            this.state.mark_completed();
+            this.mark_all_sorted();
+        }
+
+        this
+    }
+}
+
+impl<T: SortElement> SortVisualizer<T> for PatienceSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
+    fn get_states(&self) -> &[SelectionState] { &self.states }
+    fn get_comparisons(&self) -> u32 { self.state.comparisons }
+    fn get_swaps(&self) -> u32 { self.state.swaps }
+    fn get_speed(&self) -> Duration { self.state.speed }
+    fn is_running(&self) -> bool { self.state.is_running }
+    fn is_paused(&self) -> bool { self.state.is_paused }
+    fn is_completed(&self) -> bool { self.state.completed }
+    fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
+    fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
+    fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "PatienceSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Patience Sort",
+            best: "O(n)",
+            average: "O(n log n)",
+            worst: "O(n^2)",
+            space: "O(n)",
+            stable: false,
+            in_place: false,
+        }
+    }
+    // The piles are merged back over the input, the values not yet overwritten are stale
+    fn verifiable(&self) -> bool { false }
+
+    fn draw_extra(&self, drawer: &mut VisualizerDrawer, width: u16, height: u16) {
+        let format = self.state.value_format;
+        let piles: Vec<Vec<String>> = self.piles
+            .iter()
+            .map(|pile| pile.iter().map(|&value| value.format(format)).collect())
+            .collect();
+        drawer.draw_buckets("Pile", &piles, self.active_pile, width, height, 5);
+    }
+
+    fn get_progress(&self) -> f64 {
+        // Every element is dealt once and merged once
+        let total = self.array.len() * 2;
+        if total == 0 { 100.0 } else {
+            ((self.deal_index + self.merge_index) as f64 / total as f64 * 100.0).min(100.0)
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if self.state.completed || self.state.awaiting_question.is_some() {
+            return true;
+        }
+
+        // Reset states except sorted and dealt elements
+        for state in &mut self.states {
+            if *state != SelectionState::Sorted && *state != SelectionState::PartitionLeft {
+                *state = SelectionState::Normal;
+            }
+        }
+
+        let n = self.array.len();
+
+        match self.phase {
+            PatiencePhase::Dealing => {
+                if self.deal_index < n {
+                    let value = self.array[self.deal_index];
+
+                    // Find the leftmost pile whose top is not smaller than the card
+                    let mut target = self.piles.len();
+                    for (p, pile) in self.piles.iter().enumerate() {
+                        if self.state.compare_values(*pile.last().unwrap(), value).is_ge() {
+                            target = p;
+                            break;
+                        }
+                    }
+                    if target == self.piles.len() {
+                        self.piles.push(Vec::new());
+                    }
+                    self.piles[target].push(value);
+                    self.state.moves += 1;
+                    self.active_pile = Some(target);
+                    self.max_piles = self.piles.len();
+
+                    self.states[self.deal_index] = SelectionState::PartitionLeft;
+                    self.deal_index += 1;
+                    if self.deal_index < n {
+                        self.states[self.deal_index] = SelectionState::Comparing;
+                    }
+                } else {
+                    // All cards are dealt, start merging the piles
+                    self.phase = PatiencePhase::Merging;
+                    self.active_pile = None;
+                    self.state.ask_question(self.max_piles);
+                }
+            },
+            PatiencePhase::Merging => {
+                if self.merge_index >= n {
+                    self.phase = PatiencePhase::Done;
+                    return false;
+                }
+
+                // Pick the pile with the smallest visible top
+                let mut best = 0;
+                for p in 1..self.piles.len() {
+                    if self.state.compare_values(*self.piles[p].last().unwrap(), *self.piles[best].last().unwrap()).is_lt() {
+                        best = p;
+                    }
+                }
+                let value = self.piles[best].pop().unwrap();
+                self.array[self.merge_index] = value;
+                self.state.moves += 1;
+                self.states[self.merge_index] = SelectionState::Sorted;
+                self.active_pile = Some(best);
+                if self.piles[best].is_empty() {
+                    self.piles.remove(best);
+                    self.active_pile = None;
+                }
+                self.merge_index += 1;
+            },
+            PatiencePhase::Done => return false,
+        }
+
+        true
+    }
+
+    fn reset(&mut self) {
+        self.array = self.original_array.clone();
+        self.states = vec![SelectionState::Normal; self.array.len()];
+        self.piles.clear();
+        self.deal_index = 0;
+        self.merge_index = 0;
+        self.active_pile = None;
+        self.max_piles = 0;
+        self.phase = PatiencePhase::Dealing;
+        self.state.reset_state();
+        if self.array.len() <= 1 {
+            self.state.mark_completed();
+            self.mark_all_sorted();
+        }
+    }
+
+    fn mark_all_sorted(&mut self) {
+        for state in &mut self.states {
+            *state = SelectionState::Sorted;
+        }
+    }
+
+    fn get_title(&self) -> &str {
+        "TOGISOFT PATIENCE SORT VISUALIZER"
+    }
+
+    fn get_intro_text(&self) -> &str {
+        "What is Patience Sort?\n\n\
+         Patience Sort is named after the solitaire card game. Each element is dealt onto the leftmost pile\n\
+         whose top card is not smaller, or onto a new pile. Every pile stays sorted with its smallest card on top.\n\
+         The piles are then merged by repeatedly taking the smallest visible top card.\n\n\
+         Insight: the number of piles equals the length of the longest increasing subsequence.\n\n\
+         Advantages: O(n log n) with binary search over piles, adapts to presorted data.\n\
+         Disadvantages: Needs O(n) extra space for the piles.\n\n\
+         Teaching Mode: ON (Toggle with T). A question will be asked once dealing finishes.\n\n\
+         Press any key to continue..."
+    }
+
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
+        vec![
+            ("Normal", SelectionState::Normal),
+            ("Next Card", SelectionState::Comparing),
+            ("On a Pile", SelectionState::PartitionLeft),
+            ("Merged", SelectionState::Sorted),
+        ]
+    }
+
+    fn get_statistics_strings(&self) -> Vec<String> {
+        let phase_str = match self.phase {
+            PatiencePhase::Dealing => "Dealing",
+            PatiencePhase::Merging => "Merging",
+            PatiencePhase::Done => "Done",
+        };
+
+        vec![
+            format!("Array Size: {}", self.array.len()),
+            format!("Comparisons: {}", self.state.comparisons),
+            format!("Moves: {}", self.state.moves),
+            format!("Piles: {}", self.piles.len()),
+            format!("LIS Length: {}", self.max_piles),
+            format!("Phase: {}", phase_str),
+            format!("Progress: {:.1}%", self.get_progress()),
+            if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
+        ]
+    }
+
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        let hint = match self.phase {
+            PatiencePhase::Dealing => "Each value goes on the leftmost pile whose top is not smaller, so every pile stays sorted from top to bottom",
+            PatiencePhase::Merging => "Every pile is sorted, so the smallest remaining value is always one of the pile tops",
+            _ => return None,
+        };
+        Some(hint)
+    }
+
+    fn get_current_operation(&self) -> String {
+        if self.state.completed {
+            format!("✓ Array is now sorted using Patience Sort ({} piles)! Congratulations!", self.max_piles)
+        } else {
+            match self.phase {
+                PatiencePhase::Dealing => {
+                    if self.deal_index < self.array.len() {
+                        format!("Dealing array[{}] ({}) onto the leftmost pile with a top >= {}",
+                                self.deal_index,
+                                self.state.value_text(self.array[self.deal_index]),
+                                self.state.value_text(self.array[self.deal_index]))
+                    } else {
+                        format!("All cards dealt into {} piles, ready to merge", self.piles.len())
+                    }
+                },
+                PatiencePhase::Merging => {
+                    format!("Merging: taking the smallest pile top into array[{}]", self.merge_index)
+                },
+                PatiencePhase::Done => {
+                    "Patience sort completed!".to_string()
+                },
+            }
+        }
+    }
+
+    fn get_status(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "WAITING FOR QUESTION"
+        } else if self.state.completed {
+            "COMPLETED!"
+        } else if self.state.is_running && !self.state.is_paused {
+            "RUNNING..."
+        } else if self.state.is_paused {
+            "PAUSED"
+        } else {
+            "READY"
+        }
+    }
+
+    fn get_controls_text(&self) -> &str {
+        if self.state.awaiting_question.is_some() {
+            "1,2,3: Answer | ESC: Exit"
+        } else if self.state.completed {
+            "SPACE: Restart | R: Reset | T: Teaching Toggle | ESC: Exit"
+        } else {
+            "SPACE: Start/Pause | S: Step | R: Reset | T: Teaching | +/-: Speed | ESC: Exit"
+        }
+    }
+}
+
+/// Entry point for the patience sort visualization
+pub fn patience_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(PatienceSortVisualizer::with_settings(array_data, &settings), settings, last_run)
+}
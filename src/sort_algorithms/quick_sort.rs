@@ -1,16 +1,14 @@
+use crate::common::algorithm_info::AlgorithmInfo;
 use crate::common::array_manager::ArrayData;
 use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
-use crate::common::common_visualizer::{show_intro_screen, show_question_feedback, VisualizerDrawer};
+use crate::common::common_visualizer::VisualizerDrawer;
+use crate::common::element::SortElement;
 use crate::common::enums::{SelectionState, TeachingQuestion};
-use crate::common::helper::{cleanup_terminal, randomize_questions};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
 use crate::common::settings::Settings;
-use crossterm::{
-    event::{poll, read, Event, KeyCode, KeyEventKind},
-    style::Color,
-    terminal::{enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen},
-    ExecutableCommand,
-};
-use std::io::{stdout, Write};
+use crossterm::style::Color;
+use std::cmp::Ordering;
 use std::time::Duration;
 
 /// Represents the different phases of the quick sort algorithm
@@ -22,12 +20,14 @@ pub enum QuickPhase {
     SwappingElements,   // Swapping elements at left and right pointers
     SwappingWithPivot,  // Swapping the pivot with its final position
     DonePartition,      // Partitioning is complete
+    InsertionSorting,   // A range at or below the cutoff is insertion sorted instead of partitioned
 }
 
 /// Visualizes the quick sort algorithm step-by-step with interactive controls
-pub struct QuickSortVisualizer {
-    array: Vec<u32>,           // Current state of the array being sorted
-    original_array: Vec<u32>,  // Original array, used for resetting
+#[derive(Clone)]
+pub struct QuickSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array being sorted
+    original_array: Vec<T>,  // Original array, used for resetting
     states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, swapping, sorted)
     intro_text: String,        // Dynamic intro text
 
@@ -40,13 +40,16 @@ pub struct QuickSortVisualizer {
     right: usize,              // Right pointer for partitioning
     phase: QuickPhase,         // Current phase of the quick sort algorithm
     partition_count: usize,    // Number of partitions performed (for teaching questions)
-    state: VisualizerState,    // Common visualization state
+    cutoff: usize,             // Ranges of this many elements or fewer are insertion sorted, 0 always partitions
+    insertion_next: usize,     // Next element the insertion sort of a small range takes as its key
+    insertion_pos: usize,      // Where that key currently sits while it moves left
+    insertion_ranges: usize,   // Small ranges insertion sorted so far
+    state: VisualizerState<T>,    // Common visualization state
 }
 
-impl QuickSortVisualizer {
-    /// Creates a new QuickSortVisualizer with the given array
-    pub fn new(array_data: &ArrayData) -> Self {
-        let settings = Settings::load();
+impl<T: SortElement> QuickSortVisualizer<T> {
+    /// Creates a new QuickSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
         let array = array_data.data.clone();
         let len = array.len();
 
@@ -85,7 +88,7 @@ impl QuickSortVisualizer {
 
         randomize_questions(questions.clone());
 
-        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed));
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("QuickSort")));
         state.teaching_mode = settings.teaching_mode;
 
         let intro_text = format!(
@@ -112,6 +115,10 @@ impl QuickSortVisualizer {
             right: 0,
             phase: QuickPhase::DonePartition,
             partition_count: 0,
+            cutoff: settings.quick_sort_cutoff as usize,
+            insertion_next: 0,
+            insertion_pos: 0,
+            insertion_ranges: 0,
             state,
         };
 
@@ -124,11 +131,6 @@ impl QuickSortVisualizer {
             this.states[0] = SelectionState::Sorted;
         }
 
-        // Set last visualizer
-        let mut settings = Settings::load();
-        settings.last_visualizer = Some("QuickSort".to_string());
-        settings.save();
-
         if len <= 1 {
             this.state.mark_completed();
         }
@@ -136,153 +138,18 @@ impl QuickSortVisualizer {
         this
     }
 
-    /// Main loop: handles rendering, input, and stepping through the sort
-    pub fn run_visualization(&mut self) {
-        let mut stdout = stdout();
-        enable_raw_mode().unwrap();
-        stdout.execute(EnterAlternateScreen).unwrap();
-
-        show_intro_screen(self.get_intro_text());
-
-        loop {
-            self.draw(&mut stdout);
-
-            if poll(Duration::from_millis(50)).unwrap_or(false) {
-                match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
-                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                        // Handle question
-                        if let Some(q_index) = self.state.awaiting_question {
-                            match key_event.code {
-                                KeyCode::Char('1') => self.handle_question_answer(q_index, 0),
-                                KeyCode::Char('2') => self.handle_question_answer(q_index, 1),
-                                KeyCode::Char('3') => self.handle_question_answer(q_index, 2),
-                                _ => continue,
-                            }
-                            continue;
-                        }
-
-                        match key_event.code {
-                            KeyCode::Char(' ') => {
-                                if self.state.completed {
-                                    self.reset();
-                                } else {
-                                    self.state.toggle_play_pause();
-                                }
-                            },
-                            KeyCode::Char('r') | KeyCode::Char('R') => {
-                                self.reset();
-                            },
-                            KeyCode::Char('s') | KeyCode::Char('S') => {
-                                if !self.state.completed && !self.state.is_running {
-                                    if !self.step() {
-                                        self.state.mark_completed();
-                                        self.mark_all_sorted();
-                                    }
-                                }
-                            },
-                            KeyCode::Char('t') | KeyCode::Char('T') => {
-                                self.state.toggle_teaching_mode();
-                                self.intro_text = format!(
-                                    "What is Quick Sort?\n\n\
-                                     Quick Sort is a divide-and-conquer algorithm that selects a 'pivot' element and partitions the array around it.\n\
-                                     Elements smaller than the pivot go to the left, larger to the right, then recurse on subarrays.\n\n\
-                                     Advantages: Fast average O(n log n), in-place.\n\
-                                     Disadvantages: Worst case O(n^2) if poor pivots.\n\n\
-                                     Teaching Mode: {} (Toggle with T). Questions will be asked after each partition.\n\n\
-                                     Press any key to continue...",
-                                    if self.state.teaching_mode { "ON" } else { "OFF" }
-                                );
-                                let mut settings = Settings::load();
-                                settings.teaching_mode = self.state.teaching_mode;
-                                settings.save();
-                            },
-                            KeyCode::Char('+') => {
-                                self.state.increase_speed(50);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Char('-') => {
-                                self.state.decrease_speed(2000);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Esc => {
-                                let mut settings = Settings::load();
-                                settings.last_visualizer = Some("QuickSort".to_string());
-                                settings.save();
-                                cleanup_terminal();
-                                return;
-                            },
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
-            }
-
-            // Auto step
-            if self.state.is_running && !self.state.is_paused && !self.state.completed
-                && self.state.awaiting_question.is_none() {
-                std::thread::sleep(self.state.speed);
-                if !self.step() {
-                    self.state.mark_completed();
-                    self.mark_all_sorted();
-                }
-            }
-        }
-    }
-
-    fn handle_question_answer(&mut self, q_index: usize, answer: usize) {
-        if let Some(question) = self.state.questions.get(q_index) {
-            let correct = answer == question.correct_index;
-            show_question_feedback(correct, question, answer);
-            self.state.clear_question();
-        }
-    }
-
-    fn draw(&mut self, stdout: &mut std::io::Stdout) {
-        let (width, height) = size().unwrap();
-        stdout.execute(Clear(ClearType::All)).unwrap();
-
-        // Title
-        VisualizerDrawer::draw_title(stdout, self.get_title());
-
-        // Array
-        VisualizerDrawer::draw_array_bars(stdout, &self.array, &self.states, width, height, 5);
-
-        // Legend
-        VisualizerDrawer::draw_legend(stdout, &self.get_legend_items(), width, height, 5);
-
-        // Statistics
-        let stats = self.get_statistics_strings();
-        VisualizerDrawer::draw_statistics(stdout, &stats, width, height);
-
-        // Controls
-        VisualizerDrawer::draw_controls(stdout, self.get_status(), self.get_controls_text(), width, height);
-
-        // Current operation
-        if self.state.awaiting_question.is_none() {
-            let operation = self.get_current_operation();
-            let color = if self.state.completed { Color::Green } else { Color::White };
-            VisualizerDrawer::draw_operation_info(stdout, &operation, width, height, color);
-        }
-
-        // Question
-        if let Some(q_index) = self.state.awaiting_question {
-            if let Some(question) = self.state.questions.get(q_index) {
-                VisualizerDrawer::draw_question(stdout, question, width, height);
-            }
+    // Highlights the range insertion sort works on
+    fn mark_insertion_range(&mut self) {
+        for state in &mut self.states[self.low..=self.high] {
+            *state = SelectionState::Selected;
         }
-
-        stdout.flush().unwrap();
     }
 }
 
-impl SortVisualizer for QuickSortVisualizer {
-    fn get_array(&self) -> &[u32] { &self.array }
-    fn get_original_array(&self) -> &[u32] { &self.original_array }
+impl<T: SortElement> SortVisualizer<T> for QuickSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
     fn get_states(&self) -> &[SelectionState] { &self.states }
     fn get_comparisons(&self) -> u32 { self.state.comparisons }
     fn get_swaps(&self) -> u32 { self.state.swaps }
@@ -293,6 +160,34 @@ impl SortVisualizer for QuickSortVisualizer {
     fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
     fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
     fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "QuickSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Quick Sort",
+            best: "O(n log n)",
+            average: "O(n log n)",
+            worst: "O(n^2)",
+            space: "O(log n)",
+            stable: false,
+            in_place: true,
+        }
+    }
+
+    fn on_teaching_mode_changed(&mut self) {
+        // Keep the intro text in sync with the teaching mode
+        self.intro_text = format!(
+            "What is Quick Sort?\n\n\
+             Quick Sort is a divide-and-conquer algorithm that selects a 'pivot' element and partitions the array around it.\n\
+             Elements smaller than the pivot go to the left, larger to the right, then recurse on subarrays.\n\n\
+             Advantages: Fast average O(n log n), in-place.\n\
+             Disadvantages: Worst case O(n^2) if poor pivots.\n\n\
+             Teaching Mode: {} (Toggle with T). Questions will be asked after each partition.\n\n\
+             Press any key to continue...",
+            if self.state.teaching_mode { "ON" } else { "OFF" }
+        );
+    }
 
     fn get_progress(&self) -> f64 {
         if self.array.len() <= 1 {
@@ -331,6 +226,15 @@ impl SortVisualizer for QuickSortVisualizer {
                         return self.step(); // Move to next range
                     }
 
+                    // A small range is cheaper to insertion sort than to partition further
+                    if self.high - self.low < self.cutoff {
+                        self.insertion_next = self.low + 1;
+                        self.insertion_pos = self.low + 1;
+                        self.mark_insertion_range();
+                        self.phase = QuickPhase::InsertionSorting;
+                        return true;
+                    }
+
                     // Choose pivot (last element)
                     self.pivot_index = self.high;
                     self.states[self.pivot_index] = SelectionState::CurrentMin;
@@ -349,10 +253,9 @@ impl SortVisualizer for QuickSortVisualizer {
             QuickPhase::PartitioningLeft => {
                 if self.left <= self.right {
                     self.states[self.left] = SelectionState::PartitionLeft;
-                    self.state.comparisons += 1;
 
                     // Move left pointer if element is less than or equal to pivot
-                    if self.array[self.left] <= self.array[self.pivot_index] {
+                    if self.compare(self.left, self.pivot_index).is_le() {
                         self.left += 1;
                     } else {
                         // Element is greater than pivot, move to right pointer
@@ -367,11 +270,15 @@ impl SortVisualizer for QuickSortVisualizer {
             QuickPhase::PartitioningRight => {
                 if self.left <= self.right {
                     self.states[self.right] = SelectionState::PartitionRight;
-                    self.state.comparisons += 1;
 
                     // Move right pointer if element is greater than pivot
-                    if self.array[self.right] > self.array[self.pivot_index] {
-                        self.right = if self.right > 0 { self.right - 1 } else { 0 };
+                    if self.compare(self.right, self.pivot_index).is_gt() {
+                        if self.right == 0 {
+                            // Both pointers are at index 0, the whole range is above the pivot
+                            self.phase = QuickPhase::SwappingWithPivot;
+                        } else {
+                            self.right -= 1;
+                        }
                     } else {
                         // Element is less than or equal to pivot, swap with left
                         self.phase = QuickPhase::SwappingElements;
@@ -424,20 +331,45 @@ impl SortVisualizer for QuickSortVisualizer {
                 }
 
                 self.partition_count += 1;
+                self.phase = QuickPhase::ChoosingPivot;
                 // Teaching: Ask question after each partition
                 if self.state.teaching_mode && !self.state.questions.is_empty() {
                     let q_index = self.partition_count % self.state.questions.len();
                     self.state.ask_question(q_index);
-                    return true;
                 }
-
-                self.phase = QuickPhase::ChoosingPivot;
                 true
             },
             QuickPhase::DonePartition => {
                 self.phase = QuickPhase::ChoosingPivot;
                 true
             },
+            QuickPhase::InsertionSorting => {
+                if self.insertion_next > self.high {
+                    // Every key is placed, the whole range is in its final position
+                    for state in &mut self.states[self.low..=self.high] {
+                        *state = SelectionState::Sorted;
+                    }
+                    self.insertion_ranges += 1;
+                    self.phase = QuickPhase::ChoosingPivot;
+                    return true;
+                }
+                self.mark_insertion_range();
+                let pos = self.insertion_pos;
+                if pos > self.low && self.compare(pos - 1, pos).is_gt() {
+                    // The key is smaller than its left neighbour and moves one place left
+                    self.states[pos - 1] = SelectionState::Swapping;
+                    self.states[pos] = SelectionState::Swapping;
+                    self.array.swap(pos - 1, pos);
+                    self.state.swaps += 1;
+                    self.insertion_pos -= 1;
+                } else {
+                    // The key is in place, the next one follows
+                    self.states[pos] = SelectionState::Comparing;
+                    self.insertion_next += 1;
+                    self.insertion_pos = self.insertion_next;
+                }
+                true
+            },
         }
     }
 
@@ -452,6 +384,9 @@ impl SortVisualizer for QuickSortVisualizer {
         self.left = 0;
         self.right = 0;
         self.partition_count = 0;
+        self.insertion_next = 0;
+        self.insertion_pos = 0;
+        self.insertion_ranges = 0;
         self.phase = QuickPhase::DonePartition;
         self.state.reset_state();
         self.intro_text = format!(
@@ -494,15 +429,16 @@ impl SortVisualizer for QuickSortVisualizer {
         &self.intro_text
     }
 
-    fn get_legend_items(&self) -> Vec<(&str, Color)> {
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
         vec![
-            ("Normal", Color::Cyan),
-            ("Pivot", Color::Yellow),
-            ("Comparing", Color::Magenta),
-            ("Left Ptr", Color::Blue),
-            ("Right Ptr", Color::AnsiValue(208)),
-            ("Swapping", Color::Red),
-            ("Sorted", Color::Green),
+            ("Normal", SelectionState::Normal),
+            ("Pivot", SelectionState::CurrentMin),
+            ("Comparing", SelectionState::Comparing),
+            ("Left Ptr", SelectionState::PartitionLeft),
+            ("Right Ptr", SelectionState::PartitionRight),
+            ("Swapping", SelectionState::Swapping),
+            ("Insertion Range", SelectionState::Selected),
+            ("Sorted", SelectionState::Sorted),
         ]
     }
 
@@ -513,11 +449,77 @@ impl SortVisualizer for QuickSortVisualizer {
             format!("Swaps: {}", self.state.swaps),
             format!("Stack Size: {}", self.stack.len()),
             format!("Partitions: {}", self.partition_count),
+            if self.cutoff == 0 {
+                "Insertion Cutoff: OFF".to_string()
+            } else {
+                format!("Insertion Cutoff: {} (ranges insertion sorted: {})", self.cutoff, self.insertion_ranges)
+            },
             format!("Progress: {:.1}%", self.get_progress()),
             if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
         ]
     }
 
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        let hint = match self.phase {
+            QuickPhase::ChoosingPivot => "The pivot splits the range: smaller values go left and larger ones go right, then each side is sorted on its own",
+            QuickPhase::PartitioningLeft => "The left pointer skips values smaller than the pivot, they are already on the correct side",
+            QuickPhase::PartitioningRight => "The right pointer skips values larger than the pivot, they are already on the correct side",
+            QuickPhase::SwappingElements => "Both pointers stopped at a value on the wrong side, one swap fixes both",
+            QuickPhase::SwappingWithPivot => "The pointers crossed, so the pivot's final position is known and it is swapped there",
+            QuickPhase::DonePartition => "The pivot is in its final place, the ranges on either side are partitioned next",
+            QuickPhase::InsertionSorting => "On a few elements insertion sort does less work than more partitions, so hybrid sorts switch to it below a cutoff",
+        };
+        Some(hint)
+    }
+
+    fn insertion_cutoff(&self) -> Option<usize> {
+        Some(self.cutoff)
+    }
+
+    fn set_insertion_cutoff(&mut self, cutoff: usize) {
+        self.cutoff = cutoff;
+    }
+
+    fn draw_extra(&self, drawer: &mut VisualizerDrawer, width: u16, height: u16) {
+        let mut rows = vec![(
+            if self.cutoff == 0 {
+                "Insertion Cutoff: OFF, every range is partitioned ([ / ] to tune)".to_string()
+            } else {
+                format!("Insertion Cutoff: ranges of {} or fewer elements are insertion sorted ([ / ] to tune)", self.cutoff)
+            },
+            Color::Cyan,
+        )];
+        if self.phase == QuickPhase::InsertionSorting && !self.state.completed {
+            rows.push((
+                format!("Range [{}..{}] fell below the cutoff, insertion sort took over", self.low, self.high),
+                Color::Yellow,
+            ));
+        }
+        drawer.draw_text_rows(&rows, width, height, 5);
+    }
+
+    fn decision_changers(&self) -> Vec<usize> {
+        let pivot = self.pivot_index;
+        if self.state.completed || self.left > self.right || pivot >= self.array.len() {
+            return Vec::new();
+        }
+        let pivot_value = self.array[pivot];
+        match self.phase {
+            // The left pointer moves on over elements that do not order after the pivot
+            QuickPhase::PartitioningLeft => {
+                self.state.decision_changers(&self.array, self.left, pivot_value, &[self.left, pivot], Ordering::is_le)
+            }
+            // The right pointer moves on over elements that order after it
+            QuickPhase::PartitioningRight => {
+                self.state.decision_changers(&self.array, self.right, pivot_value, &[self.right, pivot], Ordering::is_gt)
+            }
+            _ => Vec::new(),
+        }
+    }
+
     fn get_current_operation(&self) -> String {
         if self.state.completed {
             "✓ Array is now sorted using Quick Sort! Congratulations!".to_string()
@@ -530,7 +532,7 @@ impl SortVisualizer for QuickSortVisualizer {
                 QuickPhase::PartitioningLeft => {
                     if self.left < self.array.len() && self.pivot_index < self.array.len() {
                         format!("Partition [{}..{}]: left={} ({}) <= pivot {}?",
-                                self.low, self.high, self.left, self.array[self.left], self.array[self.pivot_index])
+                                self.low, self.high, self.left, self.state.value_text(self.array[self.left]), self.state.value_text(self.array[self.pivot_index]))
                     } else {
                         format!("Partition [{}..{}]: Moving left pointer", self.low, self.high)
                     }
@@ -538,7 +540,7 @@ impl SortVisualizer for QuickSortVisualizer {
                 QuickPhase::PartitioningRight => {
                     if self.right < self.array.len() && self.pivot_index < self.array.len() {
                         format!("Partition [{}..{}]: right={} ({}) > pivot {}?",
-                                self.low, self.high, self.right, self.array[self.right], self.array[self.pivot_index])
+                                self.low, self.high, self.right, self.state.value_text(self.array[self.right]), self.state.value_text(self.array[self.pivot_index]))
                     } else {
                         format!("Partition [{}..{}]: Moving right pointer", self.low, self.high)
                     }
@@ -546,7 +548,7 @@ impl SortVisualizer for QuickSortVisualizer {
                 QuickPhase::SwappingElements => {
                     if self.left < self.array.len() && self.right < self.array.len() {
                         format!("Swapping left={} ({}) with right={} ({})",
-                                self.left, self.array[self.left], self.right, self.array[self.right])
+                                self.left, self.state.value_text(self.array[self.left]), self.right, self.state.value_text(self.array[self.right]))
                     } else {
                         "Swapping elements".to_string()
                     }
@@ -557,6 +559,10 @@ impl SortVisualizer for QuickSortVisualizer {
                 QuickPhase::DonePartition => {
                     format!("Moving to next subarray")
                 },
+                QuickPhase::InsertionSorting => {
+                    format!("Range [{}..{}] has {} elements, at most the cutoff {}: insertion sorting it, key at index {}",
+                            self.low, self.high, self.high - self.low + 1, self.cutoff, self.insertion_pos.min(self.high))
+                },
             }
         }
     }
@@ -587,7 +593,7 @@ impl SortVisualizer for QuickSortVisualizer {
 }
 
 /// Entry point for the quick sort visualization
-pub fn quick_sort_visualization(array_data: &ArrayData) {
-    let mut visualizer = QuickSortVisualizer::new(array_data);
-    visualizer.run_visualization();
+pub fn quick_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(QuickSortVisualizer::with_settings(array_data, &settings), settings, last_run)
 }
\ No newline at end of file
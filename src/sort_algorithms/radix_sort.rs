@@ -1,16 +1,11 @@
+use crate::common::algorithm_info::AlgorithmInfo;
 use crate::common::array_manager::ArrayData;
 use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
-use crate::common::common_visualizer::{show_intro_screen, show_question_feedback, VisualizerDrawer};
+use crate::common::element::IntegerElement;
 use crate::common::enums::{SelectionState, TeachingQuestion};
-use crate::common::helper::{cleanup_terminal, randomize_questions};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
 use crate::common::settings::Settings;
-use crossterm::{
-    event::{poll, read, Event, KeyCode, KeyEventKind},
-    style::Color,
-    terminal::{enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen},
-    ExecutableCommand,
-};
-use std::io::{stdout, Write};
 use std::time::Duration;
 
 /// Represents the different phases of the radix sort algorithm
@@ -26,48 +21,104 @@ pub enum RadixPhase {
 }
 
 /// Visualizes the radix sort algorithm step-by-step with interactive controls
-pub struct RadixSortVisualizer {
-    array: Vec<u32>,           // Current state of the array being sorted
-    original_array: Vec<u32>,  // Original array, used for resetting
-    temp_array: Vec<u32>,      // Temporary array used during sorting
+#[derive(Clone)]
+pub struct RadixSortVisualizer<T: IntegerElement = u32> {
+    array: Vec<T>,             // Current state of the array being sorted
+    original_array: Vec<T>,    // Original array, used for resetting
+    temp_array: Vec<T>,        // Temporary array used during sorting
     states: Vec<SelectionState>, // Visual state of each element
     intro_text: String,        // Dynamic intro text
 
     // Radix Sort specific fields
     current_digit: u32,       // Current digit position being processed (1=ones, 2=tens, etc.)
-    max_digits: u32,          // Maximum number of digits in any number
+    max_digits: u32,          // Digit passes shown, the real ones plus any padding
+    real_digits: u32,         // Digit passes the values actually need
+    padded_digits: u32,       // Radix padding setting, passes shown at least (0 is off)
     radix: u32,               // Base (usually 10 for decimal numbers)
     count: Vec<u32>,          // Count array for digits 0-9
     current_index: usize,     // Current index being processed
-    current_element: u32,     // Current element being processed
+    current_element: T,       // Current element being processed
     current_digit_value: u32, // Current digit value being processed
     phase: RadixPhase,        // Current phase of the radix sort algorithm
-    state: VisualizerState,   // Common visualization state
+    base: T,                  // Digits are read from each value's distance to this, zero unless a value is below it
+    state: VisualizerState<T>, // Common visualization state
 }
 
-impl RadixSortVisualizer {
-    /// Counts the number of digits in a number
-    fn count_digits(mut num: u32) -> u32 {
+impl<T: IntegerElement> RadixSortVisualizer<T> {
+    /// Counts the number of digits in a number for the given base
+    fn count_digits(mut num: u32, radix: u32) -> u32 {
         if num == 0 { return 1; }
         let mut digits = 0;
         while num > 0 {
             digits += 1;
-            num /= 10;
+            num /= radix;
         }
         digits
     }
 
+    /// Builds the intro text, mentioning the illustrative passes when padding is on
+    fn build_intro_text(teaching_mode: bool, padded_digits: u32) -> String {
+        let padding_note = if padded_digits > 0 {
+            format!(
+                "Radix Padding: at least {} digit passes are shown. Passes beyond the largest value's digits\n\
+                 only find 0s and cannot change the order, they are for illustration only.\n\n",
+                padded_digits
+            )
+        } else {
+            String::new()
+        };
+        format!(
+            "What is Radix Sort?\n\n\
+             Radix Sort is a non-comparative integer sorting algorithm that sorts data by grouping keys by individual digits.\n\
+             It processes digits from least to most significant, using stable counting sort for each digit.\n\n\
+             Advantages: Linear time O(d(n+k)) for integers.\n\
+             Disadvantages: Only for integers or fixed-length keys.\n\
+             Negative values are shifted up by the smallest one first, digits are read from the shifted values.\n\n\
+             {}Teaching Mode: {} (Toggle with T). Questions will be asked after each digit pass.\n\n\
+             Press any key to continue...",
+            padding_note,
+            if teaching_mode { "ON" } else { "OFF" }
+        )
+    }
+
+    // Recounts the digits of the largest value, padding only ever adds passes
+    fn update_digit_counts(&mut self) {
+        self.base = self.array.iter().copied().min().unwrap_or_default().min(T::default());
+        let max_num = self.array.iter().copied().max().unwrap_or_default().offset_from(self.base);
+        self.real_digits = Self::count_digits(max_num, self.radix);
+        self.max_digits = self.real_digits.max(self.padded_digits);
+    }
+
+    // A pass above the largest value's digits, shown only because of radix padding
+    fn is_padding_pass(&self) -> bool {
+        self.current_digit > self.real_digits
+    }
+
     /// Gets the digit at a specific position in a number
-    fn get_digit(&self, number: u32, digit_position: u32) -> u32 {
+    fn get_digit(&self, number: T, digit_position: u32) -> u32 {
         if digit_position == 0 {
             return 0;
         }
-        (number / self.radix.pow(digit_position - 1)) % self.radix
+        match self.radix.checked_pow(digit_position - 1) {
+            Some(divisor) => (number.offset_from(self.base) / divisor) % self.radix,
+            // The place value no longer fits in u32, so every number has a 0 there
+            None => 0,
+        }
     }
 
-    /// Creates a new RadixSortVisualizer with the given array
-    pub fn new(array_data: &ArrayData) -> Self {
-        let settings = Settings::load();
+    /// The value as the operation text shows it, with the shifted value its digits are read from
+    /// when negative values moved the base below zero, e.g. "-7 (stored as 3)"
+    fn digits_text(&self, value: T) -> String {
+        let text = self.state.value_text(value);
+        if self.base == T::default() {
+            text
+        } else {
+            format!("{} (stored as {})", text, value.offset_from(self.base))
+        }
+    }
+
+    /// Creates a new RadixSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
         let array = array_data.data.clone();
         let len = array.len();
 
@@ -106,44 +157,31 @@ impl RadixSortVisualizer {
 
         randomize_questions(questions.clone());
 
-        let max_num = *array.iter().max().unwrap_or(&0);
-        let max_digits = if max_num == 0 { 1 } else { Self::count_digits(max_num) };
-
-        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed));
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("RadixSort")));
         state.teaching_mode = settings.teaching_mode;
 
-        let intro_text = format!(
-            "What is Radix Sort?\n\n\
-             Radix Sort is a non-comparative integer sorting algorithm that sorts data by grouping keys by individual digits.\n\
-             It processes digits from least to most significant, using stable counting sort for each digit.\n\n\
-             Advantages: Linear time O(d(n+k)) for integers.\n\
-             Disadvantages: Only for integers or fixed-length keys.\n\n\
-             Teaching Mode: {} (Toggle with T). Questions will be asked after each digit pass.\n\n\
-             Press any key to continue...",
-            if state.teaching_mode { "ON" } else { "OFF" }
-        );
+        let intro_text = Self::build_intro_text(state.teaching_mode, settings.radix_padding);
 
         let mut this = Self {
             original_array: array.clone(),
             array,
-            temp_array: vec![0; len],
+            temp_array: vec![T::default(); len],
             states: vec![SelectionState::Normal; len],
             intro_text,
             current_digit: 1,
-            max_digits,
+            max_digits: 0,
+            real_digits: 0,
+            padded_digits: settings.radix_padding,
             radix: 10,
             count: vec![0; 10],
             current_index: 0,
-            current_element: 0,
+            current_element: T::default(),
             current_digit_value: 0,
             phase: RadixPhase::StartingDigit,
+            base: T::default(),
             state,
         };
-
-        // Set last visualizer
-        let mut settings = Settings::load();
-        settings.last_visualizer = Some("RadixSort".to_string());
-        settings.save();
+        this.update_digit_counts();
 
         if len <= 1 {
             this.state.mark_completed();
@@ -154,154 +192,12 @@ impl RadixSortVisualizer {
 
         this
     }
-
-    /// Main loop: handles rendering, input, and stepping through the sort
-    pub fn run_visualization(&mut self) {
-        let mut stdout = stdout();
-        enable_raw_mode().unwrap();
-        stdout.execute(EnterAlternateScreen).unwrap();
-
-        show_intro_screen(self.get_intro_text());
-
-        loop {
-            self.draw(&mut stdout);
-
-            if poll(Duration::from_millis(50)).unwrap_or(false) {
-                match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
-                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                        // Handle question
-                        if let Some(q_index) = self.state.awaiting_question {
-                            match key_event.code {
-                                KeyCode::Char('1') => self.handle_question_answer(q_index, 0),
-                                KeyCode::Char('2') => self.handle_question_answer(q_index, 1),
-                                KeyCode::Char('3') => self.handle_question_answer(q_index, 2),
-                                _ => continue,
-                            }
-                            continue;
-                        }
-
-                        match key_event.code {
-                            KeyCode::Char(' ') => {
-                                if self.state.completed {
-                                    self.reset();
-                                } else {
-                                    self.state.toggle_play_pause();
-                                }
-                            },
-                            KeyCode::Char('r') | KeyCode::Char('R') => {
-                                self.reset();
-                            },
-                            KeyCode::Char('s') | KeyCode::Char('S') => {
-                                if !self.state.completed && !self.state.is_running {
-                                    if !self.step() {
-                                        self.state.mark_completed();
-                                        self.mark_all_sorted();
-                                    }
-                                }
-                            },
-                            KeyCode::Char('t') | KeyCode::Char('T') => {
-                                self.state.toggle_teaching_mode();
-                                self.intro_text = format!(
-                                    "What is Radix Sort?\n\n\
-                                     Radix Sort is a non-comparative integer sorting algorithm that sorts data by grouping keys by individual digits.\n\
-                                     It processes digits from least to most significant, using stable counting sort for each digit.\n\n\
-                                     Advantages: Linear time O(d(n+k)) for integers.\n\
-                                     Disadvantages: Only for integers or fixed-length keys.\n\n\
-                                     Teaching Mode: {} (Toggle with T). Questions will be asked after each digit pass.\n\n\
-                                     Press any key to continue...",
-                                    if self.state.teaching_mode { "ON" } else { "OFF" }
-                                );
-                                let mut settings = Settings::load();
-                                settings.teaching_mode = self.state.teaching_mode;
-                                settings.save();
-                            },
-                            KeyCode::Char('+') => {
-                                self.state.increase_speed(50);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Char('-') => {
-                                self.state.decrease_speed(2000);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Esc => {
-                                let mut settings = Settings::load();
-                                settings.last_visualizer = Some("RadixSort".to_string());
-                                settings.save();
-                                cleanup_terminal();
-                                return;
-                            },
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
-            }
-
-            // Auto step
-            if self.state.is_running && !self.state.is_paused && !self.state.completed
-                && self.state.awaiting_question.is_none() {
-                std::thread::sleep(self.state.speed);
-                if !self.step() {
-                    self.state.mark_completed();
-                    self.mark_all_sorted();
-                }
-            }
-        }
-    }
-
-    fn handle_question_answer(&mut self, q_index: usize, answer: usize) {
-        if let Some(question) = self.state.questions.get(q_index) {
-            let correct = answer == question.correct_index;
-            show_question_feedback(correct, question, answer);
-            self.state.clear_question();
-        }
-    }
-
-    fn draw(&mut self, stdout: &mut std::io::Stdout) {
-        let (width, height) = size().unwrap();
-        stdout.execute(Clear(ClearType::All)).unwrap();
-
-        // Title
-        VisualizerDrawer::draw_title(stdout, self.get_title());
-
-        // Array
-        VisualizerDrawer::draw_array_bars(stdout, &self.array, &self.states, width, height, 5);
-
-        // Legend
-        VisualizerDrawer::draw_legend(stdout, &self.get_legend_items(), width, height, 5);
-
-        // Statistics
-        let stats = self.get_statistics_strings();
-        VisualizerDrawer::draw_statistics(stdout, &stats, width, height);
-
-        // Controls
-        VisualizerDrawer::draw_controls(stdout, self.get_status(), self.get_controls_text(), width, height);
-
-        // Current operation
-        if self.state.awaiting_question.is_none() {
-            let operation = self.get_current_operation();
-            let color = if self.state.completed { Color::Green } else { Color::White };
-            VisualizerDrawer::draw_operation_info(stdout, &operation, width, height, color);
-        }
-
-        // Question
-        if let Some(q_index) = self.state.awaiting_question {
-            if let Some(question) = self.state.questions.get(q_index) {
-                VisualizerDrawer::draw_question(stdout, question, width, height);
-            }
-        }
-
-        stdout.flush().unwrap();
-    }
 }
 
-impl SortVisualizer for RadixSortVisualizer {
-    fn get_array(&self) -> &[u32] { &self.array }
-    fn get_original_array(&self) -> &[u32] { &self.original_array }
+impl<T: IntegerElement> SortVisualizer<T> for RadixSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
     fn get_states(&self) -> &[SelectionState] { &self.states }
     fn get_comparisons(&self) -> u32 { self.state.comparisons }
     fn get_swaps(&self) -> u32 { self.state.swaps }
@@ -312,6 +208,35 @@ impl SortVisualizer for RadixSortVisualizer {
     fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
     fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
     fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "RadixSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Radix Sort",
+            best: "O(d(n + k))",
+            average: "O(d(n + k))",
+            worst: "O(d(n + k))",
+            space: "O(n + k)",
+            stable: true,
+            in_place: false,
+        }
+    }
+    // Distributes by value, so the result is always ascending
+    fn supports_custom_order(&self) -> bool { false }
+
+    fn edit_value(&mut self, index: usize, value: T) -> Result<(), String> {
+        self.array[index] = value;
+
+        // A longer number needs more digit passes
+        self.update_digit_counts();
+        Ok(())
+    }
+
+    fn on_teaching_mode_changed(&mut self) {
+        // Keep the intro text in sync with the teaching mode
+        self.intro_text = Self::build_intro_text(self.state.teaching_mode, self.padded_digits);
+    }
 
     fn get_progress(&self) -> f64 {
         if self.array.len() <= 1 || self.max_digits == 0 {
@@ -432,7 +357,7 @@ impl SortVisualizer for RadixSortVisualizer {
                             self.temp_array[pos] = element;
                         }
                     }
-                    self.state.swaps += 1;
+                    self.state.moves += 1;
                     true
                 } else {
                     self.phase = RadixPhase::CopyingBack;
@@ -446,7 +371,7 @@ impl SortVisualizer for RadixSortVisualizer {
                     self.states[self.current_index] = SelectionState::Swapping;
                     self.array[self.current_index] = self.temp_array[self.current_index];
                     self.current_index += 1;
-                    self.state.swaps += 1;
+                    self.state.moves += 1;
                     true
                 } else {
                     // Teaching: Ask question after each pass
@@ -476,30 +401,20 @@ impl SortVisualizer for RadixSortVisualizer {
     fn reset(&mut self) {
         let len = self.original_array.len();
         self.array = self.original_array.clone();
-        self.temp_array = vec![0; len];
+        self.temp_array = vec![T::default(); len];
         self.states = vec![SelectionState::Normal; len];
         self.current_index = 0;
-        self.current_element = 0;
+        self.current_element = T::default();
         self.current_digit_value = 0;
 
         // Recalculate max digits
-        let max_num = *self.array.iter().max().unwrap_or(&0);
-        self.max_digits = if max_num == 0 { 1 } else { Self::count_digits(max_num) };
+        self.update_digit_counts();
         self.count.fill(0);
 
         self.current_digit = 1;
         self.phase = RadixPhase::StartingDigit;
         self.state.reset_state();
-        self.intro_text = format!(
-            "What is Radix Sort?\n\n\
-             Radix Sort is a non-comparative integer sorting algorithm that sorts data by grouping keys by individual digits.\n\
-             It processes digits from least to most significant, using stable counting sort for each digit.\n\n\
-             Advantages: Linear time O(d(n+k)) for integers.\n\
-             Disadvantages: Only for integers or fixed-length keys.\n\n\
-             Teaching Mode: {} (Toggle with T). Questions will be asked after each digit pass.\n\n\
-             Press any key to continue...",
-            if self.state.teaching_mode { "ON" } else { "OFF" }
-        );
+        self.intro_text = Self::build_intro_text(self.state.teaching_mode, self.padded_digits);
         if len <= 1 {
             self.state.mark_completed();
             self.mark_all_sorted();
@@ -522,13 +437,13 @@ impl SortVisualizer for RadixSortVisualizer {
         &self.intro_text
     }
 
-    fn get_legend_items(&self) -> Vec<(&str, Color)> {
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
         vec![
-            ("Normal", Color::Cyan),
-            ("Being Counted", Color::Magenta),
-            ("Being Placed", Color::White),
-            ("Being Moved", Color::Red),
-            ("Sorted", Color::Green),
+            ("Normal", SelectionState::Normal),
+            ("Being Counted", SelectionState::Comparing),
+            ("Being Placed", SelectionState::Selected),
+            ("Being Moved", SelectionState::Swapping),
+            ("Sorted", SelectionState::Sorted),
         ]
     }
 
@@ -546,17 +461,43 @@ impl SortVisualizer for RadixSortVisualizer {
         vec![
             format!("Array Size: {}", self.array.len()),
             format!("Comparisons: {}", self.state.comparisons),
-            format!("Moves: {}", self.state.swaps),
+            format!("Moves: {}", self.state.moves),
             format!("Current Digit: {}", self.current_digit),
+            if self.max_digits > self.real_digits {
+                format!("Passes: {} real + {} illustrative", self.real_digits, self.max_digits - self.real_digits)
+            } else {
+                format!("Passes: {}", self.max_digits)
+            },
             format!("Phase: {}", phase_str),
             format!("Progress: {:.1}%", self.get_progress()),
             if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
         ]
     }
 
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        let hint = match self.phase {
+            RadixPhase::StartingDigit => "Values are sorted one digit at a time from the lowest; each pass is stable, so earlier digits stay in order",
+            RadixPhase::CountingOccurrences => "Counting each digit tells how many values go to each digit's slot, without comparing values",
+            RadixPhase::CalculatingPositions => "Adding up the digit counts gives where each digit's group ends in the output",
+            RadixPhase::PlacingElements => "Values are placed from the back of their digit group, which keeps the order from the previous pass",
+            RadixPhase::CopyingBack => "The output is copied back so the next digit pass starts from this order",
+            RadixPhase::NextDigit => "This digit is sorted, the next higher digit decides the order among values that differ there",
+            _ => return None,
+        };
+        Some(hint)
+    }
+
     fn get_current_operation(&self) -> String {
         if self.state.completed {
-            "✓ Array is now sorted using Radix Sort! Congratulations!".to_string()
+            if self.max_digits > self.real_digits {
+                format!("✓ Array is now sorted using Radix Sort! Only {} of the {} passes shown were needed, the rest were for illustration.",
+                        self.real_digits, self.max_digits)
+            } else {
+                "✓ Array is now sorted using Radix Sort! Congratulations!".to_string()
+            }
         } else {
             match self.phase {
                 RadixPhase::StartingDigit => {
@@ -567,7 +508,12 @@ impl SortVisualizer for RadixSortVisualizer {
                         4 => "thousands place",
                         _ => "digit place",
                     };
-                    format!("Starting sorting pass for {} (digit position {})", place_name, self.current_digit)
+                    if self.is_padding_pass() {
+                        format!("Starting illustrative pass for {} (digit position {}) - every value has a 0 here, the order cannot change",
+                                place_name, self.current_digit)
+                    } else {
+                        format!("Starting sorting pass for {} (digit position {})", place_name, self.current_digit)
+                    }
                 },
                 RadixPhase::CountingOccurrences => {
                     if self.current_index < self.array.len() {
@@ -579,7 +525,7 @@ impl SortVisualizer for RadixSortVisualizer {
                             _ => "current",
                         };
                         format!("Examining {} digit of {} (element {}) - found digit {}",
-                                place_name, self.array[self.current_index], self.current_index, self.current_digit_value)
+                                place_name, self.digits_text(self.array[self.current_index]), self.current_index, self.current_digit_value)
                     } else {
                         "Finished counting all digit occurrences".to_string()
                     }
@@ -597,14 +543,14 @@ impl SortVisualizer for RadixSortVisualizer {
                             _ => "current",
                         };
                         format!("Placing {} (has {} digit {}) into correct sorted position",
-                                self.current_element, place_name, self.current_digit_value)
+                                self.digits_text(self.current_element), place_name, self.current_digit_value)
                     } else {
                         "Placing all elements into their sorted positions".to_string()
                     }
                 },
                 RadixPhase::CopyingBack => {
                     format!("Copying sorted element {} back to main array at position {}",
-                            self.temp_array.get(self.current_index).unwrap_or(&0), self.current_index)
+                            self.state.value_text(self.temp_array.get(self.current_index).copied().unwrap_or_default()), self.current_index)
                 },
                 RadixPhase::NextDigit => {
                     if self.current_digit <= self.max_digits {
@@ -658,7 +604,47 @@ impl SortVisualizer for RadixSortVisualizer {
 }
 
 /// Entry point for the radix sort visualization
-pub fn radix_sort_visualization(array_data: &ArrayData) {
-    let mut visualizer = RadixSortVisualizer::new(array_data);
-    visualizer.run_visualization();
-}
\ No newline at end of file
+pub fn radix_sort_visualization<T: IntegerElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(RadixSortVisualizer::with_settings(array_data, &settings), settings, last_run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorts_values_near_u32_max_without_overflow() {
+        let array_data = ArrayData::new(vec![u32::MAX, 0, 1], "Large Values".to_string());
+        let mut visualizer = RadixSortVisualizer::with_settings(&array_data, &Settings::default());
+        visualizer.state.teaching_mode = false;
+
+        let mut steps = 0;
+        while visualizer.step() {
+            steps += 1;
+            assert!(steps < 10_000, "radix sort did not finish");
+        }
+
+        assert_eq!(visualizer.max_digits, 10);
+        assert_eq!(visualizer.get_array(), &[0, 1, u32::MAX]);
+    }
+
+    #[test]
+    fn radix_padding_adds_passes_without_changing_the_result() {
+        let array_data = ArrayData::new(vec![7, 3, 9, 1], "One Digit".to_string());
+        let mut visualizer = RadixSortVisualizer::with_settings(&array_data, &Settings::default());
+        visualizer.state.teaching_mode = false;
+        visualizer.padded_digits = 3;
+        visualizer.reset();
+
+        assert_eq!((visualizer.real_digits, visualizer.max_digits), (1, 3));
+        let mut steps = 0;
+        while visualizer.step() {
+            steps += 1;
+            assert!(steps < 10_000, "radix sort did not finish");
+        }
+
+        assert_eq!(visualizer.current_digit, 4);
+        assert_eq!(visualizer.get_array(), &[1, 3, 7, 9]);
+    }
+}
@@ -1,16 +1,13 @@
+use crate::common::algorithm_info::AlgorithmInfo;
 use crate::common::array_manager::ArrayData;
 use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
-use crate::common::common_visualizer::{show_intro_screen, show_question_feedback, VisualizerDrawer};
+use crate::common::element::SortElement;
 use crate::common::enums::{SelectionState, TeachingQuestion};
-use crate::common::helper::{cleanup_terminal, randomize_questions};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
 use crate::common::settings::Settings;
-use crossterm::{
-    event::{poll, read, Event, KeyCode, KeyEventKind},
-    style::Color,
-    terminal::{enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen},
-    ExecutableCommand,
-};
-use std::io::{stdout, Write};
+use std::cmp::Ordering;
+use std::ops::Range;
 use std::time::Duration;
 
 /// Represents the different phases of the selection sort algorithm
@@ -23,22 +20,22 @@ pub enum SelectionPhase {
 }
 
 /// Visualizes the selection sort algorithm step-by-step with interactive controls
-pub struct SelectionSortVisualizer {
-    array: Vec<u32>,           // Current state of the array being sorted
-    original_array: Vec<u32>,  // Original array, used for resetting
+#[derive(Clone)]
+pub struct SelectionSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array being sorted
+    original_array: Vec<T>,  // Original array, used for resetting
     states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, swapping, sorted)
     intro_text: String,        // Dynamic intro text
     current_i: usize,          // Current position being filled
     current_j: usize,          // Current index being examined
     min_index: usize,          // Index of the current minimum element
     phase: SelectionPhase,     // Current phase of the selection sort algorithm
-    state: VisualizerState,    // Common visualization state
+    state: VisualizerState<T>,    // Common visualization state
 }
 
-impl SelectionSortVisualizer {
-    /// Creates a new SelectionSortVisualizer with the given array
-    pub fn new(array_data: &ArrayData) -> Self {
-        let settings = Settings::load();
+impl<T: SortElement> SelectionSortVisualizer<T> {
+    /// Creates a new SelectionSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
         let array = array_data.data.clone();
         let len = array.len();
 
@@ -77,7 +74,7 @@ impl SelectionSortVisualizer {
 
         randomize_questions(questions.clone());
 
-        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed));
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("SelectionSort")));
         state.teaching_mode = settings.teaching_mode;
 
         let intro_text = format!(
@@ -103,11 +100,6 @@ impl SelectionSortVisualizer {
             state,
         };
 
-        // Set last visualizer
-        let mut settings = Settings::load();
-        settings.last_visualizer = Some("SelectionSort".to_string());
-        settings.save();
-
         if len <= 1 {
             this.state.mark_completed();
             this.mark_all_sorted();
@@ -116,153 +108,16 @@ impl SelectionSortVisualizer {
         this
     }
 
-    /// Main loop: handles rendering, input, and stepping through the sort
-    pub fn run_visualization(&mut self) {
-        let mut stdout = stdout();
-        enable_raw_mode().unwrap();
-        stdout.execute(EnterAlternateScreen).unwrap();
-
-        show_intro_screen(self.get_intro_text());
-
-        loop {
-            self.draw(&mut stdout);
-
-            if poll(Duration::from_millis(50)).unwrap_or(false) {
-                match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
-                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                        // Handle question
-                        if let Some(q_index) = self.state.awaiting_question {
-                            match key_event.code {
-                                KeyCode::Char('1') => self.handle_question_answer(q_index, 0),
-                                KeyCode::Char('2') => self.handle_question_answer(q_index, 1),
-                                KeyCode::Char('3') => self.handle_question_answer(q_index, 2),
-                                _ => continue,
-                            }
-                            continue;
-                        }
-
-                        match key_event.code {
-                            KeyCode::Char(' ') => {
-                                if self.state.completed {
-                                    self.reset();
-                                } else {
-                                    self.state.toggle_play_pause();
-                                }
-                            },
-                            KeyCode::Char('r') | KeyCode::Char('R') => {
-                                self.reset();
-                            },
-                            KeyCode::Char('s') | KeyCode::Char('S') => {
-                                if !self.state.completed && !self.state.is_running {
-                                    if !self.step() {
-                                        self.state.mark_completed();
-                                        self.mark_all_sorted();
-                                    }
-                                }
-                            },
-                            KeyCode::Char('t') | KeyCode::Char('T') => {
-                                self.state.toggle_teaching_mode();
-                                self.intro_text = format!(
-                                    "What is Selection Sort?\n\n\
-                                     Selection Sort divides the input list into two parts: the sorted and unsorted.\n\
-                                     In each pass, it searches the unsorted part for the minimum element and swaps it with the first unsorted element.\n\n\
-                                     Advantages: Simple, in-place.\n\
-                                     Disadvantages: O(n^2) time, not stable.\n\n\
-                                     Teaching Mode: {} (Toggle with T). Questions will be asked after each selection.\n\n\
-                                     Press any key to continue...",
-                                    if self.state.teaching_mode { "ON" } else { "OFF" }
-                                );
-                                let mut settings = Settings::load();
-                                settings.teaching_mode = self.state.teaching_mode;
-                                settings.save();
-                            },
-                            KeyCode::Char('+') => {
-                                self.state.increase_speed(50);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Char('-') => {
-                                self.state.decrease_speed(2000);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Esc => {
-                                let mut settings = Settings::load();
-                                settings.last_visualizer = Some("SelectionSort".to_string());
-                                settings.save();
-                                cleanup_terminal();
-                                return;
-                            },
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
-            }
-
-            // Auto step
-            if self.state.is_running && !self.state.is_paused && !self.state.completed
-                && self.state.awaiting_question.is_none() {
-                std::thread::sleep(self.state.speed);
-                if !self.step() {
-                    self.state.mark_completed();
-                    self.mark_all_sorted();
-                }
-            }
-        }
-    }
-
-    fn handle_question_answer(&mut self, q_index: usize, answer: usize) {
-        if let Some(question) = self.state.questions.get(q_index) {
-            let correct = answer == question.correct_index;
-            show_question_feedback(correct, question, answer);
-            self.state.clear_question();
-        }
-    }
-
-    fn draw(&mut self, stdout: &mut std::io::Stdout) {
-        let (width, height) = size().unwrap();
-        stdout.execute(Clear(ClearType::All)).unwrap();
-
-        // Title
-        VisualizerDrawer::draw_title(stdout, self.get_title());
-
-        // Array
-        VisualizerDrawer::draw_array_bars(stdout, &self.array, &self.states, width, height, 5);
-
-        // Legend
-        VisualizerDrawer::draw_legend(stdout, &self.get_legend_items(), width, height, 5);
-
-        // Statistics
-        let stats = self.get_statistics_strings();
-        VisualizerDrawer::draw_statistics(stdout, &stats, width, height);
-
-        // Controls
-        VisualizerDrawer::draw_controls(stdout, self.get_status(), self.get_controls_text(), width, height);
-
-        // Current operation
-        if self.state.awaiting_question.is_none() {
-            let operation = self.get_current_operation();
-            let color = if self.state.completed { Color::Green } else { Color::White };
-            VisualizerDrawer::draw_operation_info(stdout, &operation, width, height, color);
-        }
-
-        // Question
-        if let Some(q_index) = self.state.awaiting_question {
-            if let Some(question) = self.state.questions.get(q_index) {
-                VisualizerDrawer::draw_question(stdout, question, width, height);
-            }
-        }
-
-        stdout.flush().unwrap();
+    // The value at `index` as the user sees it, "0" past the end of the array
+    fn value_at(&self, index: usize) -> String {
+        self.array.get(index).map_or_else(|| "0".to_string(), |&value| self.state.value_text(value))
     }
 }
 
-impl SortVisualizer for SelectionSortVisualizer {
-    fn get_array(&self) -> &[u32] { &self.array }
-    fn get_original_array(&self) -> &[u32] { &self.original_array }
+impl<T: SortElement> SortVisualizer<T> for SelectionSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
     fn get_states(&self) -> &[SelectionState] { &self.states }
     fn get_comparisons(&self) -> u32 { self.state.comparisons }
     fn get_swaps(&self) -> u32 { self.state.swaps }
@@ -273,6 +128,39 @@ impl SortVisualizer for SelectionSortVisualizer {
     fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
     fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
     fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "SelectionSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Selection Sort",
+            best: "O(n^2)",
+            average: "O(n^2)",
+            worst: "O(n^2)",
+            space: "O(1)",
+            stable: false,
+            in_place: true,
+        }
+    }
+    // The first current_i positions hold the smallest values, each placed by one swap
+    fn sorted_regions(&self) -> Vec<Range<usize>> {
+        let finalized = if self.state.completed { 0..self.array.len() } else { 0..self.current_i };
+        vec![finalized]
+    }
+
+    fn on_teaching_mode_changed(&mut self) {
+        // Keep the intro text in sync with the teaching mode
+        self.intro_text = format!(
+            "What is Selection Sort?\n\n\
+             Selection Sort divides the input list into two parts: the sorted and unsorted.\n\
+             In each pass, it searches the unsorted part for the minimum element and swaps it with the first unsorted element.\n\n\
+             Advantages: Simple, in-place.\n\
+             Disadvantages: O(n^2) time, not stable.\n\n\
+             Teaching Mode: {} (Toggle with T). Questions will be asked after each selection.\n\n\
+             Press any key to continue...",
+            if self.state.teaching_mode { "ON" } else { "OFF" }
+        );
+    }
 
     fn get_progress(&self) -> f64 {
         if self.array.len() <= 1 {
@@ -312,19 +200,19 @@ impl SortVisualizer for SelectionSortVisualizer {
             },
             SelectionPhase::SearchingMin => {
                 if self.current_j < self.array.len() {
-                    // Mark current minimum
-                    self.states[self.min_index] = SelectionState::CurrentMin;
+                    // Keep the position being filled visible during the whole scan
+                    self.states[self.current_i] = SelectionState::Selected;
                     // Mark element being compared
-                    if self.current_j != self.min_index {
-                        self.states[self.current_j] = SelectionState::Comparing;
-                    }
-                    self.state.comparisons += 1;
+                    self.states[self.current_j] = SelectionState::Comparing;
 
                     // Check if current element is smaller than current minimum
-                    if self.array[self.current_j] < self.array[self.min_index] {
+                    if self.compare(self.current_j, self.min_index).is_lt() {
                         self.min_index = self.current_j;
                     }
 
+                    // The running minimum highlight follows the scan
+                    self.states[self.min_index] = SelectionState::CurrentMin;
+
                     self.current_j += 1;
 
                     // If we've checked all elements, move to found phase
@@ -342,30 +230,25 @@ impl SortVisualizer for SelectionSortVisualizer {
                 self.phase = SelectionPhase::Swapping;
             },
             SelectionPhase::Swapping => {
-                // Perform the swap if needed
-                if self.current_i != self.min_index {
-                    self.array.swap(self.current_i, self.min_index);
-                    self.state.swaps += 1;
-                }
+                // Exactly one swap per pass, a minimum already in place swaps with itself
+                self.array.swap(self.current_i, self.min_index);
+                self.state.swaps += 1;
 
-                // Mark current position as sorted
+                // Mark current position as sorted, the sorted boundary grows by one
                 self.states[self.current_i] = SelectionState::Sorted;
 
                 // Move to next position
                 self.current_i += 1;
+                self.phase = SelectionPhase::SelectingPosition;
 
-                if self.current_i >= self.array.len() {
+                // After n-1 passes the last element is the largest one
+                if self.current_i >= self.array.len() - 1 {
+                    self.current_i = self.array.len();
                     return false; // Sorting complete
                 }
 
                 // Teaching: Ask question after each outer loop iteration
-                if self.state.teaching_mode && !self.state.questions.is_empty() {
-                    let q_index = self.current_i % self.state.questions.len();
-                    self.state.ask_question(q_index);
-                    return true;
-                }
-
-                self.phase = SelectionPhase::SelectingPosition;
+                self.state.ask_question(self.current_i);
             },
         }
 
@@ -411,14 +294,14 @@ impl SortVisualizer for SelectionSortVisualizer {
         &self.intro_text
     }
 
-    fn get_legend_items(&self) -> Vec<(&str, Color)> {
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
         vec![
-            ("Normal", Color::Cyan),
-            ("Current Min", Color::Yellow),
-            ("Comparing", Color::Magenta),
-            ("Selected Pos", Color::White),
-            ("Swapping", Color::Red),
-            ("Sorted", Color::Green),
+            ("Normal", SelectionState::Normal),
+            ("Current Min", SelectionState::CurrentMin),
+            ("Comparing", SelectionState::Comparing),
+            ("Selected Pos", SelectionState::Selected),
+            ("Swapping", SelectionState::Swapping),
+            ("Sorted", SelectionState::Sorted),
         ]
     }
 
@@ -433,14 +316,36 @@ impl SortVisualizer for SelectionSortVisualizer {
         vec![
             format!("Array Size: {}", self.array.len()),
             format!("Comparisons: {}", self.state.comparisons),
-            format!("Swaps: {}", self.state.swaps),
-            format!("Current i: {}", self.current_i),
+            format!("Swaps: {} / {} (one per pass)", self.state.swaps, self.array.len().saturating_sub(1)),
+            format!("Sorted Boundary: {}", self.current_i.min(self.array.len())),
             format!("Phase: {}", phase_str),
             format!("Progress: {:.1}%", self.get_progress()),
             if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
         ]
     }
 
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        let hint = match self.phase {
+            SelectionPhase::SelectingPosition => "Everything left of this position is sorted and final, so it is filled with the smallest remaining value",
+            SelectionPhase::SearchingMin => "The unsorted part is scanned to the end because the smallest value could be anywhere in it",
+            SelectionPhase::FoundMin => "The scan finished, so the value found is the smallest remaining one and belongs at this position",
+            SelectionPhase::Swapping => "One swap per position puts the minimum in place, selection sort makes at most n-1 swaps",
+        };
+        Some(hint)
+    }
+
+    fn decision_changers(&self) -> Vec<usize> {
+        let (j, min) = (self.current_j, self.min_index);
+        if self.state.completed || self.phase != SelectionPhase::SearchingMin || j >= self.array.len() || min >= self.array.len() {
+            return Vec::new();
+        }
+        // array[j] becomes the new minimum if it orders before the current one
+        self.state.decision_changers(&self.array, j, self.array[min], &[j, min], Ordering::is_lt)
+    }
+
     fn get_current_operation(&self) -> String {
         if self.state.completed {
             "✓ Array is now sorted using Selection Sort! Congratulations!".to_string()
@@ -454,22 +359,28 @@ impl SortVisualizer for SelectionSortVisualizer {
                     format!("Step {}/{}: Searching for minimum in range [{}..{}] - Comparing {} with current min {} at index {}",
                             self.current_i + 1, self.array.len(),
                             self.current_i, self.array.len() - 1,
-                            if self.current_j < self.array.len() { self.array[self.current_j] } else { 0 },
-                            if self.min_index < self.array.len() { self.array[self.min_index] } else { 0 },
+                            self.value_at(self.current_j),
+                            self.value_at(self.min_index),
                             self.min_index)
                 },
                 SelectionPhase::FoundMin => {
                     format!("Step {}/{}: Found minimum {} at index {} - Ready to swap with position {}",
                             self.current_i + 1, self.array.len(),
-                            if self.min_index < self.array.len() { self.array[self.min_index] } else { 0 },
+                            self.value_at(self.min_index),
                             self.min_index, self.current_i)
                 },
+                SelectionPhase::Swapping if self.current_i == self.min_index => {
+                    format!("Step {}/{}: Minimum {} is already at position {} - it swaps with itself",
+                            self.current_i + 1, self.array.len(),
+                            self.state.value_text(self.array[self.current_i]),
+                            self.current_i)
+                },
                 SelectionPhase::Swapping => {
                     format!("Step {}/{}: Swapping {} (pos {}) with {} (pos {})",
                             self.current_i + 1, self.array.len(),
-                            if self.current_i < self.array.len() { self.array[self.current_i] } else { 0 },
+                            self.value_at(self.current_i),
                             self.current_i,
-                            if self.min_index < self.array.len() { self.array[self.min_index] } else { 0 },
+                            self.value_at(self.min_index),
                             self.min_index)
                 },
             }
@@ -504,7 +415,62 @@ impl SortVisualizer for SelectionSortVisualizer {
 }
 
 /// Entry point for the selection sort visualization
-pub fn selection_sort_visualization(array_data: &ArrayData) {
-    let mut visualizer = SelectionSortVisualizer::new(array_data);
-    visualizer.run_visualization();
-}
\ No newline at end of file
+pub fn selection_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(SelectionSortVisualizer::with_settings(array_data, &settings), settings, last_run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn visualizer(values: &[u32]) -> SelectionSortVisualizer {
+        let mut visualizer = SelectionSortVisualizer::with_settings(&ArrayData::new(values.to_vec(), "Test".to_string()), &Settings::default());
+        visualizer.state_mut().teaching_mode = false;
+        visualizer.state_mut().is_running = true;
+        visualizer
+    }
+
+    #[test]
+    fn sorts_with_exactly_n_minus_one_swaps() {
+        let values = [5, 1, 4, 2, 8, 2, 9, 3];
+        let mut visualizer = visualizer(&values);
+        while visualizer.step() {}
+
+        let mut expected = values.to_vec();
+        expected.sort();
+        assert_eq!(visualizer.get_array(), &expected[..]);
+        assert_eq!(visualizer.get_swaps() as usize, values.len() - 1);
+    }
+
+    #[test]
+    fn current_min_highlight_follows_the_scan() {
+        let values = [7, 3, 9, 1, 4, 1];
+        let mut visualizer = visualizer(&values);
+        while visualizer.step() {
+            // Only check once the pass has compared at least one element
+            let scanning = visualizer.phase == SelectionPhase::SearchingMin || visualizer.phase == SelectionPhase::FoundMin;
+            if !scanning || visualizer.current_j <= visualizer.current_i + 1 {
+                continue;
+            }
+            let scanned = &visualizer.array[visualizer.current_i..visualizer.current_j];
+            let min_index = visualizer.min_index;
+            assert_eq!(visualizer.array[min_index], *scanned.iter().min().unwrap());
+            assert!(visualizer.states[min_index] == SelectionState::CurrentMin);
+            assert!(visualizer.states[..visualizer.current_i].iter().all(|s| *s == SelectionState::Sorted));
+        }
+    }
+
+    #[test]
+    fn answering_questions_does_not_repeat_the_swap() {
+        let values = [4, 3, 2, 1, 6, 5];
+        let mut visualizer = visualizer(&values);
+        visualizer.state_mut().teaching_mode = true;
+        while visualizer.step() {
+            visualizer.state_mut().clear_question();
+        }
+
+        assert_eq!(visualizer.get_array(), &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(visualizer.get_swaps() as usize, values.len() - 1);
+    }
+}
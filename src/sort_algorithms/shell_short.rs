@@ -1,16 +1,12 @@
+use crate::common::algorithm_info::AlgorithmInfo;
 use crate::common::array_manager::ArrayData;
 use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
-use crate::common::common_visualizer::{show_intro_screen, show_question_feedback, VisualizerDrawer};
+use crate::common::element::SortElement;
 use crate::common::enums::{SelectionState, TeachingQuestion};
-use crate::common::helper::{cleanup_terminal, randomize_questions};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
 use crate::common::settings::Settings;
-use crossterm::{
-    event::{poll, read, Event, KeyCode, KeyEventKind},
-    style::Color,
-    terminal::{enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen},
-    ExecutableCommand,
-};
-use std::io::{stdout, Write};
+use crate::sort_algorithms::run_headless;
 use std::time::Duration;
 
 /// Represents the different phases of the shell sort algorithm
@@ -25,10 +21,59 @@ pub enum ShellPhase {
     Done,               // Sorting is complete
 }
 
+/// Gap sequences shell sort can run with
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GapSequence {
+    Knuth,   // 1, 4, 13, 40, ... (h = 3h + 1)
+    Halving, // n/2, n/4, ..., 1 (Shell's original sequence)
+}
+
+impl GapSequence {
+    /// Human-readable name of the sequence
+    pub fn label(&self) -> &'static str {
+        match self {
+            GapSequence::Knuth => "Knuth (3h+1)",
+            GapSequence::Halving => "Halving (n/2)",
+        }
+    }
+
+    /// The sequence the comparison is run with
+    pub fn other(&self) -> GapSequence {
+        match self {
+            GapSequence::Knuth => GapSequence::Halving,
+            GapSequence::Halving => GapSequence::Knuth,
+        }
+    }
+
+    /// Gaps for an array of `len` elements, largest first
+    pub fn gaps(&self, len: usize) -> Vec<usize> {
+        let mut gaps = Vec::new();
+        match self {
+            GapSequence::Knuth => {
+                let mut gap = 1;
+                while gap < len {
+                    gaps.push(gap);
+                    gap = gap * 3 + 1;
+                }
+                gaps.reverse(); // Start with largest gap
+            }
+            GapSequence::Halving => {
+                let mut gap = len / 2;
+                while gap > 0 {
+                    gaps.push(gap);
+                    gap /= 2;
+                }
+            }
+        }
+        gaps
+    }
+}
+
 /// Visualizes the shell sort algorithm step-by-step with interactive controls
-pub struct ShellSortVisualizer {
-    array: Vec<u32>,           // Current state of the array being sorted
-    original_array: Vec<u32>,  // Original array, used for resetting
+#[derive(Clone)]
+pub struct ShellSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array being sorted
+    original_array: Vec<T>,  // Original array, used for resetting
     states: Vec<SelectionState>, // Visual state of each element
     intro_text: String,        // Dynamic intro text
 
@@ -38,17 +83,23 @@ pub struct ShellSortVisualizer {
     current_index: usize,      // Current index being processed
     insertion_index: usize,    // Index where element will be inserted
     comparing_index: usize,    // Index of element being compared
-    key: u32,                  // Current element being inserted
+    key: T,                  // Current element being inserted
     phase: ShellPhase,         // Current phase of the shell sort algorithm
-    gap_sequence: Vec<usize>,  // Sequence of gap sizes (Knuth sequence)
+    gap_sequence: Vec<usize>,  // Sequence of gap sizes, from `gap_kind`
+    gap_kind: GapSequence,     // Sequence this run uses
+    gap_comparison: Option<(u32, u32)>, // Comparisons and moves of the other sequence on the same array
     gap_sequence_index: usize, // Index of current gap in the sequence
-    state: VisualizerState,    // Common visualization state
+    state: VisualizerState<T>,    // Common visualization state
 }
 
-impl ShellSortVisualizer {
-    /// Creates a new ShellSortVisualizer with the given array
-    pub fn new(array_data: &ArrayData) -> Self {
-        let settings = Settings::load();
+impl<T: SortElement> ShellSortVisualizer<T> {
+    /// Creates a new ShellSortVisualizer with the given array and settings, using Knuth's gaps
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
+        Self::with_gap_sequence(array_data, GapSequence::Knuth, settings)
+    }
+
+    /// Creates a new ShellSortVisualizer that uses the given gap sequence
+    pub fn with_gap_sequence(array_data: &ArrayData<T>, gap_kind: GapSequence, settings: &Settings) -> Self {
         let array = array_data.data.clone();
         let len = array.len();
 
@@ -87,16 +138,9 @@ impl ShellSortVisualizer {
 
         randomize_questions(questions.clone());
 
-        // Generate Knuth gap sequence: h = 3h + 1
-        let mut gap_sequence = Vec::new();
-        let mut gap = 1;
-        while gap < len {
-            gap_sequence.push(gap);
-            gap = gap * 3 + 1;
-        }
-        gap_sequence.reverse(); // Start with largest gap
+        let gap_sequence = gap_kind.gaps(len);
 
-        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed));
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("ShellSort")));
         state.teaching_mode = settings.teaching_mode;
 
         let intro_text = format!(
@@ -120,18 +164,15 @@ impl ShellSortVisualizer {
             current_index: 0,
             insertion_index: 0,
             comparing_index: 0,
-            key: 0,
+            key: T::default(),
             phase: ShellPhase::StartingGap,
             gap_sequence,
             gap_sequence_index: 0,
+            gap_kind,
+            gap_comparison: None,
             state,
         };
 
-        // Set last visualizer
-        let mut settings = Settings::load();
-        settings.last_visualizer = Some("ShellSort".to_string());
-        settings.save();
-
         if len <= 1 {
             this.state.mark_completed();
             this.mark_all_sorted();
@@ -142,154 +183,12 @@ impl ShellSortVisualizer {
 
         this
     }
-
-    /// Main loop: handles rendering, input, and stepping through the sort
-    pub fn run_visualization(&mut self) {
-        let mut stdout = stdout();
-        enable_raw_mode().unwrap();
-        stdout.execute(EnterAlternateScreen).unwrap();
-
-        show_intro_screen(self.get_intro_text());
-
-        loop {
-            self.draw(&mut stdout);
-
-            if poll(Duration::from_millis(50)).unwrap_or(false) {
-                match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
-                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                        // Handle question
-                        if let Some(q_index) = self.state.awaiting_question {
-                            match key_event.code {
-                                KeyCode::Char('1') => self.handle_question_answer(q_index, 0),
-                                KeyCode::Char('2') => self.handle_question_answer(q_index, 1),
-                                KeyCode::Char('3') => self.handle_question_answer(q_index, 2),
-                                _ => continue,
-                            }
-                            continue;
-                        }
-
-                        match key_event.code {
-                            KeyCode::Char(' ') => {
-                                if self.state.completed {
-                                    self.reset();
-                                } else {
-                                    self.state.toggle_play_pause();
-                                }
-                            },
-                            KeyCode::Char('r') | KeyCode::Char('R') => {
-                                self.reset();
-                            },
-                            KeyCode::Char('s') | KeyCode::Char('S') => {
-                                if !self.state.completed && !self.state.is_running {
-                                    if !self.step() {
-                                        self.state.mark_completed();
-                                        self.mark_all_sorted();
-                                    }
-                                }
-                            },
-                            KeyCode::Char('t') | KeyCode::Char('T') => {
-                                self.state.toggle_teaching_mode();
-                                self.intro_text = format!(
-                                    "What is Shell Sort?\n\n\
-                                     Shell Sort is an optimization of insertion sort that allows the exchange of elements that are far apart.\n\
-                                     It starts with large gaps and reduces them, performing insertion sort on gap-spaced subarrays.\n\n\
-                                     Advantages: Better than O(n^2) in practice, in-place.\n\
-                                     Disadvantages: Not stable, complexity depends on gap sequence.\n\n\
-                                     Teaching Mode: {} (Toggle with T). Questions will be asked after each gap.\n\n\
-                                     Press any key to continue...",
-                                    if self.state.teaching_mode { "ON" } else { "OFF" }
-                                );
-                                let mut settings = Settings::load();
-                                settings.teaching_mode = self.state.teaching_mode;
-                                settings.save();
-                            },
-                            KeyCode::Char('+') => {
-                                self.state.increase_speed(50);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Char('-') => {
-                                self.state.decrease_speed(2000);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Esc => {
-                                let mut settings = Settings::load();
-                                settings.last_visualizer = Some("ShellSort".to_string());
-                                settings.save();
-                                cleanup_terminal();
-                                return;
-                            },
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
-            }
-
-            // Auto step
-            if self.state.is_running && !self.state.is_paused && !self.state.completed
-                && self.state.awaiting_question.is_none() {
-                std::thread::sleep(self.state.speed);
-                if !self.step() {
-                    self.state.mark_completed();
-                    self.mark_all_sorted();
-                }
-            }
-        }
-    }
-
-    fn handle_question_answer(&mut self, q_index: usize, answer: usize) {
-        if let Some(question) = self.state.questions.get(q_index) {
-            let correct = answer == question.correct_index;
-            show_question_feedback(correct, question, answer);
-            self.state.clear_question();
-        }
-    }
-
-    fn draw(&mut self, stdout: &mut std::io::Stdout) {
-        let (width, height) = size().unwrap();
-        stdout.execute(Clear(ClearType::All)).unwrap();
-
-        // Title
-        VisualizerDrawer::draw_title(stdout, self.get_title());
-
-        // Array
-        VisualizerDrawer::draw_array_bars(stdout, &self.array, &self.states, width, height, 5);
-
-        // Legend
-        VisualizerDrawer::draw_legend(stdout, &self.get_legend_items(), width, height, 5);
-
-        // Statistics
-        let stats = self.get_statistics_strings();
-        VisualizerDrawer::draw_statistics(stdout, &stats, width, height);
-
-        // Controls
-        VisualizerDrawer::draw_controls(stdout, self.get_status(), self.get_controls_text(), width, height);
-
-        // Current operation
-        if self.state.awaiting_question.is_none() {
-            let operation = self.get_current_operation();
-            let color = if self.state.completed { Color::Green } else { Color::White };
-            VisualizerDrawer::draw_operation_info(stdout, &operation, width, height, color);
-        }
-
-        // Question
-        if let Some(q_index) = self.state.awaiting_question {
-            if let Some(question) = self.state.questions.get(q_index) {
-                VisualizerDrawer::draw_question(stdout, question, width, height);
-            }
-        }
-
-        stdout.flush().unwrap();
-    }
 }
 
-impl SortVisualizer for ShellSortVisualizer {
-    fn get_array(&self) -> &[u32] { &self.array }
-    fn get_original_array(&self) -> &[u32] { &self.original_array }
+impl<T: SortElement> SortVisualizer<T> for ShellSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
     fn get_states(&self) -> &[SelectionState] { &self.states }
     fn get_comparisons(&self) -> u32 { self.state.comparisons }
     fn get_swaps(&self) -> u32 { self.state.swaps }
@@ -300,6 +199,34 @@ impl SortVisualizer for ShellSortVisualizer {
     fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
     fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
     fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "ShellSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Shell Sort",
+            best: "O(n log n)",
+            average: "depends on the gaps",
+            worst: "O(n^2)",
+            space: "O(1)",
+            stable: false,
+            in_place: true,
+        }
+    }
+
+    fn on_teaching_mode_changed(&mut self) {
+        // Keep the intro text in sync with the teaching mode
+        self.intro_text = format!(
+            "What is Shell Sort?\n\n\
+             Shell Sort is an optimization of insertion sort that allows the exchange of elements that are far apart.\n\
+             It starts with large gaps and reduces them, performing insertion sort on gap-spaced subarrays.\n\n\
+             Advantages: Better than O(n^2) in practice, in-place.\n\
+             Disadvantages: Not stable, complexity depends on gap sequence.\n\n\
+             Teaching Mode: {} (Toggle with T). Questions will be asked after each gap.\n\n\
+             Press any key to continue...",
+            if self.state.teaching_mode { "ON" } else { "OFF" }
+        );
+    }
 
     fn get_progress(&self) -> f64 {
         if self.array.len() <= 1 || self.gap_sequence.is_empty() {
@@ -386,9 +313,8 @@ impl SortVisualizer for ShellSortVisualizer {
                 if self.insertion_index >= self.gap && self.comparing_index < self.array.len() {
                     // Highlight elements being compared
                     self.states[self.comparing_index] = SelectionState::Comparing;
-                    self.state.comparisons += 1;
 
-                    if self.array[self.comparing_index] > self.key {
+                    if self.state.compare_values(self.array[self.comparing_index], self.key).is_gt() {
                         // Need to shift this element
                         self.phase = ShellPhase::ShiftingElement;
                     } else {
@@ -409,7 +335,7 @@ impl SortVisualizer for ShellSortVisualizer {
 
                     // Shift element to the right
                     self.array[self.insertion_index] = self.array[self.comparing_index];
-                    self.state.swaps += 1;
+                    self.state.moves += 1;
 
                     self.insertion_index = self.comparing_index;
 
@@ -431,7 +357,10 @@ impl SortVisualizer for ShellSortVisualizer {
                     // Highlight position where element will be inserted
                     self.states[self.insertion_index] = SelectionState::Selected;
 
-                    // Insert the key at its correct position
+                    // Insert the key at its correct position, a move if anything was shifted past it
+                    if self.insertion_index != self.current_index {
+                        self.state.moves += 1;
+                    }
                     self.array[self.insertion_index] = self.key;
 
                     // Move to next element in the same gap group
@@ -491,18 +420,12 @@ impl SortVisualizer for ShellSortVisualizer {
         self.current_index = 0;
         self.insertion_index = 0;
         self.comparing_index = 0;
-        self.key = 0;
+        self.key = T::default();
         self.gap_sequence_index = 0;
 
         // Regenerate gap sequence
-        let mut gap_sequence = Vec::new();
-        let mut gap = 1;
-        while gap < len {
-            gap_sequence.push(gap);
-            gap = gap * 3 + 1;
-        }
-        gap_sequence.reverse();
-        self.gap_sequence = gap_sequence;
+        self.gap_sequence = self.gap_kind.gaps(len);
+        self.gap_comparison = None;
 
         self.gap = if self.gap_sequence.is_empty() { 1 } else { self.gap_sequence[0] };
         self.current_index = self.gap;
@@ -531,6 +454,17 @@ impl SortVisualizer for ShellSortVisualizer {
         }
     }
 
+    fn on_completed(&mut self) {
+        self.mark_all_sorted();
+
+        // Sort the same array with the other gap sequence to show what the choice costs
+        let array_data = ArrayData::new(self.original_array.clone(), String::new());
+        let mut other = ShellSortVisualizer::with_gap_sequence(&array_data, self.gap_kind.other(), &Settings::default());
+        other.state.set_sort_order(self.state.sort_order);
+        run_headless(&mut other);
+        self.gap_comparison = Some((other.state.comparisons, other.state.moves));
+    }
+
     fn get_title(&self) -> &str {
         "TOGISOFT SHELL SORT VISUALIZER"
     }
@@ -539,15 +473,14 @@ impl SortVisualizer for ShellSortVisualizer {
         &self.intro_text
     }
 
-    fn get_legend_items(&self) -> Vec<(&str, Color)> {
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
         vec![
-            ("Normal", Color::Cyan),
-            ("Gap Group", Color::DarkBlue),
-            ("Key", Color::Yellow),
-            ("Comparing", Color::Magenta),
-            ("Position", Color::White),
-            ("Shifting", Color::Red),
-            ("Sorted", Color::Green),
+            ("Normal", SelectionState::Normal),
+            ("Key", SelectionState::CurrentMin),
+            ("Comparing", SelectionState::Comparing),
+            ("Position", SelectionState::Selected),
+            ("Shifting", SelectionState::Swapping),
+            ("Sorted", SelectionState::Sorted),
         ]
     }
 
@@ -562,20 +495,48 @@ impl SortVisualizer for ShellSortVisualizer {
             ShellPhase::Done => "Done",
         };
 
-        vec![
+        let mut stats = vec![
             format!("Array Size: {}", self.array.len()),
             format!("Comparisons: {}", self.state.comparisons),
-            format!("Shifts: {}", self.state.swaps),
+            format!("Moves: {}", self.state.moves),
             format!("Gap: {}", self.gap),
+            format!("Gaps: {}", self.gap_kind.label()),
             format!("Phase: {}", phase_str),
             format!("Progress: {:.1}%", self.get_progress()),
             if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
-        ]
+        ];
+        if let Some((comparisons, moves)) = self.gap_comparison {
+            stats.push(format!("{} gaps: {} comparisons, {} moves", self.gap_kind.other().label(), comparisons, moves));
+        }
+        stats
+    }
+
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        let hint = match self.phase {
+            ShellPhase::StartingGap => "A large gap lets values move far in one step, the gaps shrink to 1 to finish with a plain insertion sort",
+            ShellPhase::InsertionSorting => "Values a gap apart form a sublist that is insertion sorted on its own",
+            ShellPhase::ComparingElements => "The key is compared with the value a gap to its left, like insertion sort with a stride",
+            ShellPhase::ShiftingElement => "The value a gap to the left is larger, so it shifts right by the gap to make room for the key",
+            ShellPhase::InsertingElement => "A value not larger than the key was found, so the key goes in the gap after it",
+            ShellPhase::GapComplete => "Every sublist for this gap is sorted, so the array is closer to sorted for the next smaller gap",
+            _ => return None,
+        };
+        Some(hint)
     }
 
     fn get_current_operation(&self) -> String {
         if self.state.completed {
-            "✓ Array is now sorted using Shell Sort! Congratulations!".to_string()
+            match self.gap_comparison {
+                Some((comparisons, moves)) => format!(
+                    "✓ Sorted with {} gaps in {} comparisons and {} moves, {} gaps needed {} and {} on the same array",
+                    self.gap_kind.label(), self.state.comparisons, self.state.moves,
+                    self.gap_kind.other().label(), comparisons, moves
+                ),
+                None => "✓ Array is now sorted using Shell Sort! Congratulations!".to_string(),
+            }
         } else {
             match self.phase {
                 ShellPhase::StartingGap => {
@@ -584,7 +545,7 @@ impl SortVisualizer for ShellSortVisualizer {
                 ShellPhase::InsertionSorting => {
                     if self.current_index < self.array.len() {
                         format!("Gap-{} sort: processing element {} (value: {})",
-                                self.gap, self.current_index, self.array[self.current_index])
+                                self.gap, self.current_index, self.state.value_text(self.array[self.current_index]))
                     } else {
                         format!("Gap-{} insertion sorting", self.gap)
                     }
@@ -592,7 +553,7 @@ impl SortVisualizer for ShellSortVisualizer {
                 ShellPhase::ComparingElements => {
                     if self.insertion_index < self.array.len() && self.comparing_index < self.array.len() {
                         format!("Comparing key {} with element at {} (value: {})",
-                                self.key, self.comparing_index, self.array[self.comparing_index])
+                                self.state.value_text(self.key), self.comparing_index, self.state.value_text(self.array[self.comparing_index]))
                     } else {
                         "Comparing elements...".to_string()
                     }
@@ -600,13 +561,13 @@ impl SortVisualizer for ShellSortVisualizer {
                 ShellPhase::ShiftingElement => {
                     if self.comparing_index < self.array.len() {
                         format!("Shifting element {} (value: {}) {} positions right",
-                                self.comparing_index, self.array[self.comparing_index], self.gap)
+                                self.comparing_index, self.state.value_text(self.array[self.comparing_index]), self.gap)
                     } else {
                         "Shifting element...".to_string()
                     }
                 },
                 ShellPhase::InsertingElement => {
-                    format!("Inserting key {} at position {}", self.key, self.insertion_index)
+                    format!("Inserting key {} at position {}", self.state.value_text(self.key), self.insertion_index)
                 },
                 ShellPhase::GapComplete => {
                     format!("Gap-{} sorting completed, moving to next gap", self.gap)
@@ -644,7 +605,27 @@ impl SortVisualizer for ShellSortVisualizer {
 }
 
 /// Entry point for the shell sort visualization
-pub fn shell_sort_visualization(array_data: &ArrayData) {
-    let mut visualizer = ShellSortVisualizer::new(array_data);
-    visualizer.run_visualization();
-}
\ No newline at end of file
+pub fn shell_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(ShellSortVisualizer::with_settings(array_data, &settings), settings, last_run)
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn completion_reports_the_other_gap_sequence() {
+        assert_eq!(GapSequence::Knuth.gaps(20), vec![13, 4, 1]);
+        assert_eq!(GapSequence::Halving.gaps(20), vec![10, 5, 2, 1]);
+
+        let array_data = ArrayData::new(vec![9, 3, 7, 1, 8, 2, 6, 4, 5, 0], "Shell".to_string());
+        let mut visualizer = ShellSortVisualizer::with_settings(&array_data, &Settings::default());
+        run_headless(&mut visualizer);
+        visualizer.on_completed();
+
+        let mut halving = ShellSortVisualizer::with_gap_sequence(&array_data, GapSequence::Halving, &Settings::default());
+        run_headless(&mut halving);
+        assert_eq!(halving.get_array(), &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(visualizer.gap_comparison, Some((halving.state.comparisons, halving.state.moves)));
+    }
+}
@@ -1,18 +1,20 @@
+use crate::common::algorithm_info::AlgorithmInfo;
 use crate::common::array_manager::ArrayData;
 use crate::common::base_visualizer::{SortVisualizer, VisualizerState};
-use crate::common::common_visualizer::{show_intro_screen, show_question_feedback, VisualizerDrawer};
+use crate::common::common_visualizer::VisualizerDrawer;
+use crate::common::element::SortElement;
 use crate::common::enums::{SelectionState, TeachingQuestion};
-use crate::common::helper::{cleanup_terminal, randomize_questions};
+use crate::common::helper::randomize_questions;
+use crate::common::runner::{run_suspendable, RunSummary};
 use crate::common::settings::Settings;
-use crossterm::{
-    event::{poll, read, Event, KeyCode, KeyEventKind},
-    style::Color,
-    terminal::{enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen},
-    ExecutableCommand,
-};
-use std::io::{stdout, Write};
+use crossterm::style::Color;
 use std::time::Duration;
 
+// Arrays shorter than this are a single run sorted by insertion sort, as in CPython
+const MIN_MERGE: usize = 64;
+// Asked the first time the stack invariants force a merge before every run is found
+const INVARIANT_QUESTION: usize = 3;
+
 /// Represents the different phases of the tim sort algorithm
 #[derive(Clone, Copy, PartialEq)]
 pub enum TimPhase {
@@ -23,33 +25,40 @@ pub enum TimPhase {
 }
 
 /// Visualizes the tim sort algorithm step-by-step with interactive controls
-pub struct TimSortVisualizer {
-    array: Vec<u32>,           // Current state of the array being sorted
-    original_array: Vec<u32>,  // Original array, used for resetting
+#[derive(Clone)]
+pub struct TimSortVisualizer<T: SortElement = u32> {
+    array: Vec<T>,           // Current state of the array being sorted
+    original_array: Vec<T>,  // Original array, used for resetting
     states: Vec<SelectionState>, // Visual state of each element (e.g., comparing, swapping, sorted)
 
     // Tim Sort specific fields
     current_i: usize,          // Current index
     run_start: usize,          // Start of current run
-    run_end: usize,            // End of current run
+    run_end: usize,            // End of current run (exclusive)
     min_run: usize,            // Minimum run length
+    min_run_auto: bool,        // True if min_run was computed from the array size
     stack: Vec<(usize, usize)>, // Stack of runs (start, length)
+    merge_at: usize,           // Stack index of the left run being merged
+    merges: u32,               // Merges performed so far
+    invariant_asked: bool,     // The stack invariant question was asked in this run
     merging_left: usize,       // Left run start for merging
     merging_right: usize,      // Right run start for merging
+    merge_end: usize,          // End of the right run (exclusive)
+    left_pos: usize,           // Next element of the left run in temp_array
+    right_pos: usize,          // Next element of the right run in temp_array
     merge_pos: usize,          // Current merge position
-    temp_array: Vec<u32>,      // Temporary array for merging
+    temp_array: Vec<T>,      // Temporary array for merging
     phase: TimPhase,           // Current phase of the tim sort algorithm
-    state: VisualizerState,    // Common visualization state
+    state: VisualizerState<T>,    // Common visualization state
 }
 
-impl TimSortVisualizer {
-    /// Creates a new TimSortVisualizer with the given array
-    pub fn new(array_data: &ArrayData) -> Self {
-        let settings = Settings::load();
+impl<T: SortElement> TimSortVisualizer<T> {
+    /// Creates a new TimSortVisualizer with the given array and settings
+    pub fn with_settings(array_data: &ArrayData<T>, settings: &Settings) -> Self {
         let array = array_data.data.clone();
         let len = array.len();
 
-        let mut questions = vec![
+        let questions = vec![
             TeachingQuestion {
                 text: "What is the main advantage of Tim Sort over traditional merge sort?".to_string(),
                 options: vec![
@@ -80,13 +89,24 @@ impl TimSortVisualizer {
                 correct_index: 0,
                 explanation: "Tim Sort guarantees O(n log n) time complexity, performing better than O(n log n) when the data has natural runs.".to_string(),
             },
+            TeachingQuestion {
+                text: "Why does Tim Sort merge runs as soon as A > B + C or B > C stops holding on its stack?".to_string(),
+                options: vec![
+                    "It keeps merges balanced, so every element is merged O(log n) times".to_string(),
+                    "It keeps every run on the stack sorted".to_string(),
+                    "It lets Tim Sort work without a temporary array".to_string(),
+                ],
+                correct_index: 0,
+                explanation: "With the invariants every run is longer than the two above it combined, so run lengths grow like Fibonacci numbers. The stack stays O(log n) deep and runs of similar size are merged, like the halves of a merge sort.".to_string(),
+            },
         ];
 
         randomize_questions(questions.clone());
 
-        let min_run = if len < 64 { len } else { 32 }; // Simplified min run calculation
+        let min_run_auto = settings.tim_min_run == 0;
+        let min_run = if min_run_auto { compute_min_run(len) } else { settings.tim_min_run as usize };
 
-        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed));
+        let mut state = VisualizerState::new(questions, Duration::from_millis(settings.speed_for("TimSort")));
         state.teaching_mode = settings.teaching_mode;
 
         let mut this = Self {
@@ -95,22 +115,24 @@ impl TimSortVisualizer {
             states: vec![SelectionState::Normal; len],
             current_i: 0,
             run_start: 0,
-            run_end: 0,
+            run_end: 1,
             min_run,
+            min_run_auto,
             stack: Vec::new(),
+            merge_at: 0,
+            merges: 0,
+            invariant_asked: false,
             merging_left: 0,
             merging_right: 0,
+            merge_end: 0,
+            left_pos: 0,
+            right_pos: 0,
             merge_pos: 0,
-            temp_array: vec![0; len],
+            temp_array: vec![T::default(); len],
             phase: TimPhase::FindingRun,
             state,
         };
 
-        // Set last visualizer
-        let mut settings = Settings::load();
-        settings.last_visualizer = Some("TimSort".to_string());
-        settings.save();
-
         if len <= 1 {
             this.state.mark_completed();
             this.mark_all_sorted();
@@ -119,197 +141,138 @@ impl TimSortVisualizer {
         this
     }
 
-    /// Main loop: handles rendering, input, and stepping through the sort
-    pub fn run_visualization(&mut self) {
-        let mut stdout = stdout();
-        enable_raw_mode().unwrap();
-        stdout.execute(EnterAlternateScreen).unwrap();
-
-        show_intro_screen(self.get_intro_text());
-
-        loop {
-            self.draw(&mut stdout);
-
-            if poll(Duration::from_millis(50)).unwrap_or(false) {
-                match read().unwrap_or(Event::Key(KeyCode::Esc.into())) {
-                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                        // Handle question
-                        if let Some(q_index) = self.state.awaiting_question {
-                            match key_event.code {
-                                KeyCode::Char('1') => self.handle_question_answer(q_index, 0),
-                                KeyCode::Char('2') => self.handle_question_answer(q_index, 1),
-                                KeyCode::Char('3') => self.handle_question_answer(q_index, 2),
-                                _ => continue,
-                            }
-                            continue;
-                        }
-
-                        match key_event.code {
-                            KeyCode::Char(' ') => {
-                                if self.state.completed {
-                                    self.reset();
-                                } else {
-                                    self.state.toggle_play_pause();
-                                }
-                            },
-                            KeyCode::Char('r') | KeyCode::Char('R') => {
-                                self.reset();
-                            },
-                            KeyCode::Char('s') | KeyCode::Char('S') => {
-                                if !self.state.completed && !self.state.is_running {
-                                    if !self.step() {
-                                        self.state.mark_completed();
-                                        self.mark_all_sorted();
-                                    }
-                                }
-                            },
-                            KeyCode::Char('t') | KeyCode::Char('T') => {
-                                self.state.toggle_teaching_mode();
-                                let mut settings = Settings::load();
-                                settings.teaching_mode = self.state.teaching_mode;
-                                settings.save();
-                            },
-                            KeyCode::Char('+') => {
-                                self.state.increase_speed(50);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Char('-') => {
-                                self.state.decrease_speed(2000);
-                                let mut settings = Settings::load();
-                                settings.speed = self.state.speed.as_millis() as u64;
-                                settings.save();
-                            },
-                            KeyCode::Esc => {
-                                let mut settings = Settings::load();
-                                settings.last_visualizer = Some("TimSort".to_string());
-                                settings.save();
-                                cleanup_terminal();
-                                return;
-                            },
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
-            }
-
-            // Auto step
-            if self.state.is_running && !self.state.is_paused && !self.state.completed
-                && self.state.awaiting_question.is_none() {
-                std::thread::sleep(self.state.speed);
-                if !self.step() {
-                    self.state.mark_completed();
-                    self.mark_all_sorted();
-                }
-            }
+    // Copies the runs at stack index `at` and `at + 1` into temp_array so they can be merged back
+    fn prepare_merge(&mut self, at: usize) {
+        if at + 1 < self.stack.len() {
+            let left = self.stack[at];
+            let right = self.stack[at + 1];
+            self.merge_at = at;
+            self.merging_left = left.0;
+            self.merging_right = right.0;
+            self.merge_end = right.0 + right.1;
+            self.left_pos = self.merging_left;
+            self.right_pos = self.merging_right;
+            self.merge_pos = self.merging_left;
+            self.temp_array[self.merging_left..self.merge_end].copy_from_slice(&self.array[self.merging_left..self.merge_end]);
+            self.state.moves += (self.merge_end - self.merging_left) as u32;
         }
     }
 
-    fn handle_question_answer(&mut self, q_index: usize, answer: usize) {
-        if let Some(question) = self.state.questions.get(q_index) {
-            let correct = answer == question.correct_index;
-            show_question_feedback(correct, question, answer);
-            self.state.clear_question();
+    // Writes the next merged element, returns false once both runs are used up
+    fn perform_merge(&mut self) -> bool {
+        if self.merge_pos >= self.merge_end {
+            return false;
         }
-    }
-
-    fn draw(&mut self, stdout: &mut std::io::Stdout) {
-        let (width, height) = size().unwrap();
-        stdout.execute(Clear(ClearType::All)).unwrap();
 
-        // Title
-        VisualizerDrawer::draw_title(stdout, self.get_title());
-
-        // Array
-        VisualizerDrawer::draw_array_bars(stdout, &self.array, &self.states, width, height, 5);
-
-        // Legend
-        VisualizerDrawer::draw_legend(stdout, &self.get_legend_items(), width, height, 5);
-
-        // Statistics
-        let stats = self.get_statistics_strings();
-        VisualizerDrawer::draw_statistics(stdout, &stats, width, height);
-
-        // Controls
-        VisualizerDrawer::draw_controls(stdout, self.get_status(), self.get_controls_text(), width, height);
+        let take_left = if self.left_pos >= self.merging_right {
+            false
+        } else if self.right_pos >= self.merge_end {
+            true
+        } else {
+            self.states[self.left_pos] = SelectionState::Comparing;
+            self.states[self.right_pos] = SelectionState::Comparing;
+            // Taking from the left on ties keeps the sort stable
+            self.state.compare_values(self.temp_array[self.left_pos], self.temp_array[self.right_pos]).is_le()
+        };
 
-        // Current operation
-        if self.state.awaiting_question.is_none() {
-            let operation = self.get_current_operation();
-            let color = if self.state.completed { Color::Green } else { Color::White };
-            VisualizerDrawer::draw_operation_info(stdout, &operation, width, height, color);
+        if take_left {
+            self.array[self.merge_pos] = self.temp_array[self.left_pos];
+            self.left_pos += 1;
+        } else {
+            self.array[self.merge_pos] = self.temp_array[self.right_pos];
+            self.right_pos += 1;
         }
-
-        // Question
-        if let Some(q_index) = self.state.awaiting_question {
-            if let Some(question) = self.state.questions.get(q_index) {
-                VisualizerDrawer::draw_question(stdout, question, width, height);
-            }
+        self.states[self.merge_pos] = SelectionState::Swapping;
+        self.merge_pos += 1;
+        self.state.moves += 1;
+
+        if self.merge_pos >= self.merge_end {
+            // Merge complete, the two runs become one
+            self.stack[self.merge_at] = (self.merging_left, self.merge_end - self.merging_left);
+            self.stack.remove(self.merge_at + 1);
+            self.merges += 1;
+            return false;
         }
-
-        stdout.flush().unwrap();
+        true
     }
 
-    fn prepare_merge(&mut self) {
-        if self.stack.len() >= 2 {
-            let right = self.stack.pop().unwrap();
-            let left = self.stack.pop().unwrap();
-            self.merging_left = left.0;
-            self.merging_right = left.0 + left.1;
-            self.merge_pos = self.merging_left;
-            // Simplified: mark runs for merging
-            for i in self.merging_left..self.merging_right {
-                self.states[i] = SelectionState::Comparing;
-            }
-            let right_end = self.merging_right + right.1;
-            for i in self.merging_right..right_end {
-                self.states[i] = SelectionState::Comparing;
+    // Called after a run was pushed or merged: merges while the stack breaks its invariants,
+    // then finds the next run, or merges everything once the whole array is split into runs
+    fn collapse(&mut self) {
+        let all_found = self.run_start >= self.array.len();
+        let lengths: Vec<usize> = self.stack.iter().map(|run| run.1).collect();
+        match merge_target(&lengths, all_found) {
+            Some(at) => {
+                self.phase = TimPhase::Merging;
+                self.prepare_merge(at);
+                // Teaching: Ask why the invariants are kept the first time they force a merge
+                if !all_found && !self.invariant_asked && self.state.teaching_mode {
+                    self.invariant_asked = true;
+                    self.state.ask_question(INVARIANT_QUESTION);
+                }
             }
-            self.stack.push(right);
-            self.stack.push(left);
+            None if all_found => self.phase = TimPhase::Done,
+            None => self.phase = TimPhase::FindingRun,
         }
     }
 
-    fn perform_merge(&mut self) -> bool {
-        // Simplified merge step: one element at a time
-        let left_end = self.merging_right;
-        let right_start = self.merging_right;
-        let right_len = if let Some(&right_run) = self.stack.iter().rev().nth(1) {
-            right_run.1
-        } else {
-            0
-        };
-        let right_end = right_start + right_len;
-
-        if self.merge_pos < right_end {
-            self.states[self.merge_pos] = SelectionState::Swapping;
-            self.temp_array[self.merge_pos] = self.array[self.merge_pos];
-            self.merge_pos += 1;
-            self.state.swaps += 1;
-            self.state.comparisons += 1;
-            if self.merge_pos >= right_end {
-                // Merge complete
-                for i in self.merging_left..right_end {
-                    self.array[i] = self.temp_array[i];
-                    self.states[i] = SelectionState::Sorted;
-                }
-                let merged_len = right_end - self.merging_left;
-                self.stack.push((self.merging_left, merged_len));
-                false
-            } else {
-                true
-            }
-        } else {
-            false
+    // The invariants on the three runs at the top of the stack, C being the top
+    fn invariant_rows(&self) -> Vec<(String, Color)> {
+        let lengths: Vec<usize> = self.stack.iter().map(|run| run.1).collect();
+        let check = |holds: bool| if holds { ("✓", Color::Green) } else { ("✗", Color::Red) };
+        let mut rows = Vec::new();
+        if let [.., b, c] = lengths[..] {
+            let (mark, color) = check(b > c);
+            rows.push((format!("B > C: {} > {} {}", b, c, mark), color));
+        }
+        if let [.., a, b, c] = lengths[..] {
+            let (mark, color) = check(a > b + c);
+            rows.push((format!("A > B + C: {} > {} + {} {}", a, b, c, mark), color));
         }
+        rows
+    }
+}
+
+/// Minimum run length for an array of `n` elements, computed as in CPython's listsort:
+/// `n` itself below 64, otherwise a value in 32..=64 so n / min_run is a power of two or just below one
+pub fn compute_min_run(n: usize) -> usize {
+    let mut n = n;
+    let mut remainder = 0;
+    while n >= MIN_MERGE {
+        remainder |= n & 1;
+        n >>= 1;
+    }
+    n + remainder
+}
+
+// Stack index of the left run to merge next, given the run lengths from bottom to top.
+// Keeps A > B + C and B > C on the top three runs A, B, C, merging B with the shorter
+// of its neighbours, as CPython's merge_collapse does. `force` merges until one run is left.
+fn merge_target(lengths: &[usize], force: bool) -> Option<usize> {
+    let n = lengths.len();
+    if n < 2 {
+        return None;
+    }
+    let at = n - 2;
+    let shorter_neighbour = |at: usize| if at > 0 && lengths[at - 1] < lengths[at + 1] { at - 1 } else { at };
+    if force {
+        return Some(shorter_neighbour(at));
+    }
+    let breaks_abc = at > 0 && lengths[at - 1] <= lengths[at] + lengths[at + 1];
+    let breaks_below = at > 1 && lengths[at - 2] <= lengths[at - 1] + lengths[at];
+    if breaks_abc || breaks_below {
+        Some(shorter_neighbour(at))
+    } else if lengths[at] <= lengths[at + 1] {
+        Some(at)
+    } else {
+        None
     }
 }
 
-impl SortVisualizer for TimSortVisualizer {
-    fn get_array(&self) -> &[u32] { &self.array }
-    fn get_original_array(&self) -> &[u32] { &self.original_array }
+impl<T: SortElement> SortVisualizer<T> for TimSortVisualizer<T> {
+    fn get_array(&self) -> &[T] { &self.array }
+    fn get_array_mut(&mut self) -> &mut [T] { &mut self.array }
+    fn get_original_array(&self) -> &[T] { &self.original_array }
     fn get_states(&self) -> &[SelectionState] { &self.states }
     fn get_comparisons(&self) -> u32 { self.state.comparisons }
     fn get_swaps(&self) -> u32 { self.state.swaps }
@@ -320,6 +283,37 @@ impl SortVisualizer for TimSortVisualizer {
     fn is_teaching_mode(&self) -> bool { self.state.teaching_mode }
     fn get_awaiting_question(&self) -> Option<usize> { self.state.awaiting_question }
     fn get_questions(&self) -> &[TeachingQuestion] { &self.state.questions }
+    fn state(&self) -> &VisualizerState<T> { &self.state }
+    fn state_mut(&mut self) -> &mut VisualizerState<T> { &mut self.state }
+    fn settings_key(&self) -> &str { "TimSort" }
+    fn info(&self) -> AlgorithmInfo {
+        AlgorithmInfo {
+            name: "Tim Sort",
+            best: "O(n)",
+            average: "O(n log n)",
+            worst: "O(n log n)",
+            space: "O(n)",
+            stable: true,
+            in_place: false,
+        }
+    }
+
+    fn insertion_cutoff(&self) -> Option<usize> {
+        Some(self.min_run)
+    }
+
+    // Runs found from now on are extended to the new length, a run of one is never extended
+    fn set_insertion_cutoff(&mut self, cutoff: usize) {
+        self.min_run = cutoff.max(1);
+        self.min_run_auto = false;
+    }
+
+    fn draw_extra(&self, drawer: &mut VisualizerDrawer, width: u16, height: u16) {
+        let runs: Vec<String> = self.stack.iter().map(|run| run.1.to_string()).collect();
+        let mut rows = vec![(format!("Run Stack (lengths, bottom to top): [{}]", runs.join(", ")), Color::Cyan)];
+        rows.extend(self.invariant_rows());
+        drawer.draw_text_rows(&rows, width, height, 5);
+    }
 
     fn get_progress(&self) -> f64 {
         let total = (self.array.len() * (self.array.len() - 1)) / 2;
@@ -350,22 +344,21 @@ impl SortVisualizer for TimSortVisualizer {
                 if self.current_i + 1 < n {
                     self.states[self.current_i] = SelectionState::Comparing;
                     self.states[self.current_i + 1] = SelectionState::Comparing;
-                    self.state.comparisons += 1;
 
-                    if self.array[self.current_i] <= self.array[self.current_i + 1] {
-                        self.run_end = self.current_i + 1;
+                    if self.compare(self.current_i, self.current_i + 1).is_le() {
                         self.current_i += 1;
+                        self.run_end = self.current_i + 1;
                     } else {
-                        self.run_end = self.current_i;
+                        self.run_end = self.current_i + 1;
                         self.phase = TimPhase::InsertionSort;
                     }
                 } else {
                     // End of array, push last run
-                    if self.run_end > self.run_start {
-                        self.stack.push((self.run_start, self.run_end - self.run_start));
-                    }
-                    self.phase = TimPhase::Merging;
-                    self.prepare_merge();
+                    self.run_end = n;
+                    self.stack.push((self.run_start, self.run_end - self.run_start));
+                    self.run_start = n;
+                    self.current_i = n;
+                    self.collapse();
                 }
                 return true;
             },
@@ -377,14 +370,13 @@ impl SortVisualizer for TimSortVisualizer {
                         self.states[key_idx] = SelectionState::Swapping;
                         let key = self.array[key_idx];
                         let mut j = key_idx as isize - 1;
-                        while j >= self.run_start as isize && self.array[j as usize] > key {
+                        while j >= self.run_start as isize && self.state.compare_values(self.array[j as usize], key).is_gt() {
                             let from = (j + 1) as usize;
                             let to = j as usize;
                             self.array.swap(from, to);
                             self.states[to] = SelectionState::Swapping;
                             self.states[from] = SelectionState::Normal;
                             self.state.swaps += 1;
-                            self.state.comparisons += 1;
                             j -= 1;
                         }
                         self.array[(j + 1) as usize] = key;
@@ -396,29 +388,17 @@ impl SortVisualizer for TimSortVisualizer {
                     self.run_start = self.run_end;
                     self.phase = TimPhase::FindingRun;
                     self.current_i = self.run_end;
-                    if self.run_start >= n {
-                        self.phase = TimPhase::Merging;
-                        self.prepare_merge();
-                    }
+                    self.run_end = self.run_start + 1;
+                    self.collapse();
                 }
                 return true;
             },
             TimPhase::Merging => {
-                if self.stack.len() > 1 {
-                    if !self.perform_merge() {
-                        // After merge, continue merging if needed
-                        self.stack.pop(); // Remove the merged run, but since we pushed it, adjust
-                        self.prepare_merge();
-                        if self.stack.len() <= 1 {
-                            self.phase = TimPhase::Done;
-                            return false;
-                        }
-                    }
-                } else {
-                    self.phase = TimPhase::Done;
-                    return false;
+                if !self.perform_merge() {
+                    // After merge, continue merging if the stack still needs it
+                    self.collapse();
                 }
-                return true;
+                return self.phase != TimPhase::Done;
             },
             TimPhase::Done => return false,
         }
@@ -430,12 +410,18 @@ impl SortVisualizer for TimSortVisualizer {
         self.states = vec![SelectionState::Normal; len];
         self.current_i = 0;
         self.run_start = 0;
-        self.run_end = 0;
+        self.run_end = 1;
         self.stack.clear();
+        self.merge_at = 0;
+        self.merges = 0;
+        self.invariant_asked = false;
         self.merging_left = 0;
         self.merging_right = 0;
+        self.merge_end = 0;
+        self.left_pos = 0;
+        self.right_pos = 0;
         self.merge_pos = 0;
-        self.temp_array = vec![0; len];
+        self.temp_array = vec![T::default(); len];
         self.phase = TimPhase::FindingRun;
         self.state.reset_state();
         if len <= 1 {
@@ -464,12 +450,12 @@ impl SortVisualizer for TimSortVisualizer {
          Press any key to continue..."
     }
 
-    fn get_legend_items(&self) -> Vec<(&str, Color)> {
+    fn get_legend_items(&self) -> Vec<(&str, SelectionState)> {
         vec![
-            ("Normal", Color::Cyan),
-            ("Comparing", Color::Yellow),
-            ("Swapping", Color::Red),
-            ("Sorted", Color::Green),
+            ("Normal", SelectionState::Normal),
+            ("Comparing", SelectionState::Comparing),
+            ("Swapping", SelectionState::Swapping),
+            ("Sorted", SelectionState::Sorted),
         ]
     }
 
@@ -485,14 +471,30 @@ impl SortVisualizer for TimSortVisualizer {
             format!("Array Size: {}", self.array.len()),
             format!("Comparisons: {}", self.state.comparisons),
             format!("Swaps: {}", self.state.swaps),
+            format!("Moves: {}", self.state.moves),
             format!("Current i: {}", self.current_i),
             format!("Runs on Stack: {}", self.stack.len()),
+            format!("Min Run: {}{}", self.min_run, if self.min_run_auto { " (Auto)" } else { "" }),
+            format!("Merges: {}", self.merges),
             format!("Phase: {}", phase_str),
             format!("Progress: {:.1}%", self.get_progress()),
             if self.state.teaching_mode { "Teaching: ON".to_string() } else { "Teaching: OFF".to_string() },
         ]
     }
 
+    fn get_step_hint(&self) -> Option<&str> {
+        if self.state.completed {
+            return None;
+        }
+        let hint = match self.phase {
+            TimPhase::FindingRun => "Already sorted stretches are detected as runs, so real-world data that is partly sorted needs less work",
+            TimPhase::InsertionSort => "Short runs are extended to the minimum run length with insertion sort, which is fast on small ranges",
+            TimPhase::Merging => "Runs are merged when the stack invariants break, which keeps merges between runs of similar size",
+            _ => return None,
+        };
+        Some(hint)
+    }
+
     fn get_current_operation(&self) -> String {
         if self.state.completed {
             "✓ Array is now sorted using Tim Sort! Congratulations!".to_string()
@@ -500,7 +502,7 @@ impl SortVisualizer for TimSortVisualizer {
             match self.phase {
                 TimPhase::FindingRun => {
                     if self.current_i + 1 < self.array.len() {
-                        format!("Checking if array[{}] ({}) <= array[{}] ({})", self.current_i, self.array[self.current_i], self.current_i + 1, self.array[self.current_i + 1])
+                        format!("Checking if array[{}] ({}) <= array[{}] ({})", self.current_i, self.state.value_text(self.array[self.current_i]), self.current_i + 1, self.state.value_text(self.array[self.current_i + 1]))
                     } else {
                         "End of array, pushing last run".to_string()
                     }
@@ -509,7 +511,7 @@ impl SortVisualizer for TimSortVisualizer {
                     format!("Insertion sorting to extend run starting at {}", self.run_start)
                 },
                 TimPhase::Merging => {
-                    format!("Merging runs: left [{}..{}] with right [{}..{}]", self.merging_left, self.merging_right, self.merging_right, self.merging_right + if let Some(&r) = self.stack.iter().rev().nth(1) { r.1 } else { 0 })
+                    format!("Merging runs: left [{}..{}] with right [{}..{}]", self.merging_left, self.merging_right, self.merging_right, self.merge_end)
                 },
                 TimPhase::Done => {
                     "Tim sort completed!".to_string()
@@ -544,7 +546,49 @@ impl SortVisualizer for TimSortVisualizer {
 }
 
 /// Entry point for the tim sort visualization
-pub fn tim_sort_visualization(array_data: &ArrayData) {
-    let mut visualizer = TimSortVisualizer::new(array_data);
-    visualizer.run_visualization();
-}
\ No newline at end of file
+pub fn tim_sort_visualization<T: SortElement>(array_data: &ArrayData<T>, last_run: &mut Option<RunSummary>) -> Option<Box<dyn SortVisualizer<T>>> {
+    let settings = Settings::load();
+    run_suspendable(TimSortVisualizer::with_settings(array_data, &settings), settings, last_run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_run_follows_cpython() {
+        assert_eq!(compute_min_run(10), 10);
+        assert_eq!(compute_min_run(63), 63);
+        assert_eq!(compute_min_run(64), 32);
+        assert_eq!(compute_min_run(65), 33);
+        assert_eq!(compute_min_run(100), 50);
+        assert_eq!(compute_min_run(2048), 32);
+    }
+
+    #[test]
+    fn merges_restore_the_stack_invariants() {
+        assert_eq!(merge_target(&[10], false), None);
+        assert_eq!(merge_target(&[30, 20, 5], false), None);
+        // B <= C merges the top two
+        assert_eq!(merge_target(&[30, 5, 10], false), Some(1));
+        // A <= B + C merges B with the shorter of A and C
+        assert_eq!(merge_target(&[20, 15, 10], false), Some(1));
+        assert_eq!(merge_target(&[8, 15, 10], false), Some(0));
+        // Forced merging collapses whatever is left
+        assert_eq!(merge_target(&[30, 20, 5], true), Some(1));
+    }
+
+    #[test]
+    fn configured_min_run_sorts_with_more_merges() {
+        let data: Vec<u32> = (0..40).map(|i| (i * 37 + 11) % 41).collect();
+        let array_data = ArrayData::new(data.clone(), "Test".to_string());
+        let settings = Settings { tim_min_run: 4, ..Settings::default() };
+        let mut visualizer = TimSortVisualizer::with_settings(&array_data, &settings);
+        visualizer.state.teaching_mode = false;
+        while visualizer.step() {}
+        let mut expected = data;
+        expected.sort();
+        assert_eq!(visualizer.get_array(), &expected[..]);
+        assert!(visualizer.merges >= 9, "{} merges", visualizer.merges);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::common::array_manager::ArrayData;
+use crate::common::dialog;
+use crate::common::settings::Settings;
+use crate::factory::{self, CreateFn};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::rc::Rc;
+
+// Every element is stored as key * TAG_SCALE + its input position, the position is its tag
+const TAG_SCALE: u32 = 100;
+// Elements of every tagged array, fewer than TAG_SCALE so the tag never reaches the key
+const ELEMENTS: u32 = 24;
+// Distinct keys, few enough that every array holds many duplicates
+const KEYS: u32 = 4;
+// Arrays every algorithm sorts, one unlucky array can hide an unstable algorithm
+const TRIALS: u64 = 10;
+// Steps after which a run is given up, no sort needs this many on ELEMENTS values
+const STEP_LIMIT: usize = 1_000_000;
+// Comparison sorts in the report, by the short names `factory::find_by_name` accepts.
+// Bucket, counting and radix sort order the whole values and are left out.
+const REPORTED: &[&str] = &[
+    "Bubble", "Cocktail", "Comb", "Cycle", "Gnome", "Heap", "In-Place Merge", "Insertion",
+    "Merge", "Odd-Even", "Pancake", "Patience", "Quick", "Selection", "Shell", "Tim",
+];
+
+/// What sorting the tagged arrays showed about an algorithm
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Verdict {
+    Stable,     // Equal keys kept their input order in every trial
+    Unstable,   // Equal keys changed their order in at least one trial
+    Unsorted,   // A trial ended with keys out of order, so the tags prove nothing
+    Unfinished, // A trial did not end within STEP_LIMIT steps
+}
+
+impl Verdict {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Stable => "stable",
+            Verdict::Unstable => "unstable",
+            Verdict::Unsorted => "not sorted",
+            Verdict::Unfinished => "no finish",
+        }
+    }
+}
+
+/// Random keys from 1 to KEYS, each tagged with its input position in the low digits
+pub fn tagged_values(seed: u64) -> Vec<u32> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..ELEMENTS).map(|position| rng.random_range(1..=KEYS) * TAG_SCALE + position).collect()
+}
+
+/// Returns true if the keys are in ascending order, whatever the tags
+pub fn keys_in_order(values: &[u32]) -> bool {
+    values.windows(2).all(|pair| pair[0] / TAG_SCALE <= pair[1] / TAG_SCALE)
+}
+
+/// Returns true if every run of equal keys still has its tags in input order
+pub fn keeps_tag_order(values: &[u32]) -> bool {
+    values.windows(2).all(|pair| pair[0] / TAG_SCALE != pair[1] / TAG_SCALE || pair[0] < pair[1])
+}
+
+/// Sorts TRIALS tagged arrays with the visualizer `create` builds from `settings` and judges its stability.
+/// Values are compared by key alone, so only the tags tell equal keys apart.
+pub fn is_stable_empirically(create: CreateFn, settings: &Settings) -> Verdict {
+    for trial in 0..TRIALS {
+        let array_data = ArrayData::new(tagged_values(trial), "Stability".to_string());
+        let mut visualizer = create(&array_data, settings);
+        visualizer.state_mut().set_comparator(Rc::new(|a, b| (a / TAG_SCALE).cmp(&(b / TAG_SCALE))));
+        visualizer.state_mut().teaching_mode = false;
+        visualizer.state_mut().is_running = true;
+
+        let mut steps = 0;
+        while visualizer.step() {
+            steps += 1;
+            if steps >= STEP_LIMIT {
+                return Verdict::Unfinished;
+            }
+        }
+
+        let values = visualizer.get_array();
+        if !keys_in_order(values) {
+            return Verdict::Unsorted;
+        }
+        if !keeps_tag_order(values) {
+            return Verdict::Unstable;
+        }
+    }
+    Verdict::Stable
+}
+
+/// Verdict of every comparison sort on the tagged arrays
+pub fn stability_report(settings: &Settings) -> Vec<(&'static str, Verdict)> {
+    REPORTED
+        .iter()
+        .map(|name| {
+            let entry = factory::find_by_name(name).expect("every reported sort is in the factory");
+            (*name, is_stable_empirically(entry.create, settings))
+        })
+        .collect()
+}
+
+/// The report in two columns under a short explanation
+pub fn report_text(results: &[(&str, Verdict)]) -> String {
+    let cells: Vec<String> = results
+        .iter()
+        .map(|(name, verdict)| format!("{:<15}{:<11}", name, verdict.label()))
+        .collect();
+    let rows: Vec<String> = cells.chunks(2).map(|pair| pair.join("   ").trim_end().to_string()).collect();
+    format!(
+        "{} arrays of {} elements with only {} distinct keys.\n\
+         Each element is tagged with its input position and\n\
+         compared by key alone, a sort is stable if equal\n\
+         keys keep their tags in order.\n\n{}\n\n\
+         \"not sorted\": keys were left out of order, and\n\
+         \"no finish\": the sort ran past the step limit.\n\
+         Bucket, counting and radix sort order the whole values.",
+        TRIALS,
+        ELEMENTS,
+        KEYS,
+        rows.join("\n")
+    )
+}
+
+/// Runs the report and shows it in a dialog
+pub fn show_stability_report() {
+    dialog::show_info("Stability Report", &report_text(&stability_report(&Settings::load())));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_keys_in_tag_order_are_stable() {
+        assert!(keeps_tag_order(&[103, 105, 201, 302]));
+        assert!(!keeps_tag_order(&[105, 103, 201]));
+        assert!(keys_in_order(&[105, 103, 201]));
+        assert!(!keys_in_order(&[201, 103]));
+        assert!(tagged_values(3).iter().all(|value| (1..=KEYS).contains(&(value / TAG_SCALE))));
+    }
+
+    #[test]
+    fn report_tells_stable_sorts_from_unstable_ones() {
+        let verdict = |name| is_stable_empirically(factory::find_by_name(name).unwrap().create, &Settings::default());
+        assert_eq!(verdict("Merge"), Verdict::Stable);
+        assert_eq!(verdict("Insertion"), Verdict::Stable);
+        assert_eq!(verdict("Selection"), Verdict::Unstable);
+        assert_eq!(verdict("Shell"), Verdict::Unstable);
+        assert!(report_text(&stability_report(&Settings::load())).lines().all(|line| line.len() <= 56));
+    }
+}